@@ -0,0 +1,81 @@
+//! Frame construction benchmark for the protocol transmission path
+//!
+//! Compares building command frames with a fresh allocation per command
+//! (`format_command`) against refilling a reusable buffer
+//! (`format_command_into`) for a simulated pulse loop. A counting allocator
+//! reports the number of heap allocations each approach performs per loop.
+//!
+//! Run with `cargo bench --bench frame_builder`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lumidox_ii_controller::communication::protocol::commands;
+use lumidox_ii_controller::communication::protocol::handler::{CommandTransmission, FRAME_CAPACITY};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Global allocator wrapper that counts allocations
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Number of pulses in one simulated pulse loop
+const PULSES: u16 = 100;
+
+/// Build the frames for a pulse loop, allocating a new frame per command
+fn pulse_loop_allocating() {
+    for pulse in 0..PULSES {
+        let current = CommandTransmission::format_command(commands::SET_CURRENT, 1000 + pulse).unwrap();
+        let mode = CommandTransmission::format_command(commands::SET_MODE, 3).unwrap();
+        black_box((&current, &mode));
+    }
+}
+
+/// Build the frames for a pulse loop, refilling a single reusable buffer
+fn pulse_loop_reusing(frame: &mut Vec<u8>) {
+    for pulse in 0..PULSES {
+        CommandTransmission::format_command_into(frame, commands::SET_CURRENT, 1000 + pulse);
+        black_box(&frame);
+        CommandTransmission::format_command_into(frame, commands::SET_MODE, 3);
+        black_box(&frame);
+    }
+}
+
+/// Count the heap allocations performed by a closure
+fn count_allocations<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn bench_frame_builder(c: &mut Criterion) {
+    let mut frame = Vec::with_capacity(FRAME_CAPACITY);
+
+    let allocating = count_allocations(pulse_loop_allocating);
+    let reusing = count_allocations(|| pulse_loop_reusing(&mut frame));
+    println!(
+        "Allocations per {}-pulse loop: allocating = {}, reusable buffer = {}",
+        PULSES, allocating, reusing
+    );
+
+    let mut group = c.benchmark_group("pulse_loop_frames");
+    group.bench_function("allocating", |b| b.iter(pulse_loop_allocating));
+    group.bench_function("reusable_buffer", |b| b.iter(|| pulse_loop_reusing(&mut frame)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_frame_builder);
+criterion_main!(benches);
@@ -5,7 +5,7 @@
 
 use lumidox_ii_controller::core::Result;
 use lumidox_ii_controller::communication::{AutoConnector, AutoConnectConfig, PortDetector, PortDetectionConfig,
-                   BaudDetector, BaudDetectionConfig};
+                   Baud, BaudDetector, BaudDetectionConfig};
 
 fn main() -> Result<()> {
     println!("=== Lumidox II Controller Auto-Detection Demo ===\n");
@@ -76,7 +76,7 @@ fn demo_baud_detection() -> Result<()> {
         println!("   Testing baud rates on {}...", port_name);
         
         let baud_config = BaudDetectionConfig {
-            test_baud_rates: vec![19200, 9600, 38400], // Quick test
+            test_baud_rates: vec![Baud::try_from(19200)?, Baud::try_from(9600)?, Baud::try_from(38400)?], // Quick test
             attempts_per_rate: 1,
             comprehensive_testing: false,
             ..Default::default()
@@ -0,0 +1,135 @@
+//! Event-driven monitoring of a Lumidox II Controller
+//!
+//! This example subscribes to device events and prints a running log of
+//! mode changes, output on/off transitions and safety trips while a
+//! separate thread fires a short stage sequence. Dashboards and other
+//! embedders can use the same subscription instead of polling the device.
+//!
+//! Without arguments the example runs against a scripted `MockTransport`
+//! and checks that every operation emitted the expected events, so it also
+//! serves as an integration test of event emission:
+//!
+//! ```text
+//! cargo run --example monitoring
+//! ```
+//!
+//! Pass a serial port to run the same sequence on real hardware. This fires
+//! stages 1 and 2 for one second each.
+//!
+//! ```text
+//! cargo run --example monitoring -- COM3
+//! ```
+
+use std::sync::mpsc::Receiver;
+use std::thread;
+use std::time::Duration;
+use lumidox_ii_controller::core::Result;
+use lumidox_ii_controller::communication::{MockTransport, ProtocolHandler};
+use lumidox_ii_controller::communication::protocol::commands;
+use lumidox_ii_controller::device::LumidoxDevice;
+use lumidox_ii_controller::device::controller::DeviceEvent;
+use lumidox_ii_controller::device::models::DeviceMode;
+use lumidox_ii_controller::device::OperationConfig;
+
+fn main() -> Result<()> {
+    println!("=== Lumidox II Controller Event Monitoring ===\n");
+
+    let port = std::env::args().nth(1);
+    let mut device = match port.as_deref() {
+        Some(port) => LumidoxDevice::connect(&OperationConfig::builder().port(port).build())?,
+        None => {
+            let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(simulated_device())?);
+            device.initialize()?;
+            device
+        }
+    };
+
+    // Subscribe before handing the device to the firing thread
+    let events = device.subscribe();
+    let sequence = thread::spawn(move || fire_sequence(device));
+
+    let log = print_events(events);
+    sequence.join().expect("firing thread panicked")?;
+
+    if port.is_none() {
+        assert_eq!(log, expected_events(), "simulated sequence emitted unexpected events");
+        println!("\nAll expected events were emitted.");
+    }
+    Ok(())
+}
+
+/// Fire a short stage sequence, then let the session limit trip
+///
+/// The device is dropped when the sequence ends, which closes the event
+/// channel and ends the log in `main`.
+fn fire_sequence(mut device: LumidoxDevice) -> Result<()> {
+    device.arm()?;
+    device.fire_stage(1)?;
+    thread::sleep(Duration::from_secs(1));
+    device.fire_stage(2)?;
+    thread::sleep(Duration::from_secs(1));
+    device.turn_off()?;
+
+    // An expired session turns the output off and refuses the next operation
+    device.set_max_session_duration(Some(Duration::from_millis(1)));
+    thread::sleep(Duration::from_millis(10));
+    match device.arm() {
+        Err(e) => println!("  (arm refused: {})", e),
+        Ok(()) => device.turn_off()?,
+    }
+    Ok(())
+}
+
+/// Print every event until the device is dropped
+fn print_events(events: Receiver<DeviceEvent>) -> Vec<DeviceEvent> {
+    let mut log = Vec::new();
+    for event in events {
+        match &event {
+            DeviceEvent::ModeChanged { from, to } => println!("Mode changed: {:?} -> {:?}", from, to),
+            DeviceEvent::OutputOn => println!("Output ON"),
+            DeviceEvent::OutputOff => println!("Output OFF"),
+            DeviceEvent::SafetyTripped { reason } => println!("Safety tripped: {}", reason),
+            DeviceEvent::ConnectionChanged { from, to } => println!("Connection: {:?} -> {:?}", from, to),
+        }
+        log.push(event);
+    }
+    log
+}
+
+/// Script the command frames the sequence sends to a simulated device
+fn simulated_device() -> MockTransport {
+    MockTransport::new()
+        // Initialization: standby, then identity
+        .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0)
+        .expect_read(commands::FIRMWARE_VERSION, 7)
+        .expect_read_text(&commands::MODEL_COMMANDS, "LX2-365")
+        .expect_read_text(&commands::SERIAL_COMMANDS, "SN1234")
+        .expect_read_text(&commands::WAVELENGTH_COMMANDS, "365")
+        .expect_read(commands::STAGE_CURRENTS[4], 3000)
+        // Arm, fire stages 1 and 2, turn off
+        .expect_command(commands::SET_MODE, DeviceMode::Armed as u16, 0)
+        .expect_read(commands::STAGE_CURRENTS[0], 500)
+        .expect_command(commands::SET_CURRENT, 500, 0)
+        .expect_command(commands::SET_MODE, DeviceMode::Remote as u16, 0)
+        .expect_read(commands::STAGE_CURRENTS[1], 1000)
+        .expect_command(commands::SET_CURRENT, 1000, 0)
+        .expect_command(commands::SET_MODE, DeviceMode::Remote as u16, 0)
+        .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0)
+        .expect_read(commands::READ_REMOTE_MODE, DeviceMode::Standby as u16)
+        // Session limit shutdown: standby, then local
+        .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0)
+        .expect_command(commands::SET_MODE, DeviceMode::Local as u16, 0)
+}
+
+/// Events the simulated sequence must emit, in order
+fn expected_events() -> Vec<DeviceEvent> {
+    vec![
+        DeviceEvent::ModeChanged { from: Some(DeviceMode::Standby), to: DeviceMode::Armed },
+        DeviceEvent::ModeChanged { from: Some(DeviceMode::Armed), to: DeviceMode::Remote },
+        DeviceEvent::OutputOn,
+        DeviceEvent::ModeChanged { from: Some(DeviceMode::Remote), to: DeviceMode::Standby },
+        DeviceEvent::OutputOff,
+        DeviceEvent::SafetyTripped { reason: "Maximum session duration of 0s exceeded".to_string() },
+        DeviceEvent::ModeChanged { from: Some(DeviceMode::Standby), to: DeviceMode::Local },
+    ]
+}
@@ -12,8 +12,13 @@
 
 use crate::core::{LumidoxError, Result};
 use crate::communication::{ProtocolHandler, port_detection::*, baud_detection::*};
-use crate::device::LumidoxDevice;
-use std::time::Duration;
+use crate::communication::connection_failure::{ConnectionFailure, PortVerdict};
+use crate::communication::model_check::ExpectedModel;
+use crate::device::{IdentityReadRetry, LumidoxDevice};
+use std::sync::{mpsc, Arc};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// Auto-connection configuration and settings
 #[derive(Debug, Clone)]
@@ -28,6 +33,19 @@ pub struct AutoConnectConfig {
     pub enable_caching: bool,
     /// Maximum time to spend on auto-detection
     pub max_detection_time: Duration,
+    /// Retry policy for the identity read when initializing a candidate device
+    pub identity_retry: IdentityReadRetry,
+    /// Whether to probe candidate ports concurrently instead of one at a time
+    pub parallel_probe: bool,
+    /// Maximum number of ports probed at once when `parallel_probe` is set
+    pub max_parallel_probes: usize,
+    /// Allowed USB (VID, PID) pairs; when non-empty, other ports are never opened
+    pub usb_filter: Vec<(u16, u16)>,
+    /// Model the connected device is expected to be, None to accept any model
+    pub expected_model: Option<ExpectedModel>,
+    /// Ports probed first, in order, before the general port enumeration;
+    /// a preferred port that is not present is skipped
+    pub preferred_ports: Vec<String>,
 }
 
 impl Default for AutoConnectConfig {
@@ -38,6 +56,12 @@ impl Default for AutoConnectConfig {
             verbose: false,
             enable_caching: true,
             max_detection_time: Duration::from_secs(30),
+            identity_retry: IdentityReadRetry::default(),
+            parallel_probe: false,
+            max_parallel_probes: 4,
+            usb_filter: Vec::new(),
+            expected_model: None,
+            preferred_ports: Vec::new(),
         }
     }
 }
@@ -59,6 +83,180 @@ pub struct AutoConnectResult {
     pub connection_log: Vec<String>,
     /// Device information if connection was successful
     pub device_info: Option<crate::device::models::DeviceInfo>,
+    /// Time spent in each phase of the connection process
+    pub timings: ConnectTimings,
+    /// Number of port/baud rate combinations a connection was attempted on
+    pub attempts: u32,
+    /// Number of candidate ports that were probed
+    pub ports_probed: u32,
+    /// Warnings about the connected device, such as an unexpected model
+    pub warnings: Vec<String>,
+    /// Preferred port the device was found on, None if found by enumeration
+    pub preferred_port: Option<String>,
+}
+
+impl AutoConnectResult {
+    /// Build a one-line human-readable summary of the connection
+    ///
+    /// Only populated fields are included, for example
+    /// "Connected to COM4 @ 19200 (model Lumidox II, 3 attempts, 420ms)".
+    ///
+    /// # Returns
+    /// * `String` - Connection summary suitable for CLI and GUI display
+    ///
+    /// # Example
+    /// ```
+    /// let (device, result) = AutoConnector::auto_connect(&config)?;
+    /// println!("{}", result.describe());
+    /// ```
+    pub fn describe(&self) -> String {
+        let mut summary = if self.success {
+            format!("Connected to {}", self.port_name.as_deref().unwrap_or("unknown port"))
+        } else {
+            "Connection failed".to_string()
+        };
+        if let Some(baud_rate) = self.baud_rate {
+            summary.push_str(&format!(" @ {}", baud_rate));
+        }
+
+        let mut details = Vec::new();
+        if self.preferred_port.is_some() {
+            details.push("preferred port".to_string());
+        }
+        if let Some(info) = &self.device_info {
+            details.push(format!("model {}", info.model_number));
+        }
+        if self.attempts > 0 {
+            details.push(format!("{} attempt{}", self.attempts, if self.attempts == 1 { "" } else { "s" }));
+        }
+        details.push(format!("{}ms", self.connection_time.as_millis()));
+
+        format!("{} ({})", summary, details.join(", "))
+    }
+}
+
+/// Time spent in each phase of an auto-connection attempt
+///
+/// Phases that were skipped (for example baud probing when the default baud
+/// rate worked) remain at zero. Times accumulate across all port candidates.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectTimings {
+    /// Serial port enumeration and compatibility scoring
+    pub enumeration: Duration,
+    /// Device identity verification during port detection
+    pub identification: Duration,
+    /// Individual baud rate probes in the order they were run
+    pub baud_probes: Vec<BaudProbeTiming>,
+    /// Opening the port and configuring the protocol handler
+    pub port_open: Duration,
+    /// Device initialization (standby mode and device information readback)
+    pub initialization: Duration,
+}
+
+/// Time spent probing a single baud rate on a port
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaudProbeTiming {
+    /// Port that was probed
+    pub port_name: String,
+    /// Baud rate that was probed
+    pub baud_rate: u32,
+    /// Whether the device responded at this baud rate
+    pub success: bool,
+    /// Time spent on the probe
+    pub duration: Duration,
+}
+
+impl ConnectTimings {
+    /// Record the port detection phase
+    ///
+    /// Splits the total detection time into enumeration and identity
+    /// verification using the per-candidate identification times.
+    ///
+    /// # Arguments
+    /// * `detection_time` - Total time spent in `PortDetector::detect_ports`
+    /// * `candidates` - Candidates returned by port detection
+    pub fn record_port_detection(&mut self, detection_time: Duration, candidates: &[PortCandidate]) {
+        let identification: Duration = candidates.iter().map(|candidate| candidate.identification_time).sum();
+        self.identification += identification;
+        self.enumeration += detection_time.saturating_sub(identification);
+    }
+
+    /// Record the baud rate probes run on a port
+    ///
+    /// # Arguments
+    /// * `port_name` - Port that was probed
+    /// * `results` - Results from `BaudDetector::test_all_baud_rates`
+    pub fn record_baud_probes(&mut self, port_name: &str, results: &[BaudTestResult]) {
+        self.baud_probes.extend(results.iter().map(|result| BaudProbeTiming {
+            port_name: port_name.to_string(),
+            baud_rate: result.baud_rate,
+            success: result.success,
+            duration: result.test_duration,
+        }));
+    }
+
+    /// Add the times recorded for another port
+    ///
+    /// # Arguments
+    /// * `other` - Timings to add to these
+    pub fn merge(&mut self, other: ConnectTimings) {
+        self.enumeration += other.enumeration;
+        self.identification += other.identification;
+        self.baud_probes.extend(other.baud_probes);
+        self.port_open += other.port_open;
+        self.initialization += other.initialization;
+    }
+
+    /// Get the total time spent on baud rate probing
+    ///
+    /// # Returns
+    /// * `Duration` - Sum of all baud probe durations
+    pub fn baud_probing(&self) -> Duration {
+        self.baud_probes.iter().map(|probe| probe.duration).sum()
+    }
+
+    /// Get the total time across all recorded phases
+    ///
+    /// # Returns
+    /// * `Duration` - Sum of all phase durations
+    pub fn total(&self) -> Duration {
+        self.enumeration + self.identification + self.baud_probing() + self.port_open + self.initialization
+    }
+
+    /// Format the timing breakdown for display
+    ///
+    /// # Returns
+    /// * `Vec<String>` - One line per phase, with indented per-baud probe lines
+    ///
+    /// # Example
+    /// ```
+    /// for line in result.timings.summary_lines() {
+    ///     println!("{}", line);
+    /// }
+    /// ```
+    pub fn summary_lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            "Connection timing breakdown:".to_string(),
+            format!("  Port enumeration:      {:>8.1}ms", Self::millis(self.enumeration)),
+            format!("  Identity verification: {:>8.1}ms", Self::millis(self.identification)),
+            format!("  Baud rate probing:     {:>8.1}ms", Self::millis(self.baud_probing())),
+        ];
+        for probe in &self.baud_probes {
+            lines.push(format!("    {} @ {} baud: {:.1}ms ({})",
+                probe.port_name,
+                probe.baud_rate,
+                Self::millis(probe.duration),
+                if probe.success { "ok" } else { "no response" }));
+        }
+        lines.push(format!("  Port open:             {:>8.1}ms", Self::millis(self.port_open)));
+        lines.push(format!("  Initialization:        {:>8.1}ms", Self::millis(self.initialization)));
+        lines
+    }
+
+    /// Convert a duration to fractional milliseconds
+    fn millis(duration: Duration) -> f64 {
+        duration.as_secs_f64() * 1000.0
+    }
 }
 
 /// Method used to establish connection
@@ -87,6 +285,83 @@ pub struct ConnectionCache {
     pub device_serial: Option<String>,
 }
 
+/// Outcome of probing a single port candidate
+struct PortProbe {
+    /// Port that was probed
+    port_name: String,
+    /// Connected device and the baud rate it answered at
+    connected: Option<(LumidoxDevice, u32)>,
+    /// Why the port was rejected, when no device connected
+    verdict: PortVerdict,
+    /// Number of connection attempts made on the port
+    attempts: u32,
+    /// Baud rates probed on the port
+    bauds_probed: Vec<u32>,
+    /// Time spent probing the port
+    timings: ConnectTimings,
+    /// Log lines for the port
+    log: Vec<String>,
+}
+
+impl PortProbe {
+    /// Start a probe of a port with an initial verdict
+    fn new(port_name: &str, verdict: PortVerdict) -> Self {
+        Self {
+            port_name: port_name.to_string(),
+            connected: None,
+            verdict,
+            attempts: 0,
+            bauds_probed: Vec::new(),
+            timings: ConnectTimings::default(),
+            log: Vec::new(),
+        }
+    }
+
+    /// Finish the probe with a connected device
+    fn connected(mut self, device: LumidoxDevice, baud_rate: u32) -> Self {
+        self.log.push(format!("Connected successfully: {} at {} baud", self.port_name, baud_rate));
+        self.connected = Some((device, baud_rate));
+        self
+    }
+}
+
+/// Progress of a port scan, accumulated across probed candidates
+struct PortScan {
+    /// Detailed log of connection attempts
+    connection_log: Vec<String>,
+    /// Time spent in each phase
+    timings: ConnectTimings,
+    /// Verdicts and baud rates for the failure report
+    failure: ConnectionFailure,
+    /// Number of port/baud rate combinations a connection was attempted on
+    attempts: u32,
+    /// Number of ports a probe result was recorded for
+    ports_probed: u32,
+}
+
+impl PortScan {
+    /// Record a finished probe
+    ///
+    /// # Returns
+    /// * `Option<(LumidoxDevice, String, u32)>` - Connected device, port name and baud rate
+    fn record(&mut self, probe: PortProbe) -> Option<(LumidoxDevice, String, u32)> {
+        self.ports_probed += 1;
+        self.attempts += probe.attempts;
+        self.connection_log.extend(probe.log);
+        self.timings.merge(probe.timings);
+        for baud_rate in probe.bauds_probed {
+            self.failure.record_baud(baud_rate);
+        }
+        match probe.connected {
+            Some((device, baud_rate)) => Some((device, probe.port_name, baud_rate)),
+            None => {
+                self.failure.record_port(&probe.port_name, probe.verdict);
+                None
+            }
+        }
+    }
+}
+
 /// Auto-connection utilities and functionality
 pub struct AutoConnector;
 
@@ -110,8 +385,10 @@ impl AutoConnector {
     ///     result.port_name.unwrap(), result.baud_rate.unwrap());
     /// ```
     pub fn auto_connect(config: &AutoConnectConfig) -> Result<(LumidoxDevice, AutoConnectResult)> {
-        let start_time = std::time::Instant::now();
+        let start_time = Instant::now();
         let mut connection_log = Vec::new();
+        let timings = ConnectTimings::default();
+        let mut attempts = 0;
         
         if config.verbose {
             println!("Starting automated Lumidox II Controller detection...");
@@ -122,10 +399,12 @@ impl AutoConnector {
         // Step 1: Try cached connection if enabled
         if config.enable_caching {
             if let Ok(Some((device, cache))) = Self::try_cached_connection(config) {
+                attempts += 1;
                 let connection_time = start_time.elapsed();
                 connection_log.push(format!("Used cached connection: {} at {} baud", cache.port_name, cache.baud_rate));
                 
                 let device_info = device.info().cloned();
+                let warnings = Self::check_expected_model(config, device_info.as_ref(), &mut connection_log)?;
                 let result = AutoConnectResult {
                     success: true,
                     port_name: Some(cache.port_name),
@@ -134,150 +413,484 @@ impl AutoConnector {
                     connection_time,
                     connection_log,
                     device_info,
+                    timings,
+                    attempts,
+                    ports_probed: 0,
+                    warnings,
+                    preferred_port: None,
                 };
                 
                 if config.verbose {
-                    println!("Connected using cached settings: {} at {} baud", 
-                        result.port_name.as_ref().unwrap(), result.baud_rate.unwrap());
+                    println!("{} using cached settings", result.describe());
                 }
                 
                 return Ok((device, result));
             }
         }
         
-        // Step 2: Auto-detect ports
-        connection_log.push("Scanning for compatible ports".to_string());
+        let mut scan = PortScan {
+            connection_log,
+            timings,
+            failure: ConnectionFailure::default(),
+            attempts,
+            ports_probed: 0,
+        };
+        
+        // Step 2: Probe the preferred ports, in order
+        if !config.preferred_ports.is_empty() {
+            scan.connection_log.push(format!("Probing preferred ports: {}", config.preferred_ports.join(", ")));
+            if config.verbose {
+                println!("Trying preferred ports: {}", config.preferred_ports.join(", "));
+            }
+            
+            if let Some(connected) = Self::probe_preferred(config, start_time, &mut scan)? {
+                return Self::connected_result(config, connected, true, start_time, scan);
+            }
+        }
+        
+        // Step 3: Auto-detect the remaining ports
+        scan.connection_log.push("Scanning for compatible ports".to_string());
         if config.verbose {
             println!("Scanning for compatible serial ports...");
         }
         
-        let port_candidates = PortDetector::detect_ports(&config.port_config)?;
-        connection_log.push(format!("Found {} port candidates", port_candidates.len()));
+        let detection_start = Instant::now();
+        let mut port_candidates = PortDetector::detect_ports_matching(&config.port_config, &config.usb_filter)?;
+        port_candidates.retain(|candidate| !config.preferred_ports.contains(&candidate.port_info.port_name));
+        scan.timings.record_port_detection(detection_start.elapsed(), &port_candidates);
+        scan.connection_log.push(format!("Found {} port candidates", port_candidates.len()));
+        scan.failure.ports_enumerated = port_candidates.len();
         
         if port_candidates.is_empty() {
             let connection_time = start_time.elapsed();
-            connection_log.push("No compatible ports found".to_string());
-            
-            let _result = AutoConnectResult {
-                success: false,
-                port_name: None,
-                baud_rate: None,
-                connection_method: ConnectionMethod::AutoDetected,
-                connection_time,
-                connection_log,
-                device_info: None,
-            };
+            scan.connection_log.push("No compatible ports found".to_string());
             
-            return Err(LumidoxError::DeviceError("No compatible serial ports found".to_string()));
+            let mut failure = scan.failure;
+            failure.attempts = scan.attempts;
+            failure.elapsed = connection_time;
+            return Err(LumidoxError::ConnectionFailed(Box::new(failure)));
+        }
+        
+        // Step 4: Test the port candidates, one at a time or concurrently
+        let connected = if config.parallel_probe && port_candidates.len() > 1 {
+            Self::probe_parallel(port_candidates, config, start_time, &mut scan)
+        } else {
+            Self::probe_sequential(&port_candidates, config, start_time, &mut scan)
+        };
+        
+        if let Some(connected) = connected {
+            return Self::connected_result(config, connected, false, start_time, scan);
+        }
+        
+        // Step 5: Auto-detection failed
+        let connection_time = start_time.elapsed();
+        scan.connection_log.push("Auto-detection failed for all candidates".to_string());
+        
+        if config.verbose {
+            for line in scan.timings.summary_lines() {
+                println!("{}", line);
+            }
         }
         
-        // Step 3: Test each port candidate
-        for (index, candidate) in port_candidates.iter().enumerate() {
+        let _result = AutoConnectResult {
+            success: false,
+            port_name: None,
+            baud_rate: None,
+            connection_method: ConnectionMethod::AutoDetected,
+            connection_time,
+            connection_log: scan.connection_log,
+            device_info: None,
+            timings: scan.timings,
+            attempts: scan.attempts,
+            ports_probed: scan.ports_probed,
+            warnings: Vec::new(),
+            preferred_port: None,
+        };
+        
+        let mut failure = scan.failure;
+        failure.attempts = scan.attempts;
+        failure.elapsed = connection_time;
+        if config.verbose {
+            for line in failure.report_lines() {
+                println!("{}", line);
+            }
+        }
+        
+        Err(LumidoxError::ConnectionFailed(Box::new(failure)))
+    }
+    
+    /// Build the result of a successful port scan
+    /// 
+    /// Checks the expected model and caches the connection if enabled.
+    /// 
+    /// # Arguments
+    /// * `config` - Auto-connection configuration
+    /// * `connected` - Connected device, port name and baud rate
+    /// * `preferred` - Whether the port was one of `config.preferred_ports`
+    /// * `start_time` - Start of the auto-connection
+    /// * `scan` - Scan progress up to the connection
+    /// 
+    /// # Returns
+    /// * `Result<(LumidoxDevice, AutoConnectResult)>` - Connected device and connection
+    ///   details, or `ModelMismatch` when the policy is strict
+    fn connected_result(
+        config: &AutoConnectConfig,
+        (device, port_name, baud_rate): (LumidoxDevice, String, u32),
+        preferred: bool,
+        start_time: Instant,
+        mut scan: PortScan
+    ) -> Result<(LumidoxDevice, AutoConnectResult)> {
+        let device_info = device.info().cloned();
+        let warnings = Self::check_expected_model(config, device_info.as_ref(), &mut scan.connection_log)?;
+        
+        // Cache this successful connection
+        if config.enable_caching {
+            Self::cache_connection(&port_name, baud_rate, &device);
+        }
+        
+        let result = AutoConnectResult {
+            success: true,
+            preferred_port: preferred.then(|| port_name.clone()),
+            port_name: Some(port_name),
+            baud_rate: Some(baud_rate),
+            connection_method: ConnectionMethod::AutoDetected,
+            connection_time: start_time.elapsed(),
+            connection_log: scan.connection_log,
+            device_info,
+            timings: scan.timings,
+            attempts: scan.attempts,
+            ports_probed: scan.ports_probed,
+            warnings,
+        };
+        
+        if config.verbose {
+            println!("{}", result.describe());
+        }
+        
+        Ok((device, result))
+    }
+    
+    /// Probe the preferred ports one at a time, in the configured order
+    /// 
+    /// A preferred port that is not present, for example because it was
+    /// renamed, is reported as unavailable without being opened, and one
+    /// excluded by the USB filter is skipped.
+    /// 
+    /// # Arguments
+    /// * `config` - Auto-connection configuration
+    /// * `start_time` - Start of the auto-connection, for the detection time limit
+    /// * `scan` - Scan progress to record each probe into
+    /// 
+    /// # Returns
+    /// * `Result<Option<(LumidoxDevice, String, u32)>>` - Connected device, port name
+    ///   and baud rate, or the error listing the available ports
+    fn probe_preferred(
+        config: &AutoConnectConfig,
+        start_time: Instant,
+        scan: &mut PortScan
+    ) -> Result<Option<(LumidoxDevice, String, u32)>> {
+        let available = serialport::available_ports().map_err(LumidoxError::SerialError)?;
+        let not_cancelled = AtomicBool::new(false);
+        let total = config.preferred_ports.len();
+        for (index, port_name) in config.preferred_ports.iter().enumerate() {
             if start_time.elapsed() > config.max_detection_time {
-                connection_log.push("Detection timeout reached".to_string());
+                scan.connection_log.push("Detection timeout reached".to_string());
+                for skipped in &config.preferred_ports[index..] {
+                    scan.failure.record_port(skipped, PortVerdict::NotTested);
+                }
                 break;
             }
             
-            if config.verbose {
-                println!("Testing port {} ({}/{}): {} (score: {})", 
-                    candidate.port_info.port_name, 
-                    index + 1, 
-                    port_candidates.len(),
-                    candidate.score_reason,
-                    candidate.compatibility_score);
+            match Self::preferred_candidate(port_name, &available, &config.usb_filter) {
+                Ok(candidate) => {
+                    let probe = Self::probe_candidate(&candidate, index, total, config, &not_cancelled);
+                    if let Some(connected) = scan.record(probe) {
+                        return Ok(Some(connected));
+                    }
+                }
+                Err(Some(verdict)) => {
+                    scan.connection_log.push(format!("Preferred port {}: {}", port_name, verdict));
+                    scan.failure.record_port(port_name, verdict);
+                }
+                Err(None) => {
+                    scan.connection_log.push(format!("Preferred port {} skipped: not allowed by the USB filter", port_name));
+                }
+            }
+        }
+        Ok(None)
+    }
+    
+    /// Look up a preferred port among the available ports
+    /// 
+    /// # Arguments
+    /// * `port_name` - Name of the preferred port
+    /// * `available` - Ports reported by the operating system
+    /// * `usb_filter` - Allowed USB (VID, PID) pairs; empty to allow every port
+    /// 
+    /// # Returns
+    /// * `std::result::Result<PortCandidate, Option<PortVerdict>>` - Candidate to
+    ///   probe, or the verdict for a port that is not present and None for one
+    ///   excluded by the USB filter
+    fn preferred_candidate(
+        port_name: &str,
+        available: &[serialport::SerialPortInfo],
+        usb_filter: &[(u16, u16)]
+    ) -> std::result::Result<PortCandidate, Option<PortVerdict>> {
+        let port_info = available.iter()
+            .find(|port| port.port_name == port_name)
+            .ok_or_else(|| Some(PortVerdict::Unavailable("preferred port not present".to_string())))?;
+        if !PortDetector::matches_usb_filter(port_info, usb_filter) {
+            return Err(None);
+        }
+        Ok(PortCandidate {
+            port_info: port_info.clone(),
+            compatibility_score: 100,
+            device_identified: false,
+            device_details: None,
+            score_reason: "preferred port".to_string(),
+            identification_time: Duration::ZERO,
+        })
+    }
+    
+    /// Check the connected device against the expected model
+    /// 
+    /// # Arguments
+    /// * `config` - Auto-connection configuration
+    /// * `device_info` - Identity of the connected device
+    /// * `connection_log` - Log to record a mismatch in
+    /// 
+    /// # Returns
+    /// * `Result<Vec<String>>` - Warnings for a lenient mismatch, or `ModelMismatch`
+    ///   when the policy is strict
+    fn check_expected_model(
+        config: &AutoConnectConfig,
+        device_info: Option<&crate::device::models::DeviceInfo>,
+        connection_log: &mut Vec<String>
+    ) -> Result<Vec<String>> {
+        let (Some(expected), Some(info)) = (&config.expected_model, device_info) else {
+            return Ok(Vec::new());
+        };
+        match expected.check(info) {
+            Ok(warning) => {
+                connection_log.extend(warning.clone());
+                Ok(warning.into_iter().collect())
+            }
+            Err(e) => {
+                connection_log.push(e.to_string());
+                Err(e)
+            }
+        }
+    }
+    
+    /// Probe port candidates one at a time, in order of compatibility score
+    /// 
+    /// # Arguments
+    /// * `candidates` - Port candidates from port detection
+    /// * `config` - Auto-connection configuration
+    /// * `start_time` - Start of the auto-connection, for the detection time limit
+    /// * `scan` - Scan progress to record each probe into
+    /// 
+    /// # Returns
+    /// * `Option<(LumidoxDevice, String, u32)>` - Connected device, port name and baud rate
+    fn probe_sequential(
+        candidates: &[PortCandidate],
+        config: &AutoConnectConfig,
+        start_time: Instant,
+        scan: &mut PortScan
+    ) -> Option<(LumidoxDevice, String, u32)> {
+        let not_cancelled = AtomicBool::new(false);
+        for (index, candidate) in candidates.iter().enumerate() {
+            if start_time.elapsed() > config.max_detection_time {
+                scan.connection_log.push("Detection timeout reached".to_string());
+                for skipped in &candidates[index..] {
+                    scan.failure.record_port(&skipped.port_info.port_name, PortVerdict::NotTested);
+                }
+                break;
             }
             
-            connection_log.push(format!("Testing port {}: {}", candidate.port_info.port_name, candidate.score_reason));
-            
-            // If device was already identified during port detection, try default baud rate first
-            if candidate.device_identified {
-                if let Ok(device) = Self::try_connect_with_baud(&candidate.port_info.port_name, BaudDetector::get_recommended_baud_rate()) {
-                    let connection_time = start_time.elapsed();
-                    connection_log.push(format!("Connected successfully: {} at {} baud", 
-                        candidate.port_info.port_name, BaudDetector::get_recommended_baud_rate()));
-                    
-                    let device_info = device.info().cloned();
-                    
-                    // Cache this successful connection
-                    if config.enable_caching {
-                        Self::cache_connection(&candidate.port_info.port_name, BaudDetector::get_recommended_baud_rate(), &device);
-                    }
-                    
-                    let result = AutoConnectResult {
-                        success: true,
-                        port_name: Some(candidate.port_info.port_name.clone()),
-                        baud_rate: Some(BaudDetector::get_recommended_baud_rate()),
-                        connection_method: ConnectionMethod::AutoDetected,
-                        connection_time,
-                        connection_log,
-                        device_info,
+            let probe = Self::probe_candidate(candidate, index, candidates.len(), config, &not_cancelled);
+            if let Some(connected) = scan.record(probe) {
+                return Some(connected);
+            }
+        }
+        None
+    }
+    
+    /// Probe port candidates concurrently on a bounded pool of worker threads
+    /// 
+    /// Up to `config.max_parallel_probes` workers take candidates in order of
+    /// compatibility score. A port that cannot be opened, typically because
+    /// another application holds it, is reported as unavailable without
+    /// probing it. The first successful handshake is returned immediately;
+    /// remaining workers are told to stop and any device they still connect
+    /// is closed. Probes already in progress run to their serial timeouts in
+    /// the background.
+    /// 
+    /// # Arguments
+    /// * `candidates` - Port candidates from port detection
+    /// * `config` - Auto-connection configuration
+    /// * `start_time` - Start of the auto-connection, for the detection time limit
+    /// * `scan` - Scan progress to record each probe into
+    /// 
+    /// # Returns
+    /// * `Option<(LumidoxDevice, String, u32)>` - Connected device, port name and baud rate
+    fn probe_parallel(
+        candidates: Vec<PortCandidate>,
+        config: &AutoConnectConfig,
+        start_time: Instant,
+        scan: &mut PortScan
+    ) -> Option<(LumidoxDevice, String, u32)> {
+        let total = candidates.len();
+        let candidates = Arc::new(candidates);
+        let next = Arc::new(AtomicUsize::new(0));
+        let cancel = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = mpsc::channel();
+        
+        for _ in 0..config.max_parallel_probes.clamp(1, total) {
+            let (candidates, next, cancel, sender) = (candidates.clone(), next.clone(), cancel.clone(), sender.clone());
+            let config = config.clone();
+            thread::spawn(move || {
+                while !cancel.load(Ordering::Relaxed) {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(candidate) = candidates.get(index) else { break };
+                    let probe = match Self::check_port_available(&candidate.port_info.port_name) {
+                        Ok(()) => Self::probe_candidate(candidate, index, total, &config, &cancel),
+                        Err(reason) => PortProbe::new(&candidate.port_info.port_name, PortVerdict::Unavailable(reason)),
                     };
-                    
-                    if config.verbose {
-                        println!("Successfully connected to {} at {} baud", 
-                            result.port_name.as_ref().unwrap(), result.baud_rate.unwrap());
+                    if sender.send(probe).is_err() {
+                        break;
                     }
-                    
-                    return Ok((device, result));
                 }
+            });
+        }
+        drop(sender);
+        
+        let mut reported = Vec::with_capacity(total);
+        loop {
+            let remaining = config.max_detection_time.saturating_sub(start_time.elapsed());
+            match receiver.recv_timeout(remaining) {
+                Ok(probe) => {
+                    reported.push(probe.port_name.clone());
+                    if let Some(connected) = scan.record(probe) {
+                        cancel.store(true, Ordering::Relaxed);
+                        return Some(connected);
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    cancel.store(true, Ordering::Relaxed);
+                    scan.connection_log.push("Detection timeout reached".to_string());
+                    for skipped in candidates.iter().filter(|candidate| !reported.contains(&candidate.port_info.port_name)) {
+                        scan.failure.record_port(&skipped.port_info.port_name, PortVerdict::NotTested);
+                    }
+                    return None;
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => return None,
             }
-            
-            // Try baud rate detection
-            connection_log.push(format!("Testing baud rates for {}", candidate.port_info.port_name));
-            if config.verbose {
-                println!("  Testing baud rates...");
+        }
+    }
+    
+    /// Check that a port can be opened before probing it
+    /// 
+    /// # Arguments
+    /// * `port_name` - Name of the serial port
+    /// 
+    /// # Returns
+    /// * `std::result::Result<(), String>` - Success, or why the port could not be opened
+    fn check_port_available(port_name: &str) -> std::result::Result<(), String> {
+        serialport::new(port_name, BaudDetector::get_recommended_baud_rate())
+            .timeout(Duration::from_millis(100))
+            .open()
+            .map(drop)
+            .map_err(|e| e.to_string())
+    }
+    
+    /// Probe a single port candidate and connect to it if a device answers
+    /// 
+    /// Tries the recommended baud rate first when the device was identified
+    /// during port detection, then runs baud rate detection and connects at
+    /// the best rate. `cancel` is checked between phases so a parallel scan
+    /// can stop once another port has connected.
+    /// 
+    /// # Arguments
+    /// * `candidate` - Port candidate to probe
+    /// * `index` - Position of the candidate, for progress output
+    /// * `total` - Number of candidates, for progress output
+    /// * `config` - Auto-connection configuration
+    /// * `cancel` - Set when the scan no longer needs this probe
+    /// 
+    /// # Returns
+    /// * `PortProbe` - Connected device, or the verdict explaining the rejection
+    fn probe_candidate(
+        candidate: &PortCandidate,
+        index: usize,
+        total: usize,
+        config: &AutoConnectConfig,
+        cancel: &AtomicBool
+    ) -> PortProbe {
+        let port_name = &candidate.port_info.port_name;
+        let mut probe = PortProbe::new(port_name, PortVerdict::NoResponse);
+        
+        if config.verbose {
+            println!("Testing port {} ({}/{}): {} (score: {})", 
+                port_name, 
+                index + 1, 
+                total,
+                candidate.score_reason,
+                candidate.compatibility_score);
+        }
+        
+        probe.log.push(format!("Testing port {}: {}", port_name, candidate.score_reason));
+        
+        // If device was already identified during port detection, try default baud rate first
+        if candidate.device_identified {
+            let baud_rate = BaudDetector::get_recommended_baud_rate();
+            probe.attempts += 1;
+            probe.bauds_probed.push(baud_rate);
+            match Self::try_connect_with_baud_timed(port_name, baud_rate, config.identity_retry, &mut probe.timings) {
+                Err(e) => probe.verdict = PortVerdict::ConnectFailed(e.to_string()),
+                Ok(device) => return probe.connected(device, baud_rate),
             }
-            
-            if let Ok(Some(baud_rate)) = BaudDetector::detect_baud_rate(&candidate.port_info.port_name, &config.baud_config) {
-                if let Ok(device) = Self::try_connect_with_baud(&candidate.port_info.port_name, baud_rate) {
-                    let connection_time = start_time.elapsed();
-                    connection_log.push(format!("Connected successfully: {} at {} baud", 
-                        candidate.port_info.port_name, baud_rate));
-                    
-                    let device_info = device.info().cloned();
-                    
-                    // Cache this successful connection
-                    if config.enable_caching {
-                        Self::cache_connection(&candidate.port_info.port_name, baud_rate, &device);
-                    }
-                    
-                    let result = AutoConnectResult {
-                        success: true,
-                        port_name: Some(candidate.port_info.port_name.clone()),
-                        baud_rate: Some(baud_rate),
-                        connection_method: ConnectionMethod::AutoDetected,
-                        connection_time,
-                        connection_log,
-                        device_info,
-                    };
-                    
+        }
+        
+        if cancel.load(Ordering::Relaxed) {
+            return probe;
+        }
+        
+        // Try baud rate detection
+        probe.log.push(format!("Testing baud rates for {}", port_name));
+        if config.verbose {
+            println!("  Testing baud rates...");
+        }
+        
+        let baud_results = BaudDetector::test_all_baud_rates(port_name, &config.baud_config);
+        match &baud_results {
+            Ok(results) => {
+                probe.timings.record_baud_probes(port_name, results);
+                probe.bauds_probed.extend(results.iter().map(|result| result.baud_rate));
+                if let Some(mismatch) = BaudDetector::diagnose_mismatch(port_name, results) {
                     if config.verbose {
-                        println!("Successfully connected to {} at {} baud", 
-                            result.port_name.as_ref().unwrap(), result.baud_rate.unwrap());
+                        println!("  {}", mismatch);
                     }
-                    
-                    return Ok((device, result));
+                    probe.log.push(mismatch.clone());
+                    probe.verdict = PortVerdict::BaudMismatch(mismatch);
                 }
             }
-            
-            connection_log.push(format!("No working baud rate found for {}", candidate.port_info.port_name));
+            Err(e) => probe.verdict = PortVerdict::ConnectFailed(e.to_string()),
         }
         
-        // Step 4: Auto-detection failed
-        let connection_time = start_time.elapsed();
-        connection_log.push("Auto-detection failed for all candidates".to_string());
-        
-        let _result = AutoConnectResult {
-            success: false,
-            port_name: None,
-            baud_rate: None,
-            connection_method: ConnectionMethod::AutoDetected,
-            connection_time,
-            connection_log,
-            device_info: None,
-        };
+        if let Some(baud_rate) = baud_results.ok().and_then(|results| BaudDetector::best_baud_rate(&results)) {
+            if cancel.load(Ordering::Relaxed) {
+                return probe;
+            }
+            probe.attempts += 1;
+            match Self::try_connect_with_baud_timed(port_name, baud_rate, config.identity_retry, &mut probe.timings) {
+                Err(e) => probe.verdict = PortVerdict::ConnectFailed(e.to_string()),
+                Ok(device) => return probe.connected(device, baud_rate),
+            }
+        }
         
-        Err(LumidoxError::DeviceError("Auto-detection failed to find a working Lumidox II Controller".to_string()))
+        probe.log.push(format!("No working baud rate found for {}", port_name));
+        probe
     }
     
     /// Try to connect using cached connection parameters
@@ -299,23 +912,38 @@ impl AutoConnector {
     /// Try to connect to a specific port with a specific baud rate
     /// 
     /// Attempts to establish a connection to the given port using the
-    /// specified baud rate and validates the connection.
+    /// specified baud rate and validates the connection. Time spent opening
+    /// the port and initializing the device is added to `timings`.
     /// 
     /// # Arguments
     /// * `port_name` - Name of the serial port
     /// * `baud_rate` - Baud rate to use
+    /// * `identity_retry` - Retry policy for the identity read during initialization
+    /// * `timings` - Timing breakdown to accumulate into
     /// 
     /// # Returns
     /// * `Result<LumidoxDevice>` - Connected device if successful
-    fn try_connect_with_baud(port_name: &str, baud_rate: u32) -> Result<LumidoxDevice> {
-        let port = serialport::new(port_name, baud_rate)
+    fn try_connect_with_baud_timed(
+        port_name: &str,
+        baud_rate: u32,
+        identity_retry: IdentityReadRetry,
+        timings: &mut ConnectTimings
+    ) -> Result<LumidoxDevice> {
+        let open_start = Instant::now();
+        let opened = serialport::new(port_name, baud_rate)
             .timeout(Duration::from_millis(1000))
             .open()
-            .map_err(LumidoxError::SerialError)?;
+            .map_err(LumidoxError::SerialError)
+            .and_then(ProtocolHandler::new);
+        timings.port_open += open_start.elapsed();
         
-        let protocol = ProtocolHandler::new(port)?;
-        let mut device = LumidoxDevice::new(protocol);
-        device.initialize()?;
+        let mut device = LumidoxDevice::new(opened?);
+        device.set_identity_read_retry(identity_retry);
+        
+        let init_start = Instant::now();
+        let initialized = device.initialize();
+        timings.initialization += init_start.elapsed();
+        initialized?;
         
         Ok(device)
     }
@@ -360,6 +988,12 @@ impl AutoConnector {
             verbose: false,
             enable_caching: true,
             max_detection_time: Duration::from_secs(10),
+            identity_retry: IdentityReadRetry::default(),
+            parallel_probe: false,
+            max_parallel_probes: 4,
+            usb_filter: Vec::new(),
+            expected_model: None,
+            preferred_ports: Vec::new(),
         }
     }
     
@@ -383,6 +1017,12 @@ impl AutoConnector {
             verbose: true,
             enable_caching: true,
             max_detection_time: Duration::from_secs(60),
+            identity_retry: IdentityReadRetry::default(),
+            parallel_probe: false,
+            max_parallel_probes: 4,
+            usb_filter: Vec::new(),
+            expected_model: None,
+            preferred_ports: Vec::new(),
         }
     }
     
@@ -407,7 +1047,13 @@ impl AutoConnector {
         diagnostics.push("=== Port Diagnostics ===".to_string());
         
         let config = PortDetectionConfig::default();
+        let detection_start = Instant::now();
         let candidates = PortDetector::detect_ports(&config)?;
+        let mut timings = ConnectTimings::default();
+        timings.record_port_detection(detection_start.elapsed(), &candidates);
+        diagnostics.push(format!("Port enumeration: {:.1}ms, identity verification: {:.1}ms",
+            ConnectTimings::millis(timings.enumeration),
+            ConnectTimings::millis(timings.identification)));
         
         if candidates.is_empty() {
             diagnostics.push("No compatible ports found".to_string());
@@ -439,3 +1085,124 @@ impl AutoConnector {
         Ok(diagnostics)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baud_result(baud_rate: u32, success: bool, millis: u64) -> BaudTestResult {
+        BaudTestResult {
+            baud_rate,
+            success,
+            quality_score: if success { 100 } else { 0 },
+            successful_responses: u8::from(success),
+            total_attempts: 1,
+            test_details: String::new(),
+            device_info: None,
+            test_duration: Duration::from_millis(millis),
+            response: if success { BaudResponse::Valid } else { BaudResponse::Silent },
+        }
+    }
+
+    #[test]
+    fn test_baud_probes_are_recorded_per_rate() {
+        let mut timings = ConnectTimings::default();
+        timings.record_baud_probes("COM3", &[baud_result(9600, false, 300), baud_result(19200, true, 120)]);
+
+        assert_eq!(timings.baud_probes.len(), 2);
+        assert_eq!(timings.baud_probes[1].baud_rate, 19200);
+        assert_eq!(timings.baud_probing(), Duration::from_millis(420));
+    }
+
+    #[test]
+    fn test_total_sums_all_phases() {
+        let mut timings = ConnectTimings {
+            enumeration: Duration::from_millis(10),
+            identification: Duration::from_millis(200),
+            port_open: Duration::from_millis(5),
+            initialization: Duration::from_millis(150),
+            ..ConnectTimings::default()
+        };
+        timings.record_baud_probes("COM3", &[baud_result(19200, true, 100)]);
+
+        assert_eq!(timings.total(), Duration::from_millis(465));
+        assert!(timings.summary_lines().iter().any(|line| line.contains("COM3 @ 19200 baud")));
+    }
+
+    #[test]
+    fn test_port_scan_records_each_probe() {
+        let mut scan = PortScan {
+            connection_log: Vec::new(),
+            timings: ConnectTimings::default(),
+            failure: ConnectionFailure::default(),
+            attempts: 0,
+            ports_probed: 0,
+        };
+        let mut silent = PortProbe::new("COM3", PortVerdict::NoResponse);
+        silent.attempts = 1;
+        silent.bauds_probed = vec![19200, 9600];
+        silent.timings.port_open = Duration::from_millis(5);
+
+        assert!(scan.record(silent).is_none());
+        assert!(scan.record(PortProbe::new("COM7", PortVerdict::Unavailable("Access is denied".to_string()))).is_none());
+        assert_eq!(scan.ports_probed, 2);
+        assert_eq!(scan.attempts, 1);
+        assert_eq!(scan.timings.port_open, Duration::from_millis(5));
+        assert_eq!(scan.failure.bauds_probed, vec![19200, 9600]);
+        assert_eq!(scan.failure.ports[1].verdict.to_string(), "skipped, port could not be opened: Access is denied");
+    }
+
+    #[test]
+    fn test_preferred_port_must_be_present_and_allowed() {
+        let available = vec![serialport::SerialPortInfo {
+            port_name: "COM5".to_string(),
+            port_type: serialport::SerialPortType::PciPort,
+        }];
+
+        let candidate = AutoConnector::preferred_candidate("COM5", &available, &[]).unwrap();
+        assert_eq!(candidate.port_info.port_name, "COM5");
+        assert!(!candidate.device_identified);
+        assert!(matches!(
+            AutoConnector::preferred_candidate("COM9", &available, &[]),
+            Err(Some(PortVerdict::Unavailable(_)))
+        ));
+        // The USB filter still applies: a PCI port is never opened
+        assert!(matches!(AutoConnector::preferred_candidate("COM5", &available, &[(0x0403, 0x6001)]), Err(None)));
+    }
+
+    #[test]
+    fn test_describe_includes_populated_fields() {
+        let mut result = AutoConnectResult {
+            success: true,
+            port_name: Some("COM4".to_string()),
+            baud_rate: Some(19200),
+            connection_method: ConnectionMethod::AutoDetected,
+            connection_time: Duration::from_millis(420),
+            connection_log: Vec::new(),
+            device_info: Some(crate::device::models::DeviceInfo {
+                firmware_version: "1.5".to_string(),
+                model_number: "Lumidox II".to_string(),
+                serial_number: "SN1234".to_string(),
+                wavelength: "365nm".to_string(),
+                max_current_ma: 5000,
+            }),
+            timings: ConnectTimings::default(),
+            attempts: 3,
+            ports_probed: 2,
+            warnings: Vec::new(),
+            preferred_port: None,
+        };
+        assert_eq!(result.describe(), "Connected to COM4 @ 19200 (model Lumidox II, 3 attempts, 420ms)");
+
+        result.preferred_port = Some("COM4".to_string());
+        assert_eq!(result.describe(), "Connected to COM4 @ 19200 (preferred port, model Lumidox II, 3 attempts, 420ms)");
+        result.preferred_port = None;
+
+        result.success = false;
+        result.port_name = None;
+        result.baud_rate = None;
+        result.device_info = None;
+        result.attempts = 1;
+        assert_eq!(result.describe(), "Connection failed (1 attempt, 420ms)");
+    }
+}
@@ -8,11 +8,94 @@
 //! - Testing of common serial communication baud rates
 //! - Device identification validation at each baud rate
 //! - Ranking of successful baud rates by response quality
+//! - Distinguishing silent ports from garbled responses caused by a baud mismatch
 //! - Fallback to default baud rate if detection fails
+//! - A validated `Baud` type that rejects rates the device does not support
 
+use crate::communication::protocol::handler::{ChecksumMismatch, CommandChecksumRejected};
 use crate::core::{LumidoxError, Result};
+use crate::device::models::DeviceInfo;
+use crate::device::operations::readback::plausibility::PLAUSIBLE_MAX_CURRENT_MA;
 
-use std::time::Duration;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Standard baud rates tried when a device responds but no configured rate works
+const STANDARD_BAUD_RATES: [u32; 8] = [19200, 9600, 38400, 57600, 115200, 4800, 2400, 1200];
+
+/// Serial baud rate supported by the Lumidox II
+///
+/// Only standard rates can be represented, so a typo such as 12345 is
+/// rejected with a list of the supported rates before a port is opened,
+/// instead of surfacing as an OS-level serial port error.
+///
+/// # Example
+/// ```
+/// let baud: Baud = "9600".parse()?;
+/// let port = serialport::new("COM3", baud.as_u32()).open()?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Baud(u32);
+
+impl Baud {
+    /// Baud rates the device supports, in ascending order
+    pub const SUPPORTED: [u32; 8] = [1200, 2400, 4800, 9600, 19200, 38400, 57600, 115200];
+
+    /// Get the rate in bits per second
+    ///
+    /// # Returns
+    /// * `u32` - Baud rate as passed to the serial port
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+}
+
+impl Default for Baud {
+    fn default() -> Self {
+        Baud(crate::communication::protocol::constants::DEFAULT_BAUD_RATE)
+    }
+}
+
+impl TryFrom<u32> for Baud {
+    type Error = LumidoxError;
+
+    fn try_from(rate: u32) -> Result<Self> {
+        if Self::SUPPORTED.contains(&rate) {
+            Ok(Baud(rate))
+        } else {
+            let supported: Vec<String> = Self::SUPPORTED.iter().map(u32::to_string).collect();
+            Err(LumidoxError::InvalidInput(format!(
+                "Unsupported baud rate {}; supported rates: {}",
+                rate,
+                supported.join(", ")
+            )))
+        }
+    }
+}
+
+impl FromStr for Baud {
+    type Err = LumidoxError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let rate = value.trim().parse::<u32>().map_err(|_| {
+            LumidoxError::InvalidInput(format!("Invalid baud rate '{}': expected a number such as 19200", value))
+        })?;
+        Baud::try_from(rate)
+    }
+}
+
+impl From<Baud> for u32 {
+    fn from(baud: Baud) -> Self {
+        baud.0
+    }
+}
+
+impl fmt::Display for Baud {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
 /// Baud rate detection configuration and settings
 #[derive(Debug, Clone)]
@@ -20,7 +103,7 @@ pub struct BaudDetectionConfig {
     /// Timeout for each baud rate test
     pub test_timeout: Duration,
     /// List of baud rates to test in order of preference
-    pub test_baud_rates: Vec<u32>,
+    pub test_baud_rates: Vec<Baud>,
     /// Number of identification attempts per baud rate
     pub attempts_per_rate: u8,
     /// Whether to test with multiple commands for validation
@@ -32,13 +115,13 @@ impl Default for BaudDetectionConfig {
         Self {
             test_timeout: Duration::from_millis(1500),
             test_baud_rates: vec![
-                19200,  // Default for Lumidox II
-                9600,   // Common fallback
-                38400,  // Double default
-                57600,  // High speed option
-                115200, // Very high speed
-                4800,   // Low speed fallback
-                2400,   // Very low speed
+                Baud(19200),  // Default for Lumidox II
+                Baud(9600),   // Common fallback
+                Baud(38400),  // Double default
+                Baud(57600),  // High speed option
+                Baud(115200), // Very high speed
+                Baud(4800),   // Low speed fallback
+                Baud(2400),   // Very low speed
             ],
             attempts_per_rate: 2,
             comprehensive_testing: true,
@@ -46,6 +129,47 @@ impl Default for BaudDetectionConfig {
     }
 }
 
+/// Kind of response received from a port at a tested baud rate
+///
+/// Variants are ordered from weakest to strongest evidence of a device, so
+/// the strongest response across several attempts can be taken with `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum BaudResponse {
+    /// Nothing was received
+    #[default]
+    Silent,
+    /// Bytes were received but failed validation or held implausible values,
+    /// which usually means a device is present at a different baud rate
+    Garbled,
+    /// Valid identification responses were received
+    Valid,
+}
+
+impl BaudResponse {
+    /// Classify a failed communication attempt
+    ///
+    /// Protocol errors, checksum mismatches and implausible values mean bytes
+    /// arrived but could not be decoded into sensible data. The device's
+    /// incorrect-checksum reply and `UnsupportedOperation` are well-formed
+    /// replies, so they also prove a device is present. Timeouts,
+    /// `NoResponse` and every other error mean nothing usable was read.
+    ///
+    /// # Arguments
+    /// * `error` - Error returned by the communication attempt
+    ///
+    /// # Returns
+    /// * `BaudResponse` - `Garbled` if data was received, `Silent` otherwise
+    pub fn from_error(error: &LumidoxError) -> Self {
+        match error {
+            LumidoxError::ProtocolError(_) | LumidoxError::ImplausibleValue { .. } => BaudResponse::Garbled,
+            LumidoxError::UnsupportedOperation { .. } => BaudResponse::Garbled,
+            _ if ChecksumMismatch::is_cause_of(error) => BaudResponse::Garbled,
+            _ if CommandChecksumRejected::is_cause_of(error) => BaudResponse::Garbled,
+            _ => BaudResponse::Silent,
+        }
+    }
+}
+
 /// Result of baud rate testing
 #[derive(Debug, Clone)]
 pub struct BaudTestResult {
@@ -63,6 +187,10 @@ pub struct BaudTestResult {
     pub test_details: String,
     /// Device information if successfully retrieved
     pub device_info: Option<BaudTestDeviceInfo>,
+    /// Time spent testing this baud rate
+    pub test_duration: Duration,
+    /// Strongest response received across all attempts
+    pub response: BaudResponse,
 }
 
 /// Device information retrieved during baud rate testing
@@ -101,14 +229,28 @@ impl BaudDetector {
     /// ```
     pub fn detect_baud_rate(port_name: &str, config: &BaudDetectionConfig) -> Result<Option<u32>> {
         let test_results = Self::test_all_baud_rates(port_name, config)?;
-        
-        // Find the best working baud rate
-        let best_result = test_results
-            .into_iter()
+        Ok(Self::best_baud_rate(&test_results))
+    }
+    
+    /// Select the best working baud rate from test results
+    /// 
+    /// # Arguments
+    /// * `results` - Results from `test_all_baud_rates`
+    /// 
+    /// # Returns
+    /// * `Option<u32>` - Highest-quality successful baud rate, None if none succeeded
+    /// 
+    /// # Example
+    /// ```
+    /// let results = BaudDetector::test_all_baud_rates("COM3", &config)?;
+    /// let baud_rate = BaudDetector::best_baud_rate(&results);
+    /// ```
+    pub fn best_baud_rate(results: &[BaudTestResult]) -> Option<u32> {
+        results
+            .iter()
             .filter(|result| result.success)
-            .max_by_key(|result| result.quality_score);
-        
-        Ok(best_result.map(|result| result.baud_rate))
+            .max_by_key(|result| result.quality_score)
+            .map(|result| result.baud_rate)
     }
     
     /// Test all configured baud rates and return detailed results
@@ -137,8 +279,8 @@ impl BaudDetector {
     pub fn test_all_baud_rates(port_name: &str, config: &BaudDetectionConfig) -> Result<Vec<BaudTestResult>> {
         let mut results = Vec::new();
         
-        for &baud_rate in &config.test_baud_rates {
-            let result = Self::test_single_baud_rate(port_name, baud_rate, config)?;
+        for baud in &config.test_baud_rates {
+            let result = Self::test_single_baud_rate(port_name, baud.as_u32(), config)?;
 
             // If we found a high-quality match and not doing comprehensive testing, stop early
             let should_break = !config.comprehensive_testing && result.success && result.quality_score >= 80;
@@ -150,9 +292,88 @@ impl BaudDetector {
             }
         }
         
+        // A garbled response means a device is present at some other baud rate,
+        // so keep trying the remaining standard rates instead of giving up
+        if Self::best_baud_rate(&results).is_none() && Self::device_present(&results) {
+            for baud_rate in Self::untested_baud_rates(&results) {
+                let result = Self::test_single_baud_rate(port_name, baud_rate, config)?;
+                let found = result.success;
+                results.push(result);
+                if found {
+                    break;
+                }
+            }
+        }
+        
         Ok(results)
     }
     
+    /// Check whether any tested baud rate received data from a device
+    /// 
+    /// # Arguments
+    /// * `results` - Results from `test_all_baud_rates`
+    /// 
+    /// # Returns
+    /// * `bool` - True if any rate received valid or garbled responses
+    pub fn device_present(results: &[BaudTestResult]) -> bool {
+        results.iter().any(|result| result.response != BaudResponse::Silent)
+    }
+    
+    /// Describe a baud rate mismatch found during testing
+    /// 
+    /// Reports when a device responded with garbled data but no tested baud
+    /// rate produced valid responses.
+    /// 
+    /// # Arguments
+    /// * `port_name` - Name of the tested serial port
+    /// * `results` - Results from `test_all_baud_rates`
+    /// 
+    /// # Returns
+    /// * `Option<String>` - Diagnostic message, None if a rate worked or the port was silent
+    /// 
+    /// # Example
+    /// ```
+    /// if let Some(message) = BaudDetector::diagnose_mismatch("COM3", &results) {
+    ///     println!("{}", message);
+    /// }
+    /// ```
+    pub fn diagnose_mismatch(port_name: &str, results: &[BaudTestResult]) -> Option<String> {
+        if Self::best_baud_rate(results).is_some() || !Self::device_present(results) {
+            return None;
+        }
+        
+        let garbled: Vec<String> = results
+            .iter()
+            .filter(|result| result.response == BaudResponse::Garbled)
+            .map(|result| result.baud_rate.to_string())
+            .collect();
+        let untested: Vec<String> = Self::untested_baud_rates(results)
+            .into_iter()
+            .map(|baud_rate| baud_rate.to_string())
+            .collect();
+        let suggestion = if untested.is_empty() {
+            "check the baud rate configured on the device".to_string()
+        } else {
+            format!("try {} baud", untested.join(" or "))
+        };
+        
+        Some(format!(
+            "Device present on {} but baud rate wrong (garbled responses at {} baud); {}",
+            port_name,
+            garbled.join(", "),
+            suggestion
+        ))
+    }
+    
+    /// Get the standard baud rates not yet covered by the test results
+    fn untested_baud_rates(results: &[BaudTestResult]) -> Vec<u32> {
+        STANDARD_BAUD_RATES
+            .iter()
+            .copied()
+            .filter(|&baud_rate| !results.iter().any(|result| result.baud_rate == baud_rate))
+            .collect()
+    }
+    
     /// Test a single baud rate for communication
     /// 
     /// Tests communication at a specific baud rate by attempting to send
@@ -170,19 +391,28 @@ impl BaudDetector {
         baud_rate: u32, 
         config: &BaudDetectionConfig
     ) -> Result<BaudTestResult> {
+        let start_time = Instant::now();
         let mut successful_responses = 0u8;
         let mut device_info = None;
         let mut test_details = Vec::new();
+        let mut response = BaudResponse::Silent;
         
         for attempt in 1..=config.attempts_per_rate {
             match Self::attempt_communication(port_name, baud_rate, config) {
                 Ok(info) => {
                     successful_responses += 1;
                     device_info = Some(info);
+                    response = BaudResponse::Valid;
                     test_details.push(format!("Attempt {}: Success", attempt));
                 }
                 Err(e) => {
-                    test_details.push(format!("Attempt {}: Failed ({})", attempt, e));
+                    let kind = BaudResponse::from_error(&e);
+                    response = response.max(kind);
+                    if kind == BaudResponse::Garbled {
+                        test_details.push(format!("Attempt {}: Garbled response ({})", attempt, e));
+                    } else {
+                        test_details.push(format!("Attempt {}: Failed ({})", attempt, e));
+                    }
                 }
             }
         }
@@ -202,6 +432,8 @@ impl BaudDetector {
             total_attempts: config.attempts_per_rate,
             test_details: test_details.join("; "),
             device_info,
+            test_duration: start_time.elapsed(),
+            response,
         })
     }
     
@@ -232,24 +464,40 @@ impl BaudDetector {
         let mut protocol = crate::communication::ProtocolHandler::new(port)?;
         
         // Test basic communication with device info command
-        let device_info = crate::device::info::read_device_info(&mut protocol).ok();
-
-        // Extract firmware and model info if available
-        let firmware_version = device_info.as_ref().map(|info| info.firmware_version.clone());
-        let model_number = device_info.as_ref().map(|info| info.model_number.clone());
+        let device_info = crate::communication::probe_identity(&mut protocol)?;
         
-        // Check consistency - if we got firmware but not model, it might be unreliable
-        let consistent_responses = firmware_version.is_some() && model_number.is_some();
+        // Responses at the wrong baud rate can pass format checks but decode to nonsense
+        Self::check_plausible(&device_info)?;
         
-        if firmware_version.is_some() {
-            Ok(BaudTestDeviceInfo {
-                firmware_version,
-                model_number,
-                consistent_responses,
-            })
-        } else {
-            Err(LumidoxError::DeviceError("No response to identification commands".to_string()))
+        Ok(BaudTestDeviceInfo {
+            firmware_version: Some(device_info.firmware_version),
+            model_number: Some(device_info.model_number),
+            consistent_responses: true,
+        })
+    }
+    
+    /// Check that identification values decoded from the device are plausible
+    /// 
+    /// # Arguments
+    /// * `info` - Device information read during the test
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success if plausible, `ProtocolError` describing the implausible value otherwise
+    fn check_plausible(info: &DeviceInfo) -> Result<()> {
+        let printable = |text: &str| !text.is_empty() && text.chars().all(|c| c.is_ascii_graphic() || c == ' ');
+        
+        if !printable(&info.model_number) {
+            return Err(LumidoxError::ProtocolError(
+                format!("Implausible model number: {:?}", info.model_number)
+            ));
+        }
+        if info.max_current_ma == 0 || i32::from(info.max_current_ma) > PLAUSIBLE_MAX_CURRENT_MA {
+            return Err(LumidoxError::ProtocolError(
+                format!("Implausible maximum current: {}mA", info.max_current_ma)
+            ));
         }
+        
+        Ok(())
     }
     
     /// Calculate quality score for baud rate test results
@@ -326,7 +574,7 @@ impl BaudDetector {
     pub fn quick_detection_config() -> BaudDetectionConfig {
         BaudDetectionConfig {
             test_timeout: Duration::from_millis(1000),
-            test_baud_rates: vec![19200, 9600, 38400], // Only most common rates
+            test_baud_rates: vec![Baud(19200), Baud(9600), Baud(38400)], // Only most common rates
             attempts_per_rate: 1, // Single attempt per rate
             comprehensive_testing: false, // Stop at first good match
         }
@@ -348,11 +596,100 @@ impl BaudDetector {
     pub fn thorough_detection_config() -> BaudDetectionConfig {
         BaudDetectionConfig {
             test_timeout: Duration::from_millis(2000),
-            test_baud_rates: vec![
-                19200, 9600, 38400, 57600, 115200, 4800, 2400, 1200
-            ],
+            test_baud_rates: STANDARD_BAUD_RATES.iter().map(|&rate| Baud(rate)).collect(),
             attempts_per_rate: 3, // Multiple attempts for reliability
             comprehensive_testing: true, // Test all rates
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(baud_rate: u32, response: BaudResponse) -> BaudTestResult {
+        let success = response == BaudResponse::Valid;
+        BaudTestResult {
+            baud_rate,
+            success,
+            quality_score: if success { 100 } else { 0 },
+            successful_responses: u8::from(success),
+            total_attempts: 1,
+            test_details: String::new(),
+            device_info: None,
+            test_duration: Duration::ZERO,
+            response,
+        }
+    }
+
+    #[test]
+    fn test_response_classification() {
+        let silent = LumidoxError::NoResponse;
+        let garbled = LumidoxError::ProtocolError("Invalid hex digit at position 2: 0xff".to_string());
+        assert_eq!(BaudResponse::from_error(&silent), BaudResponse::Silent);
+        assert_eq!(BaudResponse::from_error(&garbled), BaudResponse::Garbled);
+        let implausible = LumidoxError::ImplausibleValue { field: "maximum current".to_string(), raw: -9211 };
+        assert_eq!(BaudResponse::from_error(&implausible), BaudResponse::Garbled);
+        assert!(BaudResponse::Garbled > BaudResponse::Silent);
+    }
+
+    #[test]
+    fn test_bad_checksum_frame_is_garbled() {
+        use crate::communication::{probe_identity, MockTransport, ProtocolHandler};
+        use crate::communication::protocol::commands;
+
+        // Right framing and hex, wrong checksum, as a noisy line can produce
+        let transport = MockTransport::new()
+            .expect(MockTransport::command_frame(commands::FIRMWARE_VERSION, 0), b"*000300^".to_vec());
+        let mut protocol = ProtocolHandler::with_transport(transport.clone()).unwrap();
+
+        let error = probe_identity(&mut protocol).unwrap_err();
+        assert!(ChecksumMismatch::is_cause_of(&error));
+        assert_eq!(BaudResponse::from_error(&error), BaudResponse::Garbled);
+        transport.assert_consumed();
+    }
+
+    #[test]
+    fn test_rejected_checksum_reply_means_device_present() {
+        use crate::communication::{probe_identity, MockTransport, ProtocolHandler};
+        use crate::communication::protocol::commands;
+
+        // The device answered *XXXX60^, so something is listening at this rate
+        let transport = MockTransport::new().expect_rejected(commands::FIRMWARE_VERSION);
+        let mut protocol = ProtocolHandler::with_transport(transport.clone()).unwrap();
+
+        let error = probe_identity(&mut protocol).unwrap_err();
+        assert_eq!(BaudResponse::from_error(&error), BaudResponse::Garbled);
+        let unsupported = LumidoxError::UnsupportedOperation { command: "0x02".to_string(), firmware: "unknown".to_string() };
+        assert_eq!(BaudResponse::from_error(&unsupported), BaudResponse::Garbled);
+        transport.assert_consumed();
+    }
+
+    #[test]
+    fn test_diagnose_mismatch() {
+        let results = vec![result(19200, BaudResponse::Garbled), result(9600, BaudResponse::Silent)];
+        let message = BaudDetector::diagnose_mismatch("COM3", &results).unwrap();
+        assert!(message.contains("garbled responses at 19200 baud"));
+        assert!(message.contains("try 38400"));
+        assert!(!message.contains("try 9600"));
+
+        let silent = vec![result(19200, BaudResponse::Silent)];
+        assert!(BaudDetector::diagnose_mismatch("COM3", &silent).is_none());
+
+        let found = vec![result(19200, BaudResponse::Garbled), result(9600, BaudResponse::Valid)];
+        assert!(BaudDetector::diagnose_mismatch("COM3", &found).is_none());
+    }
+
+    #[test]
+    fn test_baud_accepts_only_supported_rates() {
+        assert_eq!(Baud::try_from(9600).unwrap().as_u32(), 9600);
+        assert_eq!(" 115200 ".parse::<Baud>().unwrap(), Baud(115200));
+        assert_eq!(Baud::default().as_u32(), 19200);
+        assert!(STANDARD_BAUD_RATES.iter().all(|&rate| Baud::try_from(rate).is_ok()));
+
+        let error = Baud::try_from(12345).unwrap_err().to_string();
+        assert!(error.contains("Unsupported baud rate 12345"));
+        assert!(error.contains("supported rates: 1200, 2400, 4800, 9600, 19200"));
+        assert!(matches!("fast".parse::<Baud>(), Err(LumidoxError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,162 @@
+//! Structured auto-connection failure reports for Lumidox II Controller
+//!
+//! This module defines the report returned inside
+//! `LumidoxError::ConnectionFailed` when auto-connection gives up. It records
+//! everything that was tried (ports enumerated, baud rates probed, the
+//! verdict for each port and the number of connection attempts) so the user
+//! sees why each port was rejected instead of a single opaque message.
+
+use std::fmt;
+use std::time::Duration;
+
+/// Outcome of testing a single port during auto-connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PortVerdict {
+    /// No baud rate produced a response
+    NoResponse,
+    /// A device answered, but not at a usable baud rate
+    BaudMismatch(String),
+    /// Opening the port or initializing the device failed
+    ConnectFailed(String),
+    /// The detection time limit was reached before the port was tested
+    NotTested,
+    /// The port could not be opened, typically because another application holds it
+    Unavailable(String),
+}
+
+impl fmt::Display for PortVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoResponse => write!(f, "no response"),
+            Self::BaudMismatch(diagnosis) => write!(f, "{}", diagnosis),
+            Self::ConnectFailed(error) => write!(f, "{}", error),
+            Self::NotTested => write!(f, "not tested, detection time limit reached"),
+            Self::Unavailable(error) => write!(f, "skipped, port could not be opened: {}", error),
+        }
+    }
+}
+
+/// Verdict for one port candidate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortReport {
+    /// Port that was tested
+    pub port_name: String,
+    /// Why the port was rejected
+    pub verdict: PortVerdict,
+}
+
+/// Summary of a failed auto-connection
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionFailure {
+    /// Number of compatible ports found during enumeration
+    pub ports_enumerated: usize,
+    /// Distinct baud rates probed, in the order first probed
+    pub bauds_probed: Vec<u32>,
+    /// Verdict for each enumerated port
+    pub ports: Vec<PortReport>,
+    /// Number of port/baud rate combinations a connection was attempted on
+    pub attempts: u32,
+    /// Total time spent before giving up
+    pub elapsed: Duration,
+}
+
+impl ConnectionFailure {
+    /// Record the verdict for a port
+    ///
+    /// # Arguments
+    /// * `port_name` - Port that was tested
+    /// * `verdict` - Why the port was rejected
+    pub fn record_port(&mut self, port_name: &str, verdict: PortVerdict) {
+        self.ports.push(PortReport { port_name: port_name.to_string(), verdict });
+    }
+
+    /// Record a baud rate that was probed
+    ///
+    /// # Arguments
+    /// * `baud_rate` - Probed baud rate; repeated rates are recorded once
+    pub fn record_baud(&mut self, baud_rate: u32) {
+        if !self.bauds_probed.contains(&baud_rate) {
+            self.bauds_probed.push(baud_rate);
+        }
+    }
+
+    /// Format the full report for display
+    ///
+    /// # Returns
+    /// * `Vec<String>` - One line per port followed by the probe summary
+    ///
+    /// # Example
+    /// ```
+    /// if let Err(LumidoxError::ConnectionFailed(failure)) = AutoConnector::auto_connect(&config) {
+    ///     for line in failure.report_lines() {
+    ///         println!("{}", line);
+    ///     }
+    /// }
+    /// ```
+    pub fn report_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("Auto-connection failed after {:.1}s", self.elapsed.as_secs_f32())];
+        if self.ports.is_empty() {
+            lines.push("  No compatible serial ports found".to_string());
+        }
+        for port in &self.ports {
+            lines.push(format!("  {}: {}", port.port_name, port.verdict));
+        }
+        lines.push(format!("  Ports enumerated: {}", self.ports_enumerated));
+        lines.push(format!("  Baud rates probed: {}", self.bauds_summary()));
+        lines.push(format!("  Connection attempts: {}", self.attempts));
+        lines
+    }
+
+    /// Format the probed baud rates as a comma separated list
+    fn bauds_summary(&self) -> String {
+        if self.bauds_probed.is_empty() {
+            return "none".to_string();
+        }
+        self.bauds_probed.iter().map(|baud| baud.to_string()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl fmt::Display for ConnectionFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ports.is_empty() {
+            return write!(f, "no compatible serial ports found");
+        }
+
+        let tried: Vec<String> = self.ports.iter()
+            .map(|port| format!("{} ({})", port.port_name, port.verdict))
+            .collect();
+        write!(f, "tried {}; bauds probed: {}; {} attempt{}",
+            tried.join(", "),
+            self.bauds_summary(),
+            self.attempts,
+            if self.attempts == 1 { "" } else { "s" })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_lists_every_port_verdict() {
+        let mut failure = ConnectionFailure { ports_enumerated: 3, attempts: 2, ..ConnectionFailure::default() };
+        failure.record_baud(19200);
+        failure.record_baud(9600);
+        failure.record_baud(19200);
+        failure.record_port("COM1", PortVerdict::NoResponse);
+        failure.record_port("COM3", PortVerdict::ConnectFailed("Permission denied".to_string()));
+        failure.record_port("COM4", PortVerdict::NotTested);
+
+        assert_eq!(
+            failure.to_string(),
+            "tried COM1 (no response), COM3 (Permission denied), COM4 (not tested, detection time limit reached); \
+             bauds probed: 19200, 9600; 2 attempts"
+        );
+        assert_eq!(failure.report_lines().len(), 7);
+    }
+
+    #[test]
+    fn test_display_without_ports() {
+        assert_eq!(ConnectionFailure::default().to_string(), "no compatible serial ports found");
+    }
+}
@@ -0,0 +1,112 @@
+//! Device identity reading for Lumidox II Controller
+//!
+//! This module holds the single routine that reads and parses the device
+//! identity (firmware version, model, serial number, wavelength and maximum
+//! current). Connection, port and baud rate detection, and identity
+//! verification on reconnect all use it, so identity parsing and its error
+//! handling behave the same everywhere.
+
+use crate::core::{LumidoxError, Result};
+use crate::communication::protocol::{commands, utils, ProtocolHandler};
+use crate::device::models::DeviceInfo;
+use crate::device::operations::control::get_max_current;
+
+/// Read the identity of the connected device
+///
+/// # Arguments
+/// * `protocol` - Protocol handler connected to the device
+///
+/// # Returns
+/// * `Result<DeviceInfo>` - Device identity or communication error
+///
+/// # Example
+/// ```
+/// let info = probe_identity(&mut protocol)?;
+/// println!("Connected to {} ({})", info.model_number, info.serial_number);
+/// ```
+pub fn probe_identity(protocol: &mut ProtocolHandler) -> Result<DeviceInfo> {
+    let firmware_version = format!("1.{}", protocol.send_command(commands::FIRMWARE_VERSION, 0)?);
+    let model_number = utils::read_string_data(protocol, &commands::MODEL_COMMANDS)?;
+    let serial_number = utils::read_string_data(protocol, &commands::SERIAL_COMMANDS)?;
+    let wavelength = utils::read_string_data(protocol, &commands::WAVELENGTH_COMMANDS)?;
+    let max_current_ma = get_max_current(protocol)?;
+
+    Ok(DeviceInfo {
+        firmware_version,
+        model_number,
+        serial_number,
+        wavelength,
+        max_current_ma,
+    })
+}
+
+/// Read the device identity and check it is the expected unit
+///
+/// Used when reconnecting, so a different device that appeared on the
+/// same port is not silently used in place of the original one.
+///
+/// # Arguments
+/// * `protocol` - Protocol handler connected to the device
+/// * `expected_serial` - Serial number of the device previously connected
+///
+/// # Returns
+/// * `Result<DeviceInfo>` - Device identity, or `DeviceError` if the serial number differs
+///
+/// # Example
+/// ```
+/// let info = verify_identity(&mut protocol, &previous.serial_number)?;
+/// ```
+pub fn verify_identity(protocol: &mut ProtocolHandler, expected_serial: &str) -> Result<DeviceInfo> {
+    let info = probe_identity(protocol)?;
+    if info.serial_number != expected_serial {
+        return Err(LumidoxError::DeviceError(format!(
+            "Connected device serial number {} does not match expected {}",
+            info.serial_number, expected_serial
+        )));
+    }
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::communication::MockTransport;
+    use crate::communication::protocol::handler::CommandChecksumRejected;
+
+    fn device_protocol() -> ProtocolHandler {
+        let transport = MockTransport::new()
+            .expect_read(commands::FIRMWARE_VERSION, 7)
+            .expect_read_text(&commands::MODEL_COMMANDS, "LX2-365")
+            .expect_read_text(&commands::SERIAL_COMMANDS, "SN1234")
+            .expect_read_text(&commands::WAVELENGTH_COMMANDS, "365")
+            .expect_read(commands::STAGE_CURRENTS[4], 3000);
+        ProtocolHandler::with_transport(transport).unwrap()
+    }
+
+    #[test]
+    fn test_probe_identity_parses_device_responses() {
+        let info = probe_identity(&mut device_protocol()).unwrap();
+        assert_eq!(info.firmware_version, "1.7");
+        assert_eq!(info.model_number, "LX2-365");
+        assert_eq!(info.serial_number, "SN1234");
+        assert_eq!(info.wavelength, "365");
+        assert_eq!(info.max_current_ma, 3000);
+    }
+
+    #[test]
+    fn test_verify_identity_compares_serial_number() {
+        assert!(verify_identity(&mut device_protocol(), "SN1234").is_ok());
+        assert!(matches!(
+            verify_identity(&mut device_protocol(), "SN9999"),
+            Err(LumidoxError::DeviceError(_))
+        ));
+    }
+
+    #[test]
+    fn test_probe_identity_reports_rejected_checksum() {
+        let transport = MockTransport::new().expect_rejected(commands::FIRMWARE_VERSION);
+        let mut protocol = ProtocolHandler::with_transport(transport).unwrap();
+        let error = probe_identity(&mut protocol).unwrap_err();
+        assert!(CommandChecksumRejected::is_cause_of(&error));
+    }
+}
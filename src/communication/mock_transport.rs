@@ -0,0 +1,514 @@
+//! Scripted serial transport for testing without hardware
+//!
+//! `MockTransport` implements `SerialPort`, so a `ProtocolHandler` (and a
+//! `LumidoxDevice` built on it) can run against a script of expected command
+//! frames and canned responses instead of a physical controller. Tests
+//! preload request/response pairs, run the code under test, and call
+//! `assert_consumed` to check that every expected command was sent.
+//! Commands must arrive in the order they were scripted unless the
+//! transport is made `unordered`.
+//!
+//! Command frames are reassembled from partial writes, and responses can be
+//! delivered in small chunks to exercise response reassembly, or held back
+//! until the next command to simulate a response arriving after the read
+//! timed out. A command
+//! frame that is not the next one in the script fails the write with an
+//! `UnexpectedCommand` error, which `MockTransport::is_unexpected_command`
+//! recognizes.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use serialport::{ClearBuffer, DataBits, FlowControl, Parity, SerialPort, StopBits};
+use crate::core::LumidoxError;
+use super::protocol::constants::{CMD_TERMINATOR, RESPONSE_END};
+use super::protocol::handler::CommandTransmission;
+use super::protocol::handler::response::REJECTION_FRAME;
+
+/// Error returned when a command frame is not in the script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnexpectedCommand {
+    /// Complete command frame that was written, including the terminator
+    pub frame: Vec<u8>,
+}
+
+impl fmt::Display for UnexpectedCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unexpected command frame {:?}", String::from_utf8_lossy(&self.frame))
+    }
+}
+
+impl std::error::Error for UnexpectedCommand {}
+
+/// Script and I/O state shared between clones of a transport
+#[derive(Debug, Default)]
+struct Script {
+    /// Expected command frames, their responses and whether the response is late
+    expected: Vec<(Vec<u8>, Vec<u8>, bool)>,
+    /// Late response held back until the next command frame is written
+    held: Vec<u8>,
+    /// Bytes of the command frame being written
+    written: Vec<u8>,
+    /// Response bytes waiting to be read
+    pending: VecDeque<u8>,
+    /// Maximum number of bytes returned by a single read, if limited
+    chunk_size: Option<usize>,
+    /// Configured read timeout
+    timeout: Duration,
+    /// Whether a read with nothing to return fails with `TimedOut`
+    read_timeouts: bool,
+    /// Whether expected commands may arrive in any order
+    unordered: bool,
+}
+
+/// Serial transport answering scripted command frames
+///
+/// Clones share the same script, so a test can keep a clone to call
+/// `assert_consumed` after handing the transport to a `ProtocolHandler`.
+///
+/// # Example
+/// ```
+/// let transport = MockTransport::new().expect_read(b"02", 7);
+/// let mut protocol = ProtocolHandler::with_transport(transport.clone())?;
+/// assert_eq!(protocol.send_command(b"02", 0)?, 7);
+/// transport.assert_consumed();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    script: Arc<Mutex<Script>>,
+}
+
+impl MockTransport {
+    /// Create a transport with an empty script
+    ///
+    /// # Returns
+    /// * `MockTransport` - Transport that fails every command until pairs are added
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expect a raw command frame and answer it with raw response bytes
+    ///
+    /// Each pair is consumed once, in the order the pairs were added. After
+    /// `unordered`, pairs may be matched in any order; when the same frame is
+    /// expected several times, responses are still returned in the order
+    /// they were added.
+    ///
+    /// # Arguments
+    /// * `request` - Complete command frame, including the terminator
+    /// * `response` - Bytes returned for the frame, including the end marker
+    ///
+    /// # Returns
+    /// * `MockTransport` - The transport with the pair added
+    pub fn expect(self, request: impl Into<Vec<u8>>, response: impl Into<Vec<u8>>) -> Self {
+        self.lock().expected.push((request.into(), response.into(), false));
+        self
+    }
+
+    /// Expect a read command that the device never answers
+    ///
+    /// # Arguments
+    /// * `command` - Two-character command code, e.g. `b"21"`
+    ///
+    /// # Returns
+    /// * `MockTransport` - The transport with the command added
+    pub fn expect_no_response(self, command: &[u8]) -> Self {
+        self.expect(Self::command_frame(command, 0), Vec::new())
+    }
+
+    /// Accept the expected commands in any order
+    ///
+    /// For code whose command order is not part of what is being tested.
+    ///
+    /// # Returns
+    /// * `MockTransport` - The transport matching commands in any order
+    pub fn unordered(self) -> Self {
+        self.lock().unordered = true;
+        self
+    }
+
+    /// Fail reads that have nothing to return with `io::ErrorKind::TimedOut`
+    ///
+    /// By default such a read returns no bytes. A serial port instead fails
+    /// the read once its timeout has passed, which this reproduces (without
+    /// waiting).
+    ///
+    /// # Returns
+    /// * `MockTransport` - The transport with timing-out reads
+    pub fn with_read_timeouts(self) -> Self {
+        self.lock().read_timeouts = true;
+        self
+    }
+
+    /// Expect a read command whose response arrives only after the read timed out
+    ///
+    /// Nothing can be read after the command is sent. The response is
+    /// delivered once the next command frame is written, ahead of that
+    /// command's own response, as a slow device would answer.
+    ///
+    /// # Arguments
+    /// * `command` - Two-character command code, e.g. `b"21"`
+    /// * `response` - Value returned by the device, late
+    ///
+    /// # Returns
+    /// * `MockTransport` - The transport with the pair added
+    pub fn expect_late_read(self, command: &[u8], response: u16) -> Self {
+        self.lock().expected.push((Self::command_frame(command, 0), Self::response_frame(response), true));
+        self
+    }
+
+    /// Expect a command with a value and answer it with a response value
+    ///
+    /// # Arguments
+    /// * `command` - Two-character command code, e.g. `b"41"`
+    /// * `value` - Value sent with the command
+    /// * `response` - Value returned by the device
+    ///
+    /// # Returns
+    /// * `MockTransport` - The transport with the pair added
+    pub fn expect_command(self, command: &[u8], value: u16, response: u16) -> Self {
+        self.expect(Self::command_frame(command, value), Self::response_frame(response))
+    }
+
+    /// Expect a read command (sent with value 0) and answer it with a value
+    ///
+    /// # Arguments
+    /// * `command` - Two-character command code, e.g. `b"21"`
+    /// * `response` - Value returned by the device
+    ///
+    /// # Returns
+    /// * `MockTransport` - The transport with the pair added
+    pub fn expect_read(self, command: &[u8], response: u16) -> Self {
+        self.expect_command(command, 0, response)
+    }
+
+    /// Expect reads of a string spread over several commands
+    ///
+    /// Each command returns the matching character of `text`; commands past
+    /// the end of the text return zero, as the device pads short strings.
+    ///
+    /// # Arguments
+    /// * `commands` - Commands reading one character each
+    /// * `text` - String the device reports
+    ///
+    /// # Returns
+    /// * `MockTransport` - The transport with one pair per command added
+    pub fn expect_read_text(mut self, commands: &[&[u8]], text: &str) -> Self {
+        for (index, command) in commands.iter().enumerate() {
+            let value = text.as_bytes().get(index).copied().map_or(0, u16::from);
+            self = self.expect_read(command, value);
+        }
+        self
+    }
+
+    /// Expect a read command and answer it with the controller's rejection frame
+    ///
+    /// The device sends this frame when a command arrives with an incorrect
+    /// checksum.
+    ///
+    /// # Arguments
+    /// * `command` - Two-character command code
+    ///
+    /// # Returns
+    /// * `MockTransport` - The transport with the pair added
+    pub fn expect_rejected(self, command: &[u8]) -> Self {
+        self.expect(Self::command_frame(command, 0), REJECTION_FRAME)
+    }
+
+    /// Deliver responses in reads of at most `chunk_size` bytes
+    ///
+    /// # Arguments
+    /// * `chunk_size` - Maximum bytes per read; values below 1 are treated as 1
+    ///
+    /// # Returns
+    /// * `MockTransport` - The transport delivering partial reads
+    pub fn chunked(self, chunk_size: usize) -> Self {
+        self.lock().chunk_size = Some(chunk_size.max(1));
+        self
+    }
+
+    /// Get the number of expected commands not yet sent
+    ///
+    /// # Returns
+    /// * `usize` - Remaining request/response pairs
+    pub fn remaining(&self) -> usize {
+        self.lock().expected.len()
+    }
+
+    /// Assert that every expected command was sent
+    ///
+    /// # Panics
+    /// Panics listing the unsent command frames if any remain.
+    pub fn assert_consumed(&self) {
+        let script = self.lock();
+        let unsent: Vec<String> = script.expected.iter()
+            .map(|(request, _, _)| format!("{:?}", String::from_utf8_lossy(request)))
+            .collect();
+        assert!(unsent.is_empty(), "expected commands not sent: {}", unsent.join(", "));
+    }
+
+    /// Check whether an error was caused by a command missing from the script
+    ///
+    /// # Arguments
+    /// * `error` - Error returned by the protocol handler
+    ///
+    /// # Returns
+    /// * `bool` - True if the error wraps `UnexpectedCommand`
+    pub fn is_unexpected_command(error: &LumidoxError) -> bool {
+        matches!(error, LumidoxError::IoError(e)
+            if e.get_ref().is_some_and(|inner| inner.is::<UnexpectedCommand>()))
+    }
+
+    /// Build the frame the protocol handler sends for a command, for use with `expect`
+    pub fn command_frame(command: &[u8], value: u16) -> Vec<u8> {
+        let mut frame = Vec::new();
+        CommandTransmission::format_command_into(&mut frame, command, value);
+        frame
+    }
+
+    /// Build a valid device response carrying a value
+    fn response_frame(value: u16) -> Vec<u8> {
+        let data = format!("{:04x}", value);
+        let checksum = data.bytes().fold(0u8, |sum, byte| sum.wrapping_add(byte));
+        let mut frame = vec![b'*'];
+        frame.extend(format!("{}{:02x}", data, checksum).bytes());
+        frame.push(RESPONSE_END);
+        frame
+    }
+
+    /// Lock the shared script, recovering from a test that panicked while holding it
+    fn lock(&self) -> MutexGuard<'_, Script> {
+        self.script.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Read for MockTransport {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut script = self.lock();
+        let limit = script.chunk_size.unwrap_or(usize::MAX);
+        let count = buf.len().min(script.pending.len()).min(limit);
+        if count == 0 && script.read_timeouts && !buf.is_empty() {
+            return Err(io::Error::from(io::ErrorKind::TimedOut));
+        }
+        for (slot, byte) in buf.iter_mut().zip(script.pending.drain(..count)) {
+            *slot = byte;
+        }
+        Ok(count)
+    }
+}
+
+impl Write for MockTransport {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut script = self.lock();
+        for &byte in buf {
+            script.written.push(byte);
+            if byte != CMD_TERMINATOR {
+                continue;
+            }
+
+            let frame = std::mem::take(&mut script.written);
+            let matched = if script.unordered {
+                script.expected.iter().position(|(request, _, _)| *request == frame)
+            } else {
+                script.expected.first().filter(|(request, _, _)| *request == frame).map(|_| 0)
+            };
+            match matched {
+                Some(index) => {
+                    let (_, response, late) = script.expected.remove(index);
+                    let held = std::mem::take(&mut script.held);
+                    script.pending.extend(held);
+                    if late {
+                        script.held = response;
+                    } else {
+                        script.pending.extend(response);
+                    }
+                }
+                None => return Err(io::Error::new(io::ErrorKind::InvalidInput, UnexpectedCommand { frame })),
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl SerialPort for MockTransport {
+    fn name(&self) -> Option<String> {
+        Some("MOCK".to_string())
+    }
+
+    fn baud_rate(&self) -> serialport::Result<u32> {
+        Ok(19200)
+    }
+
+    fn data_bits(&self) -> serialport::Result<DataBits> {
+        Ok(DataBits::Eight)
+    }
+
+    fn flow_control(&self) -> serialport::Result<FlowControl> {
+        Ok(FlowControl::None)
+    }
+
+    fn parity(&self) -> serialport::Result<Parity> {
+        Ok(Parity::None)
+    }
+
+    fn stop_bits(&self) -> serialport::Result<StopBits> {
+        Ok(StopBits::One)
+    }
+
+    fn timeout(&self) -> Duration {
+        self.lock().timeout
+    }
+
+    fn set_baud_rate(&mut self, _baud_rate: u32) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn set_data_bits(&mut self, _data_bits: DataBits) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn set_flow_control(&mut self, _flow_control: FlowControl) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn set_parity(&mut self, _parity: Parity) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn set_stop_bits(&mut self, _stop_bits: StopBits) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> serialport::Result<()> {
+        self.lock().timeout = timeout;
+        Ok(())
+    }
+
+    fn write_request_to_send(&mut self, _level: bool) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn write_data_terminal_ready(&mut self, _level: bool) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn read_clear_to_send(&mut self) -> serialport::Result<bool> {
+        Ok(true)
+    }
+
+    fn read_data_set_ready(&mut self) -> serialport::Result<bool> {
+        Ok(true)
+    }
+
+    fn read_ring_indicator(&mut self) -> serialport::Result<bool> {
+        Ok(false)
+    }
+
+    fn read_carrier_detect(&mut self) -> serialport::Result<bool> {
+        Ok(true)
+    }
+
+    fn bytes_to_read(&self) -> serialport::Result<u32> {
+        Ok(self.lock().pending.len() as u32)
+    }
+
+    fn bytes_to_write(&self) -> serialport::Result<u32> {
+        Ok(0)
+    }
+
+    fn clear(&self, buffer_to_clear: ClearBuffer) -> serialport::Result<()> {
+        let mut script = self.lock();
+        match buffer_to_clear {
+            ClearBuffer::Input => script.pending.clear(),
+            ClearBuffer::Output => script.written.clear(),
+            ClearBuffer::All => {
+                script.pending.clear();
+                script.written.clear();
+            }
+        }
+        Ok(())
+    }
+
+    fn try_clone(&self) -> serialport::Result<Box<dyn SerialPort>> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn set_break(&self) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn clear_break(&self) -> serialport::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::communication::ProtocolHandler;
+
+    #[test]
+    fn test_scripted_exchange_with_partial_reads() {
+        let transport = MockTransport::new()
+            .expect_command(b"41", 1000, 1000)
+            .expect_read(b"21", 1000)
+            .chunked(3);
+        let mut protocol = ProtocolHandler::with_transport(transport.clone()).unwrap();
+
+        assert_eq!(protocol.send_command(b"41", 1000).unwrap(), 1000);
+        assert_eq!(transport.remaining(), 1);
+        assert_eq!(protocol.send_command(b"21", 0).unwrap(), 1000);
+        transport.assert_consumed();
+    }
+
+    #[test]
+    fn test_unexpected_command_is_distinct_error() {
+        let transport = MockTransport::new().expect_read(b"21", 500);
+        let mut protocol = ProtocolHandler::with_transport(transport.clone()).unwrap();
+
+        let error = protocol.send_command(b"20", 0).unwrap_err();
+        assert!(MockTransport::is_unexpected_command(&error));
+        assert!(!MockTransport::is_unexpected_command(&LumidoxError::DeviceNotFound));
+        assert_eq!(transport.remaining(), 1);
+    }
+
+    #[test]
+    fn test_commands_must_arrive_in_script_order() {
+        let script = || MockTransport::new().expect_read(b"20", 200).expect_read(b"21", 1500);
+
+        let transport = script();
+        let mut protocol = ProtocolHandler::with_transport(transport.clone()).unwrap();
+        assert!(MockTransport::is_unexpected_command(&protocol.send_command(b"21", 0).unwrap_err()));
+        assert_eq!(transport.remaining(), 2);
+
+        let transport = script().unordered();
+        let mut protocol = ProtocolHandler::with_transport(transport.clone()).unwrap();
+        assert_eq!(protocol.send_command(b"21", 0).unwrap(), 1500);
+        assert_eq!(protocol.send_command(b"20", 0).unwrap(), 200);
+        transport.assert_consumed();
+    }
+
+    #[test]
+    fn test_partial_writes_are_reassembled() {
+        let mut transport = MockTransport::new().expect(b"ab\r".to_vec(), b"ok".to_vec());
+        transport.write_all(b"a").unwrap();
+        transport.write_all(b"b\r").unwrap();
+
+        let mut response = Vec::new();
+        transport.read_to_end(&mut response).unwrap();
+        assert_eq!(response, b"ok");
+        transport.assert_consumed();
+    }
+
+    #[test]
+    #[should_panic(expected = "expected commands not sent")]
+    fn test_assert_consumed_reports_unsent_commands() {
+        MockTransport::new().expect_read(b"02", 7).assert_consumed();
+    }
+}
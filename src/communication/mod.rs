@@ -2,15 +2,25 @@
 //!
 //! This module handles all communication-related functionality,
 //! including serial protocol handling, automated port detection,
-//! baud rate detection, and low-level device communication.
+//! baud rate detection, and low-level device communication. A scripted
+//! mock transport allows protocol code to be tested without hardware.
 
 pub mod protocol;
 pub mod port_detection;
 pub mod baud_detection;
 pub mod auto_connect;
+pub mod connection_failure;
+pub mod identity;
+pub mod model_check;
+pub mod mock_transport;
 
 // Re-export commonly used items for convenience
 pub use protocol::ProtocolHandler;
+pub use protocol::reference::{command_reference, CommandDoc, ResponseShape};
 pub use port_detection::{PortDetector, PortDetectionConfig};
-pub use baud_detection::{BaudDetector, BaudDetectionConfig};
-pub use auto_connect::{AutoConnector, ConnectionMethod};
+pub use baud_detection::{Baud, BaudDetector, BaudDetectionConfig, BaudResponse};
+pub use auto_connect::{AutoConnectConfig, AutoConnector, ConnectTimings, ConnectionMethod};
+pub use connection_failure::{ConnectionFailure, PortReport, PortVerdict};
+pub use identity::{probe_identity, verify_identity};
+pub use model_check::{ExpectedModel, ModelMismatchPolicy};
+pub use mock_transport::{MockTransport, UnexpectedCommand};
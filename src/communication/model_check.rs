@@ -0,0 +1,118 @@
+//! Expected device model checking for Lumidox II Controller
+//!
+//! This module checks the model number of a connected device against an
+//! expected model pattern. A mismatch either fails the connection (strict)
+//! or connects and reports a warning (lenient), so new model variants can
+//! be used while an unexpected device is still flagged.
+
+use crate::core::{LumidoxError, Result};
+use crate::device::models::DeviceInfo;
+
+/// How a device that does not match the expected model is handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModelMismatchPolicy {
+    /// Fail the connection with `LumidoxError::ModelMismatch`
+    #[default]
+    Strict,
+    /// Connect and report a warning
+    Lenient,
+}
+
+/// Expected model of the device to connect to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedModel {
+    /// Text the model number must contain, compared case-insensitively
+    pub pattern: String,
+    /// How a mismatch is handled
+    pub policy: ModelMismatchPolicy,
+}
+
+impl ExpectedModel {
+    /// Create an expected model pattern with a mismatch policy
+    ///
+    /// # Arguments
+    /// * `pattern` - Text the model number must contain, e.g. "LX2-365"
+    /// * `policy` - How a mismatch is handled
+    ///
+    /// # Returns
+    /// * `ExpectedModel` - The expected model
+    ///
+    /// # Example
+    /// ```
+    /// let expected = ExpectedModel::new("LX2", ModelMismatchPolicy::Lenient);
+    /// ```
+    pub fn new(pattern: impl Into<String>, policy: ModelMismatchPolicy) -> Self {
+        Self {
+            pattern: pattern.into(),
+            policy,
+        }
+    }
+
+    /// Check whether a model number matches the pattern
+    ///
+    /// # Arguments
+    /// * `model_number` - Model number reported by the device
+    ///
+    /// # Returns
+    /// * `bool` - True if the model number contains the pattern, ignoring case
+    pub fn matches(&self, model_number: &str) -> bool {
+        model_number.to_lowercase().contains(&self.pattern.trim().to_lowercase())
+    }
+
+    /// Check a connected device against the expected model
+    ///
+    /// # Arguments
+    /// * `info` - Identity of the connected device
+    ///
+    /// # Returns
+    /// * `Result<Option<String>>` - None if the model matches, a warning for a
+    ///   lenient mismatch, or `ModelMismatch` for a strict one
+    ///
+    /// # Example
+    /// ```
+    /// if let Some(warning) = expected.check(&info)? {
+    ///     eprintln!("Warning: {}", warning);
+    /// }
+    /// ```
+    pub fn check(&self, info: &DeviceInfo) -> Result<Option<String>> {
+        if self.matches(&info.model_number) {
+            return Ok(None);
+        }
+        let mismatch = LumidoxError::ModelMismatch {
+            expected: self.pattern.clone(),
+            actual: info.model_number.clone(),
+        };
+        match self.policy {
+            ModelMismatchPolicy::Strict => Err(mismatch),
+            ModelMismatchPolicy::Lenient => Ok(Some(mismatch.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(model_number: &str) -> DeviceInfo {
+        DeviceInfo {
+            firmware_version: "1.7".to_string(),
+            model_number: model_number.to_string(),
+            serial_number: "SN1234".to_string(),
+            wavelength: "365".to_string(),
+            max_current_ma: 3000,
+        }
+    }
+
+    #[test]
+    fn test_mismatch_policy() {
+        let strict = ExpectedModel::new("lx2-365", ModelMismatchPolicy::Strict);
+        assert_eq!(strict.check(&info("LX2-365-A")).unwrap(), None);
+        assert!(matches!(strict.check(&info("LX2-405")), Err(LumidoxError::ModelMismatch { .. })));
+
+        let lenient = ExpectedModel::new("LX2-365", ModelMismatchPolicy::Lenient);
+        assert_eq!(
+            lenient.check(&info("LX2-405")).unwrap().as_deref(),
+            Some("Connected to model LX2-405, expected LX2-365")
+        );
+    }
+}
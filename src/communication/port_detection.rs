@@ -7,12 +7,13 @@
 //! The port detection system provides:
 //! - Automatic scanning of available serial ports
 //! - Filtering for USB Serial Port devices (FTDI-based)
+//! - Optional USB VID/PID allow-list, applied before any bytes are sent
 //! - Device identification through protocol commands
 //! - Ranking of candidate ports by compatibility score
 
 use crate::core::{LumidoxError, Result};
 use serialport::{SerialPortInfo, SerialPortType};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Port detection configuration and settings
 #[derive(Debug, Clone)]
@@ -54,6 +55,8 @@ pub struct PortCandidate {
     pub device_details: Option<DeviceIdentification>,
     /// Reason for compatibility score
     pub score_reason: String,
+    /// Time spent probing the port for device identification
+    pub identification_time: Duration,
 }
 
 /// Device identification information
@@ -93,6 +96,29 @@ impl PortDetector {
     /// }
     /// ```
     pub fn detect_ports(config: &PortDetectionConfig) -> Result<Vec<PortCandidate>> {
+        Self::detect_ports_matching(config, &[])
+    }
+    
+    /// Detect compatible ports, restricted to allowed USB VID/PID pairs
+    /// 
+    /// Like `detect_ports`, but when `usb_filter` is non-empty any port that
+    /// is not a USB port with a listed VID/PID pair is skipped before device
+    /// identification, so unrelated instruments never receive handshake bytes.
+    /// An empty filter behaves exactly like `detect_ports`.
+    /// 
+    /// # Arguments
+    /// * `config` - Detection configuration settings
+    /// * `usb_filter` - Allowed (VID, PID) pairs; empty to allow every port
+    /// 
+    /// # Returns
+    /// * `Result<Vec<PortCandidate>>` - List of port candidates sorted by score
+    /// 
+    /// # Example
+    /// ```
+    /// // Only FTDI FT232R adapters
+    /// let candidates = PortDetector::detect_ports_matching(&config, &[(0x0403, 0x6001)])?;
+    /// ```
+    pub fn detect_ports_matching(config: &PortDetectionConfig, usb_filter: &[(u16, u16)]) -> Result<Vec<PortCandidate>> {
         let available_ports = serialport::available_ports()
             .map_err(|e| LumidoxError::SerialError(e))?;
         
@@ -103,16 +129,21 @@ impl PortDetector {
             if config.usb_ports_only && !Self::is_usb_port(&port_info) {
                 continue;
             }
+            if !Self::matches_usb_filter(&port_info, usb_filter) {
+                continue;
+            }
             
             // Calculate compatibility score
             let compatibility_score = Self::calculate_compatibility_score(&port_info, config);
             
             // Test device identification if enabled
+            let identification_start = Instant::now();
             let (device_identified, device_details) = if config.test_device_identification {
                 Self::test_device_identification(&port_info, config)
             } else {
                 (false, None)
             };
+            let identification_time = identification_start.elapsed();
             
             let score_reason = Self::generate_score_reason(&port_info, compatibility_score, device_identified);
             
@@ -122,6 +153,7 @@ impl PortDetector {
                 device_identified,
                 device_details,
                 score_reason,
+                identification_time,
             });
         }
         
@@ -168,6 +200,24 @@ impl PortDetector {
         matches!(port_info.port_type, SerialPortType::UsbPort(_))
     }
     
+    /// Check if a port is allowed by a USB VID/PID filter
+    /// 
+    /// # Arguments
+    /// * `port_info` - Serial port information
+    /// * `usb_filter` - Allowed (VID, PID) pairs; empty to allow every port
+    /// 
+    /// # Returns
+    /// * `bool` - True if the filter is empty or the port's VID/PID pair is listed
+    pub fn matches_usb_filter(port_info: &SerialPortInfo, usb_filter: &[(u16, u16)]) -> bool {
+        if usb_filter.is_empty() {
+            return true;
+        }
+        match &port_info.port_type {
+            SerialPortType::UsbPort(usb) => usb_filter.contains(&(usb.vid, usb.pid)),
+            _ => false,
+        }
+    }
+    
     /// Calculate compatibility score for a port
     /// 
     /// Assigns a compatibility score (0-100) based on port characteristics
@@ -251,7 +301,7 @@ impl PortDetector {
         match crate::communication::ProtocolHandler::new(port) {
             Ok(mut protocol) => {
                 // Test if we can read device info
-                let device_info_test = crate::device::info::read_device_info(&mut protocol);
+                let device_info_test = crate::communication::probe_identity(&mut protocol);
 
                 if device_info_test.is_ok() {
                     // Device responds to protocol, extract details
@@ -370,3 +420,26 @@ impl PortDetector {
         Ok(details)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serialport::UsbPortInfo;
+
+    fn port(port_type: SerialPortType) -> SerialPortInfo {
+        SerialPortInfo { port_name: "COM3".to_string(), port_type }
+    }
+
+    fn usb(vid: u16, pid: u16) -> SerialPortType {
+        SerialPortType::UsbPort(UsbPortInfo { vid, pid, serial_number: None, manufacturer: None, product: None })
+    }
+
+    #[test]
+    fn test_usb_filter_allows_only_listed_pairs() {
+        let ftdi = [(0x0403, 0x6001)];
+        assert!(PortDetector::matches_usb_filter(&port(usb(0x0403, 0x6001)), &ftdi));
+        assert!(!PortDetector::matches_usb_filter(&port(usb(0x0403, 0x6015)), &ftdi));
+        assert!(!PortDetector::matches_usb_filter(&port(SerialPortType::PciPort), &ftdi));
+        assert!(PortDetector::matches_usb_filter(&port(SerialPortType::PciPort), &[]));
+    }
+}
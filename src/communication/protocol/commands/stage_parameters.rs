@@ -2,6 +2,91 @@
 //!
 //! This module contains protocol commands for reading and configuring
 //! stage-specific parameters including currents and voltage settings.
+//!
+//! Stage numbers are not sent on the wire. Each stage owns a block of eight
+//! consecutive single-byte command codes starting at 0x77 for stage 1, and a
+//! command is addressed to a stage by picking the code in that stage's block.
+//! `stage_command` derives these codes and refuses stage numbers whose block
+//! would not fit in the single-byte command field.
+
+/// Number of stages on the Lumidox II
+pub const STAGE_COUNT: u8 = 5;
+
+/// Command code of the first parameter of stage 1 (ARM current)
+const STAGE_BLOCK_BASE: u8 = 0x77;
+
+/// Distance between the command codes of the same parameter on consecutive stages
+const STAGE_BLOCK_STRIDE: u8 = 8;
+
+/// Per-stage parameter, in command block order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageParameter {
+    /// ARM current (0x77 for stage 1)
+    ArmCurrent = 0,
+    /// FIRE current (0x78 for stage 1)
+    FireCurrent = 1,
+    /// Voltage limit (0x79 for stage 1)
+    VoltLimit = 2,
+    /// Voltage start (0x7a for stage 1)
+    VoltStart = 3,
+    /// Total power (0x7b for stage 1)
+    PowerTotal = 4,
+    /// Per-LED power (0x7c for stage 1)
+    PowerPerLed = 5,
+    /// Total power units (0x7d for stage 1)
+    TotalUnits = 6,
+    /// Per-LED power units (0x7e for stage 1)
+    PerLedUnits = 7,
+}
+
+impl StageParameter {
+    /// Every stage parameter, in command block order
+    pub const ALL: [StageParameter; 8] = [
+        StageParameter::ArmCurrent,
+        StageParameter::FireCurrent,
+        StageParameter::VoltLimit,
+        StageParameter::VoltStart,
+        StageParameter::PowerTotal,
+        StageParameter::PowerPerLed,
+        StageParameter::TotalUnits,
+        StageParameter::PerLedUnits,
+    ];
+}
+
+/// Encode the command for a stage parameter
+///
+/// # Arguments
+/// * `parameter` - Parameter to address
+/// * `stage_num` - Stage number, starting at 1
+///
+/// # Returns
+/// * `Option<[u8; 2]>` - Command as two lowercase hex digits, `None` for stage 0
+///   or a stage whose command code would not fit in a single byte
+///
+/// # Example
+/// ```
+/// assert_eq!(stage_command(StageParameter::FireCurrent, 3), Some(*b"88"));
+/// ```
+pub fn stage_command(parameter: StageParameter, stage_num: u8) -> Option<[u8; 2]> {
+    let block_offset = stage_num.checked_sub(1)?.checked_mul(STAGE_BLOCK_STRIDE)?;
+    let code = STAGE_BLOCK_BASE
+        .checked_add(parameter as u8)?
+        .checked_add(block_offset)?;
+
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    Some([HEX_DIGITS[usize::from(code >> 4)], HEX_DIGITS[usize::from(code & 0xf)]])
+}
+
+/// Check whether every parameter of a stage can be encoded
+///
+/// # Arguments
+/// * `stage_num` - Stage number, starting at 1
+///
+/// # Returns
+/// * `bool` - True if all commands in the stage's block fit in a single byte
+pub fn is_stage_encodable(stage_num: u8) -> bool {
+    stage_command(StageParameter::PerLedUnits, stage_num).is_some()
+}
 
 /// Stage FIRE current commands
 /// 
@@ -50,3 +135,38 @@ pub const STAGE_VOLT_STARTS: [&[u8]; 5] = [
     b"92", // Stage 4
     b"9a", // Stage 5
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_command_wire_bytes() {
+        assert_eq!(stage_command(StageParameter::ArmCurrent, 1), Some(*b"77"));
+        assert_eq!(stage_command(StageParameter::FireCurrent, 3), Some(*b"88"));
+        assert_eq!(stage_command(StageParameter::PowerPerLed, 4), Some(*b"94"));
+        assert_eq!(stage_command(StageParameter::PerLedUnits, 5), Some(*b"9e"));
+        assert_eq!(stage_command(StageParameter::VoltStart, 0), None);
+    }
+
+    #[test]
+    fn test_stage_command_matches_command_tables() {
+        for stage in 1..=STAGE_COUNT {
+            let index = usize::from(stage - 1);
+            assert_eq!(&stage_command(StageParameter::ArmCurrent, stage).unwrap()[..], STAGE_ARM_CURRENTS[index]);
+            assert_eq!(&stage_command(StageParameter::FireCurrent, stage).unwrap()[..], STAGE_CURRENTS[index]);
+            assert_eq!(&stage_command(StageParameter::VoltLimit, stage).unwrap()[..], STAGE_VOLT_LIMITS[index]);
+            assert_eq!(&stage_command(StageParameter::VoltStart, stage).unwrap()[..], STAGE_VOLT_STARTS[index]);
+        }
+    }
+
+    #[test]
+    fn test_high_stage_numbers_are_not_misencoded() {
+        // Stage 17 ends at 0xfe; stage 18 would wrap past 0xff
+        assert!(is_stage_encodable(17));
+        assert_eq!(stage_command(StageParameter::PerLedUnits, 17), Some(*b"fe"));
+        assert!(!is_stage_encodable(18));
+        assert_eq!(stage_command(StageParameter::FireCurrent, 18), None);
+        assert_eq!(stage_command(StageParameter::ArmCurrent, u8::MAX), None);
+    }
+}
@@ -19,3 +19,12 @@ pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(1000);
 
 /// Default baud rate
 pub const DEFAULT_BAUD_RATE: u32 = 19200;
+
+/// Timeout for control commands (mode changes, arming, firing, current setting)
+pub const CONTROL_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Timeout for single-value state readback commands
+pub const READBACK_TIMEOUT: Duration = DEFAULT_TIMEOUT;
+
+/// Timeout for characterization commands (stage parameters and device information)
+pub const CHARACTERIZATION_TIMEOUT: Duration = Duration::from_millis(2500);
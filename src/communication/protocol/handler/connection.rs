@@ -224,8 +224,7 @@ impl ConnectionManager {
     /// ```
     pub fn clear_port_buffers(port: &mut Box<dyn SerialPort>) -> Result<()> {
         // Clear input buffer
-        port.clear(serialport::ClearBuffer::Input)
-            .map_err(LumidoxError::SerialError)?;
+        Self::clear_input_buffer(port)?;
         
         // Clear output buffer
         port.clear(serialport::ClearBuffer::Output)
@@ -234,6 +233,26 @@ impl ConnectionManager {
         Ok(())
     }
     
+    /// Clear the port input buffer
+    /// 
+    /// Discards any bytes received but not yet read, such as the remains
+    /// of a response from a failed transaction.
+    /// 
+    /// # Arguments
+    /// * `port` - Mutable reference to the serial port
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error during buffer clearing
+    /// 
+    /// # Example
+    /// ```
+    /// ConnectionManager::clear_input_buffer(&mut port)?;
+    /// ```
+    pub fn clear_input_buffer(port: &mut Box<dyn SerialPort>) -> Result<()> {
+        port.clear(serialport::ClearBuffer::Input)
+            .map_err(LumidoxError::SerialError)
+    }
+    
     /// Get safe mutable access to the serial port
     /// 
     /// Provides controlled access to the underlying serial port
@@ -7,6 +7,7 @@
 //! - response: Response reading, parsing, and data interpretation
 //! - connection: Serial port connection management and configuration
 //! - validation: Protocol validation, integrity checking, and error detection
+//! - timeouts: Per-command-type timeout selection
 //! 
 //! The handler system provides:
 //! - Reliable command transmission with proper protocol formatting
@@ -15,20 +16,26 @@
 //! - Comprehensive protocol validation and error detection
 //! - Seamless integration maintaining the existing public API
 
-use crate::core::Result;
+use crate::core::{LumidoxError, Result};
+use log::debug;
 use serialport::SerialPort;
+use std::time::Duration;
+use super::constants::RESPONSE_END;
+use super::utils::verify_checksum;
 
 // Import specialized sub-modules
 pub mod transmission;
 pub mod response;
 pub mod connection;
 pub mod validation;
+pub mod timeouts;
 
 // Re-export commonly used items for convenience
-pub use transmission::{CommandTransmission, CommandTransmissionStats};
+pub use transmission::{CommandTransmission, CommandTransmissionStats, FRAME_CAPACITY};
 pub use response::ResponseProcessor;
 pub use connection::{ConnectionManager, ConnectionInfo, ConnectionHealth};
-pub use validation::{ProtocolValidator, ValidationReport};
+pub use validation::{ChecksumMismatch, CommandChecksumRejected, ProtocolValidator, ValidationReport};
+pub use timeouts::{CommandCategory, CommandTimeouts};
 
 /// Low-level protocol handler with enhanced modular architecture
 /// 
@@ -38,6 +45,20 @@ pub use validation::{ProtocolValidator, ValidationReport};
 /// providing improved internal organization and maintainability.
 pub struct ProtocolHandler {
     port: Box<dyn SerialPort>,
+    /// Timeout table used to select the read timeout for each command
+    timeouts: CommandTimeouts,
+    /// Reusable buffer for outgoing command frames
+    frame_buffer: Vec<u8>,
+    /// Reusable buffer for reassembling incoming responses
+    response_buffer: Vec<u8>,
+    /// Whether to clear the input buffer before every command
+    flush_before_send: bool,
+    /// Whether the last command failed, so the next one must flush first
+    flush_pending: bool,
+    /// Commands whose read timed out before a complete response arrived
+    late_responses: usize,
+    /// Responses rejected for a checksum mismatch, carried over on reconnect
+    pub(crate) checksum_failures: usize,
 }
 
 impl ProtocolHandler {
@@ -59,7 +80,37 @@ impl ProtocolHandler {
     /// ```
     pub fn new(port: Box<dyn SerialPort>) -> Result<Self> {
         let configured_port = ConnectionManager::initialize_connection(port)?;
-        Ok(ProtocolHandler { port: configured_port })
+        Ok(ProtocolHandler {
+            port: configured_port,
+            timeouts: CommandTimeouts::default(),
+            frame_buffer: Vec::with_capacity(FRAME_CAPACITY),
+            response_buffer: Vec::with_capacity(FRAME_CAPACITY),
+            flush_before_send: false,
+            flush_pending: false,
+            late_responses: 0,
+            checksum_failures: 0,
+        })
+    }
+    
+    /// Create a protocol handler over any serial transport
+    /// 
+    /// Accepts an unboxed transport, such as `MockTransport`, so tests can
+    /// build a handler or device without opening a port with
+    /// `serialport::new`.
+    /// 
+    /// # Arguments
+    /// * `transport` - The transport to use for communication
+    /// 
+    /// # Returns
+    /// * `Result<Self>` - The configured protocol handler or error
+    /// 
+    /// # Example
+    /// ```
+    /// let transport = MockTransport::new().expect_read(b"02", 7);
+    /// let handler = ProtocolHandler::with_transport(transport)?;
+    /// ```
+    pub fn with_transport(transport: impl SerialPort + 'static) -> Result<Self> {
+        Self::new(Box::new(transport))
     }
     
     /// Send a command and receive response
@@ -67,6 +118,11 @@ impl ProtocolHandler {
     /// This is the main public interface for protocol communication.
     /// It uses the transmission and response modules internally while
     /// maintaining the exact same API as the original implementation.
+    /// The read timeout is selected from the command timeout table based
+    /// on the command being sent. The input buffer is flushed first if
+    /// flush-before-send is enabled or the previous command failed, and a
+    /// late response to an earlier timed-out command is discarded instead
+    /// of being returned as this command's response.
     /// 
     /// # Arguments
     /// * `command` - The command bytes to send
@@ -81,11 +137,289 @@ impl ProtocolHandler {
     /// println!("Device returned: {}", result);
     /// ```
     pub fn send_command(&mut self, command: &[u8], value: u16) -> Result<i32> {
-        // Use transmission module to send the command
-        CommandTransmission::send_formatted_command(&mut self.port, command, value)?;
+        let timeout = self.timeouts.for_command(command);
+        self.exchange(command, value, timeout)?;
+        Ok(ResponseProcessor::convert_hex_response_to_decimal(&self.response_buffer))
+    }
+    
+    /// Send a command and receive response using an explicit timeout
+    /// 
+    /// Overrides the timeout table for a single call, for callers that know
+    /// a particular exchange needs more or less time than its command type.
+    /// The previous port timeout is restored afterwards, including when the
+    /// exchange fails.
+    /// 
+    /// # Arguments
+    /// * `command` - The command bytes to send
+    /// * `value` - The value parameter for the command
+    /// * `timeout` - The read timeout to use for this command
+    /// 
+    /// # Returns
+    /// * `Result<i32>` - The response value or error
+    /// 
+    /// # Example
+    /// ```
+    /// let result = handler.send_command_with_timeout(&[0x02], 0, Duration::from_secs(5))?;
+    /// ```
+    pub fn send_command_with_timeout(&mut self, command: &[u8], value: u16, timeout: Duration) -> Result<i32> {
+        let previous = self.port.timeout();
+        let result = self.exchange(command, value, timeout)
+            .map(|()| ResponseProcessor::convert_hex_response_to_decimal(&self.response_buffer));
+        if self.port.timeout() != previous {
+            let restored = ConnectionManager::configure_timeout(&mut self.port, previous);
+            // A failed exchange is the more useful error to report
+            if result.is_ok() {
+                restored?;
+            }
+        }
+        result
+    }
+    
+    /// Send a command and receive its raw response payload
+    /// 
+    /// For experimenting with commands that `LumidoxDevice` does not wrap.
+    /// The command is framed like every other command:
+    /// 
+    /// `'*'`, the command as two lowercase hex characters, the data as four
+    /// hex characters, a two-character checksum, then `'\r'`
+    /// 
+    /// `data` holds the 16-bit data value, most significant byte first; it
+    /// may be empty (value 0), one byte, or two bytes. The response
+    /// `*DDDDSS^` is returned without its start and end markers, so the
+    /// payload is the four data characters followed by the device's two
+    /// checksum characters, exactly as received. A response of `*XXXX60^`
+    /// fails with a retryable `CommunicationError` caused by
+    /// `CommandChecksumRejected`.
+    /// 
+    /// # Arguments
+    /// * `command` - Command code, e.g. `0x13`
+    /// * `data` - Data value bytes, most significant first (at most two)
+    /// 
+    /// # Returns
+    /// * `Result<Vec<u8>>` - Response payload, `InvalidInput` for more than two data bytes, or protocol error
+    /// 
+    /// # Example
+    /// ```
+    /// let payload = handler.send_raw(0x13, &[])?;
+    /// let data = std::str::from_utf8(&payload[..4])?;
+    /// println!("Remote mode state: 0x{}", data);
+    /// ```
+    pub fn send_raw(&mut self, command: u8, data: &[u8]) -> Result<Vec<u8>> {
+        if data.len() > 2 {
+            return Err(LumidoxError::InvalidInput(format!(
+                "Command data is a 16-bit value, got {} bytes", data.len()
+            )));
+        }
+        let value = data.iter().fold(0u16, |value, &byte| (value << 8) | u16::from(byte));
+        let code = format!("{:02x}", command);
+        let timeout = self.timeouts.for_command(code.as_bytes());
+        self.exchange(code.as_bytes(), value, timeout)?;
         
-        // Use response module to read and process the response
-        ResponseProcessor::read_and_process_response(&mut self.port)
+        let payload = self.response_buffer.strip_suffix(&[RESPONSE_END]).unwrap_or(&self.response_buffer);
+        Ok(payload.strip_prefix(b"*").unwrap_or(payload).to_vec())
+    }
+    
+    /// Send a command with the given read timeout, leaving that timeout configured
+    /// 
+    /// On success the validated response is left in the response buffer.
+    fn exchange(&mut self, command: &[u8], value: u16, timeout: Duration) -> Result<()> {
+        if self.port.timeout() != timeout {
+            ConnectionManager::configure_timeout(&mut self.port, timeout)?;
+        }
+        
+        // Start from a clean buffer so stale bytes cannot corrupt this response
+        if self.flush_before_send || self.flush_pending {
+            self.flush_input()?;
+        }
+        
+        let result = self.transact(command, value);
+        self.flush_pending |= result.is_err();
+        result
+    }
+    
+    /// Send a command and read and validate its response without any flushing
+    /// 
+    /// A well-formed response whose checksum does not match its data fails
+    /// with a `CommunicationError` caused by `ChecksumMismatch`, so corrupted
+    /// data is never returned as a value. The device's incorrect-checksum
+    /// reply `*XXXX60^` fails with one caused by `CommandChecksumRejected`.
+    fn transact(&mut self, command: &[u8], value: u16) -> Result<()> {
+        // Use transmission module to send the command, reusing the frame buffer
+        CommandTransmission::send_formatted_command_with_buffer(&mut self.port, &mut self.frame_buffer, command, value)?;
+        
+        // Use response module to read and process the response, reusing the response buffer
+        let read = ResponseProcessor::read_raw_response_into(self.port.as_mut(), &mut self.response_buffer);
+        if self.response_buffer.last() != Some(&RESPONSE_END) {
+            // The rest of the response may still arrive, ahead of the next command's
+            self.late_responses += 1;
+            debug!("0x{} value {}: incomplete response {:?}, {} late response(s) outstanding",
+                String::from_utf8_lossy(command), value, String::from_utf8_lossy(&self.response_buffer), self.late_responses);
+        } else if self.late_responses > 0 {
+            self.discard_late_responses();
+        }
+        read.map_err(|e| self.timeout_error(e, command))?;
+        debug!("0x{} value {} -> {:?}", String::from_utf8_lossy(command), value, String::from_utf8_lossy(&self.response_buffer));
+        if ResponseProcessor::is_rejection(&self.response_buffer) {
+            let command = format!("0x{}", String::from_utf8_lossy(command));
+            return Err(LumidoxError::communication(
+                format!("Device reported an incorrect checksum in command {}", command),
+                CommandChecksumRejected { command },
+            ));
+        }
+        ResponseProcessor::validate_response_format(&self.response_buffer)?;
+        if !verify_checksum(&self.response_buffer) {
+            self.checksum_failures += 1;
+            debug!("0x{}: checksum mismatch, {} this session", String::from_utf8_lossy(command), self.checksum_failures);
+            return Err(LumidoxError::communication(
+                format!("Checksum mismatch in response to command 0x{}", String::from_utf8_lossy(command)),
+                ChecksumMismatch { frame: self.response_buffer.clone() },
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Get the number of responses rejected for a checksum mismatch
+    /// 
+    /// Counts every mismatch since the port was opened. A steadily rising
+    /// count points at a flaky cable or electrical noise.
+    /// 
+    /// # Returns
+    /// * `usize` - Checksum failures this session
+    /// 
+    /// # Example
+    /// ```
+    /// println!("{} corrupted responses", handler.checksum_failure_count());
+    /// ```
+    pub fn checksum_failure_count(&self) -> usize {
+        self.checksum_failures
+    }
+    
+    /// Turn a read that timed out into `LumidoxError::Timeout`
+    /// 
+    /// Other errors, such as a response that ended early, are returned unchanged.
+    fn timeout_error(&self, error: LumidoxError, command: &[u8]) -> LumidoxError {
+        match error {
+            LumidoxError::IoError(e) if e.kind() == std::io::ErrorKind::TimedOut => LumidoxError::Timeout {
+                operation: format!("response to command 0x{}", String::from_utf8_lossy(command)),
+                waited: self.port.timeout(),
+            },
+            error => error,
+        }
+    }
+    
+    /// Skip late responses read ahead of the current command's response
+    /// 
+    /// The device answers commands in order, so the late response to a
+    /// timed-out command is always read before the response to the command
+    /// that follows it. While responses are outstanding, a frame followed by
+    /// another complete frame is a late one and is discarded. Once no further
+    /// frame follows, the last frame read is the current command's response
+    /// and nothing is outstanding any more, as a late response that arrived
+    /// before the command was sent has been flushed.
+    /// 
+    /// This costs one extra read timeout on the first command after a
+    /// timeout, and nothing otherwise.
+    fn discard_late_responses(&mut self) {
+        let mut next = Vec::with_capacity(FRAME_CAPACITY);
+        while self.late_responses > 0 {
+            let complete = ResponseProcessor::read_raw_response_into(self.port.as_mut(), &mut next).is_ok()
+                && next.last() == Some(&RESPONSE_END);
+            if !complete {
+                // Bytes of an unknown frame cannot be matched, so drop them before the next command
+                self.flush_pending = !next.is_empty();
+                self.late_responses = 0;
+                break;
+            }
+            debug!("Discarded late response {:?}", String::from_utf8_lossy(&self.response_buffer));
+            std::mem::swap(&mut self.response_buffer, &mut next);
+            self.late_responses -= 1;
+        }
+    }
+    
+    /// Clear the serial input buffer
+    /// 
+    /// Discards bytes left over from a previous transaction, such as the
+    /// late response to a command that timed out.
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or buffer clearing error
+    /// 
+    /// # Example
+    /// ```
+    /// handler.flush_input()?;
+    /// ```
+    pub fn flush_input(&mut self) -> Result<()> {
+        ConnectionManager::clear_input_buffer(&mut self.port)?;
+        self.flush_pending = false;
+        Ok(())
+    }
+    
+    /// Check whether the input buffer is flushed before every command
+    /// 
+    /// # Returns
+    /// * `bool` - True if every command flushes first; false if only commands after an error do
+    pub fn is_flush_before_send(&self) -> bool {
+        self.flush_before_send
+    }
+    
+    /// Set whether to flush the input buffer before every command
+    /// 
+    /// The command following a failed command is always flushed first,
+    /// regardless of this setting.
+    /// 
+    /// # Arguments
+    /// * `flush` - True to flush before every command
+    /// 
+    /// # Example
+    /// ```
+    /// handler.set_flush_before_send(true);
+    /// ```
+    pub fn set_flush_before_send(&mut self, flush: bool) {
+        self.flush_before_send = flush;
+    }
+    
+    /// Get the command timeout table
+    /// 
+    /// # Returns
+    /// * `&CommandTimeouts` - The timeouts used for each command category
+    /// 
+    /// # Example
+    /// ```
+    /// let fire_timeout = handler.command_timeouts().control;
+    /// ```
+    pub fn command_timeouts(&self) -> &CommandTimeouts {
+        &self.timeouts
+    }
+    
+    /// Replace the command timeout table
+    /// 
+    /// # Arguments
+    /// * `timeouts` - The timeouts to use for each command category
+    /// 
+    /// # Example
+    /// ```
+    /// let mut timeouts = CommandTimeouts::default();
+    /// timeouts.set_category(CommandCategory::Characterization, Duration::from_secs(5));
+    /// handler.set_command_timeouts(timeouts);
+    /// ```
+    pub fn set_command_timeouts(&mut self, timeouts: CommandTimeouts) {
+        self.timeouts = timeouts;
+    }
+    
+    /// Use the same timeout for every command
+    /// 
+    /// Convenience for slow adapters where every command needs more time.
+    /// Equivalent to `set_command_timeouts(CommandTimeouts::uniform(timeout))`.
+    /// 
+    /// # Arguments
+    /// * `timeout` - The read timeout for all commands
+    /// 
+    /// # Example
+    /// ```
+    /// handler.set_command_timeout(Duration::from_secs(2));
+    /// ```
+    pub fn set_command_timeout(&mut self, timeout: Duration) {
+        self.timeouts = CommandTimeouts::uniform(timeout);
     }
     
     /// Calculate checksum for command data
@@ -244,3 +578,118 @@ impl ProtocolHandler {
         ConnectionManager::test_connection(&mut self.port)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::communication::MockTransport;
+
+    #[test]
+    fn test_timeout_override_restored_after_error() {
+        let transport = MockTransport::new().expect_read(b"21", 1000);
+        let mut handler = ProtocolHandler::with_transport(transport.clone()).unwrap();
+        let previous = transport.timeout();
+        let slow = previous + Duration::from_secs(2);
+
+        assert_eq!(handler.send_command_with_timeout(b"21", 0, slow).unwrap(), 1000);
+        assert_eq!(transport.timeout(), previous);
+
+        assert!(handler.send_command_with_timeout(b"20", 0, slow).is_err());
+        assert_eq!(transport.timeout(), previous);
+    }
+
+    #[test]
+    fn test_set_command_timeout_applies_to_every_command() {
+        let mut handler = ProtocolHandler::with_transport(MockTransport::new()).unwrap();
+        handler.set_command_timeout(Duration::from_secs(3));
+        assert_eq!(*handler.command_timeouts(), CommandTimeouts::uniform(Duration::from_secs(3)));
+        assert_eq!(handler.command_timeouts().for_command(b"15"), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_send_raw_frames_command_and_returns_payload() {
+        let transport = MockTransport::new()
+            .expect_command(b"7f", 0x0102, 0x00ab)
+            .expect_read(b"0a", 5)
+            .expect_rejected(b"5c");
+        let mut handler = ProtocolHandler::with_transport(transport.clone()).unwrap();
+
+        let payload = handler.send_raw(0x7f, &[0x01, 0x02]).unwrap();
+        assert_eq!(&payload[..4], b"00ab");
+        assert_eq!(payload.len(), 6);
+        assert_eq!(&handler.send_raw(0x0a, &[]).unwrap()[..4], b"0005");
+        assert!(CommandChecksumRejected::is_cause_of(&handler.send_raw(0x5c, &[0]).unwrap_err()));
+        assert!(matches!(handler.send_raw(0x7f, &[1, 2, 3]), Err(LumidoxError::InvalidInput(_))));
+        transport.assert_consumed();
+    }
+
+    #[test]
+    fn test_late_response_is_not_read_by_next_command() {
+        let transport = MockTransport::new()
+            .expect_late_read(b"20", 100)
+            .expect_read(b"21", 1500)
+            .expect_read(b"13", 3);
+        let mut handler = ProtocolHandler::with_transport(transport.clone()).unwrap();
+
+        assert!(handler.send_command(b"20", 0).is_err());
+        // The ARM current arrives after the flush, ahead of the FIRE current
+        assert_eq!(handler.send_command(b"21", 0).unwrap(), 1500);
+        assert_eq!(handler.send_command(b"13", 0).unwrap(), 3);
+        assert_eq!(handler.late_responses, 0);
+        transport.assert_consumed();
+    }
+
+    #[test]
+    fn test_checksum_mismatch_is_reported_and_counted() {
+        let transport = MockTransport::new()
+            .expect(MockTransport::command_frame(b"21", 0), b"*05dc00^".to_vec())
+            .expect_read(b"21", 1500);
+        let mut handler = ProtocolHandler::with_transport(transport).unwrap();
+        assert!(verify_checksum(b"*05dc2c^"));
+        assert!(!verify_checksum(b"*05dc00^"));
+        assert!(!verify_checksum(b"*05dc2c"));
+
+        let error = handler.send_command(b"21", 0).unwrap_err();
+        assert!(ChecksumMismatch::is_cause_of(&error));
+        assert!(error.is_communication_error());
+        assert_eq!(handler.checksum_failure_count(), 1);
+
+        assert_eq!(handler.send_command(b"21", 0).unwrap(), 1500);
+        assert_eq!(handler.checksum_failure_count(), 1);
+    }
+
+    #[test]
+    fn test_incorrect_checksum_reply_is_retryable() {
+        // LumidoxII.md: "Incorrect Checksum in Sent Command: (stx)XXXX60(ack)"
+        let transport = MockTransport::new()
+            .expect_rejected(b"21")
+            .expect_read(b"21", 1500);
+        let mut handler = ProtocolHandler::with_transport(transport).unwrap();
+
+        let error = handler.send_command(b"21", 0).unwrap_err();
+        assert!(CommandChecksumRejected::is_cause_of(&error));
+        assert!(!ChecksumMismatch::is_cause_of(&error));
+        assert!(!error.is_unsupported());
+        assert!(error.is_communication_error() && error.is_retryable());
+        assert_eq!(handler.checksum_failure_count(), 0);
+
+        assert_eq!(handler.send_command(b"21", 0).unwrap(), 1500);
+    }
+
+    #[test]
+    fn test_read_timeout_is_distinct_from_corrupt_response() {
+        let transport = MockTransport::new()
+            .with_read_timeouts()
+            .expect_no_response(b"21")
+            .expect(MockTransport::command_frame(b"20", 0), b"*zz^".to_vec());
+        let mut handler = ProtocolHandler::with_transport(transport).unwrap();
+
+        let timeout = handler.send_command(b"21", 0).unwrap_err();
+        assert!(matches!(timeout, LumidoxError::Timeout { ref operation, .. } if operation == "response to command 0x21"));
+        assert!(timeout.is_retryable());
+
+        let corrupt = handler.send_command(b"20", 0).unwrap_err();
+        assert!(matches!(corrupt, LumidoxError::ProtocolError(_)));
+        assert!(!corrupt.is_retryable());
+    }
+}
@@ -17,6 +17,11 @@ use super::super::constants::RESPONSE_END;
 use serialport::SerialPort;
 use std::io::Read;
 
+/// Error response sent by the controller for a command with an incorrect checksum
+///
+/// `60` is the checksum of the literal `XXXX` data field, so the frame is fixed.
+pub(crate) const REJECTION_FRAME: &[u8] = b"*XXXX60^";
+
 /// Response processing utilities and functionality
 pub struct ResponseProcessor;
 
@@ -44,6 +49,32 @@ impl ResponseProcessor {
         Ok(Self::convert_hex_response_to_decimal(&response))
     }
     
+    /// Read and process a complete response using a reusable buffer
+    /// 
+    /// Same as `read_and_process_response`, but reassembles the response in
+    /// the provided buffer instead of allocating a new one per command.
+    /// 
+    /// # Arguments
+    /// * `port` - Mutable reference to the serial port for reading
+    /// * `response` - Reusable buffer the response is read into (cleared first)
+    /// 
+    /// # Returns
+    /// * `Result<i32>` - The processed response value or error
+    /// 
+    /// # Example
+    /// ```
+    /// let mut response = Vec::new();
+    /// let value = ResponseProcessor::read_and_process_response_with_buffer(&mut port, &mut response)?;
+    /// ```
+    pub fn read_and_process_response_with_buffer(
+        port: &mut Box<dyn SerialPort>,
+        response: &mut Vec<u8>
+    ) -> Result<i32> {
+        Self::read_raw_response_into(port.as_mut(), response)?;
+        Self::validate_response_format(response)?;
+        Ok(Self::convert_hex_response_to_decimal(response))
+    }
+    
     /// Read raw response from serial port
     /// 
     /// Reads bytes from the serial port until the response end marker is found
@@ -69,6 +100,30 @@ impl ResponseProcessor {
     /// ```
     pub fn read_raw_response(port: &mut Box<dyn SerialPort>) -> Result<Vec<u8>> {
         let mut response = Vec::new();
+        Self::read_raw_response_into(port.as_mut(), &mut response)?;
+        Ok(response)
+    }
+    
+    /// Read raw response into an existing buffer
+    /// 
+    /// Clears the buffer and reassembles the response into it byte-by-byte
+    /// until the response end marker is found, following the same protocol
+    /// behavior as `read_raw_response`.
+    /// 
+    /// # Arguments
+    /// * `port` - Reader to read the response from
+    /// * `response` - Buffer the response is read into (cleared first)
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success, or `NoResponse` if no data is received
+    /// 
+    /// # Example
+    /// ```
+    /// let mut response = Vec::new();
+    /// ResponseProcessor::read_raw_response_into(port.as_mut(), &mut response)?;
+    /// ```
+    pub fn read_raw_response_into<R: Read + ?Sized>(port: &mut R, response: &mut Vec<u8>) -> Result<()> {
+        response.clear();
         let mut buffer = [0u8; 1];
         
         loop {
@@ -92,12 +147,10 @@ impl ResponseProcessor {
         }
         
         if response.is_empty() {
-            return Err(LumidoxError::ProtocolError(
-                "No response received from device".to_string()
-            ));
+            return Err(LumidoxError::NoResponse);
         }
         
-        Ok(response)
+        Ok(())
     }
     
     /// Convert hex response to decimal value
@@ -204,6 +257,28 @@ impl ResponseProcessor {
         Ok(())
     }
     
+    /// Check whether a response is the controller's command rejection frame
+    /// 
+    /// The controller answers a command whose checksum it could not verify
+    /// with the fixed frame `*XXXX60^` ("Incorrect Checksum in Sent Command"
+    /// in the protocol document). The protocol defines no reply for an
+    /// unknown command.
+    /// 
+    /// # Arguments
+    /// * `response` - The raw response bytes
+    /// 
+    /// # Returns
+    /// * `bool` - True if the response is the rejection frame
+    /// 
+    /// # Example
+    /// ```
+    /// assert!(ResponseProcessor::is_rejection(b"*XXXX60^"));
+    /// assert!(!ResponseProcessor::is_rejection(b"*03e8a2^"));
+    /// ```
+    pub fn is_rejection(response: &[u8]) -> bool {
+        response == REJECTION_FRAME
+    }
+    
     /// Check if a byte represents a valid hex digit
     /// 
     /// Validates that a byte value represents a valid hexadecimal digit
@@ -350,3 +425,65 @@ pub struct ResponseProcessingStats {
     /// Whether the response format is valid
     pub is_valid_format: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    /// Reader that delivers its data in small chunks to simulate partial reads
+    struct ChunkedReader {
+        chunks: Vec<Vec<u8>>,
+    }
+
+    impl Read for ChunkedReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.chunks.first_mut() {
+                Some(chunk) => {
+                    let n = buf.len().min(chunk.len());
+                    buf[..n].copy_from_slice(&chunk[..n]);
+                    chunk.drain(..n);
+                    if chunk.is_empty() {
+                        self.chunks.remove(0);
+                    }
+                    Ok(n)
+                }
+                None => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_rejection() {
+        assert!(ResponseProcessor::is_rejection(b"*XXXX60^"));
+        // Valid response whose data happens to have the same checksum
+        assert!(!ResponseProcessor::is_rejection(b"*aaf860^"));
+        assert!(!ResponseProcessor::is_rejection(b"*03e8a2^"));
+        assert!(!ResponseProcessor::is_rejection(b"*03e8^"));
+    }
+
+    #[test]
+    fn test_read_raw_response_into_reassembles_partial_reads() {
+        let mut reader = ChunkedReader {
+            chunks: vec![b"*0".to_vec(), b"3e".to_vec(), b"8^".to_vec(), b"*ffff^".to_vec()],
+        };
+        let mut response = b"stale data from a previous command".to_vec();
+
+        ResponseProcessor::read_raw_response_into(&mut reader, &mut response).unwrap();
+        assert_eq!(response, b"*03e8^".to_vec());
+        assert_eq!(ResponseProcessor::convert_hex_response_to_decimal(&response), 1000);
+
+        // The next response is read from where the previous one stopped
+        ResponseProcessor::read_raw_response_into(&mut reader, &mut response).unwrap();
+        assert_eq!(response, b"*ffff^".to_vec());
+        assert_eq!(ResponseProcessor::convert_hex_response_to_decimal(&response), -1);
+    }
+
+    #[test]
+    fn test_read_raw_response_into_empty_is_error() {
+        let mut reader = ChunkedReader { chunks: Vec::new() };
+        let mut response = b"stale".to_vec();
+        assert!(matches!(ResponseProcessor::read_raw_response_into(&mut reader, &mut response), Err(LumidoxError::NoResponse)));
+        assert!(response.is_empty());
+    }
+}
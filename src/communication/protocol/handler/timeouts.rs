@@ -0,0 +1,200 @@
+//! Command timeout management for protocol handler
+//!
+//! This module provides per-command-type timeouts so that quick control
+//! commands fail fast while longer characterization reads are given enough
+//! time to complete. It includes:
+//! - Command categorization based on the protocol command code
+//! - A timeout table keyed by command category
+//! - Lookup of the appropriate timeout for a command being sent
+//!
+//! The timeout system provides:
+//! - Short timeouts for firing, arming, and other control commands
+//! - Standard timeouts for single-value state readback
+//! - Long timeouts for stage parameter and device information reads
+//! - Runtime overrides per category or per call
+
+use super::super::commands;
+use super::super::constants::{CHARACTERIZATION_TIMEOUT, CONTROL_TIMEOUT, DEFAULT_TIMEOUT, READBACK_TIMEOUT};
+use std::time::Duration;
+
+/// Category of a protocol command used to select its timeout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandCategory {
+    /// Mode changes, arming, firing, and current setting commands
+    Control,
+    /// Single-value device state readback commands
+    Readback,
+    /// Stage parameter and device information commands
+    Characterization,
+    /// Commands not recognized by the categorization table
+    Other,
+}
+
+impl CommandCategory {
+    /// Determine the category of a protocol command
+    ///
+    /// # Arguments
+    /// * `command` - The command bytes being sent
+    ///
+    /// # Returns
+    /// * `CommandCategory` - The category the command belongs to
+    ///
+    /// # Example
+    /// ```
+    /// let category = CommandCategory::from_command(commands::SET_MODE);
+    /// assert_eq!(category, CommandCategory::Control);
+    /// ```
+    pub fn from_command(command: &[u8]) -> Self {
+        if [commands::SET_MODE, commands::SET_CURRENT, commands::SET_ARM_CURRENT].contains(&command) {
+            CommandCategory::Control
+        } else if [commands::READ_REMOTE_MODE, commands::READ_ARM_CURRENT, commands::READ_FIRE_CURRENT].contains(&command) {
+            CommandCategory::Readback
+        } else if Self::is_characterization_command(command) {
+            CommandCategory::Characterization
+        } else {
+            CommandCategory::Other
+        }
+    }
+
+    /// Check whether a command reads stage parameters or device information
+    fn is_characterization_command(command: &[u8]) -> bool {
+        command == commands::FIRMWARE_VERSION
+            || commands::STAGE_CURRENTS.contains(&command)
+            || commands::STAGE_ARM_CURRENTS.contains(&command)
+            || commands::STAGE_VOLT_LIMITS.contains(&command)
+            || commands::STAGE_VOLT_STARTS.contains(&command)
+            || commands::MODEL_COMMANDS.contains(&command)
+            || commands::SERIAL_COMMANDS.contains(&command)
+            || commands::WAVELENGTH_COMMANDS.contains(&command)
+    }
+}
+
+/// Timeout table keyed by command category
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandTimeouts {
+    /// Timeout for control commands
+    pub control: Duration,
+    /// Timeout for readback commands
+    pub readback: Duration,
+    /// Timeout for characterization commands
+    pub characterization: Duration,
+    /// Timeout for uncategorized commands
+    pub other: Duration,
+}
+
+impl Default for CommandTimeouts {
+    fn default() -> Self {
+        Self {
+            control: CONTROL_TIMEOUT,
+            readback: READBACK_TIMEOUT,
+            characterization: CHARACTERIZATION_TIMEOUT,
+            other: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+impl CommandTimeouts {
+    /// Create a table using the same timeout for every category
+    ///
+    /// # Arguments
+    /// * `timeout` - The timeout for all commands
+    ///
+    /// # Returns
+    /// * `CommandTimeouts` - Table with every category set to `timeout`
+    ///
+    /// # Example
+    /// ```
+    /// handler.set_command_timeouts(CommandTimeouts::uniform(Duration::from_secs(2)));
+    /// ```
+    pub fn uniform(timeout: Duration) -> Self {
+        Self {
+            control: timeout,
+            readback: timeout,
+            characterization: timeout,
+            other: timeout,
+        }
+    }
+
+    /// Get the timeout configured for a command category
+    ///
+    /// # Arguments
+    /// * `category` - The command category
+    ///
+    /// # Returns
+    /// * `Duration` - The timeout for the category
+    pub fn for_category(&self, category: CommandCategory) -> Duration {
+        match category {
+            CommandCategory::Control => self.control,
+            CommandCategory::Readback => self.readback,
+            CommandCategory::Characterization => self.characterization,
+            CommandCategory::Other => self.other,
+        }
+    }
+
+    /// Get the timeout to use for a specific command
+    ///
+    /// # Arguments
+    /// * `command` - The command bytes being sent
+    ///
+    /// # Returns
+    /// * `Duration` - The timeout for the command's category
+    ///
+    /// # Example
+    /// ```
+    /// let timeouts = CommandTimeouts::default();
+    /// let timeout = timeouts.for_command(commands::STAGE_CURRENTS[0]);
+    /// ```
+    pub fn for_command(&self, command: &[u8]) -> Duration {
+        self.for_category(CommandCategory::from_command(command))
+    }
+
+    /// Set the timeout for a command category
+    ///
+    /// # Arguments
+    /// * `category` - The command category to update
+    /// * `timeout` - The new timeout duration
+    ///
+    /// # Example
+    /// ```
+    /// let mut timeouts = CommandTimeouts::default();
+    /// timeouts.set_category(CommandCategory::Characterization, Duration::from_secs(5));
+    /// ```
+    pub fn set_category(&mut self, category: CommandCategory, timeout: Duration) {
+        match category {
+            CommandCategory::Control => self.control = timeout,
+            CommandCategory::Readback => self.readback = timeout,
+            CommandCategory::Characterization => self.characterization = timeout,
+            CommandCategory::Other => self.other = timeout,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_categorization() {
+        assert_eq!(CommandCategory::from_command(commands::SET_MODE), CommandCategory::Control);
+        assert_eq!(CommandCategory::from_command(commands::SET_CURRENT), CommandCategory::Control);
+        assert_eq!(CommandCategory::from_command(commands::READ_FIRE_CURRENT), CommandCategory::Readback);
+        assert_eq!(CommandCategory::from_command(commands::STAGE_VOLT_LIMITS[2]), CommandCategory::Characterization);
+        assert_eq!(CommandCategory::from_command(commands::FIRMWARE_VERSION), CommandCategory::Characterization);
+        assert_eq!(CommandCategory::from_command(b"ff"), CommandCategory::Other);
+    }
+
+    #[test]
+    fn test_default_timeouts_scale_by_category() {
+        let timeouts = CommandTimeouts::default();
+        assert!(timeouts.for_command(commands::SET_CURRENT) < timeouts.for_command(commands::READ_ARM_CURRENT));
+        assert!(timeouts.for_command(commands::READ_ARM_CURRENT) < timeouts.for_command(commands::STAGE_CURRENTS[0]));
+    }
+
+    #[test]
+    fn test_set_category_overrides_timeout() {
+        let mut timeouts = CommandTimeouts::default();
+        timeouts.set_category(CommandCategory::Control, Duration::from_millis(200));
+        assert_eq!(timeouts.for_command(commands::SET_MODE), Duration::from_millis(200));
+        assert_eq!(timeouts.readback, READBACK_TIMEOUT);
+    }
+}
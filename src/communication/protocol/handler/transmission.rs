@@ -17,6 +17,12 @@ use super::super::constants::{CMD_START, CMD_TERMINATOR};
 use serialport::SerialPort;
 use std::io::Write;
 
+/// Lowercase hex digits used when encoding frame values and checksums
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Typical formatted frame length, used to size reusable frame buffers
+pub const FRAME_CAPACITY: usize = 16;
+
 /// Command transmission utilities and functionality
 pub struct CommandTransmission;
 
@@ -49,6 +55,36 @@ impl CommandTransmission {
         Ok(())
     }
     
+    /// Send a formatted command using a reusable frame buffer
+    /// 
+    /// Same as `send_formatted_command`, but builds the frame in the provided
+    /// buffer instead of allocating a new one, so repeated commands (such as
+    /// pulse loops) do not allocate once the buffer has grown to frame size.
+    /// 
+    /// # Arguments
+    /// * `port` - Mutable reference to the serial port for writing
+    /// * `frame` - Reusable buffer the frame is built in (cleared first)
+    /// * `command` - The command bytes to send
+    /// * `value` - The value parameter for the command
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or transmission error
+    /// 
+    /// # Example
+    /// ```
+    /// let mut frame = Vec::with_capacity(FRAME_CAPACITY);
+    /// CommandTransmission::send_formatted_command_with_buffer(&mut port, &mut frame, b"15", 3)?;
+    /// ```
+    pub fn send_formatted_command_with_buffer(
+        port: &mut Box<dyn SerialPort>,
+        frame: &mut Vec<u8>,
+        command: &[u8],
+        value: u16
+    ) -> Result<()> {
+        Self::format_command_into(frame, command, value);
+        Self::write_command_to_port(port, frame)
+    }
+    
     /// Format a command with value and checksum
     /// 
     /// Creates a properly formatted command according to the Lumidox II protocol
@@ -72,22 +108,43 @@ impl CommandTransmission {
     /// //         [>   , cmd, 0   , 3   , e   , 8   , checksum , \r ]
     /// ```
     pub fn format_command(command: &[u8], value: u16) -> Result<Vec<u8>> {
-        let mut cmd = vec![CMD_START];
-        cmd.extend_from_slice(command);
+        let mut cmd = Vec::with_capacity(FRAME_CAPACITY);
+        Self::format_command_into(&mut cmd, command, value);
+        Ok(cmd)
+    }
+    
+    /// Format a command into an existing buffer
+    /// 
+    /// Clears the buffer and writes the complete protocol frame into it
+    /// without any intermediate allocations. Produces exactly the same
+    /// bytes as `format_command`.
+    /// 
+    /// # Arguments
+    /// * `frame` - Buffer to write the frame into (cleared first)
+    /// * `command` - The base command bytes
+    /// * `value` - The 16-bit value parameter to include
+    /// 
+    /// # Example
+    /// ```
+    /// let mut frame = Vec::with_capacity(FRAME_CAPACITY);
+    /// CommandTransmission::format_command_into(&mut frame, b"41", 1000);
+    /// assert_eq!(frame, CommandTransmission::format_command(b"41", 1000)?);
+    /// ```
+    pub fn format_command_into(frame: &mut Vec<u8>, command: &[u8], value: u16) {
+        frame.clear();
+        frame.push(CMD_START);
+        frame.extend_from_slice(command);
         
         // Add value as 4-digit hex
-        if value == 0 {
-            cmd.extend_from_slice(b"0000");
-        } else {
-            cmd.extend_from_slice(format!("{:04x}", value).as_bytes());
+        for shift in [12, 8, 4, 0] {
+            frame.push(HEX_DIGITS[((value >> shift) & 0xf) as usize]);
         }
         
-        // Add checksum
-        let checksum = Self::calculate_command_checksum(&cmd);
-        cmd.extend_from_slice(&checksum);
-        cmd.push(CMD_TERMINATOR);
-        
-        Ok(cmd)
+        // Add checksum (sum of bytes after the start marker, modulo 256)
+        let checksum = frame[1..].iter().fold(0u8, |sum, &byte| sum.wrapping_add(byte));
+        frame.push(HEX_DIGITS[(checksum >> 4) as usize]);
+        frame.push(HEX_DIGITS[(checksum & 0xf) as usize]);
+        frame.push(CMD_TERMINATOR);
     }
     
     /// Calculate checksum for command data
@@ -239,3 +296,38 @@ pub struct CommandTransmissionStats {
     /// Total protocol overhead bytes (markers, checksum, terminator)
     pub protocol_overhead: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_command_known_frame() {
+        // "15" + "0003" -> checksum of bytes "150003" = 0x129 -> "29"
+        let frame = CommandTransmission::format_command(b"15", 3).unwrap();
+        assert_eq!(frame, b"*15000329\r".to_vec());
+    }
+
+    #[test]
+    fn test_format_command_into_matches_checksum_helper() {
+        let mut frame = Vec::new();
+        for value in [0u16, 1, 0x00ff, 1000, 0xabcd, u16::MAX] {
+            CommandTransmission::format_command_into(&mut frame, b"41", value);
+            let body_end = frame.len() - 3;
+            assert_eq!(&frame[1..3], b"41");
+            assert_eq!(frame[3..body_end].to_vec(), format!("{:04x}", value).into_bytes());
+            assert_eq!(frame[body_end..frame.len() - 1].to_vec(), CommandTransmission::calculate_command_checksum(&frame[..body_end]));
+            assert_eq!(frame[frame.len() - 1], CMD_TERMINATOR);
+        }
+    }
+
+    #[test]
+    fn test_format_command_into_reuses_buffer() {
+        let mut frame = Vec::with_capacity(FRAME_CAPACITY);
+        CommandTransmission::format_command_into(&mut frame, b"7a", 0x1234);
+        let capacity = frame.capacity();
+        CommandTransmission::format_command_into(&mut frame, b"15", 3);
+        assert_eq!(frame, CommandTransmission::format_command(b"15", 3).unwrap());
+        assert_eq!(frame.capacity(), capacity);
+    }
+}
@@ -12,9 +12,85 @@
 //! - Format validation for commands and responses
 //! - Error detection and reporting for protocol violations
 
+use std::fmt;
 use crate::core::{LumidoxError, Result};
 use super::super::constants::{CMD_START, CMD_TERMINATOR, RESPONSE_END};
 
+/// Source of the `CommunicationError` returned for a response whose
+/// checksum does not match its data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumMismatch {
+    /// Complete response frame as received
+    pub frame: Vec<u8>,
+}
+
+impl ChecksumMismatch {
+    /// Check whether an error was caused by a checksum mismatch
+    ///
+    /// # Arguments
+    /// * `error` - Error returned by a protocol operation
+    ///
+    /// # Returns
+    /// * `bool` - True for a `CommunicationError` whose source is `ChecksumMismatch`
+    ///
+    /// # Example
+    /// ```
+    /// if ChecksumMismatch::is_cause_of(&error) {
+    ///     println!("Corrupted response; check the cable");
+    /// }
+    /// ```
+    pub fn is_cause_of(error: &LumidoxError) -> bool {
+        matches!(error, LumidoxError::CommunicationError { source, .. } if source.is::<ChecksumMismatch>())
+    }
+}
+
+impl fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "checksum mismatch in response {:?}", String::from_utf8_lossy(&self.frame))
+    }
+}
+
+impl std::error::Error for ChecksumMismatch {}
+
+/// Source of the `CommunicationError` returned when the device answers
+/// `*XXXX60^`, its documented reply to a command with an incorrect checksum
+///
+/// Outgoing checksums are always computed, so the command was corrupted on
+/// the way to the device and sending it again may succeed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandChecksumRejected {
+    /// Command code that was rejected, e.g. "0x78"
+    pub command: String,
+}
+
+impl CommandChecksumRejected {
+    /// Check whether an error was caused by the device rejecting a command checksum
+    ///
+    /// # Arguments
+    /// * `error` - Error returned by a protocol operation
+    ///
+    /// # Returns
+    /// * `bool` - True for a `CommunicationError` whose source is `CommandChecksumRejected`
+    ///
+    /// # Example
+    /// ```
+    /// if CommandChecksumRejected::is_cause_of(&error) {
+    ///     println!("Command corrupted in transit; resending");
+    /// }
+    /// ```
+    pub fn is_cause_of(error: &LumidoxError) -> bool {
+        matches!(error, LumidoxError::CommunicationError { source, .. } if source.is::<CommandChecksumRejected>())
+    }
+}
+
+impl fmt::Display for CommandChecksumRejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "device reported an incorrect checksum in command {}", self.command)
+    }
+}
+
+impl std::error::Error for CommandChecksumRejected {}
+
 /// Protocol validation utilities and functionality
 pub struct ProtocolValidator;
 
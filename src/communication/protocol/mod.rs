@@ -5,11 +5,14 @@
 //! - Commands: Device command definitions and command arrays
 //! - Handler: Core protocol communication logic
 //! - Utils: Protocol utility functions for data processing
+//! - Reference: Documentation of every implemented command
 
 pub mod constants;
 pub mod commands;
 pub mod handler;
 pub mod utils;
+pub mod reference;
 
 // Re-export commonly used items for convenience
 pub use handler::ProtocolHandler;
+pub use utils::verify_checksum;
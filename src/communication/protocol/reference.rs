@@ -0,0 +1,146 @@
+//! Protocol command reference for Lumidox II Controller
+//!
+//! This module lists every protocol command the library implements, with
+//! its opcode, what it does and the shape of the device's answer. The list
+//! is built from the command definitions in `commands`, so it always matches
+//! what the library actually sends and can be checked against the command
+//! table in a firmware manual.
+//!
+//! The wavelength characters are defined at 0x81, 0x82, 0x89 and 0x8a,
+//! which are also the voltage limit and start codes of stages 2 and 3. Both
+//! uses are listed, so the overlap shows up when comparing with the manual.
+
+use std::fmt;
+use super::commands::{self, StageParameter, STAGE_COUNT};
+
+/// Shape of the data value in a command's response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseShape {
+    /// Acknowledgement of a write; the data value is not used
+    Acknowledge,
+    /// 16-bit unsigned value
+    Value {
+        /// Unit of the value after scaling, empty for a plain number
+        unit: &'static str,
+        /// Divisor applied to the raw value, 1 for none
+        divisor: u16,
+    },
+    /// One ASCII character of a text field
+    Character,
+    /// Remote mode state: 0 local, 1 standby, 2 armed, 3 remote
+    ModeState,
+    /// Index into the table of power unit names
+    UnitsIndex,
+}
+
+impl fmt::Display for ResponseShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Acknowledge => write!(f, "acknowledgement"),
+            Self::Value { unit: "", .. } => write!(f, "16-bit value"),
+            Self::Value { unit, divisor: 1 } => write!(f, "16-bit value in {}", unit),
+            Self::Value { unit, divisor } => write!(f, "16-bit value in {}/{}", unit, divisor),
+            Self::Character => write!(f, "ASCII character"),
+            Self::ModeState => write!(f, "mode state (0 local, 1 standby, 2 armed, 3 remote)"),
+            Self::UnitsIndex => write!(f, "units table index"),
+        }
+    }
+}
+
+/// Documentation of a single protocol command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandDoc {
+    /// Command code sent on the wire
+    pub opcode: u8,
+    /// What the command reads or sets
+    pub description: String,
+    /// Shape of the data value in the response
+    pub response: ResponseShape,
+}
+
+/// List every protocol command the library implements
+///
+/// Commands are listed by group (information, control, state, stage
+/// parameters) in the order of their definitions. Text fields such as the
+/// model number are read one character per command and get one entry each.
+///
+/// # Returns
+/// * `Vec<CommandDoc>` - Command documentation, one entry per command
+///
+/// # Example
+/// ```
+/// for doc in command_reference() {
+///     println!("0x{:02x} {} -> {}", doc.opcode, doc.description, doc.response);
+/// }
+/// ```
+pub fn command_reference() -> Vec<CommandDoc> {
+    let mut reference = vec![doc(commands::FIRMWARE_VERSION, "Read firmware version".to_string(), ResponseShape::Value { unit: "", divisor: 1 })];
+    reference.extend(text_field(&commands::MODEL_COMMANDS, "model number"));
+    reference.extend(text_field(&commands::SERIAL_COMMANDS, "serial number"));
+    reference.extend(text_field(&commands::WAVELENGTH_COMMANDS, "wavelength"));
+
+    reference.push(doc(commands::SET_MODE, "Set device mode (0-3, as the mode state)".to_string(), ResponseShape::Acknowledge));
+    reference.push(doc(commands::SET_CURRENT, "Set FIRE current in mA".to_string(), ResponseShape::Acknowledge));
+    reference.push(doc(commands::READ_REMOTE_MODE, "Read remote mode state".to_string(), ResponseShape::ModeState));
+    reference.push(doc(commands::READ_ARM_CURRENT, "Read ARM current".to_string(), ResponseShape::Value { unit: "mA", divisor: 1 }));
+    reference.push(doc(commands::READ_FIRE_CURRENT, "Read FIRE current".to_string(), ResponseShape::Value { unit: "mA", divisor: 1 }));
+    reference.push(doc(commands::SET_ARM_CURRENT, "Set ARM current in mA".to_string(), ResponseShape::Acknowledge));
+
+    for stage in 1..=STAGE_COUNT {
+        for parameter in StageParameter::ALL {
+            if let Some(code) = commands::stage_command(parameter, stage) {
+                let (name, response) = stage_parameter_doc(parameter);
+                reference.push(doc(&code, format!("Read stage {} {}", stage, name), response));
+            }
+        }
+    }
+    reference
+}
+
+/// Documentation of the per-character commands of a text field
+fn text_field<'a>(codes: &'a [&'a [u8]], field: &'a str) -> impl Iterator<Item = CommandDoc> + 'a {
+    codes.iter().enumerate().map(move |(index, code)| {
+        doc(code, format!("Read {} character {}", field, index + 1), ResponseShape::Character)
+    })
+}
+
+/// Name and response shape of a stage parameter
+fn stage_parameter_doc(parameter: StageParameter) -> (&'static str, ResponseShape) {
+    match parameter {
+        StageParameter::ArmCurrent => ("ARM current", ResponseShape::Value { unit: "mA", divisor: 1 }),
+        StageParameter::FireCurrent => ("FIRE current", ResponseShape::Value { unit: "mA", divisor: 1 }),
+        StageParameter::VoltLimit => ("voltage limit", ResponseShape::Value { unit: "V", divisor: 100 }),
+        StageParameter::VoltStart => ("voltage start", ResponseShape::Value { unit: "V", divisor: 100 }),
+        StageParameter::PowerTotal => ("total power", ResponseShape::Value { unit: "total units", divisor: 10 }),
+        StageParameter::PowerPerLed => ("per-LED power", ResponseShape::Value { unit: "per-LED units", divisor: 10 }),
+        StageParameter::TotalUnits => ("total power units", ResponseShape::UnitsIndex),
+        StageParameter::PerLedUnits => ("per-LED power units", ResponseShape::UnitsIndex),
+    }
+}
+
+/// Build a command entry from a two-hex-digit command code
+fn doc(code: &[u8], description: String, response: ResponseShape) -> CommandDoc {
+    let opcode = std::str::from_utf8(code)
+        .ok()
+        .and_then(|code| u8::from_str_radix(code, 16).ok())
+        .expect("protocol command codes are two hex digits");
+    CommandDoc { opcode, description, response }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_lists_every_command() {
+        let reference = command_reference();
+        assert_eq!(reference.len(), 1 + 8 + 12 + 5 + 6 + 8 * usize::from(STAGE_COUNT));
+        assert_eq!(reference.iter().filter(|doc| doc.opcode == 0x81).count(), 2);
+
+        let set_mode = reference.iter().find(|doc| doc.opcode == 0x15).unwrap();
+        assert_eq!(set_mode.response, ResponseShape::Acknowledge);
+        let fire_current = reference.iter().find(|doc| doc.opcode == 0x88).unwrap();
+        assert_eq!(fire_current.description, "Read stage 3 FIRE current");
+        assert_eq!(fire_current.response.to_string(), "16-bit value in mA");
+    }
+}
@@ -4,7 +4,36 @@
 //! including string data reading and other protocol-specific operations.
 
 use crate::core::Result;
-use super::handler::ProtocolHandler;
+use super::constants::RESPONSE_END;
+use super::handler::{ProtocolHandler, ProtocolValidator};
+
+/// Check the checksum of a response frame
+///
+/// A response frame is `*DDDDSS^`: the start marker, the data characters,
+/// two hex checksum characters and the end marker. The checksum is the sum
+/// of the data characters modulo 256, the same rule used for outgoing
+/// command frames.
+///
+/// # Arguments
+/// * `frame` - Complete response frame as received, including both markers
+///
+/// # Returns
+/// * `bool` - True if the frame is complete and its checksum matches its data
+///
+/// # Example
+/// ```
+/// assert!(verify_checksum(b"*03e800^"));
+/// assert!(!verify_checksum(b"*03e900^"));
+/// ```
+pub fn verify_checksum(frame: &[u8]) -> bool {
+    match frame.strip_suffix(&[RESPONSE_END]) {
+        Some(body) if body.len() >= 4 => {
+            let (data, checksum) = body.split_at(body.len() - 2);
+            checksum.eq_ignore_ascii_case(&ProtocolValidator::calculate_checksum(data))
+        }
+        _ => false,
+    }
+}
 
 /// Read string data from device using multiple commands
 pub fn read_string_data(
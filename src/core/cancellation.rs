@@ -0,0 +1,97 @@
+//! Cancellation support for Lumidox II Controller operations
+//!
+//! This module provides a shared cancellation token that interfaces can hand
+//! to long-running device sequences. The sequence checks the token between
+//! protocol steps and stops with `LumidoxError::OperationCancelled` when it
+//! has been cancelled, so cancellation surfaces through the normal result
+//! types from the device layer up to the user interface.
+
+use crate::core::{LumidoxError, Result};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shared flag used to request cancellation of an in-progress operation
+///
+/// Clones share the same flag, so a clone can be moved into the task running
+/// the operation while the original is kept to request cancellation.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    /// Whether cancellation has been requested
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a new token that has not been cancelled
+    ///
+    /// # Returns
+    /// * `CancellationToken` - A fresh, uncancelled token
+    ///
+    /// # Example
+    /// ```
+    /// let token = CancellationToken::new();
+    /// assert!(!token.is_cancelled());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation of operations using this token
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Clear a previous cancellation request so the token can be reused
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::SeqCst);
+    }
+
+    /// Check whether cancellation has been requested
+    ///
+    /// # Returns
+    /// * `bool` - True if `cancel` has been called since the last reset
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Return a cancellation error if cancellation has been requested
+    ///
+    /// # Arguments
+    /// * `operation` - Description of the operation, used in the error message
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success if not cancelled, `LumidoxError::OperationCancelled` otherwise
+    ///
+    /// # Example
+    /// ```
+    /// token.check("Stage 2 firing")?;
+    /// ```
+    pub fn check(&self, operation: &str) -> Result<()> {
+        if self.is_cancelled() {
+            Err(LumidoxError::OperationCancelled(operation.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_cancellation() {
+        let token = CancellationToken::new();
+        let task_token = token.clone();
+        assert!(task_token.check("firing").is_ok());
+
+        token.cancel();
+        assert!(task_token.is_cancelled());
+        assert!(matches!(
+            task_token.check("firing"),
+            Err(LumidoxError::OperationCancelled(operation)) if operation == "firing"
+        ));
+
+        token.reset();
+        assert!(!task_token.is_cancelled());
+    }
+}
@@ -16,4 +16,4 @@ pub mod validation_errors;
 pub mod system_errors;
 
 // Re-export commonly used items for convenience
-pub use types::LumidoxError;
+pub use types::{ErrorSource, LumidoxError};
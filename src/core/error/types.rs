@@ -3,8 +3,53 @@
 //! This module defines all error types used throughout the application,
 //! providing centralized error type definitions with proper error propagation.
 
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
 use thiserror::Error;
 
+/// Underlying error that caused a `LumidoxError`
+///
+/// The error is shared rather than uniquely owned, so `LumidoxError` stays
+/// cloneable without flattening the source into a string. `source()`
+/// returns the wrapped error itself, so it can be downcast to, for example,
+/// `std::io::Error` to read the OS error.
+#[derive(Clone)]
+pub struct ErrorSource(Arc<dyn std::error::Error + Send + Sync + 'static>);
+
+impl ErrorSource {
+    /// Wrap an underlying error
+    ///
+    /// # Arguments
+    /// * `error` - Error that caused the failure
+    ///
+    /// # Returns
+    /// * `ErrorSource` - Shared source error
+    pub fn new(error: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self(Arc::new(error))
+    }
+}
+
+impl Deref for ErrorSource {
+    type Target = dyn std::error::Error + Send + Sync + 'static;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl fmt::Debug for ErrorSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for ErrorSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
+
 /// Main error type for the Lumidox II Controller application
 #[derive(Error, Debug)]
 pub enum LumidoxError {
@@ -28,6 +73,28 @@ pub enum LumidoxError {
     #[error("Configuration error: {0}")]
     ConfigError(String),
 
+    /// Communication failure caused by an underlying error, such as an OS error
+    /// from the serial port
+    #[error("Device communication error: {message}: {source}")]
+    CommunicationError {
+        /// What was being done when the error occurred
+        message: String,
+        /// Error that caused the failure, returned by `source()`
+        #[source]
+        source: ErrorSource,
+    },
+
+    /// System failure caused by an underlying error, such as a file that
+    /// cannot be read
+    #[error("System error: {message}: {source}")]
+    SystemError {
+        /// What was being done when the error occurred
+        message: String,
+        /// Error that caused the failure, returned by `source()`
+        #[source]
+        source: ErrorSource,
+    },
+
     /// Protocol parsing or validation errors
     #[error("Protocol error: {0}")]
     ProtocolError(String),
@@ -40,6 +107,43 @@ pub enum LumidoxError {
     #[error("Operation cancelled: {0}")]
     OperationCancelled(String),
 
+    /// Polled condition was not met before the timeout
+    #[error("Operation timed out after {}ms", .timeout.as_millis())]
+    OperationTimeout {
+        /// Time allowed for the condition to become true
+        timeout: std::time::Duration,
+    },
+
+    /// The device did not answer within the read timeout
+    ///
+    /// Separate from `ProtocolError`, which means a response arrived but was
+    /// corrupt: a timed-out command can simply be retried.
+    #[error("Timed out after {}ms waiting for {operation}", .waited.as_millis())]
+    Timeout {
+        /// What was being waited for, e.g. "response to command 0x78"
+        operation: String,
+        /// Time waited before giving up
+        waited: std::time::Duration,
+    },
+
+    /// The device sent nothing at all in reply to a command
+    ///
+    /// Separate from `ProtocolError`, which means bytes arrived but could
+    /// not be decoded.
+    #[error("No response received from device")]
+    NoResponse,
+
+    /// No input was entered within the interactive idle timeout
+    #[error("No input for {}s", .timeout.as_secs())]
+    IdleTimeout {
+        /// Time allowed between lines of input
+        timeout: std::time::Duration,
+    },
+
+    /// Standard input ended while the interactive menus were waiting for it
+    #[error("End of input")]
+    EndOfInput,
+
     /// Operation is currently in progress
     #[error("Operation in progress")]
     OperationInProgress,
@@ -47,6 +151,208 @@ pub enum LumidoxError {
     /// Device not found or not connected
     #[error("Device not found")]
     DeviceNotFound,
+
+    /// Control operation attempted before device initialization
+    #[error("Device not initialized: call initialize() before control operations")]
+    NotInitialized,
+
+    /// Operation rejected by a safety limit
+    #[error("Safety limit: {0}")]
+    SafetyLimit(String),
+
+    /// Firing refused because the device reports a blocking fault
+    #[error("Safety interlock: firing refused, device reports {flag}")]
+    SafetyInterlock {
+        /// Active fault flags, e.g. "interlock open"
+        flag: String,
+    },
+
+    /// Numeric readback outside any plausible range
+    ///
+    /// Usually means the response was parsed wrongly, for example a firmware
+    /// sending multi-byte values in a different byte order.
+    #[error("Implausible {field} readback: raw value {raw}; check the response byte order")]
+    ImplausibleValue {
+        /// Value that was read, e.g. "ARM current"
+        field: String,
+        /// Value as parsed from the response
+        raw: i32,
+    },
+
+    /// Operation refused by the operation policy set by the embedder
+    #[error("Operation policy denied {operation} ({level} safety level)")]
+    PolicyDenied {
+        /// Name of the refused operation, e.g. "shutdown"
+        operation: String,
+        /// Safety level of the refused operation
+        level: crate::device::controller::SafetyLevel,
+    },
+
+    /// Auto-connection failed on every candidate port
+    #[error("Auto-connection failed: {0}")]
+    ConnectionFailed(Box<crate::communication::ConnectionFailure>),
+
+    /// Automatic reconnection gave up after a communication error
+    #[error("Reconnection to {port} failed after {attempts} attempts: {source}")]
+    ReconnectFailed {
+        /// Serial port that could not be reopened
+        port: String,
+        /// Number of reconnect attempts made
+        attempts: u8,
+        /// Error from the last attempt, a `LumidoxError`
+        #[source]
+        source: ErrorSource,
+    },
+
+    /// Connected device is not the expected model
+    #[error("Connected to model {actual}, expected {expected}")]
+    ModelMismatch {
+        /// Expected model pattern
+        expected: String,
+        /// Model number reported by the device
+        actual: String,
+    },
+
+    /// Command rejected by the connected firmware
+    #[error("Command {command} is not supported by firmware {firmware}")]
+    UnsupportedOperation {
+        /// Command code that was rejected, e.g. "0x78"
+        command: String,
+        /// Firmware version of the device, or "unknown" before identification
+        firmware: String,
+    },
+
+    /// Transition between two states that is not allowed
+    #[error("Invalid state transition from {from} to {to}")]
+    InvalidStateTransition {
+        /// State the transition started from
+        from: String,
+        /// State the transition was to move to
+        to: String,
+    },
+}
+
+impl LumidoxError {
+    /// Create a communication error that keeps its underlying cause
+    ///
+    /// # Arguments
+    /// * `message` - What was being done when the error occurred
+    /// * `source` - Underlying error, returned by `source()`
+    ///
+    /// # Returns
+    /// * `LumidoxError` - `CommunicationError` wrapping the source
+    ///
+    /// # Example
+    /// ```
+    /// port.write_all(&frame).map_err(|e| LumidoxError::communication("Cannot send command", e))?;
+    /// ```
+    pub fn communication(message: impl Into<String>, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::CommunicationError { message: message.into(), source: ErrorSource::new(source) }
+    }
+
+    /// Create a system error that keeps its underlying cause
+    ///
+    /// # Arguments
+    /// * `message` - What was being done when the error occurred
+    /// * `source` - Underlying error, returned by `source()`
+    ///
+    /// # Returns
+    /// * `LumidoxError` - `SystemError` wrapping the source
+    ///
+    /// # Example
+    /// ```
+    /// fs::read_to_string(&path).map_err(|e| LumidoxError::system("Cannot read settings", e))?;
+    /// ```
+    pub fn system(message: impl Into<String>, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::SystemError { message: message.into(), source: ErrorSource::new(source) }
+    }
+
+    /// Check whether this error represents a cancelled operation
+    ///
+    /// Interfaces use this to present cancellation as a neutral outcome
+    /// rather than a failure.
+    ///
+    /// # Returns
+    /// * `bool` - True for `LumidoxError::OperationCancelled`
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Self::OperationCancelled(_))
+    }
+
+    /// Check whether this error means the firmware does not support a command
+    ///
+    /// Interfaces use this to hide or disable features the connected
+    /// firmware cannot perform instead of reporting a failure.
+    ///
+    /// # Returns
+    /// * `bool` - True for `LumidoxError::UnsupportedOperation`
+    pub fn is_unsupported(&self) -> bool {
+        matches!(self, Self::UnsupportedOperation { .. })
+    }
+
+    /// Check whether this error means the connection to the device was lost
+    ///
+    /// Serial port and I/O errors indicate a lost or broken connection;
+    /// device and protocol errors come from a device that is still answering.
+    ///
+    /// # Returns
+    /// * `bool` - True for `LumidoxError::SerialError`, `LumidoxError::IoError`,
+    ///   `LumidoxError::CommunicationError` and `LumidoxError::Timeout`
+    pub fn is_communication_error(&self) -> bool {
+        matches!(self, Self::SerialError(_) | Self::IoError(_) | Self::CommunicationError { .. } | Self::Timeout { .. })
+    }
+
+    /// Check whether the failed operation can be retried as it is
+    ///
+    /// A command that timed out, or that the device reported as arriving
+    /// with an incorrect checksum, may succeed when sent again. A corrupt
+    /// response will fail the same way again.
+    ///
+    /// # Returns
+    /// * `bool` - True for `LumidoxError::Timeout` and for a `CommunicationError`
+    ///   caused by `CommandChecksumRejected`
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Timeout { .. })
+            || crate::communication::protocol::handler::CommandChecksumRejected::is_cause_of(self)
+    }
+
+    /// Get a stable identifier for the kind of error
+    ///
+    /// Used in machine-readable output. The identifier is the variant name
+    /// in snake_case and does not change with the error message.
+    ///
+    /// # Returns
+    /// * `&'static str` - Error kind such as "device_error" or "safety_limit"
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::SerialError(_) => "serial_error",
+            Self::IoError(_) => "io_error",
+            Self::InvalidInput(_) => "invalid_input",
+            Self::DeviceError(_) => "device_error",
+            Self::ConfigError(_) => "config_error",
+            Self::CommunicationError { .. } => "communication_error",
+            Self::SystemError { .. } => "system_error",
+            Self::ProtocolError(_) => "protocol_error",
+            Self::ValidationError(_) => "validation_error",
+            Self::OperationCancelled(_) => "operation_cancelled",
+            Self::OperationTimeout { .. } => "operation_timeout",
+            Self::Timeout { .. } => "timeout",
+            Self::NoResponse => "no_response",
+            Self::IdleTimeout { .. } => "idle_timeout",
+            Self::EndOfInput => "end_of_input",
+            Self::OperationInProgress => "operation_in_progress",
+            Self::DeviceNotFound => "device_not_found",
+            Self::NotInitialized => "not_initialized",
+            Self::SafetyLimit(_) => "safety_limit",
+            Self::SafetyInterlock { .. } => "safety_interlock",
+            Self::ImplausibleValue { .. } => "implausible_value",
+            Self::PolicyDenied { .. } => "policy_denied",
+            Self::ConnectionFailed(_) => "connection_failed",
+            Self::ReconnectFailed { .. } => "reconnect_failed",
+            Self::ModelMismatch { .. } => "model_mismatch",
+            Self::UnsupportedOperation { .. } => "unsupported_operation",
+            Self::InvalidStateTransition { .. } => "invalid_state_transition",
+        }
+    }
 }
 
 // Implement Clone manually for the parts that need it
@@ -54,8 +360,8 @@ impl Clone for LumidoxError {
     fn clone(&self) -> Self {
         match self {
             Self::SerialError(e) => Self::SerialError(serialport::Error::new(
-                serialport::ErrorKind::Unknown,
-                e.to_string(),
+                e.kind,
+                e.description.clone(),
             )),
             Self::IoError(e) => Self::IoError(std::io::Error::new(
                 e.kind(),
@@ -64,11 +370,80 @@ impl Clone for LumidoxError {
             Self::InvalidInput(s) => Self::InvalidInput(s.clone()),
             Self::DeviceError(s) => Self::DeviceError(s.clone()),
             Self::ConfigError(s) => Self::ConfigError(s.clone()),
+            Self::CommunicationError { message, source } => Self::CommunicationError {
+                message: message.clone(),
+                source: source.clone(),
+            },
+            Self::SystemError { message, source } => Self::SystemError {
+                message: message.clone(),
+                source: source.clone(),
+            },
             Self::ProtocolError(s) => Self::ProtocolError(s.clone()),
             Self::ValidationError(s) => Self::ValidationError(s.clone()),
             Self::OperationCancelled(s) => Self::OperationCancelled(s.clone()),
             Self::OperationInProgress => Self::OperationInProgress,
             Self::DeviceNotFound => Self::DeviceNotFound,
+            Self::NotInitialized => Self::NotInitialized,
+            Self::SafetyLimit(s) => Self::SafetyLimit(s.clone()),
+            Self::SafetyInterlock { flag } => Self::SafetyInterlock { flag: flag.clone() },
+            Self::ImplausibleValue { field, raw } => Self::ImplausibleValue {
+                field: field.clone(),
+                raw: *raw,
+            },
+            Self::PolicyDenied { operation, level } => Self::PolicyDenied {
+                operation: operation.clone(),
+                level: *level,
+            },
+            Self::ConnectionFailed(failure) => Self::ConnectionFailed(failure.clone()),
+            Self::ReconnectFailed { port, attempts, source } => Self::ReconnectFailed {
+                port: port.clone(),
+                attempts: *attempts,
+                source: source.clone(),
+            },
+            Self::OperationTimeout { timeout } => Self::OperationTimeout { timeout: *timeout },
+            Self::Timeout { operation, waited } => Self::Timeout {
+                operation: operation.clone(),
+                waited: *waited,
+            },
+            Self::NoResponse => Self::NoResponse,
+            Self::IdleTimeout { timeout } => Self::IdleTimeout { timeout: *timeout },
+            Self::EndOfInput => Self::EndOfInput,
+            Self::ModelMismatch { expected, actual } => Self::ModelMismatch {
+                expected: expected.clone(),
+                actual: actual.clone(),
+            },
+            Self::UnsupportedOperation { command, firmware } => Self::UnsupportedOperation {
+                command: command.clone(),
+                firmware: firmware.clone(),
+            },
+            Self::InvalidStateTransition { from, to } => Self::InvalidStateTransition {
+                from: from.clone(),
+                to: to.clone(),
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+    use std::io;
+
+    #[test]
+    fn test_source_chain_keeps_os_error() {
+        let error = LumidoxError::communication("Cannot open COM3", io::Error::from(io::ErrorKind::PermissionDenied));
+        assert_eq!(error.kind(), "communication_error");
+        assert!(error.is_communication_error());
+
+        let cloned = error.clone();
+        let source = cloned.source().and_then(|e| e.downcast_ref::<io::Error>()).unwrap();
+        assert_eq!(source.kind(), io::ErrorKind::PermissionDenied);
+        assert!(cloned.to_string().starts_with("Device communication error: Cannot open COM3: "));
+
+        let reconnect = LumidoxError::ReconnectFailed { port: "COM3".to_string(), attempts: 1, source: ErrorSource::new(error) };
+        assert!(reconnect.source().and_then(|e| e.downcast_ref::<LumidoxError>()).is_some());
+        let root = reconnect.source().and_then(Error::source).and_then(|e| e.downcast_ref::<io::Error>());
+        assert_eq!(root.map(io::Error::kind), Some(io::ErrorKind::PermissionDenied));
+    }
+}
@@ -6,14 +6,20 @@
 //! - `operations`: Unified operation interfaces for CLI/GUI
 //! - `types`: Common type definitions and aliases
 //! - `calculations`: Mathematical calculations and algorithms
+//! - `cancellation`: Cancellation tokens for long-running sequences
+//! - `progress`: Progress reporting for timed operations
 
 pub mod error;
 pub mod operations;
 pub mod types;
 pub mod calculations;
+pub mod cancellation;
+pub mod progress;
 
 // Re-export commonly used items for convenience
 pub use error::LumidoxError;
 pub use operations::{DeviceControlOperations, DeviceOperationData};
 pub use types::Result;
+pub use cancellation::CancellationToken;
+pub use progress::{NoProgress, ProgressReporter};
 pub use calculations::*;
@@ -72,6 +72,19 @@ impl DeviceControlOperations {
         ShutdownOperations::shutdown_device_unified(device)
     }
 
+    /// Reset the device and library state to defaults
+    /// 
+    /// Delegates to the hierarchical shutdown operations.
+    /// 
+    /// # Arguments
+    /// * `device` - Mutable reference to the device controller
+    /// 
+    /// # Returns
+    /// * `OperationResult<DeviceOperationData>` - Structured result with operation data
+    pub fn reset_device_to_defaults(device: &mut LumidoxDevice) -> OperationResult<DeviceOperationData> {
+        ShutdownOperations::reset_to_defaults_unified(device)
+    }
+
     /// Get device state as a string for logging/display (legacy helper)
     /// 
     /// Provides backward compatibility for the helper function used by
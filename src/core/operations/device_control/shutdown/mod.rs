@@ -92,6 +92,46 @@ impl ShutdownOperations {
         }
     }
 
+    /// Reset the device to its defaults using unified operation pattern
+    ///
+    /// Runs `LumidoxDevice::reset_to_defaults`, which leaves the output off
+    /// and the device in local mode with default currents.
+    ///
+    /// # Arguments
+    /// * `device` - Mutable reference to the device to reset
+    ///
+    /// # Returns
+    /// * `OperationResult<DeviceOperationData>` - Structured operation result
+    ///
+    /// # Example
+    /// ```
+    /// let response = ShutdownOperations::reset_to_defaults_unified(&mut device)?;
+    /// println!("Operation: {}", response.message);
+    /// ```
+    pub fn reset_to_defaults_unified(
+        device: &mut LumidoxDevice
+    ) -> OperationResult<DeviceOperationData> {
+        let start_time = Instant::now();
+        let previous_state = Self::get_device_state_string(device);
+
+        device.reset_to_defaults()
+            .map_err(|e| LumidoxError::DeviceError(format!("Failed to reset device to defaults: {}", e)))?;
+
+        let duration = start_time.elapsed().as_millis() as u64;
+        let data = DeviceOperationData::DeviceControl {
+            previous_state,
+            new_state: Self::get_device_state_string(device),
+            success: true,
+        };
+
+        Ok(OperationResponse::success_with_duration(
+            data,
+            "Device reset to defaults and returned to local mode".to_string(),
+            "reset_to_defaults".to_string(),
+            duration,
+        ).with_context("operation".to_string(), "device_reset_to_defaults".to_string()))
+    }
+
     /// Validate device readiness for shutdown operations
     ///
     /// Provides centralized validation logic for device shutdown readiness
@@ -10,7 +10,7 @@
 //! - Consistent error handling and device state management
 //! - Interface-independent business logic
 
-use crate::core::LumidoxError;
+use crate::core::{CancellationToken, LumidoxError};
 use crate::core::operations::result_types::{OperationResult, OperationResponse, DeviceOperationData};
 use crate::device::LumidoxDevice;
 use std::time::Instant;
@@ -39,7 +39,9 @@ impl StageOperations {
     /// The response contains `DeviceOperationData::StageFiring` with:
     /// - `stage`: The stage number that was fired
     /// - `success`: Whether the firing operation succeeded
-    /// - `current_ma`: The current value used for firing (if available)
+    /// - `current_ma`: The stage FIRE current used for firing. It is read from
+    ///   the device as the first step of the firing sequence, so it is always
+    ///   `Some` in a successful response; a failed read fails the fire.
     ///
     /// # Example
     /// ```
@@ -52,6 +54,38 @@ impl StageOperations {
     pub fn fire_stage_unified(
         device: &mut LumidoxDevice,
         stage: u8
+    ) -> OperationResult<DeviceOperationData> {
+        Self::fire_stage_cancellable_unified(device, stage, &CancellationToken::new())
+    }
+
+    /// Fire a specific stage using unified operation pattern with cancellation
+    ///
+    /// Behaves like `fire_stage_unified`, but the firing sequence can be
+    /// cancelled through the token. Cancellation is returned unchanged as
+    /// `LumidoxError::OperationCancelled` so interfaces can present it as a
+    /// neutral outcome rather than a failure.
+    ///
+    /// # Arguments
+    /// * `device` - Mutable reference to the device for firing operations
+    /// * `stage` - Stage number to fire (1-5)
+    /// * `cancel` - Token used to request cancellation from another task
+    ///
+    /// # Returns
+    /// * `OperationResult<DeviceOperationData>` - Structured operation result
+    ///
+    /// # Example
+    /// ```
+    /// let token = CancellationToken::new();
+    /// match StageOperations::fire_stage_cancellable_unified(&mut device, 3, &token) {
+    ///     Ok(response) => println!("{}", response.message),
+    ///     Err(e) if e.is_cancelled() => println!("Cancelled"),
+    ///     Err(e) => eprintln!("{}", e),
+    /// }
+    /// ```
+    pub fn fire_stage_cancellable_unified(
+        device: &mut LumidoxDevice,
+        stage: u8,
+        cancel: &CancellationToken
     ) -> OperationResult<DeviceOperationData> {
         // Validate stage number
         if !(1..=5).contains(&stage) {
@@ -62,21 +96,18 @@ impl StageOperations {
 
         let start_time = Instant::now();
 
-        // Attempt to get the current for this stage before firing (for response data)
-        let current_ma = device.get_stage_arm_current(stage).ok();
-
-        // Execute the firing operation using existing device method
-        match device.fire_stage(stage) {
-            Ok(_) => {
+        // Execute the firing operation; the device reports the FIRE current it used
+        match device.fire_stage_cancellable(stage, cancel) {
+            Ok(current_ma) => {
                 let duration = start_time.elapsed().as_millis() as u64;
 
                 let data = DeviceOperationData::StageFiring {
                     stage,
-                    current_ma,
+                    current_ma: Some(current_ma),
                     success: true,
                 };
 
-                let message = format!("Stage {} fired successfully", stage);
+                let message = format!("Stage {} fired successfully (Current: {}mA)", stage, current_ma);
 
                 Ok(OperationResponse::success_with_duration(
                     data,
@@ -85,6 +116,7 @@ impl StageOperations {
                     duration,
                 ).with_context("stage".to_string(), stage.to_string()))
             }
+            Err(e) if e.is_cancelled() => Err(e),
             Err(e) => {
                 Err(LumidoxError::DeviceError(format!("Failed to fire stage {}: {}", stage, e)))
             }
@@ -108,12 +140,6 @@ impl StageOperations {
     /// StageOperations::validate_stage_number(6)?; // Error
     /// ```
     pub fn validate_stage_number(stage: u8) -> crate::core::Result<()> {
-        if !(1..=5).contains(&stage) {
-            Err(LumidoxError::InvalidInput(
-                format!("Invalid stage number: {}. Must be 1-5.", stage)
-            ))
-        } else {
-            Ok(())
-        }
+        crate::device::operations::control::validation::validate_stage_number(stage)
     }
 }
@@ -279,3 +279,50 @@ mod performance_and_reliability_tests {
         assert!(invalid_result2.is_err(), "Second invalid validation should fail");
     }
 }
+
+#[cfg(test)]
+mod fire_stage_unified_device_tests {
+    use super::*;
+    use crate::communication::protocol::commands;
+    use crate::communication::{MockTransport, ProtocolHandler};
+    use crate::device::models::DeviceMode;
+    use crate::device::LumidoxDevice;
+
+    /// Device already in remote mode, scripted to fire stage 3 at the given current
+    fn device_firing_stage_3(current_ma: u16) -> LumidoxDevice {
+        let transport = MockTransport::new()
+            .expect_read(commands::STAGE_CURRENTS[2], current_ma)
+            .expect_command(commands::SET_CURRENT, current_ma, 0)
+            .expect_command(commands::SET_MODE, DeviceMode::Remote as u16, 0);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap());
+        device.initialized = true;
+        device.current_mode = Some(DeviceMode::Remote);
+        device
+    }
+
+    #[test]
+    fn test_successful_fire_reports_stage_fire_current() {
+        let mut device = device_firing_stage_3(820);
+
+        let response = StageOperations::fire_stage_unified(&mut device, 3).unwrap();
+        match response.data {
+            DeviceOperationData::StageFiring { stage, current_ma, success } => {
+                assert_eq!(stage, 3);
+                assert_eq!(current_ma, Some(820));
+                assert!(success);
+            }
+            other => panic!("Expected StageFiring data, got {:?}", other),
+        }
+        assert!(response.message.contains("(Current: 820mA)"));
+    }
+
+    #[test]
+    fn test_failed_fire_returns_error_instead_of_data() {
+        // Nothing scripted: reading the stage current fails before anything is sent
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(MockTransport::new()).unwrap());
+        device.initialized = true;
+
+        let error = StageOperations::fire_stage_unified(&mut device, 3).unwrap_err();
+        assert!(matches!(error, LumidoxError::DeviceError(ref msg) if msg.starts_with("Failed to fire stage 3")));
+    }
+}
@@ -10,7 +10,6 @@
 //! - Consistent error handling and stage validation
 //! - Interface-independent business logic
 
-use crate::core::LumidoxError;
 use crate::core::operations::result_types::{OperationResult, OperationResponse, DeviceOperationData};
 use crate::device::LumidoxDevice;
 use std::time::Instant;
@@ -204,12 +203,7 @@ impl StageInfoOperations {
     /// # Returns
     /// * `Result<()>` - Success if valid, error if invalid
     pub fn validate_stage_number(stage: u8) -> crate::core::Result<()> {
-        if !(1..=5).contains(&stage) {
-            return Err(LumidoxError::InvalidInput(
-                format!("Invalid stage number: {}. Must be 1-5", stage)
-            ));
-        }
-        Ok(())
+        crate::device::operations::control::validation::validate_stage_number(stage)
     }
 
     /// Read stage current (placeholder implementation)
@@ -12,6 +12,7 @@ use crate::device::models::PowerInfo;
 /// Represents all power units supported by the Lumidox II device and
 /// conversion system, matching the Python reference implementation.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PowerUnit {
     /// Watts total radiant power
     Watts,
@@ -91,6 +92,7 @@ impl PowerUnit {
 /// Contains the results of power unit conversion operations including
 /// converted values and their associated unit strings.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConversionResult {
     /// Converted total power value
     pub total_power: f32,
@@ -195,7 +195,7 @@ impl PowerDebugOperations {
         
         let duration = start_time.elapsed();
         if duration.as_millis() > 1000 {
-            eprintln!("Warning: Stage {} measurement took {}ms", stage, duration.as_millis());
+            log::warn!("Stage {} measurement took {}ms", stage, duration.as_millis());
         }
         
         Ok(measurement)
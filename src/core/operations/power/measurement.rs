@@ -14,6 +14,7 @@ use std::time::Instant;
 /// Contains all power measurement information for a specific stage including
 /// raw device data, converted values, current settings, and metadata.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PowerMeasurementData {
     /// Stage number (1-5)
     pub stage_number: u8,
@@ -24,6 +25,10 @@ pub struct PowerMeasurementData {
     /// Current settings (ARM mA, FIRE mA)
     pub current_ma: (u16, u16),
     /// Timestamp when measurement was taken
+    ///
+    /// Not serialized: an `Instant` only has meaning within this process, so
+    /// deserialized data is stamped with the time it was read.
+    #[cfg_attr(feature = "serde", serde(skip, default = "Instant::now"))]
     pub measurement_timestamp: Instant,
 }
 
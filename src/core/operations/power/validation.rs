@@ -13,6 +13,7 @@ use super::measurement::PowerMeasurementData;
 /// Contains the results of power validation operations including
 /// validation status, detected issues, and recommendations.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PowerValidationResult {
     /// Whether validation passed
     pub is_valid: bool,
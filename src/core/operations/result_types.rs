@@ -34,7 +34,14 @@ pub struct OperationMetadata {
 }
 
 /// Device operation data types
+///
+/// With the `serde` feature enabled this serializes as an internally tagged
+/// JSON object: a `"type"` field holding the snake_case variant name, followed
+/// by the variant's fields under their Rust names. This shape is relied on by
+/// JSON consumers and is pinned by tests.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum DeviceOperationData {
     /// Device control operation result
     DeviceControl {
@@ -135,7 +142,8 @@ pub enum DeviceOperationData {
         stages_data: Vec<crate::core::operations::power::PowerMeasurementData>,
         /// Target unit for conversion (if applied)
         target_unit: Option<crate::core::operations::power::PowerUnit>,
-        /// Timestamp when measurements were taken
+        /// Timestamp when measurements were taken (not serialized)
+        #[cfg_attr(feature = "serde", serde(skip, default = "std::time::Instant::now"))]
         measurement_timestamp: std::time::Instant,
     },
 }
@@ -192,3 +200,159 @@ impl OperationMetadata {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::core::operations::power::{ConversionResult, PowerMeasurementData, PowerUnit, PowerValidationResult};
+    use crate::device::models::PowerInfo;
+    use serde_json::{json, Value};
+
+    /// Serialize, check the pinned JSON shape, and check it survives a round trip
+    fn assert_json_shape(data: DeviceOperationData, expected: Value) {
+        let value = serde_json::to_value(&data).unwrap();
+        assert_eq!(value, expected);
+
+        let decoded: DeviceOperationData = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), value);
+    }
+
+    fn measurement() -> PowerMeasurementData {
+        let power_info = PowerInfo {
+            total_power: 12.5,
+            total_units: "mW".to_string(),
+            per_power: 0.5,
+            per_units: "mW".to_string(),
+        };
+        PowerMeasurementData::new(2, power_info.clone(), ConversionResult::from_raw_power_info(power_info), (100, 1500))
+    }
+
+    #[test]
+    fn test_control_and_firing_variants_json_shape() {
+        assert_json_shape(
+            DeviceOperationData::DeviceControl { previous_state: None, new_state: Some("Armed".to_string()), success: true },
+            json!({"type": "device_control", "previous_state": null, "new_state": "Armed", "success": true}),
+        );
+        assert_json_shape(
+            DeviceOperationData::StageFiring { stage: 3, current_ma: Some(1500), success: true },
+            json!({"type": "stage_firing", "stage": 3, "current_ma": 1500, "success": true}),
+        );
+        assert_json_shape(
+            DeviceOperationData::StageFiring { stage: 3, current_ma: None, success: false },
+            json!({"type": "stage_firing", "stage": 3, "current_ma": null, "success": false}),
+        );
+        assert_json_shape(
+            DeviceOperationData::CurrentFiring { current_ma: 750, success: true },
+            json!({"type": "current_firing", "current_ma": 750, "success": true}),
+        );
+    }
+
+    #[test]
+    fn test_status_variants_json_shape() {
+        assert_json_shape(
+            DeviceOperationData::StatusInfo { device_info: "LX2".to_string(), connected: true, mode: None },
+            json!({"type": "status_info", "device_info": "LX2", "connected": true, "mode": null}),
+        );
+        assert_json_shape(
+            DeviceOperationData::DeviceStatus {
+                current_mode: Some("Remote".to_string()),
+                arm_current: Some(100),
+                fire_current: None,
+                remote_mode_state: Some(3),
+                connection_healthy: true,
+                ready_for_operations: false,
+            },
+            json!({
+                "type": "device_status",
+                "current_mode": "Remote",
+                "arm_current": 100,
+                "fire_current": null,
+                "remote_mode_state": 3,
+                "connection_healthy": true,
+                "ready_for_operations": false
+            }),
+        );
+        assert_json_shape(
+            DeviceOperationData::Connection { connected: false, port_name: Some("COM3".to_string()), device_info: None },
+            json!({"type": "connection", "connected": false, "port_name": "COM3", "device_info": null}),
+        );
+    }
+
+    #[test]
+    fn test_parameter_variants_json_shape() {
+        assert_json_shape(
+            DeviceOperationData::ParameterInfo {
+                parameter_name: "volt_limit".to_string(),
+                value: Some("12.5".to_string()),
+                units: Some("V".to_string()),
+                valid_range: true,
+                metadata: None,
+            },
+            json!({
+                "type": "parameter_info",
+                "parameter_name": "volt_limit",
+                "value": "12.5",
+                "units": "V",
+                "valid_range": true,
+                "metadata": null
+            }),
+        );
+        assert_json_shape(
+            DeviceOperationData::StageInfo {
+                stage_number: 5,
+                current_ma: Some(2000),
+                voltage_v: Some(12.5),
+                power_info: None,
+                ready_for_firing: true,
+            },
+            json!({
+                "type": "stage_info",
+                "stage_number": 5,
+                "current_ma": 2000,
+                "voltage_v": 12.5,
+                "power_info": null,
+                "ready_for_firing": true
+            }),
+        );
+    }
+
+    #[test]
+    fn test_power_variants_json_shape() {
+        let power_info = json!({"total_power": 12.5, "total_units": "mW", "per_power": 0.5, "per_units": "mW"});
+        let measurement_json = json!({
+            "stage_number": 2,
+            "raw_power_info": power_info,
+            "converted_data": {
+                "total_power": 12.5,
+                "total_units": "mW",
+                "per_power": 0.5,
+                "per_units": "mW",
+                "original_power_info": power_info,
+                "target_unit": null
+            },
+            "current_ma": [100, 1500]
+        });
+
+        assert_json_shape(
+            DeviceOperationData::PowerMeasurement {
+                stage_number: 2,
+                power_data: measurement(),
+                validation_result: PowerValidationResult::success(),
+            },
+            json!({
+                "type": "power_measurement",
+                "stage_number": 2,
+                "power_data": measurement_json,
+                "validation_result": {"is_valid": true, "issues": [], "recommendations": [], "confidence": 1.0}
+            }),
+        );
+        assert_json_shape(
+            DeviceOperationData::AllStagesPower {
+                stages_data: vec![measurement()],
+                target_unit: Some(PowerUnit::MilliWatts),
+                measurement_timestamp: std::time::Instant::now(),
+            },
+            json!({"type": "all_stages_power", "stages_data": [measurement_json], "target_unit": "MilliWatts"}),
+        );
+    }
+}
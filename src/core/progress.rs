@@ -0,0 +1,154 @@
+//! Progress reporting for timed Lumidox II Controller operations
+//!
+//! Timed operations such as firing a stage for a fixed duration report their
+//! progress through the `ProgressReporter` trait instead of printing or
+//! touching interface state themselves. The CLI implements it as a countdown
+//! line and the GUI as a progress bar, so both interfaces show the same
+//! progress while the device logic stays independent of either.
+
+use std::time::{Duration, Instant};
+
+/// Interval between progress ticks of a timed wait
+///
+/// Short enough for a smooth progress bar; reporters that only show whole
+/// seconds skip the ticks that do not change what they display.
+pub const PROGRESS_TICK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Receiver of progress updates from a timed operation
+///
+/// The operation calls `start` once, `tick` repeatedly while it runs, and
+/// `finish` once when the timed part is over, also when it ends early.
+///
+/// # Example
+/// ```
+/// struct Percent;
+///
+/// impl ProgressReporter for Percent {
+///     fn tick(&mut self, elapsed: Duration, total: Duration) {
+///         println!("{:.0}%", fraction_done(elapsed, total) * 100.0);
+///     }
+/// }
+/// ```
+pub trait ProgressReporter {
+    /// Called when the timed operation begins
+    ///
+    /// # Arguments
+    /// * `label` - What is being timed, e.g. "Stage 3"
+    /// * `total` - Planned duration of the operation
+    fn start(&mut self, _label: &str, _total: Duration) {}
+
+    /// Called periodically while the operation runs
+    ///
+    /// # Arguments
+    /// * `elapsed` - Time since the operation began, capped at `total`
+    /// * `total` - Planned duration of the operation
+    fn tick(&mut self, elapsed: Duration, total: Duration);
+
+    /// Called when the timed operation is over
+    fn finish(&mut self) {}
+}
+
+/// Reporter that ignores all progress
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl ProgressReporter for NoProgress {
+    fn tick(&mut self, _elapsed: Duration, _total: Duration) {}
+}
+
+/// Fraction of a timed operation that has passed
+///
+/// # Arguments
+/// * `elapsed` - Time since the operation began
+/// * `total` - Planned duration of the operation
+///
+/// # Returns
+/// * `f32` - Progress from 0.0 to 1.0; 1.0 for a zero duration
+pub fn fraction_done(elapsed: Duration, total: Duration) -> f32 {
+    if total.is_zero() {
+        return 1.0;
+    }
+    (elapsed.as_secs_f64() / total.as_secs_f64()).min(1.0) as f32
+}
+
+/// Whole seconds left of a timed operation, rounded up
+///
+/// Rounding up makes a countdown show "1" until the very end rather than
+/// "0" for the last second.
+///
+/// # Arguments
+/// * `elapsed` - Time since the operation began
+/// * `total` - Planned duration of the operation
+///
+/// # Returns
+/// * `u64` - Seconds remaining
+pub fn seconds_remaining(elapsed: Duration, total: Duration) -> u64 {
+    let remaining = total.saturating_sub(elapsed);
+    remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0)
+}
+
+/// Wait for a duration, reporting progress while waiting
+///
+/// Ticks every `PROGRESS_TICK_INTERVAL` and once more at the end, so the
+/// reporter always sees the wait complete. Does not call `start` or
+/// `finish`; the operation around the wait does.
+///
+/// # Arguments
+/// * `total` - How long to wait
+/// * `reporter` - Receiver of the ticks
+///
+/// # Example
+/// ```
+/// reporter.start("Stage 3", duration);
+/// wait_with_progress(duration, reporter);
+/// reporter.finish();
+/// ```
+pub fn wait_with_progress(total: Duration, reporter: &mut dyn ProgressReporter) {
+    let started = Instant::now();
+    loop {
+        let elapsed = started.elapsed();
+        if elapsed >= total {
+            break;
+        }
+        reporter.tick(elapsed, total);
+        std::thread::sleep(PROGRESS_TICK_INTERVAL.min(total - elapsed));
+    }
+    reporter.tick(total, total);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        ticks: Vec<Duration>,
+    }
+
+    impl ProgressReporter for Recorder {
+        fn tick(&mut self, elapsed: Duration, _total: Duration) {
+            self.ticks.push(elapsed);
+        }
+    }
+
+    #[test]
+    fn test_countdown_helpers() {
+        let total = Duration::from_secs(5);
+        assert_eq!(seconds_remaining(Duration::ZERO, total), 5);
+        assert_eq!(seconds_remaining(Duration::from_millis(4100), total), 1);
+        assert_eq!(seconds_remaining(Duration::from_secs(6), total), 0);
+        assert_eq!(fraction_done(Duration::from_millis(2500), total), 0.5);
+        assert_eq!(fraction_done(Duration::from_secs(1), Duration::ZERO), 1.0);
+    }
+
+    #[test]
+    fn test_wait_ends_with_a_complete_tick() {
+        let mut recorder = Recorder::default();
+        let total = Duration::from_millis(250);
+        wait_with_progress(total, &mut recorder);
+
+        assert!(recorder.ticks.len() >= 2);
+        assert_eq!(recorder.ticks.last(), Some(&total));
+        assert!(recorder.ticks.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
@@ -0,0 +1,270 @@
+//! Operation configuration for device connections
+//!
+//! This module defines the `OperationConfig` used to describe how a device
+//! should be connected and operated, so interfaces can build the settings
+//! once and pass them to `LumidoxDevice::connect`.
+
+use crate::communication::{Baud, ExpectedModel};
+use crate::communication::protocol::constants::DEFAULT_TIMEOUT;
+use super::super::IdentityReadRetry;
+use std::time::Duration;
+
+/// Connection and operation settings for a device session
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationConfig {
+    /// Serial port to connect to (used directly, or as fallback after auto-detection)
+    pub port: Option<String>,
+    /// Whether to try automatic port and baud rate detection first
+    pub auto_detect: bool,
+    /// Whether to print connection progress and details
+    pub verbose: bool,
+    /// Whether to use optimized stage transitions
+    pub optimize_transitions: bool,
+    /// Serial timeout for opening the port and readback commands
+    pub timeout: Duration,
+    /// Number of additional connection attempts after a failure
+    pub retries: u8,
+    /// Whether to flush the serial input buffer before every command
+    /// (commands after an error are always flushed)
+    pub flush_before_send: bool,
+    /// Retry policy for the identity read during device initialization
+    pub identity_retry: IdentityReadRetry,
+    /// Whether to read back the device state after firing and fail if the
+    /// output did not turn on
+    pub verify_fire: bool,
+    /// Whether to refuse firing until the device has been armed, see
+    /// `LumidoxDevice::set_strict_safety`
+    pub strict_safety: bool,
+    /// Maximum session duration before the device is turned off and
+    /// returned to local mode, None for no limit
+    pub max_session_duration: Option<Duration>,
+    /// Model the connected device is expected to be, None to accept any model
+    pub expected_model: Option<ExpectedModel>,
+    /// Baud rate for a direct port connection and the only rate probed by
+    /// auto-detection, None for the default rate and the standard detection list
+    pub baud: Option<Baud>,
+}
+
+impl Default for OperationConfig {
+    fn default() -> Self {
+        Self {
+            port: None,
+            auto_detect: true,
+            verbose: false,
+            optimize_transitions: true,
+            timeout: DEFAULT_TIMEOUT,
+            retries: 0,
+            flush_before_send: false,
+            identity_retry: IdentityReadRetry::default(),
+            verify_fire: false,
+            strict_safety: false,
+            max_session_duration: None,
+            expected_model: None,
+            baud: None,
+        }
+    }
+}
+
+impl OperationConfig {
+    /// Create a configuration that connects directly to a specific port
+    /// 
+    /// # Arguments
+    /// * `port` - Serial port to connect to
+    /// 
+    /// # Returns
+    /// * `OperationConfig` - Configuration with auto-detection disabled
+    /// 
+    /// # Example
+    /// ```
+    /// let config = OperationConfig::for_port("COM3");
+    /// let device = LumidoxDevice::connect(&config)?;
+    /// ```
+    pub fn for_port(port: impl Into<String>) -> Self {
+        Self {
+            port: Some(port.into()),
+            auto_detect: false,
+            ..Self::default()
+        }
+    }
+    
+    /// Start building a configuration from the defaults
+    /// 
+    /// Settings are named at each call site, so values cannot be swapped
+    /// between same-typed parameters.
+    /// 
+    /// # Returns
+    /// * `OperationConfigBuilder` - Builder initialized with `OperationConfig::default()`
+    /// 
+    /// # Example
+    /// ```
+    /// let config = OperationConfig::builder().port("COM3").verbose(true).build();
+    /// ```
+    pub fn builder() -> OperationConfigBuilder {
+        OperationConfigBuilder::default()
+    }
+    
+    /// Check whether the configuration names something to connect to
+    /// 
+    /// A connection attempt needs either auto-detection or an explicit port;
+    /// with neither it can only fail, so interfaces use this to decide
+    /// whether to offer connecting at all.
+    /// 
+    /// # Returns
+    /// * `bool` - True if auto-detection is enabled or a port is set
+    /// 
+    /// # Example
+    /// ```
+    /// if !config.has_connection_target() {
+    ///     println!("Select a port first");
+    /// }
+    /// ```
+    pub fn has_connection_target(&self) -> bool {
+        self.auto_detect || self.port.is_some()
+    }
+}
+
+/// Builder for `OperationConfig`
+/// 
+/// Starts from `OperationConfig::default()`. Unset values keep their
+/// defaults, so auto-detection stays enabled unless `auto_detect(false)` is
+/// given, and a configured port is used as the fallback.
+#[derive(Debug, Clone, Default)]
+pub struct OperationConfigBuilder {
+    config: OperationConfig,
+}
+
+impl OperationConfigBuilder {
+    /// Set the serial port to connect to
+    pub fn port(self, port: impl Into<String>) -> Self {
+        self.port_option(Some(port.into()))
+    }
+    
+    /// Set or clear the serial port to connect to
+    pub fn port_option(mut self, port: Option<String>) -> Self {
+        self.config.port = port;
+        self
+    }
+    
+    /// Set whether to try automatic port and baud rate detection first
+    pub fn auto_detect(mut self, auto_detect: bool) -> Self {
+        self.config.auto_detect = auto_detect;
+        self
+    }
+    
+    /// Set whether to print connection progress and details
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.config.verbose = verbose;
+        self
+    }
+    
+    /// Set whether to use optimized stage transitions
+    pub fn optimize_transitions(mut self, optimize_transitions: bool) -> Self {
+        self.config.optimize_transitions = optimize_transitions;
+        self
+    }
+    
+    /// Set the serial timeout for opening the port and readback commands
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = timeout;
+        self
+    }
+    
+    /// Set the number of additional connection attempts after a failure
+    pub fn retries(mut self, retries: u8) -> Self {
+        self.config.retries = retries;
+        self
+    }
+    
+    /// Set whether to flush the serial input buffer before every command
+    pub fn flush_before_send(mut self, flush_before_send: bool) -> Self {
+        self.config.flush_before_send = flush_before_send;
+        self
+    }
+    
+    /// Set the retry policy for the identity read during device initialization
+    pub fn identity_retry(mut self, identity_retry: IdentityReadRetry) -> Self {
+        self.config.identity_retry = identity_retry;
+        self
+    }
+    
+    /// Set whether to verify the device state after firing
+    pub fn verify_fire(mut self, verify_fire: bool) -> Self {
+        self.config.verify_fire = verify_fire;
+        self
+    }
+    
+    /// Set whether firing requires the device to be armed first
+    pub fn strict_safety(mut self, strict_safety: bool) -> Self {
+        self.config.strict_safety = strict_safety;
+        self
+    }
+    
+    /// Set the maximum session duration, None for no limit
+    pub fn max_session_duration(mut self, max_session_duration: Option<Duration>) -> Self {
+        self.config.max_session_duration = max_session_duration;
+        self
+    }
+    
+    /// Set the model the connected device is expected to be, None to accept any model
+    pub fn expected_model(mut self, expected_model: Option<ExpectedModel>) -> Self {
+        self.config.expected_model = expected_model;
+        self
+    }
+    
+    /// Set the baud rate, None for the default rate and standard detection
+    pub fn baud(mut self, baud: Option<Baud>) -> Self {
+        self.config.baud = baud;
+        self
+    }
+    
+    /// Finish building the configuration
+    /// 
+    /// # Returns
+    /// * `OperationConfig` - The configured settings
+    pub fn build(self) -> OperationConfig {
+        self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_uses_auto_detection() {
+        let config = OperationConfig::default();
+        assert!(config.auto_detect);
+        assert!(config.port.is_none());
+        assert!(config.optimize_transitions);
+        assert_eq!(config.timeout, DEFAULT_TIMEOUT);
+    }
+
+    #[test]
+    fn test_connection_target_requires_port_or_auto_detection() {
+        assert!(OperationConfig::default().has_connection_target());
+        assert!(OperationConfig::for_port("COM3").has_connection_target());
+        assert!(!OperationConfig::builder().auto_detect(false).build().has_connection_target());
+    }
+
+    #[test]
+    fn test_builder_sets_named_fields() {
+        let config = OperationConfig::builder()
+            .port("COM3")
+            .verbose(true)
+            .retries(2)
+            .build();
+        assert_eq!(config.port.as_deref(), Some("COM3"));
+        assert!(config.verbose);
+        assert!(config.auto_detect);
+        assert_eq!(config.retries, 2);
+        assert_eq!(OperationConfig::builder().build(), OperationConfig::default());
+    }
+
+    #[test]
+    fn test_for_port_disables_auto_detection() {
+        let config = OperationConfig::for_port("COM3");
+        assert_eq!(config.port.as_deref(), Some("COM3"));
+        assert!(!config.auto_detect);
+        assert_eq!(config.retries, 0);
+    }
+}
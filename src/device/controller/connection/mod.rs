@@ -0,0 +1,189 @@
+//! Device connection module for Lumidox II Controller
+//!
+//! This module centralizes how a LumidoxDevice is connected, replacing the
+//! separate boolean-parameter constructors used by the CLI and GUI with a
+//! single entry point driven by an `OperationConfig`.
+//!
+//! The connection system provides:
+//! - Automatic port and baud rate detection with manual port fallback
+//! - Direct connection to a specified port
+//! - Retry of failed connection attempts
+//! - Application of optimization and timeout settings to the connected device
+//! - Optional automatic reconnection after a transient disconnect
+//! - A connection state machine shared by the device controller and GUI
+
+pub mod config;
+pub mod reconnect;
+pub mod state;
+
+// Re-export commonly used items for convenience
+pub use config::{OperationConfig, OperationConfigBuilder};
+pub use reconnect::{AutoReconnect, SessionCurrents};
+pub use state::{ConnectionState, ConnectionStateMachine};
+
+use crate::core::{LumidoxError, Result};
+use crate::communication::{AutoConnector, ConnectionMethod, ProtocolHandler};
+use crate::communication::protocol::handler::CommandTimeouts;
+use super::LumidoxDevice;
+
+/// Device connection utilities and functionality
+pub struct DeviceConnector;
+
+impl DeviceConnector {
+    /// Connect to a device using an operation configuration
+    /// 
+    /// Attempts auto-detection first when enabled, falling back to the
+    /// configured port if detection fails, and retries the whole sequence
+    /// up to `config.retries` additional times.
+    /// 
+    /// # Arguments
+    /// * `config` - Connection and operation configuration
+    /// 
+    /// # Returns
+    /// * `Result<LumidoxDevice>` - Connected and initialized device or the last connection error
+    /// 
+    /// # Example
+    /// ```
+    /// let config = OperationConfig::for_port("COM3");
+    /// let device = DeviceConnector::connect(&config)?;
+    /// ```
+    pub fn connect(config: &OperationConfig) -> Result<LumidoxDevice> {
+        let mut attempt = 0;
+        loop {
+            match Self::connect_once(config) {
+                Ok(device) => return Ok(device),
+                Err(e) if attempt < config.retries => {
+                    attempt += 1;
+                    log::info!("Connection attempt {} of {} failed: {}. Retrying...",
+                        attempt, config.retries + 1, e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    
+    /// Perform a single connection attempt
+    /// 
+    /// # Arguments
+    /// * `config` - Connection and operation configuration
+    /// 
+    /// # Returns
+    /// * `Result<LumidoxDevice>` - Connected and initialized device or connection error
+    fn connect_once(config: &OperationConfig) -> Result<LumidoxDevice> {
+        if config.auto_detect {
+            match Self::connect_auto(config) {
+                Ok(device) => return Ok(device),
+                Err(e) if config.port.is_some() => {
+                    log::info!("Auto-detection failed: {}", e);
+                    log::info!("Falling back to specified port...");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        
+        let port_name = config.port.as_deref().ok_or_else(|| {
+            LumidoxError::InvalidInput(
+                "Port must be specified when auto-detection is disabled".to_string()
+            )
+        })?;
+        
+        Self::connect_port(port_name, config)
+    }
+    
+    /// Connect using automated port and baud rate detection
+    /// 
+    /// # Arguments
+    /// * `config` - Connection and operation configuration
+    /// 
+    /// # Returns
+    /// * `Result<LumidoxDevice>` - Connected and initialized device or detection error
+    fn connect_auto(config: &OperationConfig) -> Result<LumidoxDevice> {
+        let mut auto_config = if config.verbose {
+            AutoConnector::thorough_config()
+        } else {
+            AutoConnector::quick_config()
+        };
+        auto_config.verbose = config.verbose;
+        auto_config.identity_retry = config.identity_retry;
+        auto_config.expected_model = config.expected_model.clone();
+        if let Some(baud) = config.baud {
+            auto_config.baud_config.test_baud_rates = vec![baud];
+        }
+        
+        let (mut device, result) = AutoConnector::auto_connect(&auto_config)?;
+        Self::apply_config(&mut device, config);
+        for warning in &result.warnings {
+            log::warn!("{}", warning);
+        }
+        
+        if config.verbose {
+            println!("{} using {} method",
+                result.describe(),
+                match result.connection_method {
+                    ConnectionMethod::AutoDetected => "auto-detection",
+                    ConnectionMethod::Cached => "cached settings",
+                    ConnectionMethod::Manual => "manual configuration",
+                    ConnectionMethod::Fallback => "fallback",
+                });
+            
+            if let Some(info) = &result.device_info {
+                println!("Device: {} v{} (S/N: {})",
+                    info.model_number, info.firmware_version, info.serial_number);
+            }
+            
+            for line in result.timings.summary_lines() {
+                println!("{}", line);
+            }
+        }
+        
+        Ok(device)
+    }
+    
+    /// Connect directly to a specific port
+    /// 
+    /// # Arguments
+    /// * `port_name` - Serial port to connect to
+    /// * `config` - Connection and operation configuration
+    /// 
+    /// # Returns
+    /// * `Result<LumidoxDevice>` - Connected and initialized device or connection error
+    fn connect_port(port_name: &str, config: &OperationConfig) -> Result<LumidoxDevice> {
+        let port = serialport::new(port_name, config.baud.unwrap_or_default().as_u32())
+            .timeout(config.timeout)
+            .open()
+            .map_err(|e| LumidoxError::communication(format!("Cannot open {}", port_name), e))?;
+        
+        let protocol = ProtocolHandler::new(port)?;
+        let mut device = LumidoxDevice::new(protocol);
+        Self::apply_config(&mut device, config);
+        device.initialize()?;
+        
+        if let (Some(expected), Some(info)) = (&config.expected_model, device.info()) {
+            if let Some(warning) = expected.check(info)? {
+                log::warn!("{}", warning);
+            }
+        }
+        
+        Ok(device)
+    }
+    
+    /// Apply optimization and timeout settings to a device
+    /// 
+    /// # Arguments
+    /// * `device` - Device to configure
+    /// * `config` - Configuration providing the settings
+    fn apply_config(device: &mut LumidoxDevice, config: &OperationConfig) {
+        device.set_optimize_transitions(config.optimize_transitions);
+        
+        device.protocol.set_command_timeouts(CommandTimeouts {
+            readback: config.timeout,
+            other: config.timeout,
+            ..CommandTimeouts::default()
+        });
+        device.protocol.set_flush_before_send(config.flush_before_send);
+        device.set_identity_read_retry(config.identity_retry);
+        device.set_verify_fire(config.verify_fire);
+        device.set_strict_safety(config.strict_safety);
+        device.set_max_session_duration(config.max_session_duration);
+    }
+}
@@ -0,0 +1,162 @@
+//! Automatic reconnection for Lumidox II Controller
+//!
+//! This module holds the settings for transparently reconnecting a device
+//! after a transient disconnect, such as a USB-serial adapter dropping out
+//! for a few hundred milliseconds. The port name, baud rate and timeout are
+//! remembered when the device controller is created, so the same port can
+//! be reopened without the original connection configuration.
+//!
+//! Reconnection is disabled by default. When enabled, an operation that
+//! fails with a communication error reopens the port, re-runs device
+//! initialization and is retried once.
+//!
+//! A reconnect can also be requested manually. It then restores the ARM and
+//! FIRE currents recorded in `SessionCurrents`, in case the device was power
+//! cycled rather than just briefly unplugged.
+
+use crate::core::{LumidoxError, Result};
+use crate::communication::ProtocolHandler;
+use crate::communication::protocol::handler::ConnectionInfo;
+use std::time::Duration;
+
+/// Delay before each attempt to reopen the port
+pub const RECONNECT_DELAY: Duration = Duration::from_millis(250);
+
+/// Automatic reconnection settings and the port to reconnect to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoReconnect {
+    /// Whether communication errors trigger a reconnect
+    enabled: bool,
+    /// Number of attempts to reopen the port before giving up
+    max_attempts: u8,
+    /// Serial port the device was connected on
+    port_name: String,
+    /// Baud rate the port was opened with
+    baud_rate: u32,
+    /// Read timeout the port was opened with
+    timeout: Duration,
+}
+
+impl AutoReconnect {
+    /// Remember the port of an existing connection, with reconnection disabled
+    ///
+    /// # Arguments
+    /// * `info` - Connection information of the open port
+    ///
+    /// # Returns
+    /// * `AutoReconnect` - Disabled settings for reconnecting to the same port
+    ///
+    /// # Example
+    /// ```
+    /// let reconnect = AutoReconnect::from_connection(&protocol.get_connection_info());
+    /// ```
+    pub fn from_connection(info: &ConnectionInfo) -> Self {
+        Self {
+            enabled: false,
+            max_attempts: 0,
+            port_name: info.port_name.clone(),
+            baud_rate: info.baud_rate,
+            timeout: info.timeout,
+        }
+    }
+
+    /// Enable or disable reconnection
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether communication errors trigger a reconnect
+    /// * `max_attempts` - Attempts to reopen the port; 0 disables reconnection
+    pub fn configure(&mut self, enabled: bool, max_attempts: u8) {
+        self.enabled = enabled;
+        self.max_attempts = max_attempts;
+    }
+
+    /// Check whether reconnection is enabled
+    ///
+    /// # Returns
+    /// * `bool` - True if enabled with at least one attempt
+    pub fn is_enabled(&self) -> bool {
+        self.enabled && self.max_attempts > 0
+    }
+
+    /// Get the number of attempts to reopen the port
+    ///
+    /// # Returns
+    /// * `u8` - Maximum reconnect attempts
+    pub fn max_attempts(&self) -> u8 {
+        self.max_attempts
+    }
+
+    /// Get the serial port that is reopened
+    ///
+    /// # Returns
+    /// * `&str` - Port name, e.g. "COM3"
+    pub fn port_name(&self) -> &str {
+        &self.port_name
+    }
+
+    /// Get the baud rate the port is reopened with
+    ///
+    /// # Returns
+    /// * `u32` - Baud rate
+    pub fn baud_rate(&self) -> u32 {
+        self.baud_rate
+    }
+
+    /// Check whether an error should trigger a reconnect
+    ///
+    /// # Arguments
+    /// * `error` - Error returned by the failed operation
+    ///
+    /// # Returns
+    /// * `bool` - True if reconnection is enabled and the error is a communication error
+    pub fn should_reconnect(&self, error: &LumidoxError) -> bool {
+        self.is_enabled() && error.is_communication_error()
+    }
+
+    /// Reopen the remembered port
+    ///
+    /// # Returns
+    /// * `Result<ProtocolHandler>` - Protocol handler on the reopened port, or
+    ///   `CommunicationError` with the serial port error as its source
+    pub fn reopen(&self) -> Result<ProtocolHandler> {
+        let port = serialport::new(&self.port_name, self.baud_rate)
+            .timeout(self.timeout)
+            .open()
+            .map_err(|e| LumidoxError::communication(format!("Cannot open {}", self.port_name), e))?;
+        ProtocolHandler::new(port)
+    }
+}
+
+/// ARM and FIRE currents last set or read this session
+///
+/// Recorded while the connection works, so they are still known after it
+/// drops and can be restored by `LumidoxDevice::reconnect_same_port`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionCurrents {
+    /// ARM current in mA, None if not set or read yet
+    pub arm_ma: Option<u16>,
+    /// FIRE current in mA, None if not set or read yet
+    pub fire_ma: Option<u16>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::communication::MockTransport;
+
+    #[test]
+    fn test_only_communication_errors_trigger_reconnect() {
+        let protocol = ProtocolHandler::with_transport(MockTransport::new()).unwrap();
+        let mut reconnect = AutoReconnect::from_connection(&protocol.get_connection_info());
+        let io_error = LumidoxError::IoError(std::io::Error::from(std::io::ErrorKind::BrokenPipe));
+        assert_eq!(reconnect.port_name(), "MOCK");
+        assert!(!reconnect.should_reconnect(&io_error));
+
+        reconnect.configure(true, 2);
+        assert!(reconnect.should_reconnect(&io_error));
+        assert!(!reconnect.should_reconnect(&LumidoxError::DeviceError("bad state".to_string())));
+
+        reconnect.configure(true, 0);
+        assert!(!reconnect.should_reconnect(&io_error));
+    }
+}
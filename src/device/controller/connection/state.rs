@@ -0,0 +1,136 @@
+//! Connection state machine for Lumidox II Controller
+//!
+//! This module holds the single record of whether a device connection is
+//! being established, is up, is being re-established after a transient
+//! disconnect, or has failed. The device controller and the GUI both track
+//! their connection with a `ConnectionStateMachine`, so they agree on the
+//! states and on which transitions between them are allowed.
+//!
+//! Allowed transitions:
+//! - Disconnected -> Connecting
+//! - Connecting -> Connected, Failed or Disconnected
+//! - Connected -> Reconnecting, Failed or Disconnected
+//! - Reconnecting -> Connected, Failed or Disconnected
+//! - Failed -> Connecting, Reconnecting or Disconnected
+
+use crate::core::{LumidoxError, Result};
+use super::super::state_management::events::DeviceEvent;
+
+/// State of a device connection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// No connection and none being attempted
+    #[default]
+    Disconnected,
+    /// Connection being established
+    Connecting,
+    /// Connected and initialized
+    Connected,
+    /// Re-establishing a connection that was lost
+    Reconnecting,
+    /// Connection attempt failed or an established connection was lost
+    Failed,
+}
+
+impl ConnectionState {
+    /// Check whether a transition to another state is allowed
+    ///
+    /// # Arguments
+    /// * `to` - State to move to
+    ///
+    /// # Returns
+    /// * `bool` - True if the transition is allowed
+    pub fn can_transition_to(self, to: ConnectionState) -> bool {
+        use ConnectionState::*;
+        matches!(
+            (self, to),
+            (Disconnected, Connecting)
+                | (Connecting, Connected | Failed | Disconnected)
+                | (Connected, Reconnecting | Failed | Disconnected)
+                | (Reconnecting, Connected | Failed | Disconnected)
+                | (Failed, Connecting | Reconnecting | Disconnected)
+        )
+    }
+}
+
+/// Connection state with validated transitions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConnectionStateMachine {
+    /// Current state
+    state: ConnectionState,
+}
+
+impl ConnectionStateMachine {
+    /// Create a state machine for a connection that is already established
+    ///
+    /// # Returns
+    /// * `ConnectionStateMachine` - State machine in the `Connected` state
+    pub fn connected() -> Self {
+        Self { state: ConnectionState::Connected }
+    }
+
+    /// Get the current state
+    ///
+    /// # Returns
+    /// * `ConnectionState` - Current connection state
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    /// Check whether the connection is up
+    ///
+    /// # Returns
+    /// * `bool` - True in the `Connected` state
+    pub fn is_connected(&self) -> bool {
+        self.state == ConnectionState::Connected
+    }
+
+    /// Move to another state
+    ///
+    /// # Arguments
+    /// * `to` - State to move to
+    ///
+    /// # Returns
+    /// * `Result<DeviceEvent>` - `ConnectionChanged` event describing the
+    ///   transition, or `InvalidStateTransition` if it is not allowed
+    ///
+    /// # Example
+    /// ```
+    /// let event = connection.transition(ConnectionState::Reconnecting)?;
+    /// ```
+    pub fn transition(&mut self, to: ConnectionState) -> Result<DeviceEvent> {
+        let from = self.state;
+        if !from.can_transition_to(to) {
+            return Err(LumidoxError::InvalidStateTransition {
+                from: format!("{:?}", from),
+                to: format!("{:?}", to),
+            });
+        }
+        self.state = to;
+        Ok(DeviceEvent::ConnectionChanged { from, to })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transitions_are_validated() {
+        let mut connection = ConnectionStateMachine::default();
+        assert!(matches!(
+            connection.transition(ConnectionState::Connected),
+            Err(LumidoxError::InvalidStateTransition { .. })
+        ));
+        assert_eq!(connection.state(), ConnectionState::Disconnected);
+
+        for to in [ConnectionState::Connecting, ConnectionState::Connected, ConnectionState::Reconnecting, ConnectionState::Failed] {
+            connection.transition(to).unwrap();
+        }
+        assert_eq!(
+            connection.transition(ConnectionState::Disconnected).unwrap(),
+            DeviceEvent::ConnectionChanged { from: ConnectionState::Failed, to: ConnectionState::Disconnected }
+        );
+        assert!(!ConnectionState::Connected.can_transition_to(ConnectionState::Connected));
+    }
+}
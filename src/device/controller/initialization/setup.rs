@@ -8,14 +8,43 @@
 //! - Device controller construction with various configuration options
 //! - Device initialization sequence with proper mode setting and information retrieval
 //! - Error handling for initialization failures
+//! - Retry of the identity read so a single transient failure does not abort connection
 //! - Integration with device information and protocol systems
 
 use crate::core::Result;
-use crate::communication::ProtocolHandler;
+use crate::communication::{probe_identity, ProtocolHandler};
 use crate::device::models::DeviceMode;
-use crate::device::{info, operations::control};
+use crate::device::operations::control;
+use super::super::state_management::cooldown::StageCooldown;
+use super::super::state_management::session_limit::SessionLimit;
+use super::super::state_management::events::DeviceEvents;
+use super::super::state_management::policy::OperationPolicy;
+use super::super::connection::state::ConnectionStateMachine;
+use super::super::connection::reconnect::{AutoReconnect, SessionCurrents};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+/// Retry policy for the identity read during initialization
+///
+/// The first identity read right after the initial mode change occasionally
+/// fails on a healthy device. This retry is separate from connection-level
+/// retries, so one failed read does not reopen the port or restart detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdentityReadRetry {
+    /// Total number of read attempts (values below 1 are treated as 1)
+    pub attempts: u8,
+    /// Delay between attempts
+    pub delay: Duration,
+}
+
+impl Default for IdentityReadRetry {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            delay: Duration::from_millis(50),
+        }
+    }
+}
 
 /// Device initialization and setup utilities
 pub struct DeviceInitializer;
@@ -36,6 +65,7 @@ impl DeviceInitializer {
     /// - Optimized transitions: Enabled (true)
     /// - Device info: None (retrieved during initialization)
     /// - Current mode: None (set during initialization)
+    /// - Automatic reconnection: Disabled, remembering the protocol's port
     /// 
     /// # Example
     /// ```
@@ -44,10 +74,21 @@ impl DeviceInitializer {
     /// ```
     pub fn create_default(protocol: ProtocolHandler) -> super::super::LumidoxDevice {
         super::super::LumidoxDevice {
+            auto_reconnect: AutoReconnect::from_connection(&protocol.get_connection_info()),
             protocol,
             info: None,
             current_mode: None,
             optimize_transitions: true, // Enable optimized transitions by default
+            initialized: false,
+            stage_cooldown: StageCooldown::default(),
+            identity_retry: IdentityReadRetry::default(),
+            verify_fire: false,
+            strict_safety: false,
+            session_limit: SessionLimit::default(),
+            events: DeviceEvents::default(),
+            policy: OperationPolicy::default(),
+            connection: ConnectionStateMachine::connected(),
+            session_currents: SessionCurrents::default(),
         }
     }
     
@@ -78,10 +119,21 @@ impl DeviceInitializer {
         optimize_transitions: bool
     ) -> super::super::LumidoxDevice {
         super::super::LumidoxDevice {
+            auto_reconnect: AutoReconnect::from_connection(&protocol.get_connection_info()),
             protocol,
             info: None,
             current_mode: None,
             optimize_transitions,
+            initialized: false,
+            stage_cooldown: StageCooldown::default(),
+            identity_retry: IdentityReadRetry::default(),
+            verify_fire: false,
+            strict_safety: false,
+            session_limit: SessionLimit::default(),
+            events: DeviceEvents::default(),
+            policy: OperationPolicy::default(),
+            connection: ConnectionStateMachine::connected(),
+            session_currents: SessionCurrents::default(),
         }
     }
     
@@ -122,6 +174,8 @@ impl DeviceInitializer {
         // Retrieve and cache device information
         Self::retrieve_device_information(device)?;
         
+        device.initialized = true;
+        device.session_limit.start(Instant::now());
         Ok(())
     }
     
@@ -147,6 +201,7 @@ impl DeviceInitializer {
     ) -> Result<()> {
         control::set_mode(&mut device.protocol, mode)?;
         device.current_mode = Some(mode);
+        device.events.record_mode(mode);
         Ok(())
     }
     
@@ -170,6 +225,7 @@ impl DeviceInitializer {
     /// 
     /// Reads device information from the hardware and caches it in the
     /// device controller for future access without additional protocol calls.
+    /// The read is retried according to the device's identity read retry policy.
     /// 
     /// # Arguments
     /// * `device` - Mutable reference to the device controller
@@ -189,11 +245,39 @@ impl DeviceInitializer {
     /// DeviceInitializer::retrieve_device_information(&mut device)?;
     /// ```
     pub fn retrieve_device_information(device: &mut super::super::LumidoxDevice) -> Result<()> {
-        let device_info = info::read_device_info(&mut device.protocol)?;
+        let protocol = &mut device.protocol;
+        let device_info = Self::read_with_retry(device.identity_retry, || probe_identity(protocol))?;
         device.info = Some(device_info);
         Ok(())
     }
     
+    /// Run a read operation, retrying it after failures
+    /// 
+    /// # Arguments
+    /// * `retry` - Number of attempts and delay between them
+    /// * `read` - The read operation to attempt
+    /// 
+    /// # Returns
+    /// * `Result<T>` - First successful result, or the error from the last attempt
+    /// 
+    /// # Example
+    /// ```
+    /// let info = DeviceInitializer::read_with_retry(IdentityReadRetry::default(), || probe_identity(&mut protocol))?;
+    /// ```
+    pub fn read_with_retry<T>(retry: IdentityReadRetry, mut read: impl FnMut() -> Result<T>) -> Result<T> {
+        let attempts = retry.attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            match read() {
+                Err(_) if attempt < attempts => {
+                    attempt += 1;
+                    thread::sleep(retry.delay);
+                }
+                result => return result,
+            }
+        }
+    }
+    
     /// Validate device initialization state
     /// 
     /// Checks that the device has been properly initialized and is ready
@@ -319,3 +403,41 @@ pub struct InitializationRecommendations {
     /// Recommended protocol timeout in milliseconds
     pub recommended_timeout_ms: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::LumidoxError;
+
+    fn quick_retry(attempts: u8) -> IdentityReadRetry {
+        IdentityReadRetry { attempts, delay: Duration::ZERO }
+    }
+
+    #[test]
+    fn test_identity_read_retries_after_transient_failure() {
+        let mut calls = 0;
+        let result = DeviceInitializer::read_with_retry(quick_retry(3), || {
+            calls += 1;
+            if calls == 1 {
+                Err(LumidoxError::NoResponse)
+            } else {
+                Ok("1.42")
+            }
+        });
+
+        assert_eq!(result.unwrap(), "1.42");
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_identity_read_returns_last_error_when_attempts_exhausted() {
+        let mut calls = 0;
+        let result: Result<()> = DeviceInitializer::read_with_retry(quick_retry(2), || {
+            calls += 1;
+            Err(LumidoxError::ProtocolError(format!("attempt {}", calls)))
+        });
+
+        assert!(matches!(result, Err(LumidoxError::ProtocolError(message)) if message == "attempt 2"));
+        assert_eq!(calls, 2);
+    }
+}
@@ -7,6 +7,7 @@
 //! The controller architecture is organized into focused sub-modules:
 //! - `initialization`: Device setup and initialization procedures
 //! - `state_management`: Device mode control and state tracking
+//! - `connection`: Configuration-driven device connection
 //! - `operations`: Control and information operations
 //!
 //! This modular design provides:
@@ -16,18 +17,33 @@
 //! - Scalable architecture for future feature additions
 //! - Comprehensive documentation and usage examples
 
-use crate::core::Result;
-use crate::communication::ProtocolHandler;
-use crate::device::models::{DeviceMode, DeviceInfo, PowerInfo};
+use crate::core::{CancellationToken, LumidoxError, NoProgress, ProgressReporter, Result};
+use crate::core::progress::wait_with_progress;
+use crate::core::error::ErrorSource;
+use crate::communication::{verify_identity, ProtocolHandler};
+use crate::device::models::{DeviceConfigSnapshot, DeviceMode, DeviceInfo, DeviceSnapshot, PowerInfo};
 use crate::device::operations as device_operations;
+use std::time::{Duration, Instant};
 
 // Sub-module declarations
 pub mod initialization;
 pub mod state_management;
+pub mod connection;
 
 // Re-export key types and utilities for convenience
-pub use initialization::setup::DeviceInitializer;
+pub use initialization::setup::{DeviceInitializer, IdentityReadRetry};
 pub use state_management::mode_control::DeviceStateManager;
+pub use state_management::cooldown::{CooldownMode, StageCooldown};
+pub use state_management::fire_verification::FireTarget;
+pub use state_management::session_limit::SessionLimit;
+pub use state_management::output_guard::OutputOffGuard;
+pub use state_management::events::{DeviceEvent, DeviceEvents};
+pub use state_management::policy::{OperationPolicy, SafetyLevel};
+pub use state_management::firing_readiness::{FiringPrecondition, FiringReadiness};
+pub use connection::{AutoReconnect, SessionCurrents, ConnectionState, ConnectionStateMachine, DeviceConnector, OperationConfig, OperationConfigBuilder};
+
+/// Number of times an emergency stop is retried after a communication error
+pub const EMERGENCY_STOP_RETRIES: u8 = 3;
 
 /// High-level device controller with modular architecture
 /// 
@@ -68,6 +84,28 @@ pub struct LumidoxDevice {
     pub(crate) current_mode: Option<DeviceMode>,
     /// Whether to use optimized stage transitions (true) or always use full safety sequence (false)
     pub(crate) optimize_transitions: bool,
+    /// Whether `initialize` has completed successfully
+    pub(crate) initialized: bool,
+    /// Minimum interval enforcement between fires of the same stage
+    pub(crate) stage_cooldown: StageCooldown,
+    /// Retry policy for the identity read during initialization
+    pub(crate) identity_retry: IdentityReadRetry,
+    /// Whether to read back the device state after firing
+    pub(crate) verify_fire: bool,
+    /// Whether firing requires the device to be armed by the operator first
+    pub(crate) strict_safety: bool,
+    /// Session start time and maximum session duration
+    pub(crate) session_limit: SessionLimit,
+    /// Automatic reconnection settings and the port to reconnect to
+    pub(crate) auto_reconnect: AutoReconnect,
+    /// Event subscribers and the last mode reported to them
+    pub(crate) events: DeviceEvents,
+    /// State of the connection to the device
+    pub(crate) connection: ConnectionStateMachine,
+    /// Embedder policy gating operations by safety level
+    pub(crate) policy: OperationPolicy,
+    /// ARM and FIRE currents to restore after a manual reconnect
+    pub(crate) session_currents: SessionCurrents,
 }
 
 impl LumidoxDevice {
@@ -113,6 +151,27 @@ impl LumidoxDevice {
         DeviceInitializer::create_with_optimization(protocol, optimize_transitions)
     }
 
+    /// Connect to a device using an operation configuration
+    /// 
+    /// Single entry point for establishing a device connection. Uses
+    /// auto-detection and/or the configured port, applies the optimization
+    /// and timeout settings, and initializes the device.
+    /// 
+    /// # Arguments
+    /// * `config` - Connection and operation configuration
+    /// 
+    /// # Returns
+    /// * `Result<LumidoxDevice>` - Connected and initialized device or connection error
+    /// 
+    /// # Example
+    /// ```
+    /// let config = OperationConfig { verbose: true, ..OperationConfig::default() };
+    /// let mut device = LumidoxDevice::connect(&config)?;
+    /// ```
+    pub fn connect(config: &OperationConfig) -> Result<Self> {
+        DeviceConnector::connect(config)
+    }
+
     /// Enable or disable optimized stage transitions
     /// 
     /// Configures the optimization setting for stage transitions, allowing
@@ -164,6 +223,28 @@ impl LumidoxDevice {
         DeviceInitializer::initialize_device(self)
     }
 
+    /// Set the retry policy for the identity read during initialization
+    ///
+    /// # Arguments
+    /// * `retry` - Number of attempts and delay between them
+    ///
+    /// # Example
+    /// ```
+    /// device.set_identity_read_retry(IdentityReadRetry { attempts: 5, delay: Duration::from_millis(100) });
+    /// device.initialize()?;
+    /// ```
+    pub fn set_identity_read_retry(&mut self, retry: IdentityReadRetry) {
+        self.identity_retry = retry;
+    }
+
+    /// Get the retry policy for the identity read during initialization
+    ///
+    /// # Returns
+    /// * `IdentityReadRetry` - Number of attempts and delay between them
+    pub fn identity_read_retry(&self) -> IdentityReadRetry {
+        self.identity_retry
+    }
+
     /// Get device information (cached after initialization)
     ///
     /// Returns the cached device information that was retrieved during
@@ -183,6 +264,25 @@ impl LumidoxDevice {
         self.info.as_ref()
     }
 
+    /// Check whether the device has been initialized
+    ///
+    /// Control operations (mode changes, arming, firing, and ARM current
+    /// changes) are rejected with `LumidoxError::NotInitialized` until
+    /// `initialize` has completed successfully.
+    ///
+    /// # Returns
+    /// * `bool` - True if `initialize` has completed successfully
+    ///
+    /// # Example
+    /// ```
+    /// if !device.is_initialized() {
+    ///     device.initialize()?;
+    /// }
+    /// ```
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
     /// Set device operating mode
     /// 
     /// Sets the device to the specified operating mode and updates internal
@@ -199,6 +299,8 @@ impl LumidoxDevice {
     /// device.set_mode(DeviceMode::Standby)?;
     /// ```
     pub fn set_mode(&mut self, mode: DeviceMode) -> Result<()> {
+        self.policy.check("set_mode", SafetyLevel::Medium)?;
+        self.require_active_session()?;
         DeviceStateManager::set_device_mode(self, mode)
     }
 
@@ -219,6 +321,62 @@ impl LumidoxDevice {
         DeviceStateManager::get_current_mode(self)
     }
 
+    /// Subscribe to device events
+    ///
+    /// The returned channel receives a `DeviceEvent` for every mode change,
+    /// output switching on or off, and safety check stopping an operation,
+    /// from this call onward. Events are sent from the thread running the
+    /// operation; a subscriber that drops its receiver is removed.
+    ///
+    /// # Returns
+    /// * `Receiver<DeviceEvent>` - Channel of device events
+    ///
+    /// # Example
+    /// ```
+    /// let events = device.subscribe();
+    /// std::thread::spawn(move || {
+    ///     for event in events {
+    ///         println!("{:?}", event);
+    ///     }
+    /// });
+    /// ```
+    pub fn subscribe(&mut self) -> std::sync::mpsc::Receiver<DeviceEvent> {
+        self.events.subscribe()
+    }
+
+    /// Restrict which operations may run
+    ///
+    /// Before an operation that changes the device runs, the policy is asked
+    /// whether it is allowed; a refused operation returns
+    /// `LumidoxError::PolicyDenied` without sending anything to the device.
+    /// Firing and raw commands are `High`, arming, mode changes, shutdown,
+    /// applying a configuration and resetting are `Medium`, and setting the
+    /// ARM or FIRE current is `Low`. Reads, `turn_off` and `emergency_stop`
+    /// are never gated, and neither is the shutdown performed when the
+    /// maximum session duration is exceeded.
+    ///
+    /// # Arguments
+    /// * `policy` - Policy to apply, `OperationPolicy::default()` to allow all
+    ///
+    /// # Example
+    /// ```
+    /// // Kiosk mode: the operator may fire but not shut down or reset
+    /// device.set_operation_policy(OperationPolicy::new(|operation, _| {
+    ///     !matches!(operation, "shutdown" | "reset_to_defaults")
+    /// }));
+    /// ```
+    pub fn set_operation_policy(&mut self, policy: OperationPolicy) {
+        self.policy = policy;
+    }
+
+    /// Get the operation policy
+    ///
+    /// # Returns
+    /// * `&OperationPolicy` - Policy applied to device operations
+    pub fn operation_policy(&self) -> &OperationPolicy {
+        &self.policy
+    }
+
     /// Arm the device (prepare for firing)
     ///
     /// Prepares the device for firing operations by setting it to armed mode.
@@ -231,9 +389,14 @@ impl LumidoxDevice {
     /// device.arm()?;
     /// ```
     pub fn arm(&mut self) -> Result<()> {
-        device_operations::control::arm_device(&mut self.protocol)?;
-        self.current_mode = Some(DeviceMode::Remote);
-        Ok(())
+        self.policy.check("arm", SafetyLevel::Medium)?;
+        self.with_reconnect(|device| {
+            device.require_active_session()?;
+            device_operations::control::arm_device(&mut device.protocol)?;
+            device.current_mode = Some(DeviceMode::Remote);
+            device.events.record_mode(DeviceMode::Armed);
+            Ok(())
+        })
     }
     
     /// Fire a specific stage
@@ -252,13 +415,182 @@ impl LumidoxDevice {
     /// device.fire_stage(3)?;
     /// ```
     pub fn fire_stage(&mut self, stage_num: u8) -> Result<()> {
-        if self.optimize_transitions {
-            device_operations::control::fire_stage_smart(&mut self.protocol, stage_num, self.current_mode)?;
-        } else {
-            device_operations::control::fire_stage(&mut self.protocol, stage_num)?;
-        }
-        self.current_mode = Some(DeviceMode::Remote);
-        Ok(())
+        self.fire_stage_with_info(stage_num).map(|_| ())
+    }
+
+    /// Fire a specific stage for a fixed duration, then turn it off
+    ///
+    /// Blocks for `duration` after the stage has fired. The output is turned
+    /// off when the duration has passed; if firing fails, or the wait is
+    /// interrupted by a panic, an emergency stop is sent instead.
+    ///
+    /// # Arguments
+    /// * `stage_num` - The stage number to fire (1-5)
+    /// * `duration` - How long the output stays on
+    ///
+    /// # Returns
+    /// * `Result<Duration>` - Measured on-time, from the fire completing until
+    ///   the turn-off is sent, or firing/turn-off error
+    ///
+    /// # Example
+    /// ```
+    /// let on_time = device.fire_stage_for(3, Duration::from_secs(5))?;
+    /// println!("Stage 3 was on for {:.1}s", on_time.as_secs_f64());
+    /// ```
+    pub fn fire_stage_for(&mut self, stage_num: u8, duration: Duration) -> Result<Duration> {
+        self.fire_stage_for_with_progress(stage_num, duration, &mut NoProgress)
+    }
+
+    /// Fire a specific stage for a fixed duration, reporting progress
+    ///
+    /// Behaves like `fire_stage_for` and reports the on-time to `progress`
+    /// while waiting. The reporter is started once the stage has fired and
+    /// finished before the output is turned off, so it is not started at all
+    /// if firing fails.
+    ///
+    /// # Arguments
+    /// * `stage_num` - The stage number to fire (1-5)
+    /// * `duration` - How long the output stays on
+    /// * `progress` - Receiver of the countdown, e.g. a CLI countdown line
+    ///
+    /// # Returns
+    /// * `Result<Duration>` - Measured on-time or firing/turn-off error
+    ///
+    /// # Example
+    /// ```
+    /// let mut countdown = CountdownReporter::stdout();
+    /// device.fire_stage_for_with_progress(3, Duration::from_secs(5), &mut countdown)?;
+    /// ```
+    pub fn fire_stage_for_with_progress(
+        &mut self,
+        stage_num: u8,
+        duration: Duration,
+        progress: &mut dyn ProgressReporter,
+    ) -> Result<Duration> {
+        let mut guard = OutputOffGuard::new(self);
+        guard.device().fire_stage(stage_num)?;
+        let fired_at = Instant::now();
+        progress.start(&format!("Stage {}", stage_num), duration);
+        wait_with_progress(duration, progress);
+        progress.finish();
+        let on_time = fired_at.elapsed();
+        guard.release()?;
+        Ok(on_time)
+    }
+
+    /// Fire a specific stage and return its FIRE current
+    ///
+    /// Fires the specified stage like `fire_stage` and returns the FIRE current
+    /// that was read from the stage parameters as part of the firing sequence,
+    /// avoiding a separate `get_stage_fire_current` round-trip.
+    ///
+    /// # Arguments
+    /// * `stage_num` - The stage number to fire (1-5)
+    ///
+    /// # Returns
+    /// * `Result<u16>` - Stage FIRE current in milliamps or firing error
+    ///
+    /// # Example
+    /// ```
+    /// let current_ma = device.fire_stage_with_info(3)?;
+    /// println!("Stage 3 firing at {}mA", current_ma);
+    /// ```
+    pub fn fire_stage_with_info(&mut self, stage_num: u8) -> Result<u16> {
+        self.fire_stage_cancellable(stage_num, &CancellationToken::new())
+    }
+
+    /// Fire a specific stage with support for cancellation
+    ///
+    /// Fires the specified stage like `fire_stage_with_info`, checking the
+    /// token between protocol steps. A cancelled sequence returns the device
+    /// to standby and fails with `LumidoxError::OperationCancelled`.
+    ///
+    /// # Arguments
+    /// * `stage_num` - The stage number to fire (1-5)
+    /// * `cancel` - Token used to request cancellation from another task
+    ///
+    /// # Returns
+    /// * `Result<u16>` - Stage FIRE current in milliamps, firing error, or cancellation
+    ///
+    /// # Example
+    /// ```
+    /// let token = CancellationToken::new();
+    /// match device.fire_stage_cancellable(3, &token) {
+    ///     Err(e) if e.is_cancelled() => println!("Firing cancelled"),
+    ///     other => { other?; }
+    /// }
+    /// ```
+    pub fn fire_stage_cancellable(&mut self, stage_num: u8, cancel: &CancellationToken) -> Result<u16> {
+        self.policy.check("fire_stage", SafetyLevel::High)?;
+        self.with_reconnect(|device| {
+            device.require_active_session()?;
+            device.refuse_if_not_ready()?;
+            device.stage_cooldown.enforce(stage_num)?;
+            let mode = if device.optimize_transitions { device.current_mode } else { None };
+            let result = device_operations::control::fire_stage_smart_cancellable(&mut device.protocol, stage_num, mode, cancel);
+            match result {
+                Ok(current_ma) => {
+                    device.current_mode = Some(DeviceMode::Remote);
+                    device.events.record_mode(DeviceMode::Remote);
+                    device.stage_cooldown.record_fire(stage_num, Instant::now());
+                    if device.verify_fire {
+                        device.verify_firing(FireTarget::Stage(stage_num))?;
+                    }
+                    Ok(current_ma)
+                }
+                Err(e) => {
+                    if e.is_cancelled() {
+                        device.current_mode = Some(DeviceMode::Standby);
+                        device.events.record_mode(DeviceMode::Standby);
+                    }
+                    Err(e)
+                }
+            }
+        })
+    }
+
+    /// Set the minimum interval between fires of the same stage
+    ///
+    /// Protects the LEDs from rapid re-firing. When a stage is fired again
+    /// before the interval has elapsed, the configured `CooldownMode` decides
+    /// whether to wait (blocking) or return `LumidoxError::SafetyLimit` (strict).
+    ///
+    /// # Arguments
+    /// * `cooldown` - Minimum interval; `Duration::ZERO` disables the cooldown
+    ///
+    /// # Example
+    /// ```
+    /// device.set_stage_cooldown(Duration::from_secs(5));
+    /// device.set_stage_cooldown_mode(CooldownMode::Strict);
+    /// ```
+    pub fn set_stage_cooldown(&mut self, cooldown: Duration) {
+        self.stage_cooldown.set_interval(cooldown);
+    }
+
+    /// Set how an early stage fire is handled
+    ///
+    /// # Arguments
+    /// * `mode` - `CooldownMode::Blocking` to wait, `CooldownMode::Strict` to reject
+    ///
+    /// # Example
+    /// ```
+    /// device.set_stage_cooldown_mode(CooldownMode::Blocking);
+    /// ```
+    pub fn set_stage_cooldown_mode(&mut self, mode: CooldownMode) {
+        self.stage_cooldown.set_mode(mode);
+    }
+
+    /// Get the stage firing cooldown settings and state
+    ///
+    /// # Returns
+    /// * `&StageCooldown` - Configured interval, mode, and per-stage fire times
+    ///
+    /// # Example
+    /// ```
+    /// let remaining = device.stage_cooldown().remaining(2, Instant::now());
+    /// ```
+    pub fn stage_cooldown(&self) -> &StageCooldown {
+        &self.stage_cooldown
     }
 
     /// Fire with a specific current value
@@ -277,20 +609,510 @@ impl LumidoxDevice {
     /// device.fire_with_current(2500)?;
     /// ```
     pub fn fire_with_current(&mut self, current_ma: u16) -> Result<()> {
-        if self.optimize_transitions {
-            device_operations::control::fire_with_current_smart(&mut self.protocol, current_ma, self.current_mode)?;
-        } else {
-            device_operations::control::fire_with_current(&mut self.protocol, current_ma)?;
+        self.policy.check("fire_with_current", SafetyLevel::High)?;
+        self.with_reconnect(|device| {
+            device.require_active_session()?;
+            device.refuse_if_not_ready()?;
+            if device.optimize_transitions {
+                device_operations::control::fire_with_current_smart(&mut device.protocol, current_ma, device.current_mode)?;
+            } else {
+                device_operations::control::fire_with_current(&mut device.protocol, current_ma)?;
+            }
+            device.current_mode = Some(DeviceMode::Remote);
+            device.events.record_mode(DeviceMode::Remote);
+            if device.verify_fire {
+                device.verify_firing(FireTarget::Current(current_ma))?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Fire with a current reached in steps
+    ///
+    /// Starts at `step_ma` and re-issues the fire command at each step up to
+    /// `target_ma`, waiting `step_delay` between steps, so the LED array is
+    /// not hit with the full current at once. The target is validated
+    /// against the device maximum before anything is fired. If any step
+    /// fails, the ramp stops and the output is turned off before the error
+    /// is returned.
+    ///
+    /// # Arguments
+    /// * `target_ma` - Final current in milliamps
+    /// * `step_ma` - Current increase per step in milliamps
+    /// * `step_delay` - Time to hold each level before the next step
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success with the output at `target_ma`, `InvalidInput`
+    ///   for a zero or too high current, or the error of the failed step
+    ///
+    /// # Example
+    /// ```
+    /// device.fire_with_current_ramp(2000, 200, Duration::from_millis(100))?;
+    /// ```
+    pub fn fire_with_current_ramp(&mut self, target_ma: u16, step_ma: u16, step_delay: Duration) -> Result<()> {
+        self.policy.check("fire_with_current", SafetyLevel::High)?;
+        device_operations::control::validation::validate_non_zero_current(target_ma)?;
+        if step_ma == 0 {
+            return Err(LumidoxError::InvalidInput("Ramp step must be at least 1mA".to_string()));
+        }
+        let max_current = self.get_max_current()?;
+        device_operations::control::validation::validate_current(target_ma, max_current)?;
+
+        for level in device_operations::control::current_ramp_levels(target_ma, step_ma) {
+            if let Err(e) = self.fire_with_current(level) {
+                let _ = self.turn_off();
+                return Err(e);
+            }
+            if level < target_ma {
+                std::thread::sleep(step_delay);
+            }
+        }
+        Ok(())
+    }
+
+    /// Enable or disable verification of the device state after firing
+    ///
+    /// When enabled, a fire only succeeds once the device reports that it is
+    /// firing the requested stage or current. This catches a device that
+    /// acknowledges the command but does not energize the output, at the
+    /// cost of extra readback commands per fire.
+    ///
+    /// # Arguments
+    /// * `verify` - Whether to verify each fire
+    ///
+    /// # Example
+    /// ```
+    /// device.set_verify_fire(true);
+    /// ```
+    pub fn set_verify_fire(&mut self, verify: bool) {
+        self.verify_fire = verify;
+    }
+
+    /// Set the maximum duration of a device session
+    ///
+    /// The session starts when the device is initialized. Once it has run
+    /// longer than the limit, the next device operation, whether a control
+    /// operation, a read or a keep-alive heartbeat, turns the output off,
+    /// returns the device to local mode and fails with
+    /// `LumidoxError::SafetyLimit`, regardless of how recently commands were
+    /// sent. Call `initialize` to start a new session.
+    ///
+    /// # Arguments
+    /// * `max_duration` - Maximum session duration, None to remove the limit
+    ///
+    /// # Example
+    /// ```
+    /// device.set_max_session_duration(Some(Duration::from_secs(4 * 3600)));
+    /// ```
+    pub fn set_max_session_duration(&mut self, max_duration: Option<Duration>) {
+        self.session_limit.set_max_duration(max_duration);
+    }
+
+    /// Get the session start time and maximum duration
+    ///
+    /// # Returns
+    /// * `&SessionLimit` - Session tracking state
+    ///
+    /// # Example
+    /// ```
+    /// let elapsed = device.session_limit().elapsed(Instant::now());
+    /// ```
+    pub fn session_limit(&self) -> &SessionLimit {
+        &self.session_limit
+    }
+
+    /// Check the device is initialized and the session has not run too long
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success, `NotInitialized`, or `SafetyLimit` for an expired session
+    fn require_active_session(&mut self) -> Result<()> {
+        DeviceStateManager::require_initialized(self)?;
+        self.enforce_session_limit()
+    }
+
+    /// End the session if it has run longer than the maximum duration
+    ///
+    /// Called before every device operation, reads and the keep-alive
+    /// heartbeat included, so an expired session is ended at the first
+    /// operation after the limit even if no control operation follows. The
+    /// output is turned off and the device returned to local mode before the
+    /// limit is reported. `emergency_stop` is never blocked by the limit.
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success, or `SafetyLimit` for an expired session
+    fn enforce_session_limit(&mut self) -> Result<()> {
+        if !self.session_limit.is_exceeded(Instant::now()) {
+            return Ok(());
+        }
+
+        let max_duration = self.session_limit.max_duration().unwrap_or_default();
+        self.session_limit.end();
+        self.initialized = false;
+        let message = format!("Maximum session duration of {}s exceeded", max_duration.as_secs());
+        self.events.record_safety_trip(message.clone());
+        Err(LumidoxError::SafetyLimit(match self.return_to_local() {
+            Ok(()) => format!("{}; output turned off and device returned to local mode", message),
+            Err(e) => format!("{}; shutting down the device failed: {}", message, e),
+        }))
+    }
+
+    /// Enable or disable automatic reconnection after a communication error
+    ///
+    /// When enabled, a control or readback operation that fails with a
+    /// serial or I/O error reopens the port the device was created on (same
+    /// name and baud rate), re-runs `initialize` so the cached `DeviceInfo`
+    /// stays valid, and retries the operation once. The session start time
+    /// is kept, so reconnecting does not extend the maximum session duration.
+    /// If every attempt to reopen the port fails, the operation returns
+    /// `LumidoxError::ReconnectFailed`.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to reconnect after communication errors
+    /// * `max_attempts` - Attempts to reopen the port before giving up
+    ///
+    /// # Example
+    /// ```
+    /// device.set_auto_reconnect(true, 3);
+    /// device.fire_stage(2)?; // survives a brief adapter dropout
+    /// ```
+    pub fn set_auto_reconnect(&mut self, enabled: bool, max_attempts: u8) {
+        self.auto_reconnect.configure(enabled, max_attempts);
+    }
+
+    /// Get the automatic reconnection settings
+    ///
+    /// # Returns
+    /// * `&AutoReconnect` - Reconnection settings and remembered port
+    pub fn auto_reconnect(&self) -> &AutoReconnect {
+        &self.auto_reconnect
+    }
+
+    /// Poll a device condition until it is true or the timeout passes
+    ///
+    /// The condition is evaluated immediately and then every `interval`,
+    /// with a final evaluation when the timeout is reached. An error from the
+    /// condition ends polling and is returned as-is.
+    ///
+    /// # Arguments
+    /// * `condition` - Check run against the device, true when satisfied
+    /// * `timeout` - Maximum time to wait for the condition
+    /// * `interval` - Delay between evaluations
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success once the condition holds, `OperationTimeout`
+    ///   if it never did, or the condition's error
+    ///
+    /// # Example
+    /// ```
+    /// device.poll_until(
+    ///     |device| Ok(device.read_remote_mode()? == DeviceMode::Remote),
+    ///     Duration::from_secs(2),
+    ///     Duration::from_millis(100),
+    /// )?;
+    /// ```
+    pub fn poll_until<F>(&mut self, mut condition: F, timeout: Duration, interval: Duration) -> Result<()>
+    where
+        F: FnMut(&mut LumidoxDevice) -> Result<bool>,
+    {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if condition(self)? {
+                return Ok(());
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(LumidoxError::OperationTimeout { timeout });
+            }
+            std::thread::sleep(interval.min(deadline - now));
+        }
+    }
+
+    /// Run an operation, reconnecting and retrying it once after a communication error
+    ///
+    /// The session limit is checked first, so an expired session is ended
+    /// instead of running the operation.
+    fn with_reconnect<T>(&mut self, mut operation: impl FnMut(&mut Self) -> Result<T>) -> Result<T> {
+        self.enforce_session_limit()?;
+        match operation(self) {
+            Err(e) if self.auto_reconnect.should_reconnect(&e) => {
+                self.reconnect(&e)?;
+                operation(self)
+            }
+            result => result,
+        }
+    }
+
+    /// Reopen the port and re-initialize the device
+    ///
+    /// # Arguments
+    /// * `cause` - Communication error that triggered the reconnect
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success, or `ReconnectFailed` with the last attempt's error
+    fn reconnect(&mut self, cause: &LumidoxError) -> Result<()> {
+        self.set_connection_state(ConnectionState::Reconnecting)?;
+        let attempts = self.auto_reconnect.max_attempts();
+        let mut last_error = cause.clone();
+        for attempt in 1..=attempts {
+            std::thread::sleep(connection::reconnect::RECONNECT_DELAY);
+            log::debug!("Reconnect attempt {} of {} to {} after: {}",
+                attempt, attempts, self.auto_reconnect.port_name(), last_error);
+            match self.reconnect_once() {
+                Ok(()) => {
+                    log::debug!("Reconnected to {}", self.auto_reconnect.port_name());
+                    return self.set_connection_state(ConnectionState::Connected);
+                }
+                Err(e) => last_error = e,
+            }
+        }
+        self.initialized = false;
+        self.set_connection_state(ConnectionState::Failed)?;
+        Err(LumidoxError::ReconnectFailed {
+            port: self.auto_reconnect.port_name().to_string(),
+            attempts,
+            source: ErrorSource::new(last_error),
+        })
+    }
+
+    /// Move the connection to another state and notify subscribers
+    ///
+    /// # Arguments
+    /// * `to` - State to move to
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or `InvalidStateTransition`
+    fn set_connection_state(&mut self, to: ConnectionState) -> Result<()> {
+        let event = self.connection.transition(to)?;
+        self.events.emit(event);
+        Ok(())
+    }
+
+    /// Get the state of the connection to the device
+    ///
+    /// A device is created on an open port, so it starts out connected; an
+    /// automatic reconnect moves it through `Reconnecting` to `Connected`
+    /// or `Failed`.
+    ///
+    /// # Returns
+    /// * `ConnectionState` - Current connection state
+    ///
+    /// # Example
+    /// ```
+    /// if device.connection_state() == ConnectionState::Failed {
+    ///     println!("Device connection lost");
+    /// }
+    /// ```
+    pub fn connection_state(&self) -> ConnectionState {
+        self.connection.state()
+    }
+
+    /// Get the number of responses rejected for a checksum mismatch
+    ///
+    /// Counts mismatches since the device was connected, including across
+    /// automatic reconnects.
+    ///
+    /// # Returns
+    /// * `usize` - Checksum failures this session
+    ///
+    /// # Example
+    /// ```
+    /// if device.checksum_failure_count() > 0 {
+    ///     println!("Corrupted responses seen; check the cable");
+    /// }
+    /// ```
+    pub fn checksum_failure_count(&self) -> usize {
+        self.protocol.checksum_failure_count()
+    }
+
+    /// Reconnect to the same port after a transient failure
+    ///
+    /// The fast path when the cable glitched: reopens the port the device
+    /// was connected on, with the same baud rate, instead of scanning for
+    /// ports. The device must answer with the serial number it had before;
+    /// if a different device answers, the reconnect fails and the session
+    /// stays unusable. The ARM and FIRE currents recorded this session are
+    /// then written back, so a device that was power cycled continues with
+    /// the same settings. The output is never turned on.
+    ///
+    /// # Returns
+    /// * `Result<SessionCurrents>` - Currents that were restored, or
+    ///   `DeviceError` if the serial number differs, or the error reopening
+    ///   the port or restoring a current
+    ///
+    /// # Example
+    /// ```
+    /// let restored = device.reconnect_same_port()?;
+    /// if let Some(arm_ma) = restored.arm_ma {
+    ///     println!("ARM current restored to {}mA", arm_ma);
+    /// }
+    /// ```
+    pub fn reconnect_same_port(&mut self) -> Result<SessionCurrents> {
+        let restore = self.session_currents;
+        self.set_connection_state(ConnectionState::Reconnecting)?;
+        if let Err(e) = self.reconnect_once() {
+            self.initialized = false;
+            self.set_connection_state(ConnectionState::Failed)?;
+            return Err(e);
+        }
+        self.set_connection_state(ConnectionState::Connected)?;
+
+        if let Some(arm_ma) = restore.arm_ma {
+            self.set_arm_current(arm_ma)?;
+        }
+        if let Some(fire_ma) = restore.fire_ma {
+            self.set_fire_current(fire_ma)?;
+        }
+        Ok(restore)
+    }
+
+    /// Get the ARM and FIRE currents recorded this session
+    ///
+    /// # Returns
+    /// * `SessionCurrents` - Currents last set or read, restored by `reconnect_same_port`
+    pub fn session_currents(&self) -> SessionCurrents {
+        self.session_currents
+    }
+
+    /// Make a single attempt to reopen the port and re-initialize the device
+    fn reconnect_once(&mut self) -> Result<()> {
+        let mut protocol = self.auto_reconnect.reopen()?;
+        protocol.set_command_timeouts(*self.protocol.command_timeouts());
+        protocol.set_flush_before_send(self.protocol.is_flush_before_send());
+        protocol.checksum_failures = self.protocol.checksum_failures;
+
+        // A different unit on the same port must not be initialized in place of the original
+        if let Some(expected) = self.info.as_ref().map(|info| info.serial_number.clone()) {
+            if let Err(e) = verify_identity(&mut protocol, &expected) {
+                self.initialized = false;
+                return Err(e);
+            }
         }
-        self.current_mode = Some(DeviceMode::Remote);
+        self.protocol = protocol;
+
+        let session_limit = self.session_limit;
+        self.initialize()?;
+        self.session_limit = session_limit;
         Ok(())
     }
 
+    /// Require the device to be ready before firing
+    ///
+    /// When enabled, every fire first checks `check_firing_ready` and fails
+    /// with `LumidoxError::SafetyInterlock` listing the unmet preconditions
+    /// instead of taking the device through standby and arming by itself.
+    /// The operator then has to arm the device before firing.
+    ///
+    /// # Arguments
+    /// * `strict` - Whether to check firing readiness before each fire
+    ///
+    /// # Example
+    /// ```
+    /// device.set_strict_safety(true);
+    /// device.arm()?;
+    /// device.fire_stage(1)?;
+    /// ```
+    pub fn set_strict_safety(&mut self, strict: bool) {
+        self.strict_safety = strict;
+    }
+
+    /// Check if firing requires the device to be ready first
+    ///
+    /// # Returns
+    /// * `bool` - True if strict safety is enabled
+    pub fn is_strict_safety(&self) -> bool {
+        self.strict_safety
+    }
+
+    /// Check whether the device is in a safe state to fire
+    ///
+    /// Reads the remote mode, ARM current and maximum current and reports
+    /// which firing preconditions they meet. Nothing is changed on the
+    /// device.
+    ///
+    /// # Returns
+    /// * `Result<FiringReadiness>` - Readback with the met and unmet preconditions, or query error
+    ///
+    /// # Example
+    /// ```
+    /// let readiness = device.check_firing_ready()?;
+    /// if !readiness.is_ready() {
+    ///     println!("Not ready: {}", readiness.unmet_summary());
+    /// }
+    /// ```
+    pub fn check_firing_ready(&mut self) -> Result<FiringReadiness> {
+        Ok(FiringReadiness {
+            mode: self.read_remote_mode()?,
+            arm_current_ma: self.read_arm_current()?,
+            max_current_ma: self.get_max_current()?,
+        })
+    }
+
+    /// Refuse to fire when strict safety is enabled and the device is not ready
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success, `SafetyInterlock` listing the unmet preconditions, or query error
+    fn refuse_if_not_ready(&mut self) -> Result<()> {
+        if !self.strict_safety {
+            return Ok(());
+        }
+        let readiness = self.check_firing_ready()?;
+        if readiness.is_ready() {
+            return Ok(());
+        }
+        let flag = readiness.unmet_summary();
+        self.events.record_safety_trip(format!("Not ready to fire: {}", flag));
+        Err(LumidoxError::SafetyInterlock { flag })
+    }
+
+    /// Check if fires are verified by reading back the device state
+    ///
+    /// # Returns
+    /// * `bool` - True if fire verification is enabled
+    pub fn is_verify_fire(&self) -> bool {
+        self.verify_fire
+    }
+
+    /// Read back the device state and check that it is firing as requested
+    ///
+    /// A fire that cannot be verified may still be driving the output, so
+    /// when the check or its readback fails the output is stopped with
+    /// `emergency_stop` before the verification error is returned.
+    fn verify_firing(&mut self, target: FireTarget) -> Result<()> {
+        let result = self.check_firing(target);
+        if result.is_err() {
+            if let Err(e) = self.emergency_stop() {
+                log::warn!("Output may still be on after failed fire verification: {}", e);
+            }
+        }
+        result
+    }
+
+    /// Compare the device state read back with the requested fire
+    ///
+    /// The cached mode is updated from the readback, so a failed check does
+    /// not leave the controller believing the output is on.
+    fn check_firing(&mut self, target: FireTarget) -> Result<()> {
+        let mode = self.read_remote_mode()?;
+        self.current_mode = Some(mode);
+        self.events.record_mode(mode);
+        let observed = match target {
+            FireTarget::Stage(_) => self.read_active_stage()?.map(FireTarget::Stage),
+            FireTarget::Current(_) => Some(FireTarget::Current(self.read_fire_current()?)),
+        };
+        target.check(mode, observed)
+    }
+
     /// Turn off the device
     ///
     /// Safely turns off the device output while maintaining remote control
     /// capability.
     ///
+    /// The mode is read back afterwards rather than assumed, since the
+    /// firmware leaves the device in standby and the next fire has to arm it
+    /// again. If the read-back fails, the cached mode is cleared so the next
+    /// fire uses the full arming sequence and no mode event is recorded; the
+    /// turn-off itself still succeeds.
+    ///
     /// # Returns
     /// * `Result<()>` - Success or turn-off error
     ///
@@ -299,9 +1121,21 @@ impl LumidoxDevice {
     /// device.turn_off()?;
     /// ```
     pub fn turn_off(&mut self) -> Result<()> {
-        device_operations::control::turn_off(&mut self.protocol)?;
-        self.current_mode = Some(DeviceMode::Remote);
-        Ok(())
+        self.with_reconnect(|device| {
+            device_operations::control::turn_off(&mut device.protocol)?;
+            let mode = match device_operations::readback::read_remote_mode_state(&mut device.protocol) {
+                Ok(mode) => Some(mode),
+                Err(e) => {
+                    log::debug!("Mode after turn-off unknown: {}", e);
+                    None
+                }
+            };
+            device.current_mode = mode;
+            if let Some(mode) = mode {
+                device.events.record_mode(mode);
+            }
+            Ok(())
+        })
     }
 
     /// Shutdown and return to local mode
@@ -316,9 +1150,53 @@ impl LumidoxDevice {
     /// device.shutdown()?;
     /// ```
     pub fn shutdown(&mut self) -> Result<()> {
-        device_operations::control::shutdown(&mut self.protocol)?;
+        self.policy.check("shutdown", SafetyLevel::Medium)?;
+        self.return_to_local()
+    }
+
+    /// Shut down and return to local mode without consulting the policy
+    ///
+    /// Used where the library itself ends a session, such as an expired
+    /// session limit, which an operation policy must not be able to prevent.
+    fn return_to_local(&mut self) -> Result<()> {
+        self.with_reconnect(|device| {
+            device_operations::control::shutdown(&mut device.protocol)?;
+            device.current_mode = None;
+            device.events.record_mode(DeviceMode::Standby);
+            device.events.record_mode(DeviceMode::Local);
+            Ok(())
+        })
+    }
+
+    /// Turn off the output as fast as possible
+    ///
+    /// Sends the standby command straight away, without the settle delay of
+    /// `turn_off`, without consulting the cached mode and without waiting for
+    /// a reconnect. A communication error is retried up to
+    /// `EMERGENCY_STOP_RETRIES` times. The cached mode is cleared whatever the
+    /// outcome, so the next operation re-establishes a known state. Works on a
+    /// device that was never initialized.
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success, or the error of the last attempt
+    ///
+    /// # Example
+    /// ```
+    /// device.emergency_stop()?;
+    /// ```
+    pub fn emergency_stop(&mut self) -> Result<()> {
+        let mut retries = 0;
+        let result = loop {
+            match device_operations::control::set_mode(&mut self.protocol, DeviceMode::Standby) {
+                Err(e) if e.is_communication_error() && retries < EMERGENCY_STOP_RETRIES => retries += 1,
+                result => break result,
+            }
+        };
         self.current_mode = None;
-        Ok(())
+        if result.is_ok() {
+            self.events.record_mode(DeviceMode::Standby);
+        }
+        result
     }
 
     /// Get maximum current setting
@@ -333,6 +1211,7 @@ impl LumidoxDevice {
     /// let max_current = device.get_max_current()?;
     /// ```
     pub fn get_max_current(&mut self) -> Result<u16> {
+        self.enforce_session_limit()?;
         device_operations::control::get_max_current(&mut self.protocol)
     }
     
@@ -351,6 +1230,7 @@ impl LumidoxDevice {
     /// let power_info = device.get_power_info(2)?;
     /// ```
     pub fn get_power_info(&mut self, stage_num: u8) -> Result<PowerInfo> {
+        self.enforce_session_limit()?;
         device_operations::power::get_power_info(&mut self.protocol, stage_num)
     }
 
@@ -366,6 +1246,7 @@ impl LumidoxDevice {
     /// let state = device.read_device_state()?;
     /// ```
     pub fn read_device_state(&mut self) -> Result<String> {
+        self.enforce_session_limit()?;
         device_operations::readback::get_device_state_description(&mut self.protocol)
     }
 
@@ -381,6 +1262,7 @@ impl LumidoxDevice {
     /// let settings = device.read_current_settings()?;
     /// ```
     pub fn read_current_settings(&mut self) -> Result<String> {
+        self.enforce_session_limit()?;
         device_operations::readback::get_current_settings_summary(&mut self.protocol)
     }
 
@@ -396,7 +1278,49 @@ impl LumidoxDevice {
     /// let mode = device.read_remote_mode()?;
     /// ```
     pub fn read_remote_mode(&mut self) -> Result<DeviceMode> {
-        device_operations::readback::read_remote_mode_state(&mut self.protocol)
+        self.with_reconnect(|device| device_operations::readback::read_remote_mode_state(&mut device.protocol))
+    }
+
+    /// Read the stage whose output is currently active
+    ///
+    /// Lets interfaces resynchronize with the hardware after reconnecting,
+    /// for example to highlight the stage that is firing.
+    ///
+    /// # Returns
+    /// * `Result<Option<u8>>` - Active stage (1-5), None when the output is off or
+    ///   firing at a custom current that matches no stage
+    ///
+    /// # Example
+    /// ```
+    /// if let Some(stage) = device.read_active_stage()? {
+    ///     println!("Stage {} is firing", stage);
+    /// }
+    /// ```
+    pub fn read_active_stage(&mut self) -> Result<Option<u8>> {
+        self.enforce_session_limit()?;
+        device_operations::readback::read_active_stage(&mut self.protocol)
+    }
+
+    /// Send a command and receive its raw response payload
+    ///
+    /// Passes through to `ProtocolHandler::send_raw` for diagnostics that
+    /// need the response exactly as received.
+    ///
+    /// # Arguments
+    /// * `command` - Command code, e.g. `0x21`
+    /// * `data` - Data value bytes, most significant first (at most two)
+    ///
+    /// # Returns
+    /// * `Result<Vec<u8>>` - Response payload (`DDDDSS`) or protocol error
+    ///
+    /// # Example
+    /// ```
+    /// let payload = device.send_raw(0x21, &[])?;
+    /// ```
+    pub fn send_raw(&mut self, command: u8, data: &[u8]) -> Result<Vec<u8>> {
+        self.policy.check("send_raw", SafetyLevel::High)?;
+        self.enforce_session_limit()?;
+        self.protocol.send_raw(command, data)
     }
 
     /// Read ARM current setting
@@ -411,7 +1335,9 @@ impl LumidoxDevice {
     /// let arm_current = device.read_arm_current()?;
     /// ```
     pub fn read_arm_current(&mut self) -> Result<u16> {
-        device_operations::readback::read_arm_current(&mut self.protocol)
+        let current_ma = self.with_reconnect(|device| device_operations::readback::read_arm_current(&mut device.protocol))?;
+        self.session_currents.arm_ma = Some(current_ma);
+        Ok(current_ma)
     }
 
     /// Read FIRE current setting
@@ -426,12 +1352,15 @@ impl LumidoxDevice {
     /// let fire_current = device.read_fire_current()?;
     /// ```
     pub fn read_fire_current(&mut self) -> Result<u16> {
-        device_operations::readback::read_fire_current(&mut self.protocol)
+        let current_ma = self.with_reconnect(|device| device_operations::readback::read_fire_current(&mut device.protocol))?;
+        self.session_currents.fire_ma = Some(current_ma);
+        Ok(current_ma)
     }
 
     /// Set ARM current value
     /// 
-    /// Sets the ARM current value for the device.
+    /// Sets the ARM current value for the device. The value is validated
+    /// against the device maximum current before it is sent.
     /// 
     /// # Arguments
     /// * `current_ma` - The ARM current value in milliamps
@@ -444,7 +1373,183 @@ impl LumidoxDevice {
     /// device.set_arm_current(1500)?;
     /// ```
     pub fn set_arm_current(&mut self, current_ma: u16) -> Result<()> {
-        device_operations::readback::set_arm_current(&mut self.protocol, current_ma)
+        self.policy.check("set_arm_current", SafetyLevel::Low)?;
+        self.with_reconnect(|device| {
+            device.require_active_session()?;
+            device_operations::readback::set_arm_current(&mut device.protocol, current_ma)
+        })?;
+        self.session_currents.arm_ma = Some(current_ma);
+        Ok(())
+    }
+
+    /// Set FIRE current value
+    /// 
+    /// Sets the FIRE current value for the device without firing. The value
+    /// is validated against the device maximum current before it is sent.
+    ///
+    /// In fire mode the FIRE current command changes the live output, so the
+    /// mode is read from the device first and the call is refused with
+    /// `SafetyLimit` while the device is firing. Use `fire_with_current` to
+    /// change the output current deliberately.
+    /// 
+    /// # Arguments
+    /// * `current_ma` - The FIRE current value in milliamps
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success, `SafetyLimit` while firing, or setting error
+    /// 
+    /// # Example
+    /// ```
+    /// device.set_fire_current(2000)?;
+    /// ```
+    pub fn set_fire_current(&mut self, current_ma: u16) -> Result<()> {
+        self.policy.check("set_fire_current", SafetyLevel::Low)?;
+        self.with_reconnect(|device| {
+            device.require_active_session()?;
+            if device_operations::readback::read_remote_mode_state(&mut device.protocol)? == DeviceMode::Remote {
+                return Err(LumidoxError::SafetyLimit(
+                    "FIRE current cannot be set while firing; turn the output off first".to_string(),
+                ));
+            }
+            device_operations::readback::set_fire_current(&mut device.protocol, current_ma)
+        })?;
+        self.session_currents.fire_ma = Some(current_ma);
+        Ok(())
+    }
+
+    /// Capture the current device configuration
+    ///
+    /// Reads the ARM and FIRE currents and the operating mode from the
+    /// device so the configuration can be restored later with `apply_config`.
+    ///
+    /// # Returns
+    /// * `Result<DeviceConfigSnapshot>` - Captured configuration or query error
+    ///
+    /// # Example
+    /// ```
+    /// let preset = device.snapshot_config()?;
+    /// ```
+    pub fn snapshot_config(&mut self) -> Result<DeviceConfigSnapshot> {
+        Ok(DeviceConfigSnapshot {
+            arm_current_ma: self.read_arm_current()?,
+            fire_current_ma: self.read_fire_current()?,
+            mode: self.read_remote_mode()?,
+            optimize_transitions: self.optimize_transitions,
+        })
+    }
+
+    /// Restore a previously captured device configuration
+    ///
+    /// Every value is validated against the device maximum current, read
+    /// once, before anything is changed, so an invalid snapshot leaves the
+    /// device untouched. The device is put in standby before the currents
+    /// are written, since the FIRE current changes the live output in fire
+    /// mode, and the snapshot mode is restored last. Applying a snapshot
+    /// never turns the output on: a snapshot captured while firing restores
+    /// the device to armed mode.
+    ///
+    /// # Arguments
+    /// * `snapshot` - Configuration to apply
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or validation/device error
+    ///
+    /// # Example
+    /// ```
+    /// device.apply_config(&preset)?;
+    /// ```
+    pub fn apply_config(&mut self, snapshot: &DeviceConfigSnapshot) -> Result<()> {
+        self.policy.check("apply_config", SafetyLevel::Medium)?;
+        self.require_active_session()?;
+        let max_current = self.get_max_current()?;
+        snapshot.validate(max_current)?;
+
+        self.set_mode(DeviceMode::Standby)?;
+        self.optimize_transitions = snapshot.optimize_transitions;
+        let (arm_ma, fire_ma) = (snapshot.arm_current_ma, snapshot.fire_current_ma);
+        self.with_reconnect(|device| device_operations::readback::write_validated_currents(&mut device.protocol, arm_ma, fire_ma))?;
+        self.session_currents = SessionCurrents { arm_ma: Some(arm_ma), fire_ma: Some(fire_ma) };
+        self.set_mode(snapshot.restore_mode())
+    }
+
+    /// Capture the configuration and all stage parameters
+    ///
+    /// Extends `snapshot_config` with the parameters of every stage, so a
+    /// saved snapshot also records which calibration it was taken with.
+    ///
+    /// # Returns
+    /// * `Result<DeviceSnapshot>` - Captured snapshot or query error
+    ///
+    /// # Example
+    /// ```
+    /// let snapshot = device.capture_snapshot()?;
+    /// ```
+    pub fn capture_snapshot(&mut self) -> Result<DeviceSnapshot> {
+        Ok(DeviceSnapshot {
+            config: self.snapshot_config()?,
+            stages: self.get_all_stage_parameters()?.to_vec(),
+        })
+    }
+
+    /// Restore the settable part of a device snapshot
+    ///
+    /// Applies `snapshot.config` like `apply_config`. The stage parameters
+    /// are read-only and are not written back.
+    ///
+    /// # Arguments
+    /// * `snapshot` - Snapshot to restore
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or validation/device error
+    ///
+    /// # Example
+    /// ```
+    /// device.apply_snapshot(&snapshot)?;
+    /// ```
+    pub fn apply_snapshot(&mut self, snapshot: &DeviceSnapshot) -> Result<()> {
+        self.apply_config(&snapshot.config)
+    }
+
+    /// Restore the device and library to their default state
+    ///
+    /// Turns the output off, clears the stage cooldown history, re-enables
+    /// optimized transitions, sets the ARM and FIRE currents back to the
+    /// device's stage 1 calibration values and returns the device to local
+    /// mode. The protocol has no factory-default command, so the stage 1
+    /// calibration is used as the default current setting.
+    ///
+    /// If any step after turning off fails, the output is turned off again
+    /// before the error is returned, so a failed reset never leaves the
+    /// device firing.
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or the first device error
+    ///
+    /// # Example
+    /// ```
+    /// device.reset_to_defaults()?;
+    /// ```
+    pub fn reset_to_defaults(&mut self) -> Result<()> {
+        self.policy.check("reset_to_defaults", SafetyLevel::Medium)?;
+        self.require_active_session()?;
+        self.turn_off()?;
+
+        self.optimize_transitions = true;
+        self.stage_cooldown.clear_history();
+
+        let result = self.restore_default_currents().and_then(|()| self.return_to_local());
+        if result.is_err() {
+            let _ = self.turn_off();
+        }
+        result
+    }
+
+    /// Set the ARM and FIRE currents to the stage 1 calibration values
+    fn restore_default_currents(&mut self) -> Result<()> {
+        let arm_current = self.get_stage_arm_current(1)?;
+        let fire_current = self.get_stage_fire_current(1)?;
+        self.set_arm_current(arm_current)?;
+        self.set_fire_current(fire_current)
     }
 
     /// Get complete stage parameters
@@ -462,9 +1567,50 @@ impl LumidoxDevice {
     /// let params = device.get_stage_parameters(1)?;
     /// ```
     pub fn get_stage_parameters(&mut self, stage_num: u8) -> Result<device_operations::power::StageParameters> {
+        self.enforce_session_limit()?;
         device_operations::power::get_stage_parameters(&mut self.protocol, stage_num)
     }
 
+    /// Get complete parameters for all stages
+    ///
+    /// The protocol has no batch read; every value is its own command, so
+    /// this issues the same reads as calling `get_stage_parameters` for
+    /// stages 1-5 and stops at the first stage that fails.
+    ///
+    /// # Returns
+    /// * `Result<[StageParameters; 5]>` - Parameters of stages 1-5 or the first query error
+    ///
+    /// # Example
+    /// ```
+    /// for params in device.get_all_stage_parameters()? {
+    ///     println!("Stage {}: {}mA", params.stage_number, params.fire_current_ma);
+    /// }
+    /// ```
+    pub fn get_all_stage_parameters(&mut self) -> Result<[device_operations::power::StageParameters; 5]> {
+        let mut stages = Vec::with_capacity(5);
+        for stage_num in 1..=5 {
+            stages.push(self.get_stage_parameters(stage_num)?);
+        }
+        stages.try_into()
+            .map_err(|_| LumidoxError::DeviceError("Expected parameters for 5 stages".to_string()))
+    }
+
+    /// Get complete parameters for all stages, keeping per-stage failures
+    ///
+    /// Every stage is read even when an earlier one fails, so one bad stage
+    /// does not hide the parameters of the others.
+    ///
+    /// # Returns
+    /// * `[Result<StageParameters>; 5]` - Parameters or query error for stages 1-5
+    ///
+    /// # Example
+    /// ```
+    /// let readable = device.get_all_stage_parameters_partial().into_iter().flatten().count();
+    /// ```
+    pub fn get_all_stage_parameters_partial(&mut self) -> [Result<device_operations::power::StageParameters>; 5] {
+        std::array::from_fn(|index| self.get_stage_parameters(index as u8 + 1))
+    }
+
     /// Get ARM current for specific stage
     /// 
     /// Retrieves the ARM current setting for the specified stage.
@@ -480,6 +1626,7 @@ impl LumidoxDevice {
     /// let arm_current = device.get_stage_arm_current(2)?;
     /// ```
     pub fn get_stage_arm_current(&mut self, stage_num: u8) -> Result<u16> {
+        self.enforce_session_limit()?;
         device_operations::power::get_stage_arm_current(&mut self.protocol, stage_num)
     }
 
@@ -498,6 +1645,7 @@ impl LumidoxDevice {
     /// let fire_current = device.get_stage_fire_current(3)?;
     /// ```
     pub fn get_stage_fire_current(&mut self, stage_num: u8) -> Result<u16> {
+        self.enforce_session_limit()?;
         device_operations::power::get_stage_fire_current(&mut self.protocol, stage_num)
     }
 
@@ -516,6 +1664,7 @@ impl LumidoxDevice {
     /// let volt_limit = device.get_stage_volt_limit(3)?;
     /// ```
     pub fn get_stage_volt_limit(&mut self, stage_num: u8) -> Result<f32> {
+        self.enforce_session_limit()?;
         device_operations::power::get_stage_volt_limit(&mut self.protocol, stage_num)
     }
 
@@ -534,6 +1683,369 @@ impl LumidoxDevice {
     /// let volt_start = device.get_stage_volt_start(4)?;
     /// ```
     pub fn get_stage_volt_start(&mut self, stage_num: u8) -> Result<f32> {
+        self.enforce_session_limit()?;
         device_operations::power::get_stage_volt_start(&mut self.protocol, stage_num)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::communication::MockTransport;
+    use crate::communication::protocol::commands;
+    use crate::communication::protocol::handler::CommandChecksumRejected;
+
+    #[test]
+    fn test_emergency_stop_clears_cached_mode() {
+        let transport = MockTransport::new()
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap());
+        device.current_mode = Some(DeviceMode::Remote);
+
+        device.emergency_stop().unwrap();
+        assert_eq!(device.current_mode, None);
+
+        // Nothing scripted: every retry fails, and the mode stays unknown
+        device.current_mode = Some(DeviceMode::Remote);
+        assert!(device.emergency_stop().unwrap_err().is_communication_error());
+        assert_eq!(device.current_mode, None);
+    }
+
+    #[test]
+    fn test_fire_after_turn_off_to_standby_rearms() {
+        let transport = MockTransport::new()
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0)
+            .expect_read(commands::READ_REMOTE_MODE, DeviceMode::Standby as u16)
+            .expect_read(commands::STAGE_CURRENTS[0], 500)
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0)
+            .expect_command(commands::SET_MODE, DeviceMode::Armed as u16, 0)
+            .expect_command(commands::SET_CURRENT, 500, 0)
+            .expect_command(commands::SET_MODE, DeviceMode::Remote as u16, 0);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap());
+        device.initialized = true;
+        device.current_mode = Some(DeviceMode::Remote);
+
+        device.turn_off().unwrap();
+        assert_eq!(device.current_mode, Some(DeviceMode::Standby));
+
+        // The fire goes through standby and ARM instead of switching current directly
+        assert_eq!(device.fire_stage_with_info(1).unwrap(), 500);
+        assert_eq!(device.current_mode, Some(DeviceMode::Remote));
+    }
+
+    #[test]
+    fn test_turn_off_records_only_the_mode_read_back() {
+        let transport = MockTransport::new()
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0)
+            .expect_no_response(commands::READ_REMOTE_MODE)
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0)
+            .expect_read(commands::READ_REMOTE_MODE, DeviceMode::Standby as u16);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport.clone()).unwrap());
+        device.initialized = true;
+        device.current_mode = Some(DeviceMode::Remote);
+        let events = device.subscribe();
+
+        // The read-back fails: the mode is unknown, so no mode change is reported
+        device.turn_off().unwrap();
+        assert_eq!(device.current_mode, None);
+        assert_eq!(events.try_iter().count(), 0);
+
+        device.turn_off().unwrap();
+        assert_eq!(events.try_iter().collect::<Vec<_>>(), vec![
+            DeviceEvent::ModeChanged { from: None, to: DeviceMode::Standby },
+        ]);
+        transport.assert_consumed();
+    }
+
+    #[test]
+    fn test_fire_stage_for_stops_output_when_fire_fails() {
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(MockTransport::new()).unwrap());
+        device.initialized = true;
+        device.current_mode = Some(DeviceMode::Armed);
+
+        // Nothing scripted: the fire fails and the guard's emergency stop clears the mode
+        assert!(device.fire_stage_for(1, Duration::from_millis(10)).is_err());
+        assert_eq!(device.current_mode, None);
+    }
+
+    #[test]
+    fn test_poll_until_condition_timeout_and_error() {
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(MockTransport::new()).unwrap());
+        let (timeout, interval) = (Duration::from_millis(200), Duration::from_millis(1));
+
+        let mut polls = 0;
+        device.poll_until(|_| { polls += 1; Ok(polls == 3) }, timeout, interval).unwrap();
+        assert_eq!(polls, 3);
+
+        assert!(matches!(
+            device.poll_until(|_| Ok(false), Duration::from_millis(20), interval),
+            Err(LumidoxError::OperationTimeout { timeout }) if timeout == Duration::from_millis(20)
+        ));
+
+        // The unscripted read fails, and the error is returned without waiting
+        assert!(device.poll_until(|device| Ok(device.read_arm_current()? > 0), timeout, interval)
+            .unwrap_err().is_communication_error());
+    }
+
+    #[test]
+    fn test_set_fire_current_refused_while_firing() {
+        let transport = MockTransport::new()
+            .expect_read(commands::READ_REMOTE_MODE, DeviceMode::Remote as u16)
+            .expect_read(commands::READ_REMOTE_MODE, DeviceMode::Standby as u16)
+            .expect_read(commands::STAGE_CURRENTS[4], 3000)
+            .expect_command(commands::SET_CURRENT, 1000, 0);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport.clone()).unwrap());
+        device.initialized = true;
+
+        // Firing: nothing is sent, since 0x41 would change the live output
+        assert!(matches!(device.set_fire_current(1000), Err(LumidoxError::SafetyLimit(_))));
+        assert_eq!(device.session_currents().fire_ma, None);
+
+        device.set_fire_current(1000).unwrap();
+        assert_eq!(device.session_currents().fire_ma, Some(1000));
+        transport.assert_consumed();
+    }
+
+    #[test]
+    fn test_apply_config_writes_currents_in_standby() {
+        let transport = MockTransport::new()
+            .expect_read(commands::STAGE_CURRENTS[4], 3000)
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0)
+            .expect_command(commands::SET_ARM_CURRENT, 200, 0)
+            .expect_command(commands::SET_CURRENT, 1500, 0)
+            .expect_command(commands::SET_MODE, DeviceMode::Armed as u16, 0);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport.clone()).unwrap());
+        device.initialized = true;
+        device.current_mode = Some(DeviceMode::Remote);
+        let events = device.subscribe();
+
+        // Captured while firing: the maximum is read once and the output ends armed
+        device.apply_config(&DeviceConfigSnapshot {
+            arm_current_ma: 200,
+            fire_current_ma: 1500,
+            mode: DeviceMode::Remote,
+            optimize_transitions: true,
+        }).unwrap();
+        transport.assert_consumed();
+        assert_eq!(device.current_mode, Some(DeviceMode::Armed));
+        assert_eq!(device.session_currents(), SessionCurrents { arm_ma: Some(200), fire_ma: Some(1500) });
+        assert_eq!(events.try_iter().collect::<Vec<_>>(), vec![
+            DeviceEvent::ModeChanged { from: None, to: DeviceMode::Standby },
+            DeviceEvent::ModeChanged { from: Some(DeviceMode::Standby), to: DeviceMode::Armed },
+        ]);
+    }
+
+    #[test]
+    fn test_failed_fire_verification_turns_output_off() {
+        let transport = MockTransport::new()
+            .expect_read(commands::STAGE_CURRENTS[4], 3000)
+            .expect_command(commands::SET_CURRENT, 1000, 0)
+            .expect_command(commands::SET_MODE, DeviceMode::Remote as u16, 0)
+            .expect_read(commands::READ_REMOTE_MODE, DeviceMode::Remote as u16)
+            .expect_read(commands::READ_FIRE_CURRENT, 900)
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport.clone()).unwrap());
+        device.initialized = true;
+        device.current_mode = Some(DeviceMode::Armed);
+        device.set_verify_fire(true);
+
+        let error = device.fire_with_current(1000).unwrap_err();
+        assert!(matches!(error, LumidoxError::DeviceError(ref message) if message.contains("requested 1000mA")));
+        transport.assert_consumed();
+        assert_eq!(device.current_mode, None);
+    }
+
+    #[test]
+    fn test_expired_session_is_ended_by_a_read() {
+        let transport = MockTransport::new()
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0)
+            .expect_command(commands::SET_MODE, DeviceMode::Local as u16, 0)
+            .expect_read(commands::READ_ARM_CURRENT, 200);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport.clone()).unwrap());
+        device.initialized = true;
+        device.set_max_session_duration(Some(Duration::from_secs(1)));
+        device.session_limit.start(Instant::now() - Duration::from_secs(2));
+
+        // Not a control operation, but the expired session is still shut down
+        assert!(matches!(device.read_arm_current(), Err(LumidoxError::SafetyLimit(_))));
+        assert!(!device.is_initialized());
+        assert_eq!(device.session_limit().elapsed(Instant::now()), None);
+
+        // The ended session no longer blocks reads
+        assert_eq!(device.read_arm_current().unwrap(), 200);
+        transport.assert_consumed();
+    }
+
+    #[test]
+    fn test_operations_emit_device_events() {
+        let transport = MockTransport::new()
+            .expect_read(commands::STAGE_CURRENTS[4], 3000)
+            .expect_command(commands::SET_CURRENT, 1000, 0)
+            .expect_command(commands::SET_MODE, DeviceMode::Remote as u16, 0)
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap());
+        device.initialized = true;
+        device.current_mode = Some(DeviceMode::Armed);
+        let events = device.subscribe();
+
+        device.fire_with_current(1000).unwrap();
+        device.emergency_stop().unwrap();
+
+        assert_eq!(events.try_iter().collect::<Vec<_>>(), vec![
+            DeviceEvent::ModeChanged { from: None, to: DeviceMode::Remote },
+            DeviceEvent::OutputOn,
+            DeviceEvent::ModeChanged { from: Some(DeviceMode::Remote), to: DeviceMode::Standby },
+            DeviceEvent::OutputOff,
+        ]);
+    }
+
+    #[test]
+    fn test_partial_stage_parameters_report_each_stage() {
+        // Stage 1 ARM current fails; the other stages are still read
+        let transport = MockTransport::new()
+            .expect_rejected(&commands::stage_command(commands::StageParameter::ArmCurrent, 1).unwrap());
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap());
+
+        let results = device.get_all_stage_parameters_partial();
+        assert!(CommandChecksumRejected::is_cause_of(results[0].as_ref().unwrap_err()));
+        assert!(results[1..].iter().all(|result| MockTransport::is_unexpected_command(result.as_ref().unwrap_err())));
+        assert!(device.get_all_stage_parameters().is_err());
+    }
+
+    #[test]
+    fn test_auto_reconnect_reports_failure_after_all_attempts() {
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(MockTransport::new()).unwrap());
+        device.set_auto_reconnect(true, 2);
+        let events = device.subscribe();
+
+        // The unscripted read fails as an I/O error; the mock port cannot be reopened
+        match device.read_arm_current() {
+            Err(error @ LumidoxError::ReconnectFailed { .. }) => {
+                assert!(matches!(error, LumidoxError::ReconnectFailed { ref port, attempts: 2, .. } if port == "MOCK"));
+                let cause = std::error::Error::source(&error).unwrap();
+                assert!(cause.downcast_ref::<LumidoxError>().is_some());
+            }
+            other => panic!("expected ReconnectFailed, got {:?}", other),
+        }
+        assert_eq!(device.connection_state(), ConnectionState::Failed);
+        assert_eq!(events.try_iter().collect::<Vec<_>>(), vec![
+            DeviceEvent::ConnectionChanged { from: ConnectionState::Connected, to: ConnectionState::Reconnecting },
+            DeviceEvent::ConnectionChanged { from: ConnectionState::Reconnecting, to: ConnectionState::Failed },
+        ]);
+    }
+
+    #[test]
+    fn test_manual_reconnect_keeps_session_currents_on_failure() {
+        let transport = MockTransport::new()
+            .expect_read(commands::READ_ARM_CURRENT, 200)
+            .expect_read(commands::READ_FIRE_CURRENT, 1500);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap());
+        device.initialized = true;
+        device.read_arm_current().unwrap();
+        device.read_fire_current().unwrap();
+        assert_eq!(device.session_currents(), SessionCurrents { arm_ma: Some(200), fire_ma: Some(1500) });
+
+        // The mock port cannot be reopened
+        assert!(device.reconnect_same_port().is_err());
+        assert_eq!(device.connection_state(), ConnectionState::Failed);
+        assert!(!device.initialized);
+        assert_eq!(device.session_currents().arm_ma, Some(200));
+    }
+
+    #[test]
+    fn test_kiosk_policy_denies_shutdown_and_reset_without_sending() {
+        // Only the emergency stop is scripted: anything else sent to the device fails
+        let transport = MockTransport::new()
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap());
+        device.initialized = true;
+        device.set_operation_policy(OperationPolicy::new(|operation, _| {
+            !matches!(operation, "shutdown" | "reset_to_defaults")
+        }));
+
+        assert!(matches!(
+            device.shutdown(),
+            Err(LumidoxError::PolicyDenied { ref operation, level: SafetyLevel::Medium }) if operation == "shutdown"
+        ));
+        assert!(matches!(device.reset_to_defaults(), Err(LumidoxError::PolicyDenied { .. })));
+        device.emergency_stop().unwrap();
+
+        device.set_operation_policy(OperationPolicy::max_level(SafetyLevel::Low));
+        assert!(matches!(
+            device.fire_stage(1),
+            Err(LumidoxError::PolicyDenied { level: SafetyLevel::High, .. })
+        ));
+    }
+
+    #[test]
+    fn test_current_ramp_levels_end_at_target() {
+        let levels = |target, step| device_operations::control::current_ramp_levels(target, step).collect::<Vec<u16>>();
+        assert_eq!(levels(1000, 200), vec![200, 400, 600, 800, 1000]);
+        assert_eq!(levels(500, 200), vec![200, 400, 500]);
+        assert_eq!(levels(150, 200), vec![150]);
+        assert_eq!(levels(u16::MAX, 40000), vec![40000, u16::MAX]);
+    }
+
+    #[test]
+    fn test_ramp_validates_target_before_firing() {
+        // Only the maximum current query is scripted: any fire would fail as unexpected
+        let transport = MockTransport::new()
+            .expect_command(commands::STAGE_CURRENTS[4], 0, 1000);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap());
+        device.initialized = true;
+
+        assert!(matches!(device.fire_with_current_ramp(2000, 200, Duration::ZERO), Err(LumidoxError::InvalidInput(_))));
+        assert!(matches!(device.fire_with_current_ramp(500, 0, Duration::ZERO), Err(LumidoxError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_strict_safety_refuses_unarmed_device() {
+        // Readiness readback: standby mode, ARM current 0mA, maximum 3000mA
+        let transport = MockTransport::new()
+            .expect_command(commands::READ_REMOTE_MODE, 0, DeviceMode::Standby as u16)
+            .expect_command(commands::READ_ARM_CURRENT, 0, 0)
+            .expect_command(commands::STAGE_CURRENTS[4], 0, 3000);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap());
+        device.initialized = true;
+        device.set_strict_safety(true);
+
+        assert!(matches!(
+            device.fire_stage(1),
+            Err(LumidoxError::SafetyInterlock { ref flag }) if flag == "not armed, ARM current is zero"
+        ));
+    }
+
+    #[test]
+    fn test_full_readout_returns_every_supported_field() {
+        // The scripted transport answers every documented read with a valid
+        // value, so any field that comes back empty or undecoded is a parsing
+        // regression rather than a device quirk
+        use crate::communication::protocol::commands::{stage_command, StageParameter};
+        let mut transport = MockTransport::new()
+            .expect_read(commands::READ_ARM_CURRENT, 200)
+            .expect_read(commands::READ_FIRE_CURRENT, 1500)
+            .expect_read(commands::READ_REMOTE_MODE, DeviceMode::Standby as u16);
+        for stage in 1..=5u16 {
+            let responses = [100 * stage, 500 * stage, 180, 120, 1234, 56, 3, 4];
+            for (parameter, response) in StageParameter::ALL.into_iter().zip(responses) {
+                transport = transport.expect_read(&stage_command(parameter, stage as u8).unwrap(), response);
+            }
+        }
+        let script = transport.clone();
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap());
+
+        let snapshot = device.capture_snapshot().unwrap();
+        script.assert_consumed();
+        assert_eq!((snapshot.config.arm_current_ma, snapshot.config.fire_current_ma), (200, 1500));
+        assert_eq!(snapshot.config.mode, DeviceMode::Standby);
+        assert_eq!(snapshot.stages.len(), 5);
+        for params in &snapshot.stages {
+            assert!(params.arm_current_ma > 0 && params.fire_current_ma > 0, "stage {}", params.stage_number);
+            assert!(params.volt_limit_v > 0.0 && params.volt_start_v > 0.0, "stage {}", params.stage_number);
+            assert!(params.power_total > 0.0 && params.power_per_led > 0.0, "stage {}", params.stage_number);
+            for units in [&params.total_units, &params.per_led_units] {
+                assert!(!units.is_empty() && units != "UNKNOWN UNITS", "stage {} units {:?}", params.stage_number, units);
+            }
+        }
+    }
+}
@@ -0,0 +1,210 @@
+//! Stage firing cooldown tracking for Lumidox II Controller
+//!
+//! This module enforces an optional minimum interval between fires of the
+//! same stage to protect the LEDs from rapid re-firing. The policy lives in
+//! the device wrapper so every interface gets the same protection.
+//!
+//! The cooldown system provides:
+//! - Per-stage tracking of the last successful fire time
+//! - Blocking mode that waits until the cooldown has elapsed
+//! - Strict mode that rejects early fires with a `SafetyLimit` error
+
+use crate::core::{LumidoxError, Result};
+use std::time::{Duration, Instant};
+
+/// Number of stages tracked by the cooldown
+const STAGE_COUNT: usize = 5;
+
+/// Behavior when a stage is fired before its cooldown has elapsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CooldownMode {
+    /// Sleep until the cooldown has elapsed, then fire
+    #[default]
+    Blocking,
+    /// Reject the fire with a `SafetyLimit` error
+    Strict,
+}
+
+/// Per-stage firing cooldown state
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StageCooldown {
+    /// Minimum interval between fires of the same stage (zero disables the cooldown)
+    interval: Duration,
+    /// Behavior when the cooldown has not yet elapsed
+    mode: CooldownMode,
+    /// Time of the last successful fire for each stage (index 0 = stage 1)
+    last_fired: [Option<Instant>; STAGE_COUNT],
+}
+
+impl StageCooldown {
+    /// Get the configured cooldown interval
+    ///
+    /// # Returns
+    /// * `Duration` - Minimum interval between fires (zero when disabled)
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Set the minimum interval between fires of the same stage
+    ///
+    /// # Arguments
+    /// * `interval` - Minimum interval; `Duration::ZERO` disables the cooldown
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+    }
+
+    /// Get the configured cooldown mode
+    ///
+    /// # Returns
+    /// * `CooldownMode` - Behavior for fires requested too soon
+    pub fn mode(&self) -> CooldownMode {
+        self.mode
+    }
+
+    /// Set the behavior for fires requested too soon
+    ///
+    /// # Arguments
+    /// * `mode` - Blocking or strict cooldown enforcement
+    pub fn set_mode(&mut self, mode: CooldownMode) {
+        self.mode = mode;
+    }
+
+    /// Get the time remaining before a stage may be fired again
+    ///
+    /// # Arguments
+    /// * `stage_num` - The stage number (1-5)
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// * `Duration` - Remaining cooldown, zero if the stage may fire now
+    ///
+    /// # Example
+    /// ```
+    /// let remaining = cooldown.remaining(2, Instant::now());
+    /// ```
+    pub fn remaining(&self, stage_num: u8, now: Instant) -> Duration {
+        match Self::slot(stage_num).and_then(|index| self.last_fired[index]) {
+            Some(last_fired) => self.interval.saturating_sub(now.saturating_duration_since(last_fired)),
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Check whether a stage may be fired now
+    ///
+    /// # Arguments
+    /// * `stage_num` - The stage number (1-5)
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// * `Result<Duration>` - Time to wait before firing (blocking mode), or a
+    ///   `SafetyLimit` error if the cooldown has not elapsed (strict mode)
+    pub fn check(&self, stage_num: u8, now: Instant) -> Result<Duration> {
+        let remaining = self.remaining(stage_num, now);
+        if remaining.is_zero() || self.mode == CooldownMode::Blocking {
+            return Ok(remaining);
+        }
+
+        Err(LumidoxError::SafetyLimit(format!(
+            "Stage {} cooldown active: {}ms remaining of {}ms minimum interval",
+            stage_num,
+            remaining.as_millis(),
+            self.interval.as_millis()
+        )))
+    }
+
+    /// Enforce the cooldown before firing a stage
+    ///
+    /// Sleeps for the remaining cooldown in blocking mode and rejects the fire
+    /// in strict mode.
+    ///
+    /// # Arguments
+    /// * `stage_num` - The stage number (1-5)
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success once the stage may fire, or a `SafetyLimit` error
+    pub fn enforce(&self, stage_num: u8) -> Result<()> {
+        let wait = self.check(stage_num, Instant::now())?;
+        if !wait.is_zero() {
+            std::thread::sleep(wait);
+        }
+        Ok(())
+    }
+
+    /// Record a successful fire of a stage
+    ///
+    /// # Arguments
+    /// * `stage_num` - The stage number (1-5)
+    /// * `at` - Time the stage was fired
+    pub fn record_fire(&mut self, stage_num: u8, at: Instant) {
+        if let Some(index) = Self::slot(stage_num) {
+            self.last_fired[index] = Some(at);
+        }
+    }
+
+    /// Forget the recorded fire times of every stage
+    ///
+    /// The interval and mode are kept; only the per-stage history is cleared.
+    pub fn clear_history(&mut self) {
+        self.last_fired = [None; STAGE_COUNT];
+    }
+
+    /// Map a stage number to its tracking slot
+    fn slot(stage_num: u8) -> Option<usize> {
+        let index = usize::from(stage_num).checked_sub(1)?;
+        (index < STAGE_COUNT).then_some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_cooldown_never_waits() {
+        let mut cooldown = StageCooldown::default();
+        let now = Instant::now();
+        cooldown.record_fire(1, now);
+        assert_eq!(cooldown.check(1, now).unwrap(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_blocking_mode_returns_remaining_wait() {
+        let mut cooldown = StageCooldown::default();
+        cooldown.set_interval(Duration::from_secs(10));
+        let fired = Instant::now();
+        cooldown.record_fire(2, fired);
+
+        assert_eq!(cooldown.check(2, fired + Duration::from_secs(4)).unwrap(), Duration::from_secs(6));
+        assert_eq!(cooldown.check(2, fired + Duration::from_secs(10)).unwrap(), Duration::ZERO);
+        assert_eq!(cooldown.check(3, fired).unwrap(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_early_fire() {
+        let mut cooldown = StageCooldown::default();
+        cooldown.set_interval(Duration::from_secs(10));
+        cooldown.set_mode(CooldownMode::Strict);
+        let fired = Instant::now();
+        cooldown.record_fire(5, fired);
+
+        assert!(matches!(
+            cooldown.check(5, fired + Duration::from_secs(1)),
+            Err(LumidoxError::SafetyLimit(_))
+        ));
+        assert!(cooldown.check(5, fired + Duration::from_secs(11)).is_ok());
+    }
+
+    #[test]
+    fn test_clear_history_keeps_settings() {
+        let mut cooldown = StageCooldown::default();
+        cooldown.set_interval(Duration::from_secs(10));
+        cooldown.set_mode(CooldownMode::Strict);
+        let now = Instant::now();
+        cooldown.record_fire(3, now);
+
+        cooldown.clear_history();
+        assert_eq!(cooldown.remaining(3, now), Duration::ZERO);
+        assert_eq!(cooldown.interval(), Duration::from_secs(10));
+        assert_eq!(cooldown.mode(), CooldownMode::Strict);
+    }
+}
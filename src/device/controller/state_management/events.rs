@@ -0,0 +1,136 @@
+//! Device event notifications for Lumidox II Controller
+//!
+//! This module lets embedders observe the device without polling. Each
+//! subscriber receives a channel of `DeviceEvent`s emitted by the device
+//! controller as operations change the device mode, switch the output on or
+//! off, or are stopped by a safety check, and as the connection moves
+//! between connection states.
+//!
+//! Events describe the mode the controller commanded, not a readback. The
+//! reported mode is tracked separately from the cached mode used for
+//! optimized transitions, so events always name the mode that was actually
+//! sent to the device.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use crate::device::models::DeviceMode;
+use super::super::connection::state::ConnectionState;
+
+/// Event emitted by the device controller
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceEvent {
+    /// The device was switched to a different mode
+    ModeChanged {
+        /// Previously reported mode, None if unknown
+        from: Option<DeviceMode>,
+        /// Mode the device was switched to
+        to: DeviceMode,
+    },
+    /// The output was switched on (remote firing mode entered)
+    OutputOn,
+    /// The output was switched off (remote firing mode left)
+    OutputOff,
+    /// An operation was stopped by a safety check
+    SafetyTripped {
+        /// Description of the check that tripped
+        reason: String,
+    },
+    /// The connection moved to another state
+    ConnectionChanged {
+        /// State before the transition
+        from: ConnectionState,
+        /// State after the transition
+        to: ConnectionState,
+    },
+}
+
+/// Subscribers and the last mode reported to them
+#[derive(Debug, Default)]
+pub struct DeviceEvents {
+    /// Channels of the current subscribers
+    subscribers: Vec<Sender<DeviceEvent>>,
+    /// Mode reported by the last `ModeChanged` event
+    reported_mode: Option<DeviceMode>,
+}
+
+impl DeviceEvents {
+    /// Add a subscriber
+    ///
+    /// # Returns
+    /// * `Receiver<DeviceEvent>` - Channel receiving every event emitted from now on
+    ///
+    /// # Example
+    /// ```
+    /// let receiver = events.subscribe();
+    /// ```
+    pub fn subscribe(&mut self) -> Receiver<DeviceEvent> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+
+    /// Record that the device was switched to a mode
+    ///
+    /// Emits `ModeChanged` when the mode differs from the last one reported,
+    /// followed by `OutputOn` when remote firing mode is entered or
+    /// `OutputOff` when it is left.
+    ///
+    /// # Arguments
+    /// * `mode` - Mode the device was switched to
+    pub fn record_mode(&mut self, mode: DeviceMode) {
+        let from = self.reported_mode;
+        if from == Some(mode) {
+            return;
+        }
+        self.reported_mode = Some(mode);
+        self.emit(DeviceEvent::ModeChanged { from, to: mode });
+        if mode == DeviceMode::Remote {
+            self.emit(DeviceEvent::OutputOn);
+        } else if from == Some(DeviceMode::Remote) {
+            self.emit(DeviceEvent::OutputOff);
+        }
+    }
+
+    /// Record that a safety check stopped an operation
+    ///
+    /// # Arguments
+    /// * `reason` - Description of the check that tripped
+    pub fn record_safety_trip(&mut self, reason: impl Into<String>) {
+        self.emit(DeviceEvent::SafetyTripped { reason: reason.into() });
+    }
+
+    /// Send an event to every subscriber, dropping those that have gone away
+    ///
+    /// # Arguments
+    /// * `event` - Event to send
+    pub fn emit(&mut self, event: DeviceEvent) {
+        self.subscribers.retain(|subscriber| subscriber.send(event.clone()).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_events_follow_mode_changes() {
+        let mut events = DeviceEvents::default();
+        let receiver = events.subscribe();
+
+        events.record_mode(DeviceMode::Standby);
+        events.record_mode(DeviceMode::Remote);
+        events.record_mode(DeviceMode::Remote);
+        events.record_mode(DeviceMode::Standby);
+
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![
+            DeviceEvent::ModeChanged { from: None, to: DeviceMode::Standby },
+            DeviceEvent::ModeChanged { from: Some(DeviceMode::Standby), to: DeviceMode::Remote },
+            DeviceEvent::OutputOn,
+            DeviceEvent::ModeChanged { from: Some(DeviceMode::Remote), to: DeviceMode::Standby },
+            DeviceEvent::OutputOff,
+        ]);
+
+        drop(receiver);
+        events.record_safety_trip("interlock open");
+        assert!(events.subscribers.is_empty());
+    }
+}
@@ -0,0 +1,80 @@
+//! Fire acknowledgement verification for Lumidox II Controller
+//!
+//! The device acknowledges a fire command before it has energized the
+//! output, so a successful acknowledgement does not prove the output is on.
+//! When fire verification is enabled the device wrapper reads back the mode
+//! and the active stage or FIRE current after firing, and this module
+//! decides whether the readback matches what was requested.
+
+use crate::core::{LumidoxError, Result};
+use crate::device::models::DeviceMode;
+
+/// What a fire command asked the device to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FireTarget {
+    /// Fire a stage (1-5)
+    Stage(u8),
+    /// Fire at a custom current in milliamps
+    Current(u16),
+}
+
+impl FireTarget {
+    /// Check the device readback against the requested fire
+    ///
+    /// # Arguments
+    /// * `mode` - Mode read back from the device
+    /// * `observed` - Active stage or FIRE current read back, if any
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success if the device is firing as requested, or a
+    ///   `DeviceError` describing the mismatch
+    ///
+    /// # Example
+    /// ```
+    /// FireTarget::Stage(3).check(DeviceMode::Remote, Some(FireTarget::Stage(3)))?;
+    /// ```
+    pub fn check(self, mode: DeviceMode, observed: Option<FireTarget>) -> Result<()> {
+        if mode != DeviceMode::Remote {
+            return Err(LumidoxError::DeviceError(format!(
+                "Fire verification failed: {} acknowledged but device reports {:?} mode",
+                self, mode
+            )));
+        }
+        if observed != Some(self) {
+            return Err(LumidoxError::DeviceError(format!(
+                "Fire verification failed: requested {} but device reports {}",
+                self,
+                observed.map_or_else(|| "no active stage".to_string(), |observed| observed.to_string())
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for FireTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stage(stage) => write!(f, "stage {}", stage),
+            Self::Current(current_ma) => write!(f, "{}mA", current_ma),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_accepts_matching_readback() {
+        assert!(FireTarget::Stage(2).check(DeviceMode::Remote, Some(FireTarget::Stage(2))).is_ok());
+        assert!(FireTarget::Current(1500).check(DeviceMode::Remote, Some(FireTarget::Current(1500))).is_ok());
+    }
+
+    #[test]
+    fn test_check_rejects_output_not_energized() {
+        assert!(FireTarget::Stage(2).check(DeviceMode::Armed, Some(FireTarget::Stage(2))).is_err());
+        assert!(FireTarget::Stage(2).check(DeviceMode::Remote, None).is_err());
+        assert!(FireTarget::Stage(2).check(DeviceMode::Remote, Some(FireTarget::Stage(3))).is_err());
+        assert!(FireTarget::Current(1500).check(DeviceMode::Remote, Some(FireTarget::Current(0))).is_err());
+    }
+}
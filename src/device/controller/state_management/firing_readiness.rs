@@ -0,0 +1,140 @@
+//! Pre-flight firing readiness for Lumidox II Controller
+//!
+//! Firing normally takes the device through standby and arming by itself.
+//! With strict safety enabled the device wrapper instead requires the
+//! operator to have put the device in a safe firing state first, and
+//! checks it before sending any fire command. This module reports which of
+//! the preconditions a device readback satisfies and which it misses.
+
+use crate::device::models::DeviceMode;
+
+/// A condition the device must meet before firing under strict safety
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FiringPrecondition {
+    /// Device is under remote control rather than the front panel
+    RemoteMode,
+    /// Device is armed (or already firing)
+    Armed,
+    /// ARM current is set above zero
+    ArmCurrentSet,
+    /// ARM current does not exceed the device maximum current
+    ArmCurrentWithinMax,
+}
+
+impl FiringPrecondition {
+    /// All preconditions, in the order they are checked
+    pub const ALL: [Self; 4] = [Self::RemoteMode, Self::Armed, Self::ArmCurrentSet, Self::ArmCurrentWithinMax];
+
+    /// Describe the precondition as unmet
+    ///
+    /// # Returns
+    /// * `&'static str` - Short description of what is missing
+    pub fn unmet_description(self) -> &'static str {
+        match self {
+            Self::RemoteMode => "not in remote mode",
+            Self::Armed => "not armed",
+            Self::ArmCurrentSet => "ARM current is zero",
+            Self::ArmCurrentWithinMax => "ARM current exceeds the maximum current",
+        }
+    }
+}
+
+impl std::fmt::Display for FiringPrecondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::RemoteMode => "remote mode",
+            Self::Armed => "armed",
+            Self::ArmCurrentSet => "ARM current set",
+            Self::ArmCurrentWithinMax => "ARM current within maximum",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Device state read before firing, and the preconditions it meets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiringReadiness {
+    /// Mode read back from the device
+    pub mode: DeviceMode,
+    /// ARM current setting in mA
+    pub arm_current_ma: u16,
+    /// Maximum current of the device in mA
+    pub max_current_ma: u16,
+}
+
+impl FiringReadiness {
+    /// Check whether a single precondition is met
+    ///
+    /// # Arguments
+    /// * `precondition` - Precondition to check
+    ///
+    /// # Returns
+    /// * `bool` - True if the readback satisfies it
+    pub fn meets(&self, precondition: FiringPrecondition) -> bool {
+        match precondition {
+            FiringPrecondition::RemoteMode => self.mode != DeviceMode::Local,
+            FiringPrecondition::Armed => matches!(self.mode, DeviceMode::Armed | DeviceMode::Remote),
+            FiringPrecondition::ArmCurrentSet => self.arm_current_ma > 0,
+            FiringPrecondition::ArmCurrentWithinMax => self.arm_current_ma <= self.max_current_ma,
+        }
+    }
+
+    /// Preconditions the device meets
+    ///
+    /// # Returns
+    /// * `Vec<FiringPrecondition>` - Satisfied preconditions
+    pub fn satisfied(&self) -> Vec<FiringPrecondition> {
+        FiringPrecondition::ALL.into_iter().filter(|precondition| self.meets(*precondition)).collect()
+    }
+
+    /// Preconditions the device does not meet
+    ///
+    /// # Returns
+    /// * `Vec<FiringPrecondition>` - Unmet preconditions, empty if ready to fire
+    pub fn unmet(&self) -> Vec<FiringPrecondition> {
+        FiringPrecondition::ALL.into_iter().filter(|precondition| !self.meets(*precondition)).collect()
+    }
+
+    /// Check whether every precondition is met
+    ///
+    /// # Returns
+    /// * `bool` - True if the device is ready to fire
+    pub fn is_ready(&self) -> bool {
+        self.unmet().is_empty()
+    }
+
+    /// Describe the unmet preconditions
+    ///
+    /// # Returns
+    /// * `String` - Comma separated descriptions, e.g. "not armed, ARM current is zero"
+    pub fn unmet_summary(&self) -> String {
+        self.unmet()
+            .into_iter()
+            .map(FiringPrecondition::unmet_description)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readiness_lists_unmet_preconditions() {
+        let ready = FiringReadiness { mode: DeviceMode::Armed, arm_current_ma: 500, max_current_ma: 3000 };
+        assert!(ready.is_ready());
+        assert_eq!(ready.satisfied().len(), FiringPrecondition::ALL.len());
+
+        let local = FiringReadiness { mode: DeviceMode::Local, arm_current_ma: 0, max_current_ma: 3000 };
+        assert_eq!(local.unmet(), vec![
+            FiringPrecondition::RemoteMode,
+            FiringPrecondition::Armed,
+            FiringPrecondition::ArmCurrentSet,
+        ]);
+        assert_eq!(local.unmet_summary(), "not in remote mode, not armed, ARM current is zero");
+
+        let standby = FiringReadiness { mode: DeviceMode::Standby, arm_current_ma: 4000, max_current_ma: 3000 };
+        assert_eq!(standby.unmet(), vec![FiringPrecondition::Armed, FiringPrecondition::ArmCurrentWithinMax]);
+    }
+}
@@ -2,7 +2,20 @@
 //!
 //! This module handles device mode control and state tracking,
 //! providing utilities for device state management and mode transitions.
+//! It also tracks per-stage firing times for cooldown enforcement, checks
+//! fire readbacks when fire verification is enabled, checks firing
+//! readiness when strict safety is enabled, tracks the session
+//! duration for the maximum session limit, guards timed firing so the
+//! output is always turned off, notifies subscribers of device events, and
+//! applies the embedder's operation policy.
 
 pub mod mode_control;
+pub mod cooldown;
+pub mod fire_verification;
+pub mod firing_readiness;
+pub mod session_limit;
+pub mod output_guard;
+pub mod events;
+pub mod policy;
 
 // Re-export commonly used items for convenience
@@ -47,6 +47,7 @@ impl DeviceStateManager {
     ) -> Result<()> {
         control::set_mode(&mut device.protocol, mode)?;
         device.current_mode = Some(mode);
+        device.events.record_mode(mode);
         Ok(())
     }
     
@@ -70,6 +71,31 @@ impl DeviceStateManager {
     pub fn get_current_mode(device: &super::super::LumidoxDevice) -> Option<DeviceMode> {
         device.current_mode
     }
+
+    /// Require that the device has been initialized
+    /// 
+    /// Guards control operations so that commands are never sent to a device
+    /// whose mode and information have not been established by `initialize`.
+    /// Turning the device off and shutting it down remain permitted so an
+    /// uninitialized device can always be made safe.
+    /// 
+    /// # Arguments
+    /// * `device` - Reference to the device controller
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success if initialized, `LumidoxError::NotInitialized` otherwise
+    /// 
+    /// # Example
+    /// ```
+    /// DeviceStateManager::require_initialized(&device)?;
+    /// ```
+    pub fn require_initialized(device: &super::super::LumidoxDevice) -> Result<()> {
+        if device.initialized {
+            Ok(())
+        } else {
+            Err(crate::core::LumidoxError::NotInitialized)
+        }
+    }
     
     /// Validate current device state consistency
     /// 
@@ -0,0 +1,64 @@
+//! Output turn-off guard for Lumidox II Controller
+//!
+//! This module provides a guard that keeps the device output from being left
+//! on by a timed operation. The guard is created before firing; if it is
+//! dropped without being released, because an error was returned or a panic
+//! unwound through the operation, it sends an emergency stop. Releasing the
+//! guard turns the output off through the normal turn-off path instead.
+
+use crate::core::Result;
+use super::super::LumidoxDevice;
+
+/// Guard that turns the output off when dropped
+pub struct OutputOffGuard<'a> {
+    /// Device whose output is guarded
+    device: &'a mut LumidoxDevice,
+    /// Whether the emergency stop still has to run on drop
+    armed: bool,
+}
+
+impl<'a> OutputOffGuard<'a> {
+    /// Guard the output of a device
+    ///
+    /// # Arguments
+    /// * `device` - Device whose output must be turned off afterwards
+    ///
+    /// # Returns
+    /// * `OutputOffGuard` - Guard that stops the output when dropped
+    ///
+    /// # Example
+    /// ```
+    /// let mut guard = OutputOffGuard::new(&mut device);
+    /// guard.device().fire_stage(3)?;
+    /// guard.release()?;
+    /// ```
+    pub fn new(device: &'a mut LumidoxDevice) -> Self {
+        Self { device, armed: true }
+    }
+
+    /// Get the guarded device
+    ///
+    /// # Returns
+    /// * `&mut LumidoxDevice` - Device whose output is guarded
+    pub fn device(&mut self) -> &mut LumidoxDevice {
+        self.device
+    }
+
+    /// Turn the output off normally and disarm the guard
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or turn-off error
+    pub fn release(mut self) -> Result<()> {
+        self.armed = false;
+        self.device.turn_off()
+    }
+}
+
+impl Drop for OutputOffGuard<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            // Errors cannot be reported from drop; the cached mode is cleared either way
+            let _ = self.device.emergency_stop();
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Operation policy for Lumidox II Controller
+//!
+//! This module lets an embedder restrict which device operations may run,
+//! for example a kiosk deployment that must never fire at a custom current
+//! or shut the device down. Every operation that changes the device is
+//! classified by `SafetyLevel`, and before it runs the device asks the
+//! policy's predicate whether it is allowed. A refused operation returns
+//! `LumidoxError::PolicyDenied` without sending anything to the device.
+//!
+//! Read operations are not gated. Turning the output off and the emergency
+//! stop are never gated either, so a policy cannot prevent making the device
+//! safe.
+
+use crate::core::{LumidoxError, Result};
+use std::fmt;
+use std::sync::Arc;
+
+/// Safety impact of a device operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SafetyLevel {
+    /// No safety concerns (read operations)
+    None,
+    /// Low safety impact (configuration changes)
+    Low,
+    /// Medium safety impact (device state changes such as arming or shutdown)
+    Medium,
+    /// High safety impact (firing operations and raw commands)
+    High,
+}
+
+impl fmt::Display for SafetyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::None => "none",
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Predicate deciding whether an operation may run
+type PolicyPredicate = dyn Fn(&str, SafetyLevel) -> bool + Send + Sync;
+
+/// Policy gating device operations
+///
+/// The default policy allows every operation.
+///
+/// # Example
+/// ```
+/// // Kiosk mode: no firing operations and no shutdown
+/// device.set_operation_policy(OperationPolicy::new(|operation, level| {
+///     level < SafetyLevel::High && operation != "shutdown"
+/// }));
+/// ```
+#[derive(Clone, Default)]
+pub struct OperationPolicy {
+    /// Predicate called with the operation name and safety level, None to allow all
+    predicate: Option<Arc<PolicyPredicate>>,
+}
+
+impl OperationPolicy {
+    /// Create a policy from a predicate
+    ///
+    /// # Arguments
+    /// * `predicate` - Called with the operation name (e.g. "fire_stage") and
+    ///   its safety level; returns true to allow the operation
+    ///
+    /// # Returns
+    /// * `OperationPolicy` - Policy applying the predicate
+    pub fn new(predicate: impl Fn(&str, SafetyLevel) -> bool + Send + Sync + 'static) -> Self {
+        Self { predicate: Some(Arc::new(predicate)) }
+    }
+
+    /// Create a policy allowing operations up to a safety level
+    ///
+    /// # Arguments
+    /// * `max_level` - Highest safety level allowed
+    ///
+    /// # Returns
+    /// * `OperationPolicy` - Policy refusing every operation above `max_level`
+    ///
+    /// # Example
+    /// ```
+    /// device.set_operation_policy(OperationPolicy::max_level(SafetyLevel::Medium));
+    /// ```
+    pub fn max_level(max_level: SafetyLevel) -> Self {
+        Self::new(move |_, level| level <= max_level)
+    }
+
+    /// Check whether an operation may run
+    ///
+    /// # Arguments
+    /// * `operation` - Operation name, e.g. "fire_stage"
+    /// * `level` - Safety level of the operation
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success if allowed, otherwise `PolicyDenied`
+    pub fn check(&self, operation: &str, level: SafetyLevel) -> Result<()> {
+        match &self.predicate {
+            Some(predicate) if !predicate(operation, level) => Err(LumidoxError::PolicyDenied {
+                operation: operation.to_string(),
+                level,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Debug for OperationPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OperationPolicy")
+            .field("restricted", &self.predicate.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_gates_by_level_and_operation() {
+        assert!(OperationPolicy::default().check("fire_stage", SafetyLevel::High).is_ok());
+
+        let policy = OperationPolicy::max_level(SafetyLevel::Medium);
+        assert!(policy.check("arm", SafetyLevel::Medium).is_ok());
+        assert!(matches!(
+            policy.check("fire_stage", SafetyLevel::High),
+            Err(LumidoxError::PolicyDenied { ref operation, level: SafetyLevel::High }) if operation == "fire_stage"
+        ));
+
+        let kiosk = OperationPolicy::new(|operation, _| operation != "shutdown");
+        assert!(kiosk.check("shutdown", SafetyLevel::Medium).is_err());
+        assert!(kiosk.check("arm", SafetyLevel::Medium).is_ok());
+    }
+}
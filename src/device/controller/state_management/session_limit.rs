@@ -0,0 +1,112 @@
+//! Maximum session duration tracking for Lumidox II Controller
+//!
+//! This module tracks how long the device session has been running and
+//! enforces an optional maximum duration, independent of how recently
+//! commands were sent. The session starts when the device is initialized.
+//! Once the limit is exceeded, the next device operation of any kind, reads
+//! and keep-alive heartbeats included, makes the device wrapper turn the
+//! output off, return the device to local mode and reject the operation with
+//! a `SafetyLimit` error; a new session starts on the next `initialize`.
+
+use std::time::{Duration, Instant};
+
+/// Session start time and optional maximum duration
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionLimit {
+    /// Maximum session duration, None for no limit
+    max_duration: Option<Duration>,
+    /// Time the current session started, None before initialization
+    started: Option<Instant>,
+}
+
+impl SessionLimit {
+    /// Get the configured maximum session duration
+    ///
+    /// # Returns
+    /// * `Option<Duration>` - Maximum duration, None when unlimited
+    pub fn max_duration(&self) -> Option<Duration> {
+        self.max_duration
+    }
+
+    /// Set the maximum session duration
+    ///
+    /// # Arguments
+    /// * `max_duration` - Maximum duration, None to remove the limit
+    pub fn set_max_duration(&mut self, max_duration: Option<Duration>) {
+        self.max_duration = max_duration;
+    }
+
+    /// Start a new session
+    ///
+    /// # Arguments
+    /// * `now` - Time the session starts
+    pub fn start(&mut self, now: Instant) {
+        self.started = Some(now);
+    }
+
+    /// End the current session
+    pub fn end(&mut self) {
+        self.started = None;
+    }
+
+    /// Get how long the current session has been running
+    ///
+    /// # Arguments
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// * `Option<Duration>` - Elapsed session time, None when no session is running
+    pub fn elapsed(&self, now: Instant) -> Option<Duration> {
+        self.started.map(|started| now.saturating_duration_since(started))
+    }
+
+    /// Check whether the current session has run longer than allowed
+    ///
+    /// # Arguments
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// * `bool` - True if a limit is set and the running session exceeds it
+    ///
+    /// # Example
+    /// ```
+    /// if device.session_limit().is_exceeded(Instant::now()) {
+    ///     println!("Session will be shut down on the next operation");
+    /// }
+    /// ```
+    pub fn is_exceeded(&self, now: Instant) -> bool {
+        match (self.max_duration, self.elapsed(now)) {
+            (Some(max_duration), Some(elapsed)) => elapsed > max_duration,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_limit_exceeded_only_after_start() {
+        let start = Instant::now();
+        let mut limit = SessionLimit::default();
+        limit.set_max_duration(Some(Duration::from_secs(60)));
+        assert!(!limit.is_exceeded(start + Duration::from_secs(120)));
+
+        limit.start(start);
+        assert!(!limit.is_exceeded(start + Duration::from_secs(60)));
+        assert!(limit.is_exceeded(start + Duration::from_secs(61)));
+
+        limit.end();
+        assert!(!limit.is_exceeded(start + Duration::from_secs(61)));
+    }
+
+    #[test]
+    fn test_session_without_limit_never_exceeded() {
+        let start = Instant::now();
+        let mut limit = SessionLimit::default();
+        limit.start(start);
+        assert_eq!(limit.elapsed(start + Duration::from_secs(5)), Some(Duration::from_secs(5)));
+        assert!(!limit.is_exceeded(start + Duration::from_secs(86_400)));
+    }
+}
@@ -4,13 +4,14 @@
 //! organized into sub-modules for better maintainability:
 //! - `models`: Device data structures and types
 //! - `operations`: Device control and power operations
-//! - `info`: Device information retrieval
 //! - `controller`: Main device controller orchestrating all operations
+//! - `shared`: Cloneable, thread-safe handle to a device
 
 pub mod models;
 pub mod operations;
-pub mod info;
 pub mod controller;
+pub mod shared;
 
 // Re-export commonly used items for convenience
-pub use controller::LumidoxDevice;
+pub use controller::{CooldownMode, IdentityReadRetry, LumidoxDevice, OperationConfig, OperationPolicy, SafetyLevel};
+pub use shared::SharedDevice;
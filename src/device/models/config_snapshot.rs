@@ -0,0 +1,128 @@
+//! Device configuration snapshot model definitions
+//!
+//! This module contains the `DeviceConfigSnapshot` used to capture a device's
+//! operating configuration and later restore it, for example when switching
+//! between experiment presets, and the `DeviceSnapshot` that adds the stage
+//! parameters for reference. With the `serde` feature enabled snapshots can
+//! be serialized to and from preset files.
+
+use crate::core::{LumidoxError, Result};
+use crate::device::operations::power::StageParameters;
+use super::device_state::DeviceMode;
+
+/// Captured device operating configuration
+///
+/// Holds the settings needed to return a device to a known configuration.
+/// Snapshots are created by `LumidoxDevice::snapshot_config` and applied with
+/// `LumidoxDevice::apply_config`, which validates every value before changing
+/// anything on the device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceConfigSnapshot {
+    /// ARM current setting in milliamps
+    pub arm_current_ma: u16,
+    /// FIRE current setting in milliamps
+    pub fire_current_ma: u16,
+    /// Device operating mode at the time of the snapshot
+    pub mode: DeviceMode,
+    /// Whether optimized stage transitions are enabled
+    pub optimize_transitions: bool,
+}
+
+impl DeviceConfigSnapshot {
+    /// Validate the snapshot against the device current limit
+    ///
+    /// # Arguments
+    /// * `max_current_ma` - Maximum current supported by the target device
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success if every value can be applied, error describing the first invalid value
+    ///
+    /// # Example
+    /// ```
+    /// snapshot.validate(device.get_max_current()?)?;
+    /// ```
+    pub fn validate(&self, max_current_ma: u16) -> Result<()> {
+        Self::validate_current("ARM", self.arm_current_ma, max_current_ma)?;
+        Self::validate_current("FIRE", self.fire_current_ma, max_current_ma)
+    }
+
+    /// Get the mode that applying this snapshot will set
+    ///
+    /// Applying a snapshot never turns the output on: a snapshot captured in
+    /// `Remote` (firing) mode is restored as `Armed`.
+    ///
+    /// # Returns
+    /// * `DeviceMode` - Mode to set when applying the snapshot
+    pub fn restore_mode(&self) -> DeviceMode {
+        match self.mode {
+            DeviceMode::Remote => DeviceMode::Armed,
+            mode => mode,
+        }
+    }
+
+    /// Validate a single current value
+    fn validate_current(label: &str, current_ma: u16, max_current_ma: u16) -> Result<()> {
+        if current_ma == 0 {
+            return Err(LumidoxError::ValidationError(
+                format!("{} current cannot be zero", label)
+            ));
+        }
+        if current_ma > max_current_ma {
+            return Err(LumidoxError::ValidationError(
+                format!("{} current {}mA exceeds device maximum {}mA", label, current_ma, max_current_ma)
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Full device snapshot: the settable configuration and all stage parameters
+///
+/// Created by `LumidoxDevice::capture_snapshot` and restored with
+/// `LumidoxDevice::apply_snapshot`. Only `config` is written back: the ARM
+/// and FIRE currents, the mode (never restored as firing) and the
+/// optimized transitions setting. The stage parameters are factory
+/// calibration that the protocol has no command to write, so they are
+/// read-only, kept for reference and skipped on restore.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceSnapshot {
+    /// Settable configuration, restored by `apply_snapshot`
+    pub config: DeviceConfigSnapshot,
+    /// Parameters of every stage (read-only, not restored)
+    pub stages: Vec<StageParameters>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> DeviceConfigSnapshot {
+        DeviceConfigSnapshot {
+            arm_current_ma: 200,
+            fire_current_ma: 1500,
+            mode: DeviceMode::Standby,
+            optimize_transitions: true,
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_values_within_limit() {
+        assert!(snapshot().validate(1500).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range_currents() {
+        assert!(matches!(snapshot().validate(1000), Err(LumidoxError::ValidationError(_))));
+        let zero_arm = DeviceConfigSnapshot { arm_current_ma: 0, ..snapshot() };
+        assert!(matches!(zero_arm.validate(1500), Err(LumidoxError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_restore_mode_never_fires() {
+        let firing = DeviceConfigSnapshot { mode: DeviceMode::Remote, ..snapshot() };
+        assert_eq!(firing.restore_mode(), DeviceMode::Armed);
+        assert_eq!(snapshot().restore_mode(), DeviceMode::Standby);
+    }
+}
@@ -2,7 +2,9 @@
 //!
 //! This module contains types and structures related to device identification
 //! and configuration information including firmware version, model number,
-//! serial number, and wavelength specifications.
+//! serial number, and wavelength specifications. With the `serde` feature
+//! enabled `DeviceInfo` can be serialized, for example to record which unit
+//! a measurement was taken on.
 
 /// Device information structure
 /// 
@@ -10,9 +12,15 @@
 /// about the Lumidox II device. This information is typically read once
 /// during device initialization and used for display and logging purposes.
 /// 
+/// With the `serde` feature each field serializes under its Rust name
+/// (`firmware_version`, `model_number`, `serial_number`, `wavelength`,
+/// `max_current_ma`). These names are part of the JSON format and must not
+/// be renamed.
+/// 
 /// All string fields are read from the device using specific protocol
 /// commands and represent the actual hardware configuration.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeviceInfo {
     /// Firmware version string from the device
     /// 
@@ -44,3 +52,99 @@ pub struct DeviceInfo {
     /// Used for validation and safety checks during operation.
     pub max_current_ma: u16,
 }
+
+impl DeviceInfo {
+    /// Get the key identifying the characterization this device reports
+    ///
+    /// Per-stage data (currents, power values) read from a device is only
+    /// valid for the serial number and firmware it was read from.
+    ///
+    /// # Returns
+    /// * `CharacterizationKey` - Serial number and firmware version of the device
+    pub fn characterization_key(&self) -> CharacterizationKey {
+        CharacterizationKey {
+            serial_number: self.serial_number.clone(),
+            firmware_version: self.firmware_version.clone(),
+        }
+    }
+}
+
+/// Identity of the device a set of per-stage data was read from
+///
+/// Cached per-stage data must be discarded and read again when the key of
+/// the connected device differs from the key the data was read for. A
+/// firmware update on the same unit can change stage currents and power
+/// values, so both fields take part in the comparison.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharacterizationKey {
+    /// Device serial number
+    pub serial_number: String,
+    /// Firmware version running on the device
+    pub firmware_version: String,
+}
+
+impl CharacterizationKey {
+    /// Check whether this is the same unit running different firmware
+    ///
+    /// # Arguments
+    /// * `cached` - Key the cached per-stage data was read for
+    ///
+    /// # Returns
+    /// * `bool` - True if the serial numbers match but the firmware versions differ
+    ///
+    /// # Example
+    /// ```
+    /// if current_key.firmware_changed(&cached_key) {
+    ///     println!("Firmware updated, re-reading stage data");
+    /// }
+    /// ```
+    pub fn firmware_changed(&self, cached: &CharacterizationKey) -> bool {
+        self.serial_number == cached.serial_number && self.firmware_version != cached.firmware_version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(serial_number: &str, firmware_version: &str) -> CharacterizationKey {
+        CharacterizationKey {
+            serial_number: serial_number.to_string(),
+            firmware_version: firmware_version.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_firmware_changed_only_for_same_unit() {
+        let cached = key("SN1234", "1.5");
+        assert!(key("SN1234", "1.6").firmware_changed(&cached));
+        assert!(!key("SN1234", "1.5").firmware_changed(&cached));
+        assert!(!key("SN9999", "1.6").firmware_changed(&cached));
+        assert_ne!(key("SN9999", "1.5"), cached);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_device_info_json_round_trip() {
+        let info = DeviceInfo {
+            firmware_version: "1.7".to_string(),
+            model_number: "LX2-365".to_string(),
+            serial_number: "SN1234".to_string(),
+            wavelength: "365".to_string(),
+            max_current_ma: 3000,
+        };
+
+        let value = serde_json::to_value(&info).unwrap();
+        assert_eq!(value, serde_json::json!({
+            "firmware_version": "1.7",
+            "model_number": "LX2-365",
+            "serial_number": "SN1234",
+            "wavelength": "365",
+            "max_current_ma": 3000,
+        }));
+
+        let decoded: DeviceInfo = serde_json::from_value(value).unwrap();
+        assert_eq!(decoded.characterization_key(), info.characterization_key());
+        assert_eq!((decoded.model_number, decoded.wavelength, decoded.max_current_ma), (info.model_number, info.wavelength, info.max_current_ma));
+    }
+}
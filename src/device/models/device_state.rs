@@ -12,6 +12,7 @@
 /// The numeric values correspond to the protocol values sent to the device
 /// via the SET_MODE command (0x15).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeviceMode {
     /// Local mode (device controlled locally) - 0x0000
     /// 
@@ -8,11 +8,13 @@
 //! - `device_info`: Device identification and information types
 //! - `power`: Power measurement and energy-related types
 //! - `parameters`: Configuration parameters and stage-related types
+//! - `config_snapshot`: Capturable and restorable device configuration
 
 pub mod device_state;
 pub mod device_info;
 pub mod power;
 pub mod parameters;
+pub mod config_snapshot;
 
 // Maintain backward compatibility by re-exporting from legacy types module
 pub mod types;
@@ -22,3 +24,4 @@ pub use device_state::*;
 pub use device_info::*;
 pub use power::*;
 pub use parameters::*;
+pub use config_snapshot::*;
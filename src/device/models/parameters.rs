@@ -49,9 +49,9 @@ impl Stage {
     /// assert_eq!(stage.current_ma, 0);
     /// ```
     pub fn new(number: u8) -> Result<Self> {
-        if !(1..=5).contains(&number) {
+        if !(1..=commands::STAGE_COUNT).contains(&number) {
             return Err(LumidoxError::InvalidInput(
-                format!("Invalid stage number: {}. Must be 1-5", number)
+                format!("Invalid stage number: {}. Must be 1-{}", number, commands::STAGE_COUNT)
             ));
         }
         Ok(Stage { number, current_ma: 0 })
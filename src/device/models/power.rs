@@ -1,7 +1,9 @@
 //! Power measurement model definitions
 //!
 //! This module contains types and structures related to power measurements
-//! and energy calculations for the Lumidox II device.
+//! and energy calculations for the Lumidox II device. With the `serde`
+//! feature enabled `PowerInfo` serializes with its field names as JSON keys;
+//! those names are part of the JSON format and must not be renamed.
 
 /// Power measurement data
 /// 
@@ -12,6 +14,7 @@
 /// The power values are provided in floating-point format with associated
 /// unit strings to maintain precision and clarity in measurements.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PowerInfo {
     /// Total power measurement value
     /// 
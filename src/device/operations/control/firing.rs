@@ -3,43 +3,78 @@
 //! This module provides functions specifically for firing stages and
 //! managing current-based firing operations with intelligent transitions.
 
-use crate::core::{LumidoxError, Result};
+use crate::core::{CancellationToken, LumidoxError, Result};
 use crate::communication::{ProtocolHandler, protocol::commands};
 use crate::device::models::{DeviceMode, Stage};
 use super::arming::arm_device;
 use super::modes::set_mode;
+use crate::device::operations::readback::plausibility::plausible_current;
 use std::thread;
 use std::time::Duration;
 
 /// Fire a specific stage with intelligent mode transition
-pub fn fire_stage_smart(protocol: &mut ProtocolHandler, stage_num: u8, current_mode: Option<DeviceMode>) -> Result<()> {
+///
+/// Returns the FIRE current (in mA) read for the stage during the sequence.
+pub fn fire_stage_smart(protocol: &mut ProtocolHandler, stage_num: u8, current_mode: Option<DeviceMode>) -> Result<u16> {
+    fire_stage_smart_cancellable(protocol, stage_num, current_mode, &CancellationToken::new())
+}
+
+/// Fire a specific stage with intelligent mode transition and cancellation
+///
+/// The token is checked between protocol steps. If cancellation is requested
+/// after the device state has been changed, the device is returned to standby
+/// before `LumidoxError::OperationCancelled` is returned, so a cancelled
+/// sequence never leaves the stage firing.
+///
+/// Returns the FIRE current (in mA) read for the stage during the sequence.
+pub fn fire_stage_smart_cancellable(
+    protocol: &mut ProtocolHandler,
+    stage_num: u8,
+    current_mode: Option<DeviceMode>,
+    cancel: &CancellationToken,
+) -> Result<u16> {
     let stage = Stage::new(stage_num)?;
+    let operation = format!("Stage {} firing", stage_num);
     
-    // Get the current for this stage
-    let current = protocol.send_command(stage.current_command(), 0)? as u16;
+    // Get the current for this stage; an implausible value must never be sent back as SET_CURRENT
+    let current = plausible_current(&format!("stage {} FIRE current", stage_num), protocol.send_command(stage.current_command(), 0)?)?;
+    cancel.check(&operation)?;
     
     // Intelligent sequence based on current device state
     match current_mode {
         Some(DeviceMode::Remote) | Some(DeviceMode::Armed) => {
             // Device is already active - direct transition without turning off
             protocol.send_command(commands::SET_CURRENT, current)?;
+            abort_if_cancelled(protocol, cancel, &operation)?;
             set_mode(protocol, DeviceMode::Remote)?;
         }
         _ => {
             // Device is off or in local mode - use full sequence
             set_mode(protocol, DeviceMode::Standby)?;
             thread::sleep(Duration::from_millis(100));
+            abort_if_cancelled(protocol, cancel, &operation)?;
             arm_device(protocol)?;
             protocol.send_command(commands::SET_CURRENT, current)?;
+            abort_if_cancelled(protocol, cancel, &operation)?;
             set_mode(protocol, DeviceMode::Remote)?;
         }
     }
     
-    Ok(())
+    Ok(current)
+}
+
+/// Return the device to standby and stop the sequence if cancellation was requested
+fn abort_if_cancelled(protocol: &mut ProtocolHandler, cancel: &CancellationToken, operation: &str) -> Result<()> {
+    if cancel.is_cancelled() {
+        set_mode(protocol, DeviceMode::Standby)?;
+    }
+    cancel.check(operation)
 }
 
 /// Fire a specific stage (legacy function for backward compatibility)
-pub fn fire_stage(protocol: &mut ProtocolHandler, stage_num: u8) -> Result<()> {
+///
+/// Returns the FIRE current (in mA) read for the stage during the sequence.
+pub fn fire_stage(protocol: &mut ProtocolHandler, stage_num: u8) -> Result<u16> {
     fire_stage_smart(protocol, stage_num, None)
 }
 
@@ -78,7 +113,25 @@ pub fn fire_with_current(protocol: &mut ProtocolHandler, current_ma: u16) -> Res
     fire_with_current_smart(protocol, current_ma, None)
 }
 
+/// Current levels of a ramp towards a target current
+///
+/// Levels rise by `step_ma` from `step_ma` and always end exactly at
+/// `target_ma`, so the last step may be smaller than the others. A step of
+/// zero, or one at least as large as the target, yields the target alone.
+///
+/// # Example
+/// ```
+/// let levels: Vec<u16> = current_ramp_levels(500, 200).collect();
+/// assert_eq!(levels, vec![200, 400, 500]);
+/// ```
+pub fn current_ramp_levels(target_ma: u16, step_ma: u16) -> impl Iterator<Item = u16> {
+    (1..)
+        .map_while(move |index| step_ma.checked_mul(index))
+        .take_while(move |level| *level > 0 && *level < target_ma)
+        .chain(std::iter::once(target_ma))
+}
+
 /// Get maximum current setting
 pub fn get_max_current(protocol: &mut ProtocolHandler) -> Result<u16> {
-    Ok(protocol.send_command(commands::STAGE_CURRENTS[4], 0)? as u16)
+    plausible_current("maximum current", protocol.send_command(commands::STAGE_CURRENTS[4], 0)?)
 }
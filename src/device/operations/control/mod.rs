@@ -12,6 +12,6 @@ pub mod modes;
 pub mod validation;
 
 // Re-export commonly used functions for backward compatibility
-pub use firing::{fire_stage, fire_stage_smart, fire_with_current, fire_with_current_smart, get_max_current};
+pub use firing::{current_ramp_levels, fire_stage, fire_stage_smart, fire_stage_smart_cancellable, fire_with_current, fire_with_current_smart, get_max_current};
 pub use arming::arm_device;
 pub use modes::{set_mode, turn_off, shutdown};
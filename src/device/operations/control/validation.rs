@@ -4,17 +4,61 @@
 //! including current limits, stage numbers, and parameter ranges.
 
 use crate::core::{LumidoxError, Result};
+use crate::communication::protocol::commands::{is_stage_encodable, stage_command, StageParameter, STAGE_COUNT};
 
 /// Validate stage number is within valid range (1-5)
 pub fn validate_stage_number(stage_num: u8) -> Result<()> {
-    if !(1..=5).contains(&stage_num) {
+    validate_stage_number_for(stage_num, STAGE_COUNT)
+}
+
+/// Validate stage number against a device stage count
+///
+/// Also rejects stages whose commands cannot be encoded in the single-byte
+/// protocol command field, so a model with more stages than the protocol can
+/// address fails loudly instead of sending another stage's command.
+///
+/// # Arguments
+/// * `stage_num` - Stage number to validate
+/// * `stage_count` - Number of stages on the device
+///
+/// # Returns
+/// * `Result<()>` - Success, `InvalidInput` for an out-of-range stage, or
+///   `ProtocolError` for a stage the protocol cannot address
+pub fn validate_stage_number_for(stage_num: u8, stage_count: u8) -> Result<()> {
+    if !(1..=stage_count).contains(&stage_num) {
         return Err(LumidoxError::InvalidInput(
-            format!("Invalid stage number: {}. Must be 1-5", stage_num)
+            format!("Invalid stage number: {}. Must be 1-{}", stage_num, stage_count)
+        ));
+    }
+    if !is_stage_encodable(stage_num) {
+        return Err(LumidoxError::ProtocolError(
+            format!("Stage {} cannot be encoded in the single-byte stage command field", stage_num)
         ));
     }
     Ok(())
 }
 
+/// Validate a stage number and encode the command for one of its parameters
+///
+/// # Arguments
+/// * `parameter` - Stage parameter to address
+/// * `stage_num` - Stage number (1-5)
+///
+/// # Returns
+/// * `Result<[u8; 2]>` - Command bytes, or the validation error for the stage
+///
+/// # Example
+/// ```
+/// let command = stage_parameter_command(StageParameter::VoltLimit, 2)?;
+/// let raw = protocol.send_command(&command, 0)?;
+/// ```
+pub fn stage_parameter_command(parameter: StageParameter, stage_num: u8) -> Result<[u8; 2]> {
+    validate_stage_number(stage_num)?;
+    stage_command(parameter, stage_num).ok_or_else(|| LumidoxError::ProtocolError(
+        format!("Stage {} cannot be encoded in the single-byte stage command field", stage_num)
+    ))
+}
+
 /// Validate current value against maximum allowed
 pub fn validate_current(current_ma: u16, max_current_ma: u16) -> Result<()> {
     if current_ma > max_current_ma {
@@ -34,3 +78,17 @@ pub fn validate_non_zero_current(current_ma: u16) -> Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_stage_number_uses_stage_count() {
+        assert!(validate_stage_number(5).is_ok());
+        assert!(matches!(validate_stage_number(6), Err(LumidoxError::InvalidInput(_))));
+        assert!(validate_stage_number_for(12, 16).is_ok());
+        assert!(matches!(validate_stage_number_for(0, 16), Err(LumidoxError::InvalidInput(_))));
+        assert!(matches!(validate_stage_number_for(20, 24), Err(LumidoxError::ProtocolError(_))));
+    }
+}
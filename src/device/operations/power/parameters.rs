@@ -6,20 +6,36 @@
 //! Based on LumidoxII.md protocol specification, this module will implement
 //! missing protocol commands for complete stage parameter access.
 
-use crate::core::{LumidoxError, Result};
+use crate::core::Result;
 use crate::communication::ProtocolHandler;
+use crate::communication::protocol::commands::StageParameter;
+use super::super::control::validation::{stage_parameter_command, validate_stage_number};
+use super::super::readback::plausibility::{plausible_current, plausible_voltage};
 
 /// Stage parameter structure for complete stage information
+///
+/// With the `serde` feature each field serializes under its Rust name; these
+/// names are part of the JSON format and must not be renamed.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StageParameters {
+    /// Stage number (1-5)
     pub stage_number: u8,
+    /// ARM current in milliamps
     pub arm_current_ma: u16,
+    /// FIRE current in milliamps
     pub fire_current_ma: u16,
+    /// Voltage limit in volts
     pub volt_limit_v: f32,
+    /// Voltage start in volts
     pub volt_start_v: f32,
+    /// Total power at the FIRE current
     pub power_total: f32,
+    /// Power per LED at the FIRE current
     pub power_per_led: f32,
+    /// Units of `power_total`, e.g. "mW"
     pub total_units: String,
+    /// Units of `power_per_led`, e.g. "mW"
     pub per_led_units: String,
 }
 
@@ -32,19 +48,13 @@ pub struct StageParameters {
 /// - VOLT Start: 0x7a, 0x82, 0x8a, 0x92, 0x9a (Stages 1-5)
 /// - Power measurements: Combined from existing power info functionality
 pub fn get_stage_parameters(protocol: &mut ProtocolHandler, stage_num: u8) -> Result<StageParameters> {
-    if !(1..=5).contains(&stage_num) {
-        return Err(LumidoxError::InvalidInput(
-            format!("Invalid stage number: {}. Must be 1-5", stage_num)
-        ));
-    }
+    validate_stage_number(stage_num)?;
 
     // Get ARM current for this stage
     let arm_current_ma = get_stage_arm_current(protocol, stage_num)?;
 
-    // Get FIRE current for this stage using existing STAGE_CURRENTS commands
-    let stage_idx = (stage_num - 1) as usize;
-    let fire_command = crate::communication::protocol::commands::STAGE_CURRENTS[stage_idx];
-    let fire_current_ma = protocol.send_command(fire_command, 0)? as u16;
+    // Get FIRE current for this stage
+    let fire_current_ma = get_stage_fire_current(protocol, stage_num)?;
 
     // Get voltage parameters for this stage
     let volt_limit_v = get_stage_volt_limit(protocol, stage_num)?;
@@ -70,80 +80,42 @@ pub fn get_stage_parameters(protocol: &mut ProtocolHandler, stage_num: u8) -> Re
 ///
 /// Protocol commands: 0x77 (Stage 1), 0x7f (Stage 2), 0x87 (Stage 3), 0x8f (Stage 4), 0x97 (Stage 5)
 pub fn get_stage_arm_current(protocol: &mut ProtocolHandler, stage_num: u8) -> Result<u16> {
-    if !(1..=5).contains(&stage_num) {
-        return Err(LumidoxError::InvalidInput(
-            format!("Invalid stage number: {}. Must be 1-5", stage_num)
-        ));
-    }
-
-    // Get the appropriate command for this stage (stages are 1-indexed, array is 0-indexed)
-    let stage_idx = (stage_num - 1) as usize;
-    let command = crate::communication::protocol::commands::STAGE_ARM_CURRENTS[stage_idx];
+    // Get the command for this stage's parameter block
+    let command = stage_parameter_command(StageParameter::ArmCurrent, stage_num)?;
 
     // Send command and get ARM current value
-    let arm_current = protocol.send_command(command, 0)? as u16;
-
-    Ok(arm_current)
+    plausible_current(&format!("stage {} ARM current", stage_num), protocol.send_command(&command, 0)?)
 }
 
 /// Get FIRE current for a specific stage
 ///
 /// Protocol commands: 0x78 (Stage 1), 0x80 (Stage 2), 0x88 (Stage 3), 0x90 (Stage 4), 0x98 (Stage 5)
 pub fn get_stage_fire_current(protocol: &mut ProtocolHandler, stage_num: u8) -> Result<u16> {
-    if !(1..=5).contains(&stage_num) {
-        return Err(LumidoxError::InvalidInput(
-            format!("Invalid stage number: {}. Must be 1-5", stage_num)
-        ));
-    }
-
-    // Get the appropriate command for this stage (stages are 1-indexed, array is 0-indexed)
-    let stage_idx = (stage_num - 1) as usize;
-    let fire_command = crate::communication::protocol::commands::STAGE_CURRENTS[stage_idx];
+    // Get the command for this stage's parameter block
+    let fire_command = stage_parameter_command(StageParameter::FireCurrent, stage_num)?;
 
     // Send command and get FIRE current value
-    let fire_current = protocol.send_command(fire_command, 0)? as u16;
-
-    Ok(fire_current)
+    plausible_current(&format!("stage {} FIRE current", stage_num), protocol.send_command(&fire_command, 0)?)
 }
 
 /// Get voltage limit for a specific stage
 ///
 /// Protocol commands: 0x79 (Stage 1), 0x81 (Stage 2), 0x89 (Stage 3), 0x91 (Stage 4), 0x99 (Stage 5)
 pub fn get_stage_volt_limit(protocol: &mut ProtocolHandler, stage_num: u8) -> Result<f32> {
-    if !(1..=5).contains(&stage_num) {
-        return Err(LumidoxError::InvalidInput(
-            format!("Invalid stage number: {}. Must be 1-5", stage_num)
-        ));
-    }
+    // Get the command for this stage's parameter block
+    let command = stage_parameter_command(StageParameter::VoltLimit, stage_num)?;
 
-    // Get the appropriate command for this stage (stages are 1-indexed, array is 0-indexed)
-    let stage_idx = (stage_num - 1) as usize;
-    let command = crate::communication::protocol::commands::STAGE_VOLT_LIMITS[stage_idx];
-
-    // Send command and get voltage limit value
-    // Convert from device units to volts (assuming device returns in appropriate units)
-    let volt_limit = protocol.send_command(command, 0)? as f32 / 10.0;
-
-    Ok(volt_limit)
+    // Send command and get voltage limit value, converted from 0.01V device units to volts
+    plausible_voltage(&format!("stage {} voltage limit", stage_num), protocol.send_command(&command, 0)?)
 }
 
 /// Get voltage start for a specific stage
 ///
 /// Protocol commands: 0x7a (Stage 1), 0x82 (Stage 2), 0x8a (Stage 3), 0x92 (Stage 4), 0x9a (Stage 5)
 pub fn get_stage_volt_start(protocol: &mut ProtocolHandler, stage_num: u8) -> Result<f32> {
-    if !(1..=5).contains(&stage_num) {
-        return Err(LumidoxError::InvalidInput(
-            format!("Invalid stage number: {}. Must be 1-5", stage_num)
-        ));
-    }
-
-    // Get the appropriate command for this stage (stages are 1-indexed, array is 0-indexed)
-    let stage_idx = (stage_num - 1) as usize;
-    let command = crate::communication::protocol::commands::STAGE_VOLT_STARTS[stage_idx];
-
-    // Send command and get voltage start value
-    // Convert from device units to volts (assuming device returns in appropriate units)
-    let volt_start = protocol.send_command(command, 0)? as f32 / 10.0;
+    // Get the command for this stage's parameter block
+    let command = stage_parameter_command(StageParameter::VoltStart, stage_num)?;
 
-    Ok(volt_start)
+    // Send command and get voltage start value, converted from 0.01V device units to volts
+    plausible_voltage(&format!("stage {} voltage start", stage_num), protocol.send_command(&command, 0)?)
 }
@@ -1,18 +1,19 @@
 //! Current readback and control operations for Lumidox II Controller
 //!
 //! This module provides functions for reading current ARM and FIRE current settings
-//! and controlling ARM current values.
+//! and controlling ARM and FIRE current values.
 
 use crate::core::{LumidoxError, Result};
 use crate::communication::{ProtocolHandler, protocol::commands};
+use crate::device::operations::control::get_max_current;
+use super::plausibility::plausible_current;
 
 /// Read current ARM current setting from device
 /// 
 /// Uses protocol command 0x20 to read the current ARM current setting.
 /// Returns the ARM current value in milliamps (mA).
 pub fn read_arm_current(protocol: &mut ProtocolHandler) -> Result<u16> {
-    let current_value = protocol.send_command(commands::READ_ARM_CURRENT, 0)? as u16;
-    Ok(current_value)
+    plausible_current("ARM current", protocol.send_command(commands::READ_ARM_CURRENT, 0)?)
 }
 
 /// Read current FIRE current setting from device
@@ -20,14 +21,14 @@ pub fn read_arm_current(protocol: &mut ProtocolHandler) -> Result<u16> {
 /// Uses protocol command 0x21 to read the current FIRE current setting.
 /// Returns the FIRE current value in milliamps (mA).
 pub fn read_fire_current(protocol: &mut ProtocolHandler) -> Result<u16> {
-    let current_value = protocol.send_command(commands::READ_FIRE_CURRENT, 0)? as u16;
-    Ok(current_value)
+    plausible_current("FIRE current", protocol.send_command(commands::READ_FIRE_CURRENT, 0)?)
 }
 
 /// Set ARM current value
 /// 
 /// Uses protocol command 0x40 to set the ARM current.
-/// The current value should be specified in milliamps (mA).
+/// The current value should be specified in milliamps (mA) and is validated
+/// against the device maximum current before it is sent.
 /// 
 /// # Arguments
 /// * `protocol` - Protocol handler for device communication
@@ -37,14 +38,75 @@ pub fn read_fire_current(protocol: &mut ProtocolHandler) -> Result<u16> {
 /// * `Ok(())` if the ARM current was set successfully
 /// * `Err(LumidoxError)` if the operation failed or current value is invalid
 pub fn set_arm_current(protocol: &mut ProtocolHandler, current_ma: u16) -> Result<()> {
-    // Validate current value is not zero
+    validate_arm_current(current_ma)?;
+    validate_current_limit(protocol, "ARM", current_ma)?;
+    
+    protocol.send_command(commands::SET_ARM_CURRENT, current_ma)?;
+    Ok(())
+}
+
+/// Set FIRE current value without firing
+/// 
+/// Uses protocol command 0x41 to set the FIRE current while leaving the
+/// device mode unchanged. The current value should be specified in milliamps
+/// (mA) and is validated against the device maximum current before it is sent.
+/// 
+/// # Arguments
+/// * `protocol` - Protocol handler for device communication
+/// * `current_ma` - FIRE current value in milliamps
+/// 
+/// # Returns
+/// * `Ok(())` if the FIRE current was set successfully
+/// * `Err(LumidoxError)` if the operation failed or current value is invalid
+pub fn set_fire_current(protocol: &mut ProtocolHandler, current_ma: u16) -> Result<()> {
     if current_ma == 0 {
         return Err(LumidoxError::InvalidInput(
-            "ARM current cannot be zero".to_string()
+            "FIRE current cannot be zero".to_string()
         ));
     }
+    validate_current_limit(protocol, "FIRE", current_ma)?;
     
-    protocol.send_command(commands::SET_ARM_CURRENT, current_ma)?;
+    protocol.send_command(commands::SET_CURRENT, current_ma)?;
+    Ok(())
+}
+
+/// Write ARM and FIRE currents that were already validated
+/// 
+/// Sends 0x40 and 0x41 without reading the device maximum again, for
+/// callers that validated both values together. The device must not be in
+/// fire mode, where 0x41 changes the live output.
+/// 
+/// # Arguments
+/// * `protocol` - Protocol handler for device communication
+/// * `arm_current_ma` - ARM current value in milliamps
+/// * `fire_current_ma` - FIRE current value in milliamps
+/// 
+/// # Returns
+/// * `Ok(())` if both currents were written
+/// * `Err(LumidoxError)` if either command failed
+pub fn write_validated_currents(protocol: &mut ProtocolHandler, arm_current_ma: u16, fire_current_ma: u16) -> Result<()> {
+    protocol.send_command(commands::SET_ARM_CURRENT, arm_current_ma)?;
+    protocol.send_command(commands::SET_CURRENT, fire_current_ma)?;
+    Ok(())
+}
+
+/// Validate a current value against the device maximum current
+/// 
+/// # Arguments
+/// * `protocol` - Protocol handler for device communication
+/// * `label` - Name of the current being set, used in the error message
+/// * `current_ma` - Requested current value in milliamps
+/// 
+/// # Returns
+/// * `Ok(())` if the value does not exceed the device maximum
+/// * `Err(LumidoxError)` if the maximum cannot be read or is exceeded
+fn validate_current_limit(protocol: &mut ProtocolHandler, label: &str, current_ma: u16) -> Result<()> {
+    let max_current = get_max_current(protocol)?;
+    if current_ma > max_current {
+        return Err(LumidoxError::InvalidInput(
+            format!("Cannot set {} current above {}mA (requested: {}mA)", label, max_current, current_ma)
+        ));
+    }
     Ok(())
 }
 
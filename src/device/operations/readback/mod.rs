@@ -2,14 +2,18 @@
 //!
 //! This module organizes readback operations into focused sub-modules:
 //! - `state`: Device state reading and status operations
-//! - `current`: ARM/FIRE current readback and ARM current control operations
+//! - `current`: ARM/FIRE current readback and control operations
+//! - `plausibility`: Bounds checks on parsed numeric readbacks
 
 pub mod state;
 pub mod current;
+pub mod plausibility;
 
 // Re-export commonly used functions for convenience
 pub use state::{
     read_remote_mode_state,
+    mode_from_state_value,
+    read_active_stage,
     // Note: These functions are available but not currently used
     // is_remote_controlled,
     // is_ready_for_firing,
@@ -20,5 +24,7 @@ pub use current::{
     read_arm_current, 
     read_fire_current, 
     set_arm_current, 
+    set_fire_current,
+    write_validated_currents,
     get_current_settings_summary
 };
@@ -0,0 +1,81 @@
+//! Plausibility checks for numeric readbacks
+//!
+//! Responses carry a 16-bit value that is parsed as a signed number. If a
+//! firmware sent multi-byte values in a different byte order, or the
+//! parsing were wrong, the result would still be a number, just a wildly
+//! wrong one: 1500mA read byte-swapped is -9211. Readbacks are therefore
+//! checked against bounds no real Lumidox II value comes near, and a value
+//! outside them fails with `LumidoxError::ImplausibleValue` instead of being
+//! passed on as data.
+
+use crate::core::{LumidoxError, Result};
+
+/// Highest current any readback may report, in milliamps
+///
+/// Well above the maximum current of any Lumidox II model.
+pub const PLAUSIBLE_MAX_CURRENT_MA: i32 = 10_000;
+
+/// Highest voltage any readback may report, in device units (0.01V)
+pub const PLAUSIBLE_MAX_VOLTAGE_RAW: i32 = 10_000;
+
+/// Check a current readback and convert it to milliamps
+///
+/// # Arguments
+/// * `field` - Name of the value, e.g. "ARM current"
+/// * `raw` - Parsed response value
+///
+/// # Returns
+/// * `Result<u16>` - Current in mA, or `ImplausibleValue` outside 0..=10000mA
+///
+/// # Example
+/// ```
+/// let current_ma = plausible_current("FIRE current", protocol.send_command(commands::READ_FIRE_CURRENT, 0)?)?;
+/// ```
+pub fn plausible_current(field: &str, raw: i32) -> Result<u16> {
+    check_range(field, raw, PLAUSIBLE_MAX_CURRENT_MA)?;
+    Ok(raw as u16)
+}
+
+/// Check a voltage readback and convert it to volts
+///
+/// # Arguments
+/// * `field` - Name of the value, e.g. "stage 2 voltage limit"
+/// * `raw` - Parsed response value in 0.01V units (XMULT=100)
+///
+/// # Returns
+/// * `Result<f32>` - Voltage in volts, or `ImplausibleValue` outside 0..=100V
+pub fn plausible_voltage(field: &str, raw: i32) -> Result<f32> {
+    check_range(field, raw, PLAUSIBLE_MAX_VOLTAGE_RAW)?;
+    Ok(raw as f32 / 100.0)
+}
+
+/// Fail with `ImplausibleValue` unless `raw` is within `0..=max`
+fn check_range(field: &str, raw: i32, max: i32) -> Result<()> {
+    if (0..=max).contains(&raw) {
+        Ok(())
+    } else {
+        Err(LumidoxError::ImplausibleValue { field: field.to_string(), raw })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_swapped_values_are_implausible() {
+        assert_eq!(plausible_current("ARM current", 1500).unwrap(), 1500);
+        assert_eq!(plausible_voltage("voltage limit", 2450).unwrap(), 24.5);
+
+        // 1500 = 0x05dc, byte-swapped 0xdc05
+        let swapped = i32::from(i16::from_be_bytes([0xdc, 0x05]));
+        assert!(matches!(
+            plausible_current("ARM current", swapped),
+            Err(LumidoxError::ImplausibleValue { ref field, raw }) if field == "ARM current" && raw == swapped
+        ));
+        // 2450 = 0x0992, byte-swapped 0x9209
+        assert!(plausible_voltage("voltage limit", i32::from(i16::from_be_bytes([0x92, 0x09]))).is_err());
+        assert!(plausible_voltage("voltage limit", 10_001).is_err());
+        assert!(plausible_current("FIRE current", 20_000).is_err());
+    }
+}
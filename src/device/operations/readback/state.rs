@@ -6,6 +6,7 @@
 use crate::core::Result;
 use crate::communication::{ProtocolHandler, protocol::commands};
 use crate::device::models::DeviceMode;
+use crate::device::operations::power::get_stage_fire_current;
 
 /// Read current remote mode state from device
 /// 
@@ -17,8 +18,14 @@ use crate::device::models::DeviceMode;
 /// - 0x0003: Remote mode (On, Fire)
 pub fn read_remote_mode_state(protocol: &mut ProtocolHandler) -> Result<DeviceMode> {
     let state_value = protocol.send_command(commands::READ_REMOTE_MODE, 0)?;
-    
-    let mode = match state_value {
+    Ok(mode_from_state_value(state_value))
+}
+
+/// Convert a remote mode state value (command 0x13) to a DeviceMode
+/// 
+/// Unknown values map to Local mode.
+pub fn mode_from_state_value(state_value: i32) -> DeviceMode {
+    match state_value {
         0 => DeviceMode::Local,
         1 => DeviceMode::Standby,
         2 => DeviceMode::Armed,
@@ -27,9 +34,7 @@ pub fn read_remote_mode_state(protocol: &mut ProtocolHandler) -> Result<DeviceMo
             // Default to Local mode for unknown values
             DeviceMode::Local
         }
-    };
-    
-    Ok(mode)
+    }
 }
 
 /// Check if device is in remote control mode
@@ -65,3 +70,25 @@ pub fn get_device_state_description(protocol: &mut ProtocolHandler) -> Result<St
     
     Ok(description.to_string())
 }
+
+/// Read the stage whose output is currently active
+/// 
+/// The protocol has no direct stage readback, so the active stage is found by
+/// matching the device FIRE current (0x21) against each stage's configured
+/// FIRE current. Returns None when the output is off (device not in Remote
+/// mode) or when firing at a custom current that matches no stage. If several
+/// stages share the same FIRE current, the lowest-numbered stage is returned.
+pub fn read_active_stage(protocol: &mut ProtocolHandler) -> Result<Option<u8>> {
+    if read_remote_mode_state(protocol)? != DeviceMode::Remote {
+        return Ok(None);
+    }
+    
+    let fire_current = super::current::read_fire_current(protocol)?;
+    for stage in 1..=5u8 {
+        if get_stage_fire_current(protocol, stage)? == fire_current {
+            return Ok(Some(stage));
+        }
+    }
+    
+    Ok(None)
+}
@@ -0,0 +1,318 @@
+//! Shared device handle for Lumidox II Controller
+//!
+//! `SharedDevice` lets several threads or tasks use one connected device
+//! without each of them managing the locking. Every method locks the device
+//! for the duration of the operation, so operations from different handles
+//! run one after another and never interleave on the serial link.
+//!
+//! Device operations block on serial I/O. From async code, call them inside
+//! `tokio::task::spawn_blocking` (or the runtime's equivalent) rather than
+//! directly on an executor thread.
+//!
+//! The firmware drops back to local mode when the remote session sits idle
+//! for too long. `SharedDevice::start_keepalive` runs a heartbeat thread that
+//! reads the remote mode periodically to keep the session alive. The
+//! heartbeat takes the same lock as every other operation, so it only ever
+//! runs between foreground operations.
+
+use std::sync::{mpsc, Arc, Mutex, MutexGuard, PoisonError, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use crate::core::{LumidoxError, Result};
+use crate::device::controller::LumidoxDevice;
+use crate::device::models::{DeviceInfo, DeviceMode};
+
+/// Cloneable, thread-safe handle to a device
+///
+/// Clones share the same device. A long-running operation such as
+/// `fire_stage_for` holds the lock for its whole duration, and every other
+/// handle waits until it finishes, including one calling `emergency_stop`.
+///
+/// # Example
+/// ```
+/// let device = SharedDevice::new(LumidoxDevice::connect(&config)?);
+/// let worker = device.clone();
+/// std::thread::spawn(move || worker.fire_stage(3));
+/// ```
+#[derive(Clone)]
+pub struct SharedDevice {
+    /// Device shared by all clones of the handle
+    inner: Arc<Mutex<LumidoxDevice>>,
+    /// Running heartbeat, shared by all clones of the handle
+    keepalive: Arc<Mutex<Option<Keepalive>>>,
+}
+
+/// Background heartbeat thread and the channel that stops it
+struct Keepalive {
+    /// Dropping or sending on this wakes the thread and ends it
+    stop: mpsc::Sender<()>,
+    /// Heartbeat thread
+    thread: JoinHandle<()>,
+}
+
+impl SharedDevice {
+    /// Wrap a device in a shared handle
+    ///
+    /// # Arguments
+    /// * `device` - Connected device to share
+    ///
+    /// # Returns
+    /// * `SharedDevice` - Handle that can be cloned into other threads or tasks
+    pub fn new(device: LumidoxDevice) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(device)),
+            keepalive: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Lock the device for several operations in a row
+    ///
+    /// Other handles wait until the guard is dropped. A lock poisoned by a
+    /// panic in another thread is recovered so the device can still be
+    /// turned off.
+    ///
+    /// # Returns
+    /// * `MutexGuard<LumidoxDevice>` - Exclusive access to the device
+    ///
+    /// # Example
+    /// ```
+    /// let mut device = shared.lock();
+    /// device.set_fire_current(1500)?;
+    /// device.fire_with_current(1500)?;
+    /// ```
+    pub fn lock(&self) -> MutexGuard<'_, LumidoxDevice> {
+        lock_device(&self.inner)
+    }
+
+    /// Run a closure with exclusive access to the device
+    ///
+    /// # Arguments
+    /// * `operation` - Operation to run while the device is locked
+    ///
+    /// # Returns
+    /// * `T` - Whatever the closure returns
+    ///
+    /// # Example
+    /// ```
+    /// let current = shared.with(|device| device.read_fire_current())?;
+    /// ```
+    pub fn with<T>(&self, operation: impl FnOnce(&mut LumidoxDevice) -> T) -> T {
+        operation(&mut self.lock())
+    }
+
+    /// Fire a specific stage
+    ///
+    /// # Arguments
+    /// * `stage_num` - The stage number to fire (1-5)
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or firing error
+    pub fn fire_stage(&self, stage_num: u8) -> Result<()> {
+        self.lock().fire_stage(stage_num)
+    }
+
+    /// Arm the device (prepare for firing)
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or arming error
+    pub fn arm(&self) -> Result<()> {
+        self.lock().arm()
+    }
+
+    /// Turn off the device output
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or turn-off error
+    pub fn turn_off(&self) -> Result<()> {
+        self.lock().turn_off()
+    }
+
+    /// Get device information
+    ///
+    /// # Returns
+    /// * `Option<DeviceInfo>` - Copy of the device information if initialized
+    pub fn info(&self) -> Option<DeviceInfo> {
+        self.lock().info().cloned()
+    }
+
+    /// Start a heartbeat that keeps the device in remote mode
+    ///
+    /// Spawns a thread that reads the remote mode every `interval`, waiting
+    /// for the device lock like any other operation. Pick an interval well
+    /// below the firmware's idle timeout. A heartbeat that is already running
+    /// is stopped and replaced.
+    ///
+    /// If a heartbeat read fails, for example because the port has gone
+    /// away, the error is logged and the heartbeat stops; it does not retry
+    /// beyond the device's own auto-reconnect. The next foreground operation
+    /// then reports the failure itself. A heartbeat that finds the device
+    /// already in local mode logs a warning and keeps running, since it
+    /// cannot restore remote mode by reading.
+    ///
+    /// The heartbeat never extends a maximum session duration set with
+    /// `set_max_session_duration`: each heartbeat read runs the session-limit
+    /// check, so the first heartbeat after the limit has elapsed turns the
+    /// output off, returns the device to local mode and stops the heartbeat,
+    /// even while the device sits firing with no other operation pending.
+    /// The heartbeat also ends once
+    /// every handle to the device has been dropped.
+    ///
+    /// # Arguments
+    /// * `interval` - Time between heartbeat reads
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success, or `InvalidInput` for a zero interval
+    ///
+    /// # Example
+    /// ```
+    /// shared.arm()?;
+    /// shared.start_keepalive(Duration::from_secs(5))?;
+    /// // ... operator pauses ...
+    /// shared.fire_stage(3)?;
+    /// shared.stop_keepalive();
+    /// ```
+    pub fn start_keepalive(&self, interval: Duration) -> Result<()> {
+        if interval.is_zero() {
+            return Err(LumidoxError::InvalidInput("Keep-alive interval must be greater than zero".to_string()));
+        }
+        self.stop_keepalive();
+
+        let (stop, stopped) = mpsc::channel();
+        let device = Arc::downgrade(&self.inner);
+        let thread = thread::spawn(move || run_keepalive(device, interval, stopped));
+        *self.keepalive.lock().unwrap_or_else(PoisonError::into_inner) = Some(Keepalive { stop, thread });
+        Ok(())
+    }
+
+    /// Stop the heartbeat started by `start_keepalive`
+    ///
+    /// Waits for a heartbeat read in progress to finish. Does nothing if no
+    /// heartbeat is running.
+    pub fn stop_keepalive(&self) {
+        let keepalive = self.keepalive.lock().unwrap_or_else(PoisonError::into_inner).take();
+        if let Some(keepalive) = keepalive {
+            let _ = keepalive.stop.send(());
+            let _ = keepalive.thread.join();
+        }
+    }
+
+    /// Check whether the heartbeat is running
+    ///
+    /// # Returns
+    /// * `bool` - True if a heartbeat was started and has not stopped or failed
+    pub fn is_keepalive_running(&self) -> bool {
+        self.keepalive
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .is_some_and(|keepalive| !keepalive.thread.is_finished())
+    }
+}
+
+/// Lock a device, recovering the lock if another thread panicked
+fn lock_device(device: &Mutex<LumidoxDevice>) -> MutexGuard<'_, LumidoxDevice> {
+    device.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Body of the heartbeat thread
+///
+/// # Arguments
+/// * `device` - Device to keep alive; the thread ends once it is dropped
+/// * `interval` - Time between heartbeat reads
+/// * `stopped` - Receives the stop request, or disconnects when the handle is dropped
+fn run_keepalive(device: Weak<Mutex<LumidoxDevice>>, interval: Duration, stopped: mpsc::Receiver<()>) {
+    while let Err(mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+        let Some(device) = device.upgrade() else {
+            break;
+        };
+        let result = lock_device(&device).read_remote_mode();
+        match result {
+            Ok(DeviceMode::Local) => log::warn!("Keep-alive: device is in local mode"),
+            Ok(mode) => log::trace!("Keep-alive: device in {:?} mode", mode),
+            Err(e @ LumidoxError::SafetyLimit(_)) => {
+                log::warn!("Keep-alive stopped, session ended: {}", e);
+                break;
+            }
+            Err(e) => {
+                log::error!("Keep-alive stopped: {}", e);
+                break;
+            }
+        }
+    }
+}
+
+impl From<LumidoxDevice> for SharedDevice {
+    fn from(device: LumidoxDevice) -> Self {
+        Self::new(device)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::communication::{MockTransport, ProtocolHandler};
+    use crate::communication::protocol::commands;
+    use crate::device::models::DeviceMode;
+
+    #[test]
+    fn test_clones_share_one_device() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedDevice>();
+
+        let transport = MockTransport::new()
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0);
+        let shared = SharedDevice::new(LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap()));
+        let handle = shared.clone();
+
+        std::thread::spawn(move || handle.with(|device| device.emergency_stop()))
+            .join()
+            .unwrap()
+            .unwrap();
+        assert_eq!(shared.lock().current_mode(), None);
+        assert!(shared.info().is_none());
+    }
+
+    #[test]
+    fn test_keepalive_stops_when_the_port_fails() {
+        let transport = MockTransport::new()
+            .expect_command(commands::READ_REMOTE_MODE, 0, DeviceMode::Armed as u16)
+            .expect_command(commands::READ_REMOTE_MODE, 0, DeviceMode::Armed as u16);
+        let shared = SharedDevice::new(LumidoxDevice::new(ProtocolHandler::with_transport(transport).unwrap()));
+        assert!(shared.start_keepalive(Duration::ZERO).is_err());
+
+        shared.start_keepalive(Duration::from_millis(5)).unwrap();
+        // Two heartbeats succeed, the third finds no expected response and ends the thread
+        for _ in 0..200 {
+            if !shared.is_keepalive_running() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert!(!shared.is_keepalive_running());
+        shared.stop_keepalive();
+    }
+
+    #[test]
+    fn test_keepalive_ends_an_expired_session() {
+        let transport = MockTransport::new()
+            .expect_command(commands::SET_MODE, DeviceMode::Standby as u16, 0)
+            .expect_command(commands::SET_MODE, DeviceMode::Local as u16, 0);
+        let mut device = LumidoxDevice::new(ProtocolHandler::with_transport(transport.clone()).unwrap());
+        device.initialized = true;
+        device.set_max_session_duration(Some(Duration::from_secs(1)));
+        device.session_limit.start(std::time::Instant::now() - Duration::from_secs(2));
+        let shared = SharedDevice::new(device);
+
+        // The first heartbeat finds the limit elapsed, shuts the device down and stops
+        shared.start_keepalive(Duration::from_millis(5)).unwrap();
+        for _ in 0..400 {
+            if !shared.is_keepalive_running() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert!(!shared.is_keepalive_running());
+        assert!(!shared.lock().is_initialized());
+        transport.assert_consumed();
+    }
+}
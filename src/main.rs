@@ -116,18 +116,27 @@ fn run_cli_interface() -> Result<()> {
     use clap::Parser;
     use ui::Cli;
 
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+
+    // Fill in defaults from the configuration file; flags take precedence
+    cli.apply_config_file()?;
+
+    // Route diagnostics to stderr and the optional log file
+    ui::cli::logging::init_logging(cli.log_level().into(), cli.log_file.as_deref())?;
 
     // Validate CLI arguments
     cli.validate();
 
+    // Destructive actions are confirmed alike in interactive and command mode
+    ui::cli::confirm::set_confirm_policy(ui::cli::confirm::ConfirmPolicy::from_flags(cli.confirm_fires, cli.yes));
+
     // Determine optimization setting
     let optimize_transitions = cli.optimize_transitions();
 
     if cli.is_command_mode() {
         run_command_mode(&cli, optimize_transitions)
     } else {
-        run_interactive_mode(&cli, optimize_transitions)
+        run_interactive_mode(&cli)
     }
 }
 
@@ -140,22 +149,23 @@ fn run_command_mode(cli: &ui::Cli, optimize_transitions: bool) -> Result<()> {
         Some(Commands::ListPorts) => {
             list_serial_ports()?;
         }
-        Some(Commands::DetectPorts) | Some(Commands::TestBaud { .. }) | Some(Commands::PortDiagnostics) => {
-            // Port detection commands don't need device connection
+        Some(Commands::DetectPorts) | Some(Commands::TestBaud { .. }) | Some(Commands::PortDiagnostics) |
+        Some(Commands::CommandRef) | Some(Commands::Completions { .. }) => {
+            // Port detection commands, the command reference and completions don't need device connection
             run_command_mode_with_optimization(cli.command.as_ref().unwrap().clone(), "".to_string(), optimize_transitions)?;
         }
         Some(command) => {
             // Commands that need device connection
             if cli.auto {
                 // Use auto-detection
-                run_auto_command(command, optimize_transitions, cli.verbose)?;
+                run_device_command(command, &cli.operation_config(), cli.format)?;
             } else {
                 // Manual port specification required
-                let port_name = cli.port.clone().ok_or_else(|| {
-                    core::LumidoxError::InvalidInput("Port must be specified for non-interactive mode (use --auto for automatic detection)".to_string())
-                })?;
+                if cli.port.is_none() {
+                    return Err(core::LumidoxError::InvalidInput("Port must be specified for non-interactive mode (use --auto for automatic detection)".to_string()));
+                }
 
-                run_command_mode_with_optimization(command.clone(), port_name, optimize_transitions)?;
+                run_device_command(command, &cli.operation_config(), cli.format)?;
             }
         }
         None => {
@@ -169,50 +179,30 @@ fn run_command_mode(cli: &ui::Cli, optimize_transitions: bool) -> Result<()> {
 
 /// Run CLI in interactive mode
 #[cfg(feature = "cli")]
-fn run_interactive_mode(cli: &ui::Cli, optimize_transitions: bool) -> Result<()> {
-    use ui::run_interactive_mode_with_optimization;
+fn run_interactive_mode(cli: &ui::Cli) -> Result<()> {
+    use ui::cli::interactive::InteractiveSystem;
 
     if cli.verbose {
         println!("Running in CLI Interactive mode");
     }
 
-    run_interactive_mode_with_optimization(cli.port.clone(), cli.auto, cli.verbose, optimize_transitions)
+    ui::cli::interactive::input::idle::set_idle_timeout(cli.idle_timeout.map(std::time::Duration::from_secs));
+    ui::cli::interactive::input::eof::set_eof_action(cli.on_eof);
+    let confirm_fires = ui::cli::confirm::confirm_policy().prompts();
+    InteractiveSystem::run_interactive_with_confirmation(&cli.operation_config(), None, confirm_fires)
 }
 
-/// Execute a command with auto-detected device
+/// Connect using the CLI configuration and execute a device command
+///
+/// Manual-port and `--auto` invocations both connect through
+/// `LumidoxDevice::connect`, so settings such as `--verify-fire` apply to
+/// either, and every device command is available with `--auto`. With
+/// `--format json` a connection or command failure is printed as JSON.
 #[cfg(feature = "cli")]
-fn run_auto_command(command: &ui::Commands, optimize_transitions: bool, verbose: bool) -> Result<()> {
-    use ui::cli::device::create_device_controller_auto;
-    use ui::Commands;
-
-    let mut device = create_device_controller_auto(optimize_transitions, verbose)?;
-
-    match command {
-        Commands::Stage1 => { println!("Firing stage 1."); device.fire_stage(1)? }
-        Commands::Stage2 => { println!("Firing stage 2."); device.fire_stage(2)? }
-        Commands::Stage3 => { println!("Firing stage 3."); device.fire_stage(3)? }
-        Commands::Stage4 => { println!("Firing stage 4."); device.fire_stage(4)? }
-        Commands::Stage5 => { println!("Firing stage 5."); device.fire_stage(5)? }
-        Commands::Current { value } => { println!("Firing with {}mA.", value); device.fire_with_current(*value)? }
-        Commands::Arm => { println!("Arming device."); device.arm()? }
-        Commands::Off => { println!("Turning off device."); device.turn_off()? }
-        Commands::Info => {
-            if let Some(info) = device.info() {
-                println!("Controller Firmware Version: {}", info.firmware_version);
-                println!("Device Model Number: {}", info.model_number);
-                println!("Device Serial Number: {}", info.serial_number);
-                println!("Device Wavelength: {}", info.wavelength);
-            } else {
-                println!("Device information not available");
-            }
-        }
-        _ => {
-            // For other commands, this shouldn't happen in auto mode, but handle gracefully
-            return Err(core::LumidoxError::InvalidInput("Command not supported in auto mode".to_string()));
-        }
-    }
-
-    Ok(())
+fn run_device_command(command: &ui::Commands, config: &device::OperationConfig, format: ui::cli::OutputFormat) -> Result<()> {
+    let result = device::LumidoxDevice::connect(config)
+        .and_then(|mut device| ui::cli::commands::execute_device_command(&mut device, command, format));
+    ui::cli::commands::json_output::report_error(format, command, result)
 }
 
 /// Detect if we're running in a GUI environment
@@ -3,8 +3,15 @@
 //! This module defines the command-line interface structure including
 //! the main CLI arguments and all available commands.
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 use std::process;
+use std::time::Duration;
+use crate::communication::{Baud, ExpectedModel, ModelMismatchPolicy};
+use crate::core::{LumidoxError, Result};
+use crate::device::OperationConfig;
+use super::config_file::ConfigFile;
+use super::interactive::input::eof::EofAction;
 
 #[derive(Parser)]
 #[command(name = "lumidox-ii-controller")]
@@ -19,6 +26,10 @@ pub struct Cli {
     #[arg(short, long)]
     pub port: Option<String>,
 
+    /// Baud rate to connect at (default 19200); with --auto, the only rate probed
+    #[arg(long, value_name = "RATE")]
+    pub baud: Option<Baud>,
+
     /// Automatically detect COM port and baud rate
     #[arg(short, long)]
     pub auto: bool,
@@ -31,39 +42,332 @@ pub struct Cli {
     #[arg(short, long)]
     pub interactive: bool,
 
-    /// Disable optimized stage transitions (always use full safety sequence)
+    /// Use optimized stage transitions (the default)
+    #[arg(long)]
+    pub optimize: bool,
+
+    /// Disable optimized stage transitions: every stage change runs the full
+    /// safety re-arm sequence (off, re-arm, fire)
     #[arg(long)]
     pub no_optimize: bool,
+
+    /// Ask for confirmation before firing, arming or changing a setpoint,
+    /// in interactive mode and for single commands
+    #[arg(long)]
+    pub confirm_fires: bool,
+
+    /// Answer yes to every confirmation; required for `run` script batches
+    /// to proceed unattended when confirmations are enabled
+    #[arg(short = 'y', long)]
+    pub yes: bool,
+
+    /// In interactive mode, turn the device off and disconnect after this many
+    /// seconds without input, releasing the port for other users
+    #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub idle_timeout: Option<u64>,
+
+    /// In interactive mode, what to do when standard input ends (e.g. a piped
+    /// script runs out): the output is always turned off first, then `shutdown`
+    /// also returns the device to local mode and `off` leaves it in remote mode
+    #[arg(long, value_enum, value_name = "ACTION", default_value_t = EofAction::Shutdown)]
+    pub on_eof: EofAction,
+
+    /// Read back the device state after firing and fail if the output did not turn on
+    #[arg(long)]
+    pub verify_fire: bool,
+
+    /// Refuse to fire unless the device is already armed in remote mode with
+    /// a valid ARM current, instead of arming it automatically
+    #[arg(long)]
+    pub strict_safety: bool,
+
+    /// Turn the device off and return it to local mode once a session has run this many seconds
+    #[arg(long, value_name = "SECONDS")]
+    pub max_session: Option<u64>,
+
+    /// Model the device is expected to be; connecting to another model fails
+    /// (matched as case-insensitive text within the model number)
+    #[arg(long, value_name = "MODEL")]
+    pub expected_model: Option<String>,
+
+    /// Connect to a device that does not match --expected-model, printing a warning instead of failing
+    #[arg(long, requires = "expected_model")]
+    pub warn_model_mismatch: bool,
+
+    /// Output format for info, status, read-state, stage firing and current setting commands
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    /// Read default settings from this file instead of searching for lumidox.toml
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Most verbose diagnostic messages to print; debug traces every protocol round-trip
+    #[arg(long, value_enum, default_value_t = LogLevel::Warn)]
+    pub log_level: LogLevel,
+
+    /// Also append diagnostic messages, with timestamps, to this file
+    #[arg(long, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
 }
 
-#[derive(Subcommand, Clone)]
+/// Level of diagnostic messages to print
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// No diagnostic messages
+    Off,
+    /// Errors only
+    Error,
+    /// Errors and warnings
+    #[default]
+    Warn,
+    /// Also connection progress such as retries
+    Info,
+    /// Also protocol round-trips and reconnect attempts
+    Debug,
+    /// Everything
+    Trace,
+}
+
+impl From<LogLevel> for log::LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Off => log::LevelFilter::Off,
+            LogLevel::Error => log::LevelFilter::Error,
+            LogLevel::Warn => log::LevelFilter::Warn,
+            LogLevel::Info => log::LevelFilter::Info,
+            LogLevel::Debug => log::LevelFilter::Debug,
+            LogLevel::Trace => log::LevelFilter::Trace,
+        }
+    }
+}
+
+/// Output format for command results
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// Single-line JSON object for scripting
+    Json,
+}
+
+/// Parse a duration such as "5s", "500ms", "1.5s" or "2m"
+///
+/// A number without a unit is taken as seconds.
+///
+/// # Arguments
+/// * `value` - Duration text from the command line
+///
+/// # Returns
+/// * `Result<Duration, String>` - Parsed duration or a message for clap
+pub fn parse_duration(value: &str) -> std::result::Result<Duration, String> {
+    let value = value.trim();
+    let (number, seconds_per_unit) = if let Some(number) = value.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = value.strip_suffix('s') {
+        (number, 1.0)
+    } else if let Some(number) = value.strip_suffix('m') {
+        (number, 60.0)
+    } else {
+        (value, 1.0)
+    };
+    number.trim().parse::<f64>().ok()
+        .and_then(|number| Duration::try_from_secs_f64(number * seconds_per_unit).ok())
+        .ok_or_else(|| format!("invalid duration '{}': expected e.g. 5s, 500ms or 2m", value))
+}
+
+/// What a `fire` command fires
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FireTarget {
+    /// A stage, by number
+    Stage(u8),
+    /// A specific current in mA
+    Current(u16),
+}
+
+/// Check that a `fire` command names exactly one of a stage or a current
+///
+/// # Arguments
+/// * `stage` - Value of `--stage`
+/// * `current` - Value of `--current`
+///
+/// # Returns
+/// * `Result<FireTarget>` - What to fire, or `InvalidInput` if neither or both are given
+///
+/// # Example
+/// ```
+/// assert_eq!(fire_target(Some(3), None)?, FireTarget::Stage(3));
+/// ```
+pub fn fire_target(stage: Option<u8>, current: Option<u16>) -> Result<FireTarget> {
+    match (stage, current) {
+        (Some(stage), None) => Ok(FireTarget::Stage(stage)),
+        (None, Some(current)) => Ok(FireTarget::Current(current)),
+        _ => Err(LumidoxError::InvalidInput(
+            "fire needs exactly one of --stage or --current".to_string()
+        )),
+    }
+}
+
+#[derive(Subcommand, Clone, Debug)]
 pub enum Commands {
-    /// Fire stage 1
-    Stage1,
-    /// Fire stage 2
-    Stage2,
-    /// Fire stage 3
-    Stage3,
-    /// Fire stage 4
-    Stage4,
-    /// Fire stage 5
-    Stage5,
+    /// Fire a stage or a specific current
+    ///
+    /// Give exactly one of `--stage` or `--current`.
+    Fire {
+        /// Stage number to fire
+        #[arg(long, value_name = "STAGE", conflicts_with = "current")]
+        stage: Option<u8>,
+        /// Current to fire with, in mA
+        #[arg(long, value_name = "MILLIAMPS")]
+        current: Option<u16>,
+        /// Turn the output off again after this long, e.g. 5s or 500ms (stage firing only)
+        #[arg(long, value_parser = parse_duration, conflicts_with = "current")]
+        duration: Option<Duration>,
+    },
+    /// Fire stage 1 (same as `fire --stage 1`)
+    #[command(hide = true)]
+    Stage1 {
+        /// Turn the output off again after this long, e.g. 5s or 500ms
+        #[arg(long, value_parser = parse_duration)]
+        duration: Option<Duration>,
+    },
+    /// Fire stage 2 (same as `fire --stage 2`)
+    #[command(hide = true)]
+    Stage2 {
+        /// Turn the output off again after this long, e.g. 5s or 500ms
+        #[arg(long, value_parser = parse_duration)]
+        duration: Option<Duration>,
+    },
+    /// Fire stage 3 (same as `fire --stage 3`)
+    #[command(hide = true)]
+    Stage3 {
+        /// Turn the output off again after this long, e.g. 5s or 500ms
+        #[arg(long, value_parser = parse_duration)]
+        duration: Option<Duration>,
+    },
+    /// Fire stage 4 (same as `fire --stage 4`)
+    #[command(hide = true)]
+    Stage4 {
+        /// Turn the output off again after this long, e.g. 5s or 500ms
+        #[arg(long, value_parser = parse_duration)]
+        duration: Option<Duration>,
+    },
+    /// Fire stage 5 (same as `fire --stage 5`)
+    #[command(hide = true)]
+    Stage5 {
+        /// Turn the output off again after this long, e.g. 5s or 500ms
+        #[arg(long, value_parser = parse_duration)]
+        duration: Option<Duration>,
+    },
     /// Fire with specific current in mA
     Current {
         /// Current value in mA
         #[arg(value_name = "MILLIAMPS")]
         value: u16
     },
+    /// Fire with a current reached in steps
+    ///
+    /// Fires at `--step`, then raises the current by `--step` every
+    /// `--delay` until `--target` is reached. The output is turned off if
+    /// any step fails.
+    Ramp {
+        /// Final current in mA
+        #[arg(long, value_name = "MILLIAMPS")]
+        target: u16,
+        /// Current increase per step in mA
+        #[arg(long, value_name = "MILLIAMPS", default_value_t = 100, value_parser = clap::value_parser!(u16).range(1..))]
+        step: u16,
+        /// Time to hold each level, e.g. 100ms or 1s
+        #[arg(long, value_parser = parse_duration, default_value = "100ms")]
+        delay: Duration,
+    },
     /// Arm the device (prepare for firing)
     Arm,
-    /// Turn off device
+    /// Turn off output while keeping the device under remote control
     Off,
+    /// Emergency stop: turn off output immediately
+    ///
+    /// Skips the settle delay of `off` and retries on communication errors.
+    Stop,
+    /// Shut down and release the device, returning control to the front panel
+    ///
+    /// Use this to end a session. `off` only disables the output and keeps
+    /// the device in remote mode.
+    Shutdown,
+    /// Turn off, restore default ARM/FIRE currents and return to local mode
+    ///
+    /// Also clears the stage cooldown history and re-enables optimized
+    /// transitions, giving the next experiment a clean starting point.
+    ResetDefaults,
     /// Show device information
     Info,
     /// Display current device status (state, currents, operational status)
     Status,
+    /// Print device status and stage parameters as Prometheus metrics
+    ///
+    /// One-shot output in the text exposition format, for use with the
+    /// node-exporter textfile collector.
+    Metrics,
     /// Read and display current remote mode state
     ReadState,
+    /// Issue a named readback and print the parsed value
+    ///
+    /// Names: arm-current, fire-current, mode, temperature, flags. With
+    /// `--verbose` the raw response frame is printed as well, for checking
+    /// the parsing against real hardware.
+    Read {
+        /// Readback to issue
+        #[arg(value_name = "WHAT")]
+        what: String,
+        /// Also print the raw response in hex
+        #[arg(short, long)]
+        verbose: bool,
+    },
+    /// Sample the device mode and currents at a fixed interval
+    ///
+    /// Runs until `--count` samples have been taken, or until interrupted.
+    /// With `--csv` every sample is appended to a CSV file and flushed.
+    Monitor {
+        /// Interval between samples in milliseconds
+        #[arg(long, value_name = "MILLISECONDS", default_value_t = 1000)]
+        interval_ms: u64,
+        /// Number of samples to take (runs until interrupted if omitted)
+        #[arg(long)]
+        count: Option<u32>,
+        /// Append samples to this CSV file
+        #[arg(long, value_name = "PATH")]
+        csv: Option<PathBuf>,
+        /// Append to an existing CSV file instead of failing
+        #[arg(long, requires = "csv", conflicts_with = "overwrite")]
+        append: bool,
+        /// Replace an existing CSV file instead of failing
+        #[arg(long, requires = "csv")]
+        overwrite: bool,
+    },
+    /// Continuously redraw the device state, mode and FIRE current setpoint
+    ///
+    /// Connects once and redraws every `--interval` until Ctrl+C, like the
+    /// Unix `watch` command. If the output is on when interrupted, the
+    /// device is turned off.
+    Watch {
+        /// Time between redraws, e.g. 2s or 500ms
+        #[arg(long, value_parser = parse_duration, default_value = "2s")]
+        interval: Duration,
+    },
+    /// Measure sustained fire/off throughput on one stage
+    ///
+    /// Fires the stage and turns it off again as fast as possible for the
+    /// given time, then reports cycles per second, the error count and
+    /// cycle latency percentiles. The output is off when the run ends.
+    Bench {
+        /// How long to keep cycling, in seconds
+        #[arg(long, value_name = "SECONDS", default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        duration_secs: u64,
+        /// Stage number to fire (1-5)
+        #[arg(long, value_name = "STAGE", value_parser = clap::value_parser!(u8).range(1..=5))]
+        stage: u8,
+    },
     /// Read current ARM current setting
     ReadArmCurrent,
     /// Read current FIRE current setting
@@ -74,6 +378,12 @@ pub enum Commands {
         #[arg(value_name = "MILLIAMPS")]
         value: u16
     },
+    /// Set FIRE current value without firing
+    SetFireCurrent {
+        /// FIRE current value in mA
+        #[arg(value_name = "MILLIAMPS")]
+        value: u16
+    },
     /// Display complete stage parameters (ARM current, FIRE current, voltages, power)
     StageInfo {
         /// Stage number (1-5)
@@ -92,6 +402,27 @@ pub enum Commands {
         #[arg(value_name = "STAGE")]
         stage: u8
     },
+    /// Write the parameters of all five stages as CSV
+    ExportStages {
+        /// CSV file to write (stdout if omitted)
+        #[arg(long, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+    /// Save the device configuration and stage parameters to a JSON file
+    SaveConfig {
+        /// JSON file to write
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+    },
+    /// Restore the ARM/FIRE currents and mode from a save-config file
+    ///
+    /// Stage parameters in the file are read-only calibration values and are
+    /// not restored. A configuration saved while firing is restored armed.
+    LoadConfig {
+        /// JSON file written by save-config
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+    },
     /// List available COM ports
     ListPorts,
     /// Detect compatible Lumidox II ports automatically
@@ -104,6 +435,89 @@ pub enum Commands {
     },
     /// Show detailed port diagnostics and compatibility information
     PortDiagnostics,
+    /// Print the protocol commands the library implements
+    ///
+    /// Lists each opcode with its description and response shape, for
+    /// checking against the command table in the device manual.
+    #[command(hide = true)]
+    CommandRef,
+    /// Print a shell completion script to stdout
+    ///
+    /// For example `lumidox-ii-controller completions zsh > _lumidox-ii-controller`.
+    /// Runs without connecting to a device.
+    #[command(hide = true)]
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+    /// Connect and run a line-oriented command interpreter
+    ///
+    /// Each line is a command such as `fire 3`, `current 1500`, `arm`, `off`
+    /// or `status`. Type `help` for the full list and `quit` to exit.
+    Repl,
+    /// Connect and run the commands in a script file
+    ///
+    /// Each line is a command as typed in the REPL, such as `arm`,
+    /// `stage 3`, `current 500` or `off`, or `wait 2s` to pause. Lines
+    /// starting with `#` are comments. The whole script is checked before
+    /// anything is sent to the device.
+    ///
+    /// With `--confirm-fires`, each destructive step asks for confirmation;
+    /// pass `--yes` to let the batch proceed unattended.
+    Run {
+        /// Script file to run
+        #[arg(value_name = "SCRIPT")]
+        script: PathBuf,
+    },
+}
+
+impl Commands {
+    /// Describe what a destructive command is about to do
+    ///
+    /// Commands that fire, arm or change device settings are destructive;
+    /// reads, port commands and the safety commands `off` and `stop` are
+    /// not, so turning the output off is never held up by a prompt.
+    ///
+    /// # Returns
+    /// * `Option<String>` - Action for a confirmation prompt, e.g.
+    ///   "Fire stage 3", or None for a non-destructive command
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(Commands::Shutdown.destructive_action().as_deref(), Some("Shut down the device"));
+    /// ```
+    pub fn destructive_action(&self) -> Option<String> {
+        let action = match self {
+            Commands::Fire { stage: Some(stage), .. } => format!("Fire stage {}", stage),
+            Commands::Fire { current: Some(current), .. } => format!("Fire with {}mA", current),
+            Commands::Fire { .. } => "Fire".to_string(),
+            Commands::Stage1 { .. } => "Fire stage 1".to_string(),
+            Commands::Stage2 { .. } => "Fire stage 2".to_string(),
+            Commands::Stage3 { .. } => "Fire stage 3".to_string(),
+            Commands::Stage4 { .. } => "Fire stage 4".to_string(),
+            Commands::Stage5 { .. } => "Fire stage 5".to_string(),
+            Commands::Current { value } => format!("Fire with {}mA", value),
+            Commands::Ramp { target, .. } => format!("Ramp the output to {}mA", target),
+            Commands::Bench { stage, .. } => format!("Cycle stage {} on and off", stage),
+            Commands::Arm => "Arm the device".to_string(),
+            Commands::Shutdown => "Shut down the device".to_string(),
+            Commands::ResetDefaults => "Reset the device to defaults".to_string(),
+            Commands::SetArmCurrent { value } => format!("Set ARM current to {}mA", value),
+            Commands::SetFireCurrent { value } => format!("Set FIRE current to {}mA", value),
+            Commands::LoadConfig { file } => format!("Restore the configuration in {}", file.display()),
+            _ => return None,
+        };
+        Some(action)
+    }
+
+    /// Check whether the command fires, arms or changes device settings
+    ///
+    /// # Returns
+    /// * `bool` - True if the command needs confirmation when confirmations are enabled
+    pub fn is_destructive(&self) -> bool {
+        self.destructive_action().is_some()
+    }
 }
 
 impl Cli {
@@ -133,13 +547,74 @@ impl Cli {
             eprintln!("  <command> [options]      (for direct CLI command execution)");
             process::exit(1);
         }
+
+        if let Some(Commands::Fire { stage, current, .. }) = &self.command {
+            if let Err(e) = fire_target(*stage, *current) {
+                eprintln!("Error: {}", e);
+                eprintln!("Use either:");
+                eprintln!("  fire --stage <STAGE> [--duration <TIME>]");
+                eprintln!("  fire --current <MILLIAMPS>");
+                process::exit(1);
+            }
+        }
+
+        if self.optimize && self.no_optimize {
+            eprintln!("Error: --optimize and --no-optimize cannot be used together.");
+            eprintln!("Use --no-optimize to always run the full safety re-arm sequence,");
+            eprintln!("or --optimize (the default) to allow optimized stage transitions.");
+            process::exit(1);
+        }
+    }
+
+    /// Fill in settings from the configuration file
+    ///
+    /// Loads the file given with `--config`, or the first `lumidox.toml`
+    /// found in the search locations, and uses its port, auto, verbose and
+    /// optimize_transitions values where the command line did not set them.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Success (also when no file is found), `SystemError`
+    ///   for an unreadable file or `ConfigError` for an invalid one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut cli = Cli::parse();
+    /// cli.apply_config_file()?;
+    /// ```
+    pub fn apply_config_file(&mut self) -> Result<()> {
+        if let Some((path, config)) = ConfigFile::load(self.config.as_deref())? {
+            config.apply_to(self);
+            if self.verbose {
+                println!("Using settings from {}", path.display());
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the level of diagnostic messages to print
+    ///
+    /// `--verbose` raises the level to at least info, so connection retries
+    /// are shown as they were before logging was added.
+    ///
+    /// # Returns
+    ///
+    /// * `LogLevel` - The `--log-level` value, raised to info by `--verbose`
+    pub fn log_level(&self) -> LogLevel {
+        if self.verbose {
+            self.log_level.max(LogLevel::Info)
+        } else {
+            self.log_level
+        }
     }
 
     /// Get the optimize transitions setting
     ///
     /// Returns true if optimized transitions should be used, false if the full
-    /// safety sequence should always be used. This inverts the `no_optimize` flag
-    /// to provide a positive boolean for easier use in the application logic.
+    /// safety sequence should always be used. `--no-optimize` always selects the
+    /// full safety re-arm sequence; `--optimize` or neither flag selects
+    /// optimized transitions. `validate()` rejects giving both.
     ///
     /// # Returns
     ///
@@ -159,6 +634,43 @@ impl Cli {
         !self.no_optimize
     }
 
+    /// Build the device operation configuration from the CLI arguments
+    ///
+    /// Collects the connection-related flags (port, auto-detection, verbosity,
+    /// and transition optimization) into a single `OperationConfig` that can
+    /// be passed to the device connection helpers.
+    ///
+    /// # Returns
+    ///
+    /// * `OperationConfig` - Connection configuration for this invocation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let cli = Cli::parse();
+    /// let device = LumidoxDevice::connect(&cli.operation_config())?;
+    /// ```
+    pub fn operation_config(&self) -> OperationConfig {
+        OperationConfig::builder()
+            .port_option(self.port.clone())
+            .auto_detect(self.auto)
+            .verbose(self.verbose)
+            .optimize_transitions(self.optimize_transitions())
+            .verify_fire(self.verify_fire)
+            .strict_safety(self.strict_safety)
+            .max_session_duration(self.max_session.map(Duration::from_secs))
+            .expected_model(self.expected_model.as_ref().map(|pattern| {
+                let policy = if self.warn_model_mismatch {
+                    ModelMismatchPolicy::Lenient
+                } else {
+                    ModelMismatchPolicy::Strict
+                };
+                ExpectedModel::new(pattern.clone(), policy)
+            }))
+            .baud(self.baud)
+            .build()
+    }
+
     /// Check if the application should run in CLI interactive mode
     ///
     /// Returns true if interactive mode is explicitly requested or if no specific
@@ -223,3 +735,115 @@ impl Cli {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optimize_flags_select_transition_behavior() {
+        let parse = |args: &[&str]| Cli::try_parse_from(std::iter::once("lumidox").chain(args.iter().copied())).unwrap();
+        assert!(parse(&[]).optimize_transitions());
+        assert!(parse(&["--optimize"]).optimize_transitions());
+        assert!(!parse(&["--no-optimize"]).optimize_transitions());
+        assert!(!parse(&["--no-optimize"]).operation_config().optimize_transitions);
+    }
+
+    #[test]
+    fn test_stage_duration_parsing() {
+        assert_eq!(parse_duration("5s"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("soon").is_err());
+
+        let cli = Cli::try_parse_from(["lumidox", "stage3", "--duration", "5s"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Stage3 { duration: Some(d) }) if d == Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_watch_interval_parses_human_durations() {
+        let interval = |args: &[&str]| match Cli::try_parse_from(args).unwrap().command {
+            Some(Commands::Watch { interval }) => interval,
+            other => panic!("expected watch, got {:?}", other),
+        };
+        assert_eq!(interval(&["lumidox", "watch"]), Duration::from_secs(2));
+        assert_eq!(interval(&["lumidox", "watch", "--interval", "500ms"]), Duration::from_millis(500));
+        assert!(Cli::try_parse_from(["lumidox", "watch", "--interval", "soon"]).is_err());
+    }
+
+    #[test]
+    fn test_idle_timeout_must_be_positive() {
+        let cli = Cli::try_parse_from(["lumidox", "--interactive", "--idle-timeout", "600"]).unwrap();
+        assert_eq!(cli.idle_timeout, Some(600));
+        assert!(Cli::try_parse_from(["lumidox", "--interactive", "--idle-timeout", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_on_eof_defaults_to_shutdown() {
+        assert_eq!(Cli::try_parse_from(["lumidox", "--interactive"]).unwrap().on_eof, EofAction::Shutdown);
+        let cli = Cli::try_parse_from(["lumidox", "--interactive", "--on-eof", "off"]).unwrap();
+        assert_eq!(cli.on_eof, EofAction::Off);
+        assert!(Cli::try_parse_from(["lumidox", "--interactive", "--on-eof", "ignore"]).is_err());
+    }
+
+    #[test]
+    fn test_yes_flag_and_destructive_commands() {
+        let cli = Cli::try_parse_from(["lumidox", "-y", "--confirm-fires", "--port", "COM3", "shutdown"]).unwrap();
+        assert!(cli.yes && cli.confirm_fires);
+        assert_eq!(cli.command.unwrap().destructive_action().as_deref(), Some("Shut down the device"));
+
+        let fire = Cli::try_parse_from(["lumidox", "fire", "--stage", "3"]).unwrap().command.unwrap();
+        assert_eq!(fire.destructive_action().as_deref(), Some("Fire stage 3"));
+        assert!(!Commands::Stop.is_destructive());
+        assert!(!Commands::ReadState.is_destructive());
+    }
+
+    #[test]
+    fn test_baud_rejects_unsupported_rates() {
+        let cli = Cli::try_parse_from(["lumidox", "--port", "COM3", "--baud", "9600", "info"]).unwrap();
+        assert_eq!(cli.operation_config().baud.map(Baud::as_u32), Some(9600));
+
+        let error = Cli::try_parse_from(["lumidox", "--port", "COM3", "--baud", "12345", "info"]).err().unwrap();
+        assert!(error.to_string().contains("supported rates: 1200, 2400"));
+    }
+
+    #[test]
+    fn test_bench_requires_valid_stage() {
+        let cli = Cli::try_parse_from(["lumidox", "bench", "--stage", "3", "--duration-secs", "30"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Bench { duration_secs: 30, stage: 3 })));
+        assert!(Cli::try_parse_from(["lumidox", "bench", "--stage", "6"]).is_err());
+        assert!(Cli::try_parse_from(["lumidox", "bench", "--stage", "1", "--duration-secs", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_ramp_defaults() {
+        let cli = Cli::try_parse_from(["lumidox", "ramp", "--target", "2000", "--step", "200", "--delay", "250ms"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Ramp { target: 2000, step: 200, delay }) if delay == Duration::from_millis(250)));
+        let cli = Cli::try_parse_from(["lumidox", "ramp", "--target", "500"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Ramp { step: 100, delay, .. }) if delay == Duration::from_millis(100)));
+        assert!(Cli::try_parse_from(["lumidox", "ramp", "--target", "500", "--step", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_verbose_raises_log_level() {
+        let cli = Cli::try_parse_from(["lumidox", "--verbose", "status"]).unwrap();
+        assert_eq!(cli.log_level(), LogLevel::Info);
+        let cli = Cli::try_parse_from(["lumidox", "--verbose", "--log-level", "debug", "status"]).unwrap();
+        assert_eq!(cli.log_level(), LogLevel::Debug);
+        let cli = Cli::try_parse_from(["lumidox", "status"]).unwrap();
+        assert_eq!(log::LevelFilter::from(cli.log_level()), log::LevelFilter::Warn);
+    }
+
+    #[test]
+    fn test_fire_takes_exactly_one_target() {
+        let cli = Cli::try_parse_from(["lumidox", "fire", "--stage", "7", "--duration", "2s"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Fire { stage: Some(7), current: None, duration: Some(_) })));
+        assert!(Cli::try_parse_from(["lumidox", "fire", "--stage", "2", "--current", "500"]).is_err());
+        assert!(Cli::try_parse_from(["lumidox", "fire", "--current", "500", "--duration", "2s"]).is_err());
+
+        assert_eq!(fire_target(None, Some(500)).unwrap(), FireTarget::Current(500));
+        assert!(matches!(fire_target(None, None), Err(LumidoxError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,207 @@
+//! Firing throughput benchmark for Lumidox II Controller CLI
+//!
+//! This module implements the `bench` command, which fires a stage and turns
+//! it off again as fast as the link allows for a fixed time, then reports the
+//! sustained cycle rate, the number of failed cycles and latency percentiles
+//! of a complete fire/off cycle. Comparing the numbers between firmware
+//! versions or cables shows where the time goes.
+//!
+//! The output is turned off when the run ends, also when it ends with an
+//! error. A run that keeps failing stops early rather than hammering a
+//! device that no longer answers.
+
+use std::time::{Duration, Instant};
+use crate::core::Result;
+use crate::device::LumidoxDevice;
+use crate::device::controller::OutputOffGuard;
+
+/// Consecutive failed cycles after which the run stops early
+pub const MAX_CONSECUTIVE_ERRORS: u32 = 10;
+
+/// Result of a benchmark run
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    /// Number of fire/off cycles that completed without error
+    pub cycles: u32,
+    /// Number of cycles that failed
+    pub errors: u32,
+    /// Time the run took
+    pub elapsed: Duration,
+    /// Whether the run stopped early after `MAX_CONSECUTIVE_ERRORS` failures
+    pub stopped_early: bool,
+    /// Duration of each completed cycle, sorted from fastest to slowest
+    latencies: Vec<Duration>,
+}
+
+impl BenchReport {
+    /// Create a report from the measured cycles
+    ///
+    /// # Arguments
+    /// * `latencies` - Duration of each completed cycle, in any order
+    /// * `errors` - Number of failed cycles
+    /// * `elapsed` - Time the run took
+    /// * `stopped_early` - Whether the run gave up after repeated failures
+    ///
+    /// # Returns
+    /// * `BenchReport` - Report with the latencies sorted
+    pub fn new(mut latencies: Vec<Duration>, errors: u32, elapsed: Duration, stopped_early: bool) -> Self {
+        latencies.sort_unstable();
+        Self {
+            cycles: latencies.len() as u32,
+            errors,
+            elapsed,
+            stopped_early,
+            latencies,
+        }
+    }
+
+    /// Completed cycles per second over the whole run
+    ///
+    /// # Returns
+    /// * `f64` - Sustained cycle rate, 0 for an empty run
+    pub fn cycles_per_sec(&self) -> f64 {
+        if self.elapsed.is_zero() {
+            return 0.0;
+        }
+        f64::from(self.cycles) / self.elapsed.as_secs_f64()
+    }
+
+    /// Cycle latency at a percentile (nearest rank)
+    ///
+    /// # Arguments
+    /// * `percentile` - Percentile from 0 to 100
+    ///
+    /// # Returns
+    /// * `Option<Duration>` - Latency at the percentile, None if no cycle completed
+    ///
+    /// # Example
+    /// ```
+    /// let p99 = report.percentile(99.0);
+    /// ```
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * self.latencies.len() as f64).ceil() as usize;
+        self.latencies.get(rank.saturating_sub(1)).copied()
+    }
+
+    /// Format the report for the terminal
+    ///
+    /// # Returns
+    /// * `String` - Multi-line summary of throughput, errors and latency
+    pub fn summary(&self) -> String {
+        let millis = |latency: Option<Duration>| {
+            latency.map_or_else(|| "-".to_string(), |latency| format!("{:.1}ms", latency.as_secs_f64() * 1000.0))
+        };
+        let mut summary = format!(
+            "Cycles: {} in {:.1}s ({:.2} cycles/s)\nErrors: {}\nCycle latency: p50 {} | p90 {} | p99 {} | max {}",
+            self.cycles,
+            self.elapsed.as_secs_f64(),
+            self.cycles_per_sec(),
+            self.errors,
+            millis(self.percentile(50.0)),
+            millis(self.percentile(90.0)),
+            millis(self.percentile(99.0)),
+            millis(self.latencies.last().copied()),
+        );
+        if self.stopped_early {
+            summary.push_str(&format!("\nStopped early after {} consecutive errors", MAX_CONSECUTIVE_ERRORS));
+        }
+        summary
+    }
+}
+
+/// Fire and turn off a stage repeatedly for a fixed time
+///
+/// A cycle is one `fire_stage` followed by one `turn_off`; its latency covers
+/// both. A failed cycle is counted and the run continues, unless
+/// `MAX_CONSECUTIVE_ERRORS` cycles fail in a row.
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `stage` - Stage number to fire (1-5)
+/// * `duration` - How long to keep cycling
+///
+/// # Returns
+/// * `Result<BenchReport>` - Measurements, or the error turning the output
+///   off at the end
+///
+/// # Example
+/// ```
+/// let report = run_bench(&mut device, 3, Duration::from_secs(10))?;
+/// println!("{}", report.summary());
+/// ```
+pub fn run_bench(device: &mut LumidoxDevice, stage: u8, duration: Duration) -> Result<BenchReport> {
+    let mut guard = OutputOffGuard::new(device);
+    let mut latencies = Vec::new();
+    let mut errors = 0;
+    let mut consecutive_errors = 0;
+    let mut stopped_early = false;
+
+    let start = Instant::now();
+    while start.elapsed() < duration {
+        let cycle_start = Instant::now();
+        let result = guard.device().fire_stage(stage).and_then(|()| guard.device().turn_off());
+        match result {
+            Ok(()) => {
+                latencies.push(cycle_start.elapsed());
+                consecutive_errors = 0;
+            }
+            Err(e) => {
+                log::debug!("Bench cycle failed: {}", e);
+                errors += 1;
+                consecutive_errors += 1;
+                if consecutive_errors >= MAX_CONSECUTIVE_ERRORS {
+                    stopped_early = true;
+                    break;
+                }
+            }
+        }
+    }
+    let elapsed = start.elapsed();
+
+    guard.release()?;
+    Ok(BenchReport::new(latencies, errors, elapsed, stopped_early))
+}
+
+/// Run the bench command
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `stage` - Stage number to fire (1-5)
+/// * `duration_secs` - How long to keep cycling, in seconds
+///
+/// # Returns
+/// * `Result<()>` - Success or the error turning the output off
+pub fn run_bench_command(device: &mut LumidoxDevice, stage: u8, duration_secs: u64) -> Result<()> {
+    println!("Cycling stage {} on and off for {}s...", stage, duration_secs);
+    let report = run_bench(device, stage, Duration::from_secs(duration_secs))?;
+    println!("{}", report.summary());
+    println!("Output turned off.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_percentiles_and_rate() {
+        let latencies = (1..=100).rev().map(Duration::from_millis).collect();
+        let report = BenchReport::new(latencies, 2, Duration::from_secs(4), false);
+
+        assert_eq!(report.cycles, 100);
+        assert_eq!(report.cycles_per_sec(), 25.0);
+        assert_eq!(report.percentile(50.0), Some(Duration::from_millis(50)));
+        assert_eq!(report.percentile(99.0), Some(Duration::from_millis(99)));
+        assert_eq!(report.percentile(100.0), Some(Duration::from_millis(100)));
+        assert!(report.summary().contains("Errors: 2"));
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let report = BenchReport::new(Vec::new(), MAX_CONSECUTIVE_ERRORS, Duration::ZERO, true);
+
+        assert_eq!(report.percentile(50.0), None);
+        assert_eq!(report.cycles_per_sec(), 0.0);
+        assert!(report.summary().contains("Stopped early"));
+    }
+}
@@ -0,0 +1,44 @@
+//! Protocol command reference for Lumidox II Controller CLI
+//!
+//! This module implements the hidden `command-ref` command, which prints
+//! every protocol command the library implements as a table of opcode,
+//! description and response shape. It runs without a device.
+
+use crate::communication::{command_reference, CommandDoc};
+
+/// Print the protocol command reference
+///
+/// # Example
+/// ```
+/// print_command_reference();
+/// ```
+pub fn print_command_reference() {
+    println!("{:<6} {:<40} Response", "Opcode", "Description");
+    for doc in command_reference() {
+        println!("{}", format_command_doc(&doc));
+    }
+}
+
+/// Format one command as a table row
+fn format_command_doc(doc: &CommandDoc) -> String {
+    format!("0x{:02x}   {:<40} {}", doc.opcode, doc.description, doc.response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::communication::ResponseShape;
+
+    #[test]
+    fn test_format_command_doc() {
+        let doc = CommandDoc {
+            opcode: 0x7a,
+            description: "Read stage 1 voltage start".to_string(),
+            response: ResponseShape::Value { unit: "V", divisor: 100 },
+        };
+        assert_eq!(
+            format_command_doc(&doc),
+            "0x7a   Read stage 1 voltage start               16-bit value in V/100"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! ARM/FIRE current setting commands for Lumidox II Controller CLI
+//!
+//! This module executes the commands that read and set the device ARM and
+//! FIRE currents, printing results as text or as a single-line JSON object
+//! so the currents can be configured from scripts. JSON results also carry
+//! the current as a percentage of the device maximum, so results from
+//! devices with different maxima can be compared.
+
+use crate::core::{LumidoxError, Result};
+use crate::device::LumidoxDevice;
+use super::super::args::{Commands, OutputFormat};
+
+/// Check whether a command reads or sets the ARM/FIRE current
+///
+/// # Arguments
+/// * `command` - The CLI command
+///
+/// # Returns
+/// * `bool` - True if the command is handled by `run_current_command`
+pub fn is_current_command(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::ReadArmCurrent
+            | Commands::ReadFireCurrent
+            | Commands::SetArmCurrent { .. }
+            | Commands::SetFireCurrent { .. }
+    )
+}
+
+/// Execute an ARM/FIRE current command
+///
+/// Set commands validate the value against the device maximum current
+/// before sending. Errors are returned rather than printed so scripts see a
+/// non-zero exit status.
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `command` - Current setting command to execute
+/// * `format` - Output format for the result
+///
+/// # Returns
+/// * `Result<()>` - Success or device/validation error
+///
+/// # Example
+/// ```
+/// run_current_command(&mut device, &Commands::SetArmCurrent { value: 500 }, OutputFormat::Json)?;
+/// ```
+pub fn run_current_command(device: &mut LumidoxDevice, command: &Commands, format: OutputFormat) -> Result<()> {
+    let max_current_ma = device.info().map(|info| info.max_current_ma);
+    let report = |command: &str, field: &str, current_ma: u16, text: &str| {
+        print_result(format, command, field, current_ma, max_current_ma, text)
+    };
+
+    match *command {
+        Commands::ReadArmCurrent => {
+            let current = device.read_arm_current()?;
+            report("read_arm_current", "arm_current_ma", current, &format!("ARM Current: {}mA", current))?;
+        }
+        Commands::ReadFireCurrent => {
+            let current = device.read_fire_current()?;
+            report("read_fire_current", "fire_current_ma", current, &format!("FIRE Current: {}mA", current))?;
+        }
+        Commands::SetArmCurrent { value } => {
+            device.set_arm_current(value)?;
+            report("set_arm_current", "arm_current_ma", value, &format!("ARM current set to {}mA.", value))?;
+        }
+        Commands::SetFireCurrent { value } => {
+            device.set_fire_current(value)?;
+            report("set_fire_current", "fire_current_ma", value, &format!("FIRE current set to {}mA.", value))?;
+        }
+        _ => {
+            return Err(LumidoxError::InvalidInput("Not a current setting command".to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Print a current command result in the requested format
+fn print_result(format: OutputFormat, command: &str, field: &str, current_ma: u16, max_current_ma: Option<u16>, text: &str) -> Result<()> {
+    match format {
+        OutputFormat::Text => println!("{}", text),
+        OutputFormat::Json => println!("{}", format_json(command, field, current_ma, max_current_ma)?),
+    }
+    Ok(())
+}
+
+/// Express a current as a percentage of the device maximum
+///
+/// # Arguments
+/// * `current_ma` - Current in milliamps
+/// * `max_current_ma` - Device maximum current, if known
+///
+/// # Returns
+/// * `Option<f32>` - Percentage of the maximum, `None` if the maximum is unknown or zero
+pub fn percent_of_max(current_ma: u16, max_current_ma: Option<u16>) -> Option<f32> {
+    max_current_ma
+        .filter(|&max| max > 0)
+        .map(|max| f32::from(current_ma) * 100.0 / f32::from(max))
+}
+
+/// Format a current command result as a single-line JSON object
+///
+/// The percent field is named after the mA field (`arm_current_ma` becomes
+/// `arm_current_percent`), rounded to one decimal place, and is `null` when
+/// the device maximum is unknown.
+#[cfg(feature = "serde")]
+fn format_json(command: &str, field: &str, current_ma: u16, max_current_ma: Option<u16>) -> Result<String> {
+    let percent_field = format!("{}_percent", field.trim_end_matches("_ma"));
+    let percent = percent_of_max(current_ma, max_current_ma).map(|percent| (f64::from(percent) * 10.0).round() / 10.0);
+    let mut object = serde_json::Map::new();
+    object.insert("command".to_string(), command.into());
+    object.insert(field.to_string(), current_ma.into());
+    object.insert(percent_field, percent.into());
+    serde_json::to_string(&object).map_err(|e| LumidoxError::system("Cannot serialize current result", e))
+}
+
+/// Format a current command result as JSON (unavailable without `serde`)
+#[cfg(not(feature = "serde"))]
+fn format_json(_command: &str, _field: &str, _current_ma: u16, _max_current_ma: Option<u16>) -> Result<String> {
+    Err(LumidoxError::ConfigError("JSON output needs a build with the serde feature".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_format_json() {
+        let json = format_json("set_arm_current", "arm_current_ma", 500, Some(2000)).unwrap();
+        assert!(!json.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::json!({"command": "set_arm_current", "arm_current_ma": 500, "arm_current_percent": 25.0})
+        );
+        let json = format_json("set_fire_current", "fire_current_ma", 1000, Some(3000)).unwrap();
+        assert!(json.contains(r#""fire_current_percent":33.3"#));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_format_json_unknown_max() {
+        let json = format_json("read_fire_current", "fire_current_ma", 1500, None).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::json!({"command": "read_fire_current", "fire_current_ma": 1500, "fire_current_percent": null})
+        );
+        assert_eq!(percent_of_max(1500, Some(0)), None);
+    }
+
+    #[test]
+    fn test_is_current_command() {
+        assert!(is_current_command(&Commands::SetFireCurrent { value: 100 }));
+        assert!(is_current_command(&Commands::ReadArmCurrent));
+        assert!(!is_current_command(&Commands::Status));
+    }
+}
@@ -25,7 +25,7 @@ pub enum DeviceControlCategory {
     StageFiring,
     /// Custom current control operations
     CurrentControl,
-    /// Power state control operations (Arm, Off)
+    /// Power state control operations (Arm, Off, Shutdown)
     PowerControl,
 }
 
@@ -43,7 +43,7 @@ pub enum InformationCategory {
 /// Sub-categories for parameter management operations
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParameterCategory {
-    /// Current setting operations (ReadArmCurrent, ReadFireCurrent, SetArmCurrent)
+    /// Current setting operations (ReadArmCurrent, ReadFireCurrent, SetArmCurrent, SetFireCurrent)
     CurrentSettings,
     /// Stage parameter operations (StageInfo, StageArm, StageVoltages)
     StageParameters,
@@ -103,15 +103,19 @@ impl CommandCategory {
     /// Determine the category of a command
     pub fn from_command(command: &Commands) -> Self {
         match command {
-            Commands::Stage1 | Commands::Stage2 | Commands::Stage3 | 
-            Commands::Stage4 | Commands::Stage5 | Commands::Current { .. } |
-            Commands::Arm | Commands::Off => Self::DeviceControl,
+            Commands::Fire { .. } | Commands::Stage1 { .. } | Commands::Stage2 { .. } | Commands::Stage3 { .. } | 
+            Commands::Stage4 { .. } | Commands::Stage5 { .. } | Commands::Current { .. } | Commands::Ramp { .. } |
+            Commands::Arm | Commands::Off | Commands::Stop | Commands::Shutdown | Commands::ResetDefaults |
+            Commands::Repl | Commands::Run { .. } | Commands::Bench { .. } => Self::DeviceControl,
             
-            Commands::Info | Commands::Status | Commands::ReadState => Self::Information,
+            Commands::Info | Commands::Status | Commands::ReadState | Commands::Metrics |
+            Commands::Monitor { .. } | Commands::Watch { .. } | Commands::Read { .. } | Commands::CommandRef | Commands::Completions { .. } => Self::Information,
             
             Commands::ReadArmCurrent | Commands::ReadFireCurrent | 
-            Commands::SetArmCurrent { .. } | Commands::StageInfo { .. } |
-            Commands::StageArm { .. } | Commands::StageVoltages { .. } => Self::Parameters,
+            Commands::SetArmCurrent { .. } | Commands::SetFireCurrent { .. } |
+            Commands::StageInfo { .. } | Commands::ExportStages { .. } |
+            Commands::StageArm { .. } | Commands::StageVoltages { .. } |
+            Commands::SaveConfig { .. } | Commands::LoadConfig { .. } => Self::Parameters,
             
             Commands::ListPorts | Commands::DetectPorts | 
             Commands::TestBaud { .. } | Commands::PortDiagnostics => Self::PortManagement,
@@ -143,12 +147,13 @@ impl DeviceControlCategory {
     /// Determine the device control sub-category of a command
     pub fn from_command(command: &Commands) -> Option<Self> {
         match command {
-            Commands::Stage1 | Commands::Stage2 | Commands::Stage3 | 
-            Commands::Stage4 | Commands::Stage5 => Some(Self::StageFiring),
+            Commands::Fire { stage: Some(_), .. } |
+            Commands::Stage1 { .. } | Commands::Stage2 { .. } | Commands::Stage3 { .. } | 
+            Commands::Stage4 { .. } | Commands::Stage5 { .. } | Commands::Bench { .. } => Some(Self::StageFiring),
             
-            Commands::Current { .. } => Some(Self::CurrentControl),
+            Commands::Fire { .. } | Commands::Current { .. } | Commands::Ramp { .. } => Some(Self::CurrentControl),
             
-            Commands::Arm | Commands::Off => Some(Self::PowerControl),
+            Commands::Arm | Commands::Off | Commands::Stop | Commands::Shutdown | Commands::ResetDefaults => Some(Self::PowerControl),
             
             _ => None,
         }
@@ -160,8 +165,8 @@ impl InformationCategory {
     pub fn from_command(command: &Commands) -> Option<Self> {
         match command {
             Commands::Info => Some(Self::DeviceInfo),
-            Commands::Status => Some(Self::StatusReading),
-            Commands::ReadState => Some(Self::StateReading),
+            Commands::Status | Commands::Metrics | Commands::Monitor { .. } | Commands::Watch { .. } => Some(Self::StatusReading),
+            Commands::ReadState | Commands::Read { .. } => Some(Self::StateReading),
             _ => None,
         }
     }
@@ -172,10 +177,11 @@ impl ParameterCategory {
     pub fn from_command(command: &Commands) -> Option<Self> {
         match command {
             Commands::ReadArmCurrent | Commands::ReadFireCurrent | 
-            Commands::SetArmCurrent { .. } => Some(Self::CurrentSettings),
+            Commands::SetArmCurrent { .. } | Commands::SetFireCurrent { .. } |
+            Commands::SaveConfig { .. } | Commands::LoadConfig { .. } => Some(Self::CurrentSettings),
             
             Commands::StageInfo { .. } | Commands::StageArm { .. } | 
-            Commands::StageVoltages { .. } => Some(Self::StageParameters),
+            Commands::StageVoltages { .. } | Commands::ExportStages { .. } => Some(Self::StageParameters),
             
             _ => None,
         }
@@ -200,23 +206,25 @@ impl CommandPriority {
     pub fn from_command(command: &Commands) -> Self {
         match command {
             // Critical safety operations
-            Commands::Off => Self::Critical,
+            Commands::Off | Commands::Stop | Commands::Shutdown | Commands::ResetDefaults => Self::Critical,
             
             // High priority device control
-            Commands::Stage1 | Commands::Stage2 | Commands::Stage3 | 
-            Commands::Stage4 | Commands::Stage5 | Commands::Current { .. } |
-            Commands::Arm => Self::High,
+            Commands::Fire { .. } | Commands::Stage1 { .. } | Commands::Stage2 { .. } | Commands::Stage3 { .. } | 
+            Commands::Stage4 { .. } | Commands::Stage5 { .. } | Commands::Current { .. } | Commands::Ramp { .. } |
+            Commands::Arm | Commands::Repl | Commands::Run { .. } | Commands::Bench { .. } => Self::High,
             
             // Normal priority parameter operations
-            Commands::SetArmCurrent { .. } => Self::Normal,
+            Commands::SetArmCurrent { .. } | Commands::LoadConfig { .. } => Self::Normal,
             
             // Low priority information and diagnostics
-            Commands::Info | Commands::Status | Commands::ReadState |
+            Commands::Info | Commands::Status | Commands::ReadState | Commands::Metrics |
+            Commands::Monitor { .. } | Commands::Watch { .. } | Commands::Read { .. } |
             Commands::ReadArmCurrent | Commands::ReadFireCurrent |
             Commands::StageInfo { .. } | Commands::StageArm { .. } |
-            Commands::StageVoltages { .. } | Commands::ListPorts |
+            Commands::StageVoltages { .. } | Commands::ExportStages { .. } | Commands::SaveConfig { .. } |
+            Commands::ListPorts |
             Commands::DetectPorts | Commands::TestBaud { .. } |
-            Commands::PortDiagnostics => Self::Low,
+            Commands::PortDiagnostics | Commands::CommandRef | Commands::Completions { .. } => Self::Low,
         }
     }
 }
@@ -226,21 +234,25 @@ impl CommandSafetyLevel {
     pub fn from_command(command: &Commands) -> Self {
         match command {
             // High risk operations that change device state significantly
-            Commands::Stage1 | Commands::Stage2 | Commands::Stage3 | 
-            Commands::Stage4 | Commands::Stage5 | Commands::Current { .. } => Self::HighRisk,
+            Commands::Fire { .. } | Commands::Stage1 { .. } | Commands::Stage2 { .. } | Commands::Stage3 { .. } | 
+            Commands::Stage4 { .. } | Commands::Stage5 { .. } | Commands::Current { .. } | Commands::Ramp { .. } |
+            Commands::Repl | Commands::Run { .. } | Commands::Bench { .. } => Self::HighRisk,
             
             // Medium risk operations that change device state
-            Commands::Arm | Commands::Off | Commands::SetArmCurrent { .. } => Self::MediumRisk,
+            Commands::Arm | Commands::Off | Commands::Stop | Commands::Shutdown | Commands::ResetDefaults |
+            Commands::SetArmCurrent { .. } | Commands::LoadConfig { .. } => Self::MediumRisk,
             
             // Low risk operations with minimal impact
             Commands::ReadArmCurrent | Commands::ReadFireCurrent => Self::LowRisk,
             
             // Safe operations with no device state changes
-            Commands::Info | Commands::Status | Commands::ReadState |
+            Commands::Info | Commands::Status | Commands::ReadState | Commands::Metrics |
+            Commands::Monitor { .. } | Commands::Watch { .. } | Commands::Read { .. } |
             Commands::StageInfo { .. } | Commands::StageArm { .. } |
-            Commands::StageVoltages { .. } | Commands::ListPorts |
+            Commands::StageVoltages { .. } | Commands::ExportStages { .. } | Commands::SaveConfig { .. } |
+            Commands::ListPorts |
             Commands::DetectPorts | Commands::TestBaud { .. } |
-            Commands::PortDiagnostics => Self::Safe,
+            Commands::PortDiagnostics | Commands::CommandRef | Commands::Completions { .. } => Self::Safe,
         }
     }
 
@@ -258,8 +270,8 @@ impl CommandRequirement {
         // Most commands require device connection except port management
         match command {
             Commands::ListPorts | Commands::DetectPorts | 
-            Commands::TestBaud { .. } | Commands::PortDiagnostics => {
-                // Port management commands don't require device connection
+            Commands::TestBaud { .. } | Commands::PortDiagnostics | Commands::CommandRef => {
+                // Port management commands and the command reference don't require device connection
             }
             _ => {
                 requirements.push(Self::DeviceConnection);
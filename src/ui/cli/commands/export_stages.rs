@@ -0,0 +1,112 @@
+//! Stage parameter CSV export for Lumidox II Controller CLI
+//!
+//! This module implements the `export-stages` command, which reads the
+//! parameters of all five stages and writes them as a CSV table with one row
+//! per stage, ready to paste into a spreadsheet. Numbers are formatted by
+//! Rust's standard formatting, which always uses `.` as the decimal
+//! separator regardless of the system locale.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use crate::core::Result;
+use crate::device::LumidoxDevice;
+use crate::device::operations::power::StageParameters;
+
+/// Header line of the stage parameter CSV
+pub const STAGES_CSV_HEADER: &str = "stage,fire_current_ma,arm_current_ma,total_power,total_units,per_led_power,per_led_units,volt_limit_v,volt_start_v";
+
+/// Run the export-stages command
+///
+/// Reads all stages before writing anything, so a failed read does not
+/// leave a partial file behind.
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `output` - CSV file to write, or None for stdout
+///
+/// # Returns
+/// * `Result<()>` - Success, device error, or I/O error
+///
+/// # Example
+/// ```
+/// run_export_stages_command(&mut device, Some(Path::new("stages.csv")))?;
+/// ```
+pub fn run_export_stages_command(device: &mut LumidoxDevice, output: Option<&Path>) -> Result<()> {
+    let stages = device.get_all_stage_parameters()?;
+
+    match output {
+        Some(path) => {
+            let mut file = File::create(path)?;
+            write_stages_csv(&mut file, &stages)?;
+            file.flush()?;
+            eprintln!("Wrote {} stages to {}", stages.len(), path.display());
+        }
+        None => write_stages_csv(&mut io::stdout().lock(), &stages)?,
+    }
+    Ok(())
+}
+
+/// Write stage parameters as CSV, header first
+///
+/// # Arguments
+/// * `writer` - Destination of the CSV text
+/// * `stages` - Stage parameters, one row each
+///
+/// # Returns
+/// * `Result<()>` - Success or I/O error
+pub fn write_stages_csv(writer: &mut impl Write, stages: &[StageParameters]) -> Result<()> {
+    writeln!(writer, "{}", STAGES_CSV_HEADER)?;
+    for stage in stages {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{}",
+            stage.stage_number,
+            stage.fire_current_ma,
+            stage.arm_current_ma,
+            stage.power_total,
+            csv_field(&stage.total_units),
+            stage.power_per_led,
+            csv_field(&stage.per_led_units),
+            stage.volt_limit_v,
+            stage.volt_start_v
+        )?;
+    }
+    Ok(())
+}
+
+/// Quote a text field if it contains a comma, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stages_csv_uses_dot_decimals_and_quotes_units() {
+        let stage = StageParameters {
+            stage_number: 2,
+            arm_current_ma: 100,
+            fire_current_ma: 1500,
+            volt_limit_v: 12.5,
+            volt_start_v: 3.25,
+            power_total: 1234.5,
+            power_per_led: 0.75,
+            total_units: "mW".to_string(),
+            per_led_units: "mW/well, avg".to_string(),
+        };
+
+        let mut csv = Vec::new();
+        write_stages_csv(&mut csv, &[stage]).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], STAGES_CSV_HEADER);
+        assert_eq!(lines[1], "2,1500,100,1234.5,mW,0.75,\"mW/well, avg\",12.5,3.25");
+    }
+}
@@ -0,0 +1,273 @@
+//! JSON output mode for Lumidox II Controller CLI commands
+//!
+//! With `--format json` the info, status, read-state and stage firing
+//! commands print a single-line JSON object on stdout instead of prose, and
+//! a command that fails prints a JSON error object. Every object carries a
+//! `success` flag and the `operation` name (the command name as typed on the
+//! command line); errors add a stable `error_kind` taken from
+//! `LumidoxError::kind` and the error message.
+
+use std::fmt::Display;
+use std::time::Duration;
+use crate::core::{LumidoxError, Result};
+use crate::device::LumidoxDevice;
+use super::super::args::{Commands, OutputFormat};
+
+/// Single-line JSON object built field by field
+#[derive(Debug, Clone, Default)]
+pub struct JsonObject {
+    /// Rendered `"key":value` pairs in insertion order
+    fields: Vec<String>,
+}
+
+impl JsonObject {
+    /// Start the result object of an operation
+    ///
+    /// # Arguments
+    /// * `success` - Whether the operation succeeded
+    /// * `operation` - Operation name, e.g. "read-state"
+    ///
+    /// # Returns
+    /// * `JsonObject` - Object with the `success` and `operation` fields
+    pub fn result(success: bool, operation: &str) -> Self {
+        Self::default().value("success", success).string("operation", operation)
+    }
+
+    /// Add a string field
+    pub fn string(mut self, key: &str, value: &str) -> Self {
+        self.fields.push(format!("{}:{}", quote(key), quote(value)));
+        self
+    }
+
+    /// Add a number or boolean field
+    pub fn value(mut self, key: &str, value: impl Display) -> Self {
+        self.fields.push(format!("{}:{}", quote(key), value));
+        self
+    }
+
+    /// Add a floating-point field, `null` when not finite
+    pub fn float(self, key: &str, value: f32) -> Self {
+        if value.is_finite() {
+            self.value(key, value)
+        } else {
+            self.value(key, "null")
+        }
+    }
+
+    /// Add an optional number field, `null` when missing
+    pub fn optional_value(self, key: &str, value: Option<impl Display>) -> Self {
+        match value {
+            Some(value) => self.value(key, value),
+            None => self.value(key, "null"),
+        }
+    }
+
+    /// Render the object
+    ///
+    /// # Returns
+    /// * `String` - JSON object without a line terminator
+    pub fn render(&self) -> String {
+        format!("{{{}}}", self.fields.join(","))
+    }
+}
+
+/// Check whether a command has a JSON form
+///
+/// # Arguments
+/// * `command` - The CLI command
+///
+/// # Returns
+/// * `bool` - True for info, status, read-state and stage firing
+pub fn has_json_output(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Info
+            | Commands::Status
+            | Commands::ReadState
+            | Commands::Fire { stage: Some(_), current: None, .. }
+            | Commands::Stage1 { .. }
+            | Commands::Stage2 { .. }
+            | Commands::Stage3 { .. }
+            | Commands::Stage4 { .. }
+            | Commands::Stage5 { .. }
+    )
+}
+
+/// Execute a command and print its result as JSON
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `command` - Command with a JSON form, see `has_json_output`
+///
+/// # Returns
+/// * `Result<()>` - Success, device error, or `InvalidInput` for a command without a JSON form
+///
+/// # Example
+/// ```
+/// run_json_command(&mut device, &Commands::ReadState)?;
+/// ```
+pub fn run_json_command(device: &mut LumidoxDevice, command: &Commands) -> Result<()> {
+    let result = JsonObject::result(true, &operation_name(command));
+    let object = match *command {
+        Commands::Info => {
+            let info = device.info().cloned()
+                .ok_or_else(|| LumidoxError::DeviceError("Device information not available".to_string()))?;
+            result
+                .string("firmware_version", &info.firmware_version)
+                .string("model_number", &info.model_number)
+                .string("serial_number", &info.serial_number)
+                .string("wavelength", &info.wavelength)
+                .value("max_current_ma", info.max_current_ma)
+        }
+        Commands::Status => {
+            let mode = device.read_remote_mode()?;
+            result
+                .string("mode", &format!("{:?}", mode))
+                .value("arm_current_ma", device.read_arm_current()?)
+                .value("fire_current_ma", device.read_fire_current()?)
+        }
+        Commands::ReadState => {
+            let mode = device.read_remote_mode()?;
+            result.string("mode", &format!("{:?}", mode)).value("state", mode as u8)
+        }
+        Commands::Fire { stage: Some(stage), current: None, duration } => fire_stage(device, result, stage, duration)?,
+        Commands::Stage1 { duration } => fire_stage(device, result, 1, duration)?,
+        Commands::Stage2 { duration } => fire_stage(device, result, 2, duration)?,
+        Commands::Stage3 { duration } => fire_stage(device, result, 3, duration)?,
+        Commands::Stage4 { duration } => fire_stage(device, result, 4, duration)?,
+        Commands::Stage5 { duration } => fire_stage(device, result, 5, duration)?,
+        _ => {
+            return Err(LumidoxError::InvalidInput(format!(
+                "The {} command has no JSON output", operation_name(command)
+            )));
+        }
+    };
+
+    println!("{}", object.render());
+    Ok(())
+}
+
+/// Print a failed command as a JSON error object when JSON output is selected
+///
+/// The error is passed through unchanged so the exit status still reports
+/// the failure.
+///
+/// # Arguments
+/// * `format` - Selected output format
+/// * `command` - Command that was run
+/// * `result` - Result of connecting and running the command
+///
+/// # Returns
+/// * `Result<()>` - `result`, unchanged
+///
+/// # Example
+/// ```
+/// report_error(format, &command, execute_device_command(&mut device, &command, format))?;
+/// ```
+pub fn report_error(format: OutputFormat, command: &Commands, result: Result<()>) -> Result<()> {
+    if let (OutputFormat::Json, Err(error)) = (format, &result) {
+        println!("{}", error_json(&operation_name(command), error));
+    }
+    result
+}
+
+/// Format an error as a JSON error object
+///
+/// # Arguments
+/// * `operation` - Operation name
+/// * `error` - Error returned by the operation
+///
+/// # Returns
+/// * `String` - Object with `success`, `operation`, `error_kind` and `error`
+pub fn error_json(operation: &str, error: &LumidoxError) -> String {
+    JsonObject::result(false, operation)
+        .string("error_kind", error.kind())
+        .string("error", &error.to_string())
+        .render()
+}
+
+/// Get the command-line name of a command, e.g. "read-state"
+///
+/// # Arguments
+/// * `command` - The CLI command
+///
+/// # Returns
+/// * `String` - Subcommand name in kebab-case
+pub fn operation_name(command: &Commands) -> String {
+    let variant = format!("{:?}", command);
+    let mut name = String::new();
+    for character in variant.chars().take_while(|character| character.is_ascii_alphanumeric()) {
+        if character.is_ascii_uppercase() && !name.is_empty() {
+            name.push('-');
+        }
+        name.push(character.to_ascii_lowercase());
+    }
+    name
+}
+
+/// Fire a stage, optionally for a fixed duration, and describe the result
+fn fire_stage(device: &mut LumidoxDevice, result: JsonObject, stage: u8, duration: Option<Duration>) -> Result<JsonObject> {
+    let (fire_current_ma, on_time) = match duration {
+        Some(duration) => {
+            let on_time = device.fire_stage_for(stage, duration)?;
+            (device.get_stage_fire_current(stage).ok(), Some(on_time))
+        }
+        None => (Some(device.fire_stage_with_info(stage)?), None),
+    };
+
+    let result = result
+        .value("stage", stage)
+        .optional_value("fire_current_ma", fire_current_ma)
+        .optional_value("on_time_ms", on_time.map(|on_time| on_time.as_millis()));
+    Ok(match device.get_power_info(stage) {
+        Ok(power) => result
+            .float("total_power", power.total_power)
+            .string("total_units", &power.total_units)
+            .float("per_power", power.per_power)
+            .string("per_units", &power.per_units),
+        Err(_) => result.value("total_power", "null").value("per_power", "null"),
+    })
+}
+
+/// Quote and escape a JSON string
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            character if character.is_control() => quoted.push_str(&format!("\\u{:04x}", character as u32)),
+            character => quoted.push(character),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_names_match_command_line() {
+        assert_eq!(operation_name(&Commands::ReadState), "read-state");
+        assert_eq!(operation_name(&Commands::Stage3 { duration: None }), "stage3");
+        assert_eq!(operation_name(&Commands::Info), "info");
+    }
+
+    #[test]
+    fn test_error_json_has_stable_kind() {
+        assert_eq!(
+            error_json("status", &LumidoxError::DeviceError("bad \"reply\"".to_string())),
+            r#"{"success":false,"operation":"status","error_kind":"device_error","error":"Device communication error: bad \"reply\""}"#
+        );
+        assert_eq!(
+            JsonObject::result(true, "stage1").value("stage", 1).optional_value("on_time_ms", None::<u128>).float("total_power", f32::NAN).render(),
+            r#"{"success":true,"operation":"stage1","stage":1,"on_time_ms":null,"total_power":null}"#
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Prometheus metrics output for Lumidox II Controller CLI
+//!
+//! This module reads the device status and stage parameters once and prints
+//! them in the Prometheus text exposition format, suitable for the
+//! node-exporter textfile collector. The metric set is derived from the
+//! typed `DeviceOperationData::DeviceStatus`, `DeviceInfo` and
+//! `StageParameters` structures, so values that cannot be read are omitted
+//! rather than reported as zero.
+//!
+//! The Lumidox II protocol does not report a temperature, so no temperature
+//! metric is produced.
+
+use std::fmt::Write;
+use crate::core::{DeviceOperationData, Result};
+use crate::core::operations::information::DeviceStatusOperations;
+use crate::device::LumidoxDevice;
+use crate::device::models::DeviceInfo;
+use crate::device::operations::power::StageParameters;
+
+/// Read the device status and print it as Prometheus metrics
+///
+/// Stages whose parameters cannot be read are left out of the per-stage
+/// metrics. A failure to read the overall status is returned as an error.
+///
+/// # Arguments
+/// * `device` - Connected device
+///
+/// # Returns
+/// * `Result<()>` - Success or device error
+///
+/// # Example
+/// ```
+/// run_metrics_command(&mut device)?;
+/// ```
+pub fn run_metrics_command(device: &mut LumidoxDevice) -> Result<()> {
+    let status = DeviceStatusOperations::get_device_status_unified(device)?.data;
+    let stages: Vec<StageParameters> = device.get_all_stage_parameters_partial()
+        .into_iter()
+        .flatten()
+        .collect();
+
+    print!("{}", render_metrics(&status, device.info(), &stages));
+    Ok(())
+}
+
+/// Render device data in the Prometheus text exposition format
+///
+/// # Arguments
+/// * `status` - Device status data; other data variants produce no status metrics
+/// * `info` - Device identification, if available
+/// * `stages` - Parameters of the stages that were read
+///
+/// # Returns
+/// * `String` - Metrics text, one `# HELP`/`# TYPE` header per metric family
+///
+/// # Example
+/// ```
+/// let text = render_metrics(&response.data, device.info(), &stages);
+/// assert!(text.contains("lumidox_up 1"));
+/// ```
+pub fn render_metrics(status: &DeviceOperationData, info: Option<&DeviceInfo>, stages: &[StageParameters]) -> String {
+    let mut metrics = MetricsText::default();
+
+    metrics.gauge("lumidox_up", "Whether the device answered the metrics read", &[(String::new(), 1.0)]);
+
+    if let Some(info) = info {
+        let labels = format!(
+            "firmware=\"{}\",model=\"{}\",serial=\"{}\",wavelength=\"{}\"",
+            escape_label(&info.firmware_version),
+            escape_label(&info.model_number),
+            escape_label(&info.serial_number),
+            escape_label(&info.wavelength)
+        );
+        metrics.gauge("lumidox_device_info", "Device identification", &[(labels, 1.0)]);
+        metrics.gauge("lumidox_max_current_milliamps", "Maximum device current in mA",
+            &[(String::new(), f64::from(info.max_current_ma))]);
+    }
+
+    if let DeviceOperationData::DeviceStatus {
+        current_mode, arm_current, fire_current, remote_mode_state, connection_healthy, ready_for_operations
+    } = status {
+        if let Some(mode) = current_mode {
+            metrics.gauge("lumidox_mode", "Current device mode",
+                &[(format!("mode=\"{}\"", escape_label(mode)), 1.0)]);
+        }
+        if let Some(state) = remote_mode_state {
+            metrics.gauge("lumidox_remote_mode_state", "Remote mode state reported by the device",
+                &[(String::new(), f64::from(*state))]);
+        }
+        if let Some(current) = arm_current {
+            metrics.gauge("lumidox_arm_current_milliamps", "ARM current setting in mA",
+                &[(String::new(), f64::from(*current))]);
+        }
+        if let Some(current) = fire_current {
+            metrics.gauge("lumidox_fire_current_milliamps", "FIRE current setting in mA",
+                &[(String::new(), f64::from(*current))]);
+        }
+        metrics.gauge("lumidox_connection_healthy", "Whether the device connection is healthy",
+            &[(String::new(), bool_value(*connection_healthy))]);
+        metrics.gauge("lumidox_ready", "Whether the device is ready for operations",
+            &[(String::new(), bool_value(*ready_for_operations))]);
+    }
+
+    if !stages.is_empty() {
+        let stage_samples = |value: fn(&StageParameters) -> f64| -> Vec<(String, f64)> {
+            stages.iter()
+                .map(|stage| (format!("stage=\"{}\"", stage.stage_number), value(stage)))
+                .collect()
+        };
+        metrics.gauge("lumidox_stage_arm_current_milliamps", "Stage ARM current in mA",
+            &stage_samples(|stage| f64::from(stage.arm_current_ma)));
+        metrics.gauge("lumidox_stage_fire_current_milliamps", "Stage FIRE current in mA",
+            &stage_samples(|stage| f64::from(stage.fire_current_ma)));
+        metrics.gauge("lumidox_stage_voltage_limit_volts", "Stage voltage limit in V",
+            &stage_samples(|stage| f64::from(stage.volt_limit_v)));
+        metrics.gauge("lumidox_stage_voltage_start_volts", "Stage start voltage in V",
+            &stage_samples(|stage| f64::from(stage.volt_start_v)));
+
+        let power: Vec<(String, f64)> = stages.iter()
+            .map(|stage| (
+                format!("stage=\"{}\",units=\"{}\"", stage.stage_number, escape_label(&stage.total_units)),
+                f64::from(stage.power_total),
+            ))
+            .collect();
+        metrics.gauge("lumidox_stage_power_total", "Stage total power in the units reported by the device", &power);
+    }
+
+    metrics.text
+}
+
+/// Prometheus text being built
+#[derive(Default)]
+struct MetricsText {
+    text: String,
+}
+
+impl MetricsText {
+    /// Append a gauge family with its samples
+    ///
+    /// Each sample is a label set (without braces, empty for none) and a value.
+    fn gauge(&mut self, name: &str, help: &str, samples: &[(String, f64)]) {
+        let _ = writeln!(self.text, "# HELP {} {}", name, help);
+        let _ = writeln!(self.text, "# TYPE {} gauge", name);
+        for (labels, value) in samples {
+            if labels.is_empty() {
+                let _ = writeln!(self.text, "{} {}", name, value);
+            } else {
+                let _ = writeln!(self.text, "{}{{{}}} {}", name, labels, value);
+            }
+        }
+    }
+}
+
+/// Convert a flag to a gauge value
+fn bool_value(flag: bool) -> f64 {
+    if flag { 1.0 } else { 0.0 }
+}
+
+/// Escape a label value as required by the text exposition format
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_metrics_from_status() {
+        let status = DeviceOperationData::DeviceStatus {
+            current_mode: Some("Armed".to_string()),
+            arm_current: Some(500),
+            fire_current: None,
+            remote_mode_state: Some(2),
+            connection_healthy: true,
+            ready_for_operations: false,
+        };
+        let stage = StageParameters {
+            stage_number: 2,
+            arm_current_ma: 100,
+            fire_current_ma: 1500,
+            volt_limit_v: 12.5,
+            volt_start_v: 8.0,
+            power_total: 42.5,
+            power_per_led: 1.5,
+            total_units: "mW".to_string(),
+            per_led_units: "mW".to_string(),
+        };
+
+        let text = render_metrics(&status, None, &[stage]);
+        assert!(text.contains("# TYPE lumidox_up gauge\nlumidox_up 1\n"));
+        assert!(text.contains("lumidox_mode{mode=\"Armed\"} 1\n"));
+        assert!(text.contains("lumidox_arm_current_milliamps 500\n"));
+        assert!(!text.contains("lumidox_fire_current_milliamps "));
+        assert!(text.contains("lumidox_ready 0\n"));
+        assert!(text.contains("lumidox_stage_fire_current_milliamps{stage=\"2\"} 1500\n"));
+        assert!(text.contains("lumidox_stage_power_total{stage=\"2\",units=\"mW\"} 42.5\n"));
+        assert!(!text.contains("lumidox_device_info"));
+    }
+
+    #[test]
+    fn test_escape_label() {
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+}
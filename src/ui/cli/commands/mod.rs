@@ -3,11 +3,29 @@
 //! This module handles the execution of specific commands in non-interactive mode,
 //! providing direct command-line access to device operations.
 
-use crate::core::Result;
-use crate::communication::{PortDetector, PortDetectionConfig, BaudDetector, BaudDetectionConfig, AutoConnector};
-use super::{args::Commands, device::create_device_controller_with_optimization};
+use std::time::Duration;
+use crate::core::{LumidoxError, Result};
+use crate::device::LumidoxDevice;
+use crate::communication::{PortDetector, PortDetectionConfig, BaudDetector, BaudDetectionConfig, BaudResponse, AutoConnector};
+use super::{args::{fire_target, Commands, FireTarget, OutputFormat}, device::create_device_controller_with_optimization};
+use super::progress::CountdownReporter;
+use super::confirm::confirm_command;
 
 pub mod power_debug;
+pub mod bench;
+pub mod command_ref;
+pub mod current_settings;
+pub mod export_stages;
+pub mod json_output;
+pub mod metrics;
+pub mod monitor;
+pub mod readback;
+pub mod repl;
+pub mod script;
+pub mod snapshot_file;
+pub mod watch;
+
+use current_settings::run_current_command;
 
 /// Run a specific command in non-interactive mode
 pub fn run_command_mode(command: Commands, port_name: String) -> Result<()> {
@@ -16,33 +34,92 @@ pub fn run_command_mode(command: Commands, port_name: String) -> Result<()> {
 
 /// Run a specific command in non-interactive mode with specified optimization setting
 pub fn run_command_mode_with_optimization(command: Commands, port_name: String, optimize_transitions: bool) -> Result<()> {
-    let mut device = create_device_controller_with_optimization(&port_name, optimize_transitions)?;
+    run_command_mode_with_format(command, port_name, optimize_transitions, OutputFormat::Text)
+}
 
-    match command {
-        Commands::Stage1 => {
-            println!("Firing stage 1.");
-            device.fire_stage(1)?
-        }
-        Commands::Stage2 => {
-            println!("Firing stage 2.");
-            device.fire_stage(2)?
-        }
-        Commands::Stage3 => {
-            println!("Firing stage 3.");
-            device.fire_stage(3)?
-        }
-        Commands::Stage4 => {
-            println!("Firing stage 4.");
-            device.fire_stage(4)?
-        }
-        Commands::Stage5 => {
-            println!("Firing stage 5.");
-            device.fire_stage(5)?
-        }
+/// Run a specific command in non-interactive mode with specified optimization setting and output format
+///
+/// The output format applies to the ARM/FIRE current setting commands and
+/// the commands with a JSON form (see `json_output`); with JSON output a
+/// failure is also printed as a JSON error object.
+/// Port management commands and the command reference run without
+/// connecting to a device.
+pub fn run_command_mode_with_format(command: Commands, port_name: String, optimize_transitions: bool, format: OutputFormat) -> Result<()> {
+    if !requires_device(&command) {
+        return run_port_command(&command);
+    }
+
+    let result = create_device_controller_with_optimization(&port_name, optimize_transitions)
+        .and_then(|mut device| execute_device_command(&mut device, &command, format));
+    json_output::report_error(format, &command, result)
+}
+
+/// Check whether a command needs a connected device
+///
+/// # Arguments
+/// * `command` - The CLI command
+///
+/// # Returns
+/// * `bool` - False for port listing, detection and diagnostics commands,
+///   the command reference and shell completions
+pub fn requires_device(command: &Commands) -> bool {
+    !matches!(
+        command,
+        Commands::ListPorts | Commands::DetectPorts | Commands::TestBaud { .. } | Commands::PortDiagnostics |
+        Commands::CommandRef | Commands::Completions { .. }
+    )
+}
+
+/// Execute a command against a connected device
+///
+/// This is the single execution path for device commands, shared by
+/// manual-port and auto-detect invocations. Port management commands, the
+/// command reference and shell completions are run without using the device.
+/// Destructive commands are confirmed first when the confirmation policy
+/// asks for it, see `confirm::confirm_command`.
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `command` - Command to execute
+/// * `format` - Output format for the ARM/FIRE current setting commands and
+///   the commands with a JSON form
+///
+/// # Returns
+/// * `Result<()>` - Success or device error
+///
+/// # Example
+/// ```
+/// let mut device = LumidoxDevice::connect(&cli.operation_config())?;
+/// execute_device_command(&mut device, &Commands::Status, OutputFormat::Text)?;
+/// ```
+pub fn execute_device_command(device: &mut LumidoxDevice, command: &Commands, format: OutputFormat) -> Result<()> {
+    confirm_command(command)?;
+    if format == OutputFormat::Json && json_output::has_json_output(command) {
+        return json_output::run_json_command(device, command);
+    }
+
+    match *command {
+        Commands::Fire { stage, current, duration } => match fire_target(stage, current)? {
+            FireTarget::Stage(stage) => fire_stage_command(device, stage, duration)?,
+            FireTarget::Current(value) => {
+                println!("Firing with {}mA.", value);
+                device.fire_with_current(value)?
+            }
+        },
+        Commands::Stage1 { duration } => fire_stage_command(device, 1, duration)?,
+        Commands::Stage2 { duration } => fire_stage_command(device, 2, duration)?,
+        Commands::Stage3 { duration } => fire_stage_command(device, 3, duration)?,
+        Commands::Stage4 { duration } => fire_stage_command(device, 4, duration)?,
+        Commands::Stage5 { duration } => fire_stage_command(device, 5, duration)?,
         Commands::Current { value } => {
             println!("Firing with {}mA.", value);
             device.fire_with_current(value)?
         }
+        Commands::Ramp { target, step, delay } => {
+            println!("Ramping to {}mA in {}mA steps every {}ms.", target, step, delay.as_millis());
+            device.fire_with_current_ramp(target, step, delay)?;
+            println!("Firing at {}mA.", target);
+        }
         Commands::Arm => {
             println!("Arming device.");
             device.arm()?
@@ -51,6 +128,18 @@ pub fn run_command_mode_with_optimization(command: Commands, port_name: String,
             println!("Turning off device.");
             device.turn_off()?
         }
+        Commands::Stop => {
+            println!("Emergency stop: turning off output.");
+            device.emergency_stop()?
+        }
+        Commands::Shutdown => {
+            println!("Shutting down device and returning to local mode.");
+            device.shutdown()?
+        }
+        Commands::ResetDefaults => {
+            println!("Resetting device to defaults.");
+            device.reset_to_defaults()?
+        }
         Commands::Info => {
             if let Some(info) = device.info() {
                 println!("Controller Firmware Version: {}", info.firmware_version);
@@ -74,6 +163,14 @@ pub fn run_command_mode_with_optimization(command: Commands, port_name: String,
                 Err(e) => println!("Error reading current settings: {}", e),
             }
         }
+        Commands::Metrics => metrics::run_metrics_command(device)?,
+        Commands::ExportStages { ref output } => export_stages::run_export_stages_command(device, output.as_deref())?,
+        Commands::SaveConfig { ref file } => snapshot_file::run_save_config_command(device, file)?,
+        Commands::LoadConfig { ref file } => snapshot_file::run_load_config_command(device, file)?,
+        Commands::Monitor { .. } => monitor::run_monitor_command(device, command)?,
+        Commands::Watch { interval } => watch::run_watch_command(device, interval)?,
+        Commands::Bench { duration_secs, stage } => bench::run_bench_command(device, stage, duration_secs)?,
+        Commands::Read { ref what, verbose } => readback::run_read_command(device, what, verbose)?,
         Commands::ReadState => {
             println!("Reading remote mode state...");
             match device.read_remote_mode() {
@@ -81,26 +178,9 @@ pub fn run_command_mode_with_optimization(command: Commands, port_name: String,
                 Err(e) => println!("Error reading remote mode state: {}", e),
             }
         }
-        Commands::ReadArmCurrent => {
-            println!("Reading ARM current setting...");
-            match device.read_arm_current() {
-                Ok(current) => println!("ARM Current: {}mA", current),
-                Err(e) => println!("Error reading ARM current: {}", e),
-            }
-        }
-        Commands::ReadFireCurrent => {
-            println!("Reading FIRE current setting...");
-            match device.read_fire_current() {
-                Ok(current) => println!("FIRE Current: {}mA", current),
-                Err(e) => println!("Error reading FIRE current: {}", e),
-            }
-        }
-        Commands::SetArmCurrent { value } => {
-            println!("Setting ARM current to {}mA...", value);
-            match device.set_arm_current(value) {
-                Ok(()) => println!("ARM current set successfully."),
-                Err(e) => println!("Error setting ARM current: {}", e),
-            }
+        Commands::ReadArmCurrent | Commands::ReadFireCurrent |
+        Commands::SetArmCurrent { .. } | Commands::SetFireCurrent { .. } => {
+            run_current_command(device, command, format)?
         }
         Commands::StageInfo { stage } => {
             println!("Reading complete parameters for stage {}...", stage);
@@ -109,8 +189,8 @@ pub fn run_command_mode_with_optimization(command: Commands, port_name: String,
                     println!("Stage {} Parameters:", params.stage_number);
                     println!("  ARM Current: {}mA", params.arm_current_ma);
                     println!("  FIRE Current: {}mA", params.fire_current_ma);
-                    println!("  Voltage Limit: {:.1}V", params.volt_limit_v);
-                    println!("  Voltage Start: {:.1}V", params.volt_start_v);
+                    println!("  Voltage Limit: {:.2}V", params.volt_limit_v);
+                    println!("  Voltage Start: {:.2}V", params.volt_start_v);
                     println!("  Total Power: {:.1} {}", params.power_total, params.total_units);
                     println!("  Per LED Power: {:.1} {}", params.power_per_led, params.per_led_units);
                 }
@@ -127,15 +207,84 @@ pub fn run_command_mode_with_optimization(command: Commands, port_name: String,
         Commands::StageVoltages { stage } => {
             println!("Reading voltage parameters for stage {}...", stage);
             match device.get_stage_volt_limit(stage) {
-                Ok(limit) => println!("Stage {} Voltage Limit: {:.1}V", stage, limit),
+                Ok(limit) => println!("Stage {} Voltage Limit: {:.2}V", stage, limit),
                 Err(e) => println!("Error reading voltage limit: {}", e),
             }
             match device.get_stage_volt_start(stage) {
-                Ok(start) => println!("Stage {} Voltage Start: {:.1}V", stage, start),
+                Ok(start) => println!("Stage {} Voltage Start: {:.2}V", stage, start),
                 Err(e) => println!("Error reading voltage start: {}", e),
             }
         }
-        Commands::ListPorts => unreachable!(),
+        Commands::ListPorts | Commands::DetectPorts | Commands::TestBaud { .. } | Commands::PortDiagnostics |
+        Commands::CommandRef | Commands::Completions { .. } => run_port_command(command)?,
+        Commands::Repl => repl::run_repl(device, format)?,
+        Commands::Run { ref script } => script::run_script_command(device, script, format)?,
+    }
+
+    Ok(())
+}
+
+/// Fire a stage, turning it off again after a duration when one is given
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `stage` - Stage number to fire
+/// * `duration` - How long the output stays on, None to leave it on
+///
+/// # Returns
+/// * `Result<()>` - Success or device error
+fn fire_stage_command(device: &mut LumidoxDevice, stage: u8, duration: Option<Duration>) -> Result<()> {
+    match duration {
+        Some(duration) => {
+            println!("Firing stage {} for {:.1}s.", stage, duration.as_secs_f64());
+            let on_time = device.fire_stage_for_with_progress(stage, duration, &mut CountdownReporter::stdout())?;
+            println!("Stage {} turned off after {:.1}s.", stage, on_time.as_secs_f64());
+        }
+        None => {
+            println!("Firing stage {}.", stage);
+            device.fire_stage(stage)?;
+        }
+    }
+    Ok(())
+}
+
+/// Print a shell completion script for the CLI to stdout
+///
+/// # Arguments
+/// * `shell` - Shell to generate completions for
+///
+/// # Returns
+/// * `Result<()>` - Success or I/O error writing to stdout
+fn print_completions(shell: clap_complete::Shell) -> Result<()> {
+    std::io::Write::write_all(&mut std::io::stdout().lock(), &completion_script(shell))?;
+    Ok(())
+}
+
+/// Generate a shell completion script for the CLI
+///
+/// # Arguments
+/// * `shell` - Shell to generate completions for
+///
+/// # Returns
+/// * `Vec<u8>` - Completion script
+fn completion_script(shell: clap_complete::Shell) -> Vec<u8> {
+    let mut command = <super::Cli as clap::CommandFactory>::command();
+    let name = command.get_name().to_string();
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut command, name, &mut script);
+    script
+}
+
+/// Execute a command that does not need a device
+///
+/// # Arguments
+/// * `command` - Port listing, detection or diagnostics command, or the command reference
+///
+/// # Returns
+/// * `Result<()>` - Success or port enumeration error
+fn run_port_command(command: &Commands) -> Result<()> {
+    match command {
+        Commands::ListPorts => super::ports::list_serial_ports()?,
         Commands::DetectPorts => {
             println!("Detecting compatible Lumidox II Controller ports...");
             let config = PortDetectionConfig::default();
@@ -169,11 +318,15 @@ pub fn run_command_mode_with_optimization(command: Commands, port_name: String,
         Commands::TestBaud { port } => {
             println!("Testing baud rates on port {}...", port);
             let config = BaudDetectionConfig::default();
-            match BaudDetector::test_all_baud_rates(&port, &config) {
+            match BaudDetector::test_all_baud_rates(port, &config) {
                 Ok(results) => {
                     println!("Baud rate test results:");
-                    for result in results {
-                        let status = if result.success { "✓" } else { "✗" };
+                    for result in &results {
+                        let status = match result.response {
+                            BaudResponse::Valid => "✓",
+                            BaudResponse::Garbled => "~",
+                            BaudResponse::Silent => "✗",
+                        };
                         println!("{} {} baud - Score: {} ({}/{})",
                             status,
                             result.baud_rate,
@@ -187,6 +340,10 @@ pub fn run_command_mode_with_optimization(command: Commands, port_name: String,
                             }
                         }
                     }
+
+                    if let Some(mismatch) = BaudDetector::diagnose_mismatch(port, &results) {
+                        println!("{}", mismatch);
+                    }
                 }
                 Err(e) => println!("Error testing baud rates: {}", e),
             }
@@ -202,7 +359,36 @@ pub fn run_command_mode_with_optimization(command: Commands, port_name: String,
                 Err(e) => println!("Error running diagnostics: {}", e),
             }
         }
+        Commands::CommandRef => command_ref::print_command_reference(),
+        Commands::Completions { shell } => print_completions(*shell)?,
+        _ => {
+            return Err(LumidoxError::InvalidInput("Not a port management command".to_string()));
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_device() {
+        assert!(requires_device(&Commands::Status));
+        assert!(requires_device(&Commands::Shutdown));
+        assert!(requires_device(&Commands::ResetDefaults));
+        assert!(requires_device(&Commands::Repl));
+        assert!(!requires_device(&Commands::ListPorts));
+        assert!(!requires_device(&Commands::TestBaud { port: "COM3".to_string() }));
+        assert!(!requires_device(&Commands::CommandRef));
+        assert!(!requires_device(&Commands::Completions { shell: clap_complete::Shell::Zsh }));
+    }
+
+    #[test]
+    fn test_completions_cover_subcommands() {
+        let script = String::from_utf8(completion_script(clap_complete::Shell::Bash)).unwrap();
+        assert!(script.contains("lumidox-ii-controller"));
+        assert!(script.contains("command-ref"));
+    }
+}
@@ -0,0 +1,278 @@
+//! Periodic device monitoring for Lumidox II Controller CLI
+//!
+//! This module implements the `monitor` command, which samples the device
+//! mode and ARM/FIRE currents at a fixed interval and prints one line per
+//! sample. With `--csv` every sample is also appended to a CSV file and
+//! flushed immediately, so a long run produces a time series that survives
+//! the process being interrupted.
+//!
+//! The Lumidox II protocol does not report a temperature, so the CSV has no
+//! temperature column.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::core::{LumidoxError, Result};
+use crate::device::LumidoxDevice;
+use crate::device::models::DeviceMode;
+use super::super::args::Commands;
+
+/// Header line written at the start of a new CSV file
+pub const CSV_HEADER: &str = "timestamp_ms,mode,arm_current_ma,fire_current_ma,output_active";
+
+/// How an existing CSV file is handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvFileMode {
+    /// Create a new file; fail if the path already exists
+    Create,
+    /// Append to an existing file, writing the header only if it is empty
+    Append,
+    /// Replace an existing file
+    Overwrite,
+}
+
+impl CsvFileMode {
+    /// Select the file mode from the `--append` and `--overwrite` flags
+    ///
+    /// # Arguments
+    /// * `append` - Whether `--append` was given
+    /// * `overwrite` - Whether `--overwrite` was given
+    ///
+    /// # Returns
+    /// * `CsvFileMode` - Append, overwrite or create (the default)
+    pub fn from_flags(append: bool, overwrite: bool) -> Self {
+        match (append, overwrite) {
+            (true, _) => Self::Append,
+            (false, true) => Self::Overwrite,
+            (false, false) => Self::Create,
+        }
+    }
+}
+
+/// One monitoring sample
+///
+/// Values that could not be read are `None` and written as empty CSV fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonitorSample {
+    /// Time the sample was taken, in milliseconds since the Unix epoch
+    pub timestamp_ms: u128,
+    /// Device mode read back from the device
+    pub mode: Option<DeviceMode>,
+    /// ARM current setting in mA
+    pub arm_current_ma: Option<u16>,
+    /// FIRE current setting in mA
+    pub fire_current_ma: Option<u16>,
+}
+
+impl MonitorSample {
+    /// Read a sample from the device
+    ///
+    /// # Arguments
+    /// * `device` - Connected device
+    ///
+    /// # Returns
+    /// * `MonitorSample` - Sample with the values that could be read
+    pub fn read(device: &mut LumidoxDevice) -> Self {
+        Self {
+            timestamp_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_millis())
+                .unwrap_or_default(),
+            mode: device.read_remote_mode().ok(),
+            arm_current_ma: device.read_arm_current().ok(),
+            fire_current_ma: device.read_fire_current().ok(),
+        }
+    }
+
+    /// Check whether the output was active when the sample was taken
+    ///
+    /// # Returns
+    /// * `Option<bool>` - True in remote firing mode, None if the mode is unknown
+    pub fn output_active(&self) -> Option<bool> {
+        self.mode.map(|mode| mode == DeviceMode::Remote)
+    }
+
+    /// Format the sample as a CSV row matching `CSV_HEADER`
+    ///
+    /// # Returns
+    /// * `String` - Comma separated values without a line terminator
+    ///
+    /// # Example
+    /// ```
+    /// writeln!(file, "{}", sample.csv_row())?;
+    /// ```
+    pub fn csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{}",
+            self.timestamp_ms,
+            optional_field(self.mode.map(|mode| format!("{:?}", mode))),
+            optional_field(self.arm_current_ma),
+            optional_field(self.fire_current_ma),
+            optional_field(self.output_active())
+        )
+    }
+
+    /// Format the sample for the terminal
+    ///
+    /// # Returns
+    /// * `String` - Human-readable summary of the sample
+    pub fn summary(&self) -> String {
+        let value = |current: Option<u16>| current.map_or_else(|| "?".to_string(), |current| format!("{}mA", current));
+        format!(
+            "Mode: {} | ARM: {} | FIRE: {} | Output: {}",
+            self.mode.map_or_else(|| "?".to_string(), |mode| format!("{:?}", mode)),
+            value(self.arm_current_ma),
+            value(self.fire_current_ma),
+            match self.output_active() {
+                Some(true) => "on",
+                Some(false) => "off",
+                None => "?",
+            }
+        )
+    }
+}
+
+/// CSV file receiving one row per sample
+pub struct CsvLogger {
+    file: File,
+}
+
+impl CsvLogger {
+    /// Open the CSV file and write the header if the file is new or empty
+    ///
+    /// # Arguments
+    /// * `path` - CSV file path
+    /// * `mode` - How an existing file is handled
+    ///
+    /// # Returns
+    /// * `Result<CsvLogger>` - Open logger, `InvalidInput` if the file exists in
+    ///   create mode, or an I/O error
+    ///
+    /// # Example
+    /// ```
+    /// let mut logger = CsvLogger::open(Path::new("run.csv"), CsvFileMode::Append)?;
+    /// ```
+    pub fn open(path: &Path, mode: CsvFileMode) -> Result<Self> {
+        let mut options = OpenOptions::new();
+        match mode {
+            CsvFileMode::Create => options.write(true).create_new(true),
+            CsvFileMode::Append => options.append(true).create(true),
+            CsvFileMode::Overwrite => options.write(true).create(true).truncate(true),
+        };
+
+        let mut file = options.open(path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::AlreadyExists => LumidoxError::InvalidInput(format!(
+                "CSV file {} already exists; use --append or --overwrite",
+                path.display()
+            )),
+            _ => LumidoxError::IoError(e),
+        })?;
+
+        if file.metadata()?.len() == 0 {
+            writeln!(file, "{}", CSV_HEADER)?;
+            file.flush()?;
+        }
+
+        Ok(Self { file })
+    }
+
+    /// Append a sample and flush it to disk
+    ///
+    /// # Arguments
+    /// * `sample` - Sample to write
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or I/O error
+    pub fn write_sample(&mut self, sample: &MonitorSample) -> Result<()> {
+        writeln!(self.file, "{}", sample.csv_row())?;
+        self.file.flush()?;
+        Ok(())
+    }
+}
+
+/// Run the monitor command
+///
+/// Samples until `count` samples have been taken, or until interrupted when
+/// no count is given. Read failures leave empty fields rather than ending
+/// the run; a CSV write failure ends it with an error.
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `command` - `Commands::Monitor` with the monitoring options
+///
+/// # Returns
+/// * `Result<()>` - Success or CSV file error
+///
+/// # Example
+/// ```
+/// run_monitor_command(&mut device, &command)?;
+/// ```
+pub fn run_monitor_command(device: &mut LumidoxDevice, command: &Commands) -> Result<()> {
+    let Commands::Monitor { interval_ms, count, ref csv, append, overwrite } = *command else {
+        return Err(LumidoxError::InvalidInput("Not a monitor command".to_string()));
+    };
+
+    let mut logger = match csv {
+        Some(path) => Some(CsvLogger::open(path, CsvFileMode::from_flags(append, overwrite))?),
+        None => None,
+    };
+
+    println!("Monitoring every {}ms{}...", interval_ms, if count.is_none() { " (Ctrl+C to stop)" } else { "" });
+    let interval = Duration::from_millis(interval_ms);
+    let mut taken = 0u32;
+    while count.is_none_or(|count| taken < count) {
+        if taken > 0 {
+            std::thread::sleep(interval);
+        }
+
+        let sample = MonitorSample::read(device);
+        println!("{}", sample.summary());
+        if let Some(logger) = logger.as_mut() {
+            logger.write_sample(&sample)?;
+        }
+        taken += 1;
+    }
+
+    Ok(())
+}
+
+/// Format an optional value as a CSV field, empty when missing
+fn optional_field<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(mode: Option<DeviceMode>) -> MonitorSample {
+        MonitorSample { timestamp_ms: 1_700_000_000_000, mode, arm_current_ma: Some(500), fire_current_ma: None }
+    }
+
+    #[test]
+    fn test_csv_row_leaves_missing_values_empty() {
+        assert_eq!(sample(Some(DeviceMode::Remote)).csv_row(), "1700000000000,Remote,500,,true");
+        assert_eq!(sample(None).csv_row(), "1700000000000,,500,,");
+        assert_eq!(CSV_HEADER.split(',').count(), sample(None).csv_row().split(',').count());
+    }
+
+    #[test]
+    fn test_csv_logger_file_modes() {
+        let path = std::env::temp_dir().join(format!("lumidox-monitor-{}.csv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        CsvLogger::open(&path, CsvFileMode::Create).unwrap().write_sample(&sample(None)).unwrap();
+        assert!(matches!(CsvLogger::open(&path, CsvFileMode::Create), Err(LumidoxError::InvalidInput(_))));
+
+        CsvLogger::open(&path, CsvFileMode::Append).unwrap().write_sample(&sample(None)).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 3);
+        assert_eq!(contents.matches(CSV_HEADER).count(), 1);
+
+        CsvLogger::open(&path, CsvFileMode::Overwrite).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), format!("{}\n", CSV_HEADER));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,185 @@
+//! Named readback command for Lumidox II Controller CLI
+//!
+//! This module implements the `read` command, which issues a single named
+//! readback and prints the parsed value. With `--verbose` the raw response is
+//! printed as well. The value is parsed from the same response that is shown
+//! raw, so the output can be used to check parsing against real hardware.
+//!
+//! The Lumidox II protocol has no temperature or fault flags command; those
+//! readbacks report that the device does not provide them.
+
+use crate::core::{LumidoxError, Result};
+use crate::communication::protocol::commands;
+use crate::device::LumidoxDevice;
+use crate::device::operations::readback::mode_from_state_value;
+
+/// Readback that can be named on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Readback {
+    /// ARM current setting (command 0x20)
+    ArmCurrent,
+    /// FIRE current setting (command 0x21)
+    FireCurrent,
+    /// Remote mode state (command 0x13)
+    Mode,
+    /// Device temperature, not reported by the protocol
+    Temperature,
+    /// Device fault flags, not reported by the protocol
+    Flags,
+}
+
+impl Readback {
+    /// Every readback, in the order listed in help and error messages
+    pub const ALL: [Readback; 5] = [
+        Readback::ArmCurrent,
+        Readback::FireCurrent,
+        Readback::Mode,
+        Readback::Temperature,
+        Readback::Flags,
+    ];
+
+    /// Look up a readback by its command-line name
+    ///
+    /// # Arguments
+    /// * `name` - Readback name, e.g. "arm-current" (case-insensitive)
+    ///
+    /// # Returns
+    /// * `Result<Readback>` - Readback, or `InvalidInput` listing the valid names
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(Readback::parse("mode")?, Readback::Mode);
+    /// ```
+    pub fn parse(name: &str) -> Result<Self> {
+        Self::ALL.into_iter()
+            .find(|readback| readback.name().eq_ignore_ascii_case(name.trim()))
+            .ok_or_else(|| LumidoxError::InvalidInput(format!(
+                "Unknown readback '{}'; expected one of: {}",
+                name,
+                Self::ALL.map(Readback::name).join(", ")
+            )))
+    }
+
+    /// Get the command-line name of the readback
+    ///
+    /// # Returns
+    /// * `&'static str` - Name such as "fire-current"
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ArmCurrent => "arm-current",
+            Self::FireCurrent => "fire-current",
+            Self::Mode => "mode",
+            Self::Temperature => "temperature",
+            Self::Flags => "flags",
+        }
+    }
+
+    /// Get the protocol command issued for the readback
+    ///
+    /// # Returns
+    /// * `Option<&'static [u8]>` - Command code, None when the protocol has no command for it
+    pub fn command(self) -> Option<&'static [u8]> {
+        match self {
+            Self::ArmCurrent => Some(commands::READ_ARM_CURRENT),
+            Self::FireCurrent => Some(commands::READ_FIRE_CURRENT),
+            Self::Mode => Some(commands::READ_REMOTE_MODE),
+            Self::Temperature | Self::Flags => None,
+        }
+    }
+
+    /// Format the value read back by the readback's command
+    ///
+    /// # Arguments
+    /// * `value` - 16-bit data value from the response
+    ///
+    /// # Returns
+    /// * `String` - Parsed value for display
+    pub fn describe(self, value: u16) -> String {
+        match self {
+            Self::ArmCurrent => format!("ARM Current: {}mA", value),
+            Self::FireCurrent => format!("FIRE Current: {}mA", value),
+            Self::Mode => format!("Mode: {:?} (state {})", mode_from_state_value(i32::from(value)), value),
+            Self::Temperature | Self::Flags => format!("{}: {}", self.name(), value),
+        }
+    }
+}
+
+/// Run the read command
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `what` - Readback name
+/// * `verbose` - Whether to print the raw response
+///
+/// # Returns
+/// * `Result<()>` - Success, `InvalidInput` for an unknown name,
+///   `UnsupportedOperation` for temperature, or device error
+///
+/// # Example
+/// ```
+/// run_read_command(&mut device, "fire-current", true)?;
+/// ```
+pub fn run_read_command(device: &mut LumidoxDevice, what: &str, verbose: bool) -> Result<()> {
+    let readback = Readback::parse(what)?;
+
+    if let Some(command) = readback.command() {
+        let payload = device.send_raw(command_code(command)?, &[])?;
+        println!("{}", readback.describe(payload_value(&payload)?));
+        if verbose {
+            let frame: Vec<u8> = [&b"*"[..], &payload, &b"^"[..]].concat();
+            println!("Raw response: {} [{}]", String::from_utf8_lossy(&frame), hex_bytes(&frame));
+        }
+        return Ok(());
+    }
+
+    Err(LumidoxError::UnsupportedOperation {
+        command: format!("{} readback", readback.name()),
+        firmware: device.info()
+            .map(|info| info.firmware_version.clone())
+            .unwrap_or_else(|| "unknown".to_string()),
+    })
+}
+
+/// Convert a two-character command code such as `b"20"` to its byte value
+fn command_code(command: &[u8]) -> Result<u8> {
+    std::str::from_utf8(command)
+        .ok()
+        .and_then(|code| u8::from_str_radix(code, 16).ok())
+        .ok_or_else(|| LumidoxError::ProtocolError(format!("Invalid command code {:?}", command)))
+}
+
+/// Parse the 16-bit data value from a response payload (`DDDDSS`)
+fn payload_value(payload: &[u8]) -> Result<u16> {
+    payload.get(..4)
+        .and_then(|data| std::str::from_utf8(data).ok())
+        .and_then(|data| u16::from_str_radix(data, 16).ok())
+        .ok_or_else(|| LumidoxError::ProtocolError(format!(
+            "Malformed response payload: {}", String::from_utf8_lossy(payload)
+        )))
+}
+
+/// Format bytes as space separated hex
+fn hex_bytes(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_readback_names() {
+        assert_eq!(Readback::parse("arm-current").unwrap(), Readback::ArmCurrent);
+        assert_eq!(Readback::parse("Mode").unwrap(), Readback::Mode);
+        assert!(matches!(Readback::parse("voltage"), Err(LumidoxError::InvalidInput(ref message)) if message.contains("fire-current")));
+        assert_eq!(command_code(Readback::FireCurrent.command().unwrap()).unwrap(), 0x21);
+    }
+
+    #[test]
+    fn test_parse_payload_value() {
+        assert_eq!(payload_value(b"03e8c1").unwrap(), 1000);
+        assert_eq!(Readback::Mode.describe(payload_value(b"0002b8").unwrap()), "Mode: Armed (state 2)");
+        assert!(payload_value(b"zz").is_err());
+        assert_eq!(hex_bytes(b"*0^"), "2a 30 5e");
+    }
+}
@@ -0,0 +1,153 @@
+//! Line-oriented command interpreter for Lumidox II Controller CLI
+//!
+//! This module provides a REPL that reads one command per line and runs it
+//! against a live connection. Lines use the same syntax as the CLI
+//! subcommands (`arm`, `off`, `status`, `current 1500`, `stage-info 2`) and
+//! are executed through `execute_device_command`, so the REPL and direct
+//! command mode behave identically. `fire <stage>` is accepted as shorthand
+//! for `fire --stage <stage>`.
+
+use std::io::{self, BufRead, Write};
+use clap::Parser;
+use crate::core::{LumidoxError, Result};
+use crate::device::LumidoxDevice;
+use super::super::args::{Commands, OutputFormat};
+use super::execute_device_command;
+
+/// Prompt shown before each REPL line
+const PROMPT: &str = "lumidox> ";
+
+/// A single parsed REPL line
+#[derive(Clone)]
+pub enum ReplInput {
+    /// Blank line, nothing to do
+    Empty,
+    /// Leave the REPL
+    Quit,
+    /// Command to execute against the device
+    Command(Commands),
+}
+
+/// Parser for one REPL line, reusing the CLI subcommand definitions
+#[derive(Parser)]
+#[command(name = "", no_binary_name = true, disable_version_flag = true)]
+struct ReplLine {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+/// Parse a REPL line into a command
+///
+/// # Arguments
+/// * `line` - Line entered by the user
+///
+/// # Returns
+/// * `Result<ReplInput>` - Parsed input, or `InvalidInput` with the parser
+///   message (including help text for `help`)
+///
+/// # Example
+/// ```
+/// assert!(matches!(parse_line("fire 3")?, ReplInput::Command(Commands::Fire { stage: Some(3), .. })));
+/// ```
+pub fn parse_line(line: &str) -> Result<ReplInput> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let args: Vec<String> = match words.as_slice() {
+        [] => return Ok(ReplInput::Empty),
+        ["quit"] | ["exit"] => return Ok(ReplInput::Quit),
+        ["fire", stage, options @ ..] if !stage.starts_with('-') => ["fire", "--stage", stage].into_iter()
+            .chain(options.iter().copied())
+            .map(str::to_string)
+            .collect(),
+        _ => words.iter().map(|word| word.to_string()).collect(),
+    };
+
+    let parsed = ReplLine::try_parse_from(args)
+        .map_err(|e| LumidoxError::InvalidInput(e.render().to_string().trim_end().to_string()))?;
+
+    match parsed.command {
+        Commands::Repl => Err(LumidoxError::InvalidInput("Already in the REPL".to_string())),
+        command => Ok(ReplInput::Command(command)),
+    }
+}
+
+/// Run the REPL on standard input against a connected device
+///
+/// Command errors are printed and the loop continues; the REPL ends on
+/// `quit`, `exit` or end of input. The device is left in its current state,
+/// so use `off` or `shutdown` before leaving if the output is active.
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `format` - Output format for the ARM/FIRE current setting commands
+///
+/// # Returns
+/// * `Result<()>` - Success, or an I/O error reading input
+///
+/// # Example
+/// ```
+/// let mut device = LumidoxDevice::connect(&cli.operation_config())?;
+/// run_repl(&mut device, OutputFormat::Text)?;
+/// ```
+pub fn run_repl(device: &mut LumidoxDevice, format: OutputFormat) -> Result<()> {
+    println!("Lumidox II command interpreter. Type 'help' for commands, 'quit' to exit.");
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("{}", PROMPT);
+        io::stdout().flush()?;
+
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => break,
+        };
+
+        match parse_line(&line) {
+            Ok(ReplInput::Empty) => {}
+            Ok(ReplInput::Quit) => break,
+            Ok(ReplInput::Command(command)) => {
+                if let Err(e) = execute_device_command(device, &command, format) {
+                    println!("Error: {}", e);
+                }
+            }
+            Err(LumidoxError::InvalidInput(message)) => println!("{}", message),
+            Err(e) => println!("Error: {}", e),
+        }
+    }
+
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(line: &str) -> Commands {
+        match parse_line(line) {
+            Ok(ReplInput::Command(command)) => command,
+            _ => panic!("'{}' did not parse as a command", line),
+        }
+    }
+
+    #[test]
+    fn test_parse_line_reuses_cli_commands() {
+        assert!(matches!(command("fire 3"), Commands::Fire { stage: Some(3), current: None, duration: None }));
+        assert!(matches!(command("fire 3 --duration 500ms"), Commands::Fire { stage: Some(3), duration: Some(_), .. }));
+        assert!(matches!(command("fire --current 800"), Commands::Fire { stage: None, current: Some(800), .. }));
+        assert!(matches!(command("stage3"), Commands::Stage3 { duration: None }));
+        assert!(matches!(command("current 1500"), Commands::Current { value: 1500 }));
+        assert!(matches!(command("  arm "), Commands::Arm));
+        assert!(matches!(command("stage-info 2"), Commands::StageInfo { stage: 2 }));
+        assert!(matches!(parse_line(""), Ok(ReplInput::Empty)));
+        assert!(matches!(parse_line("quit"), Ok(ReplInput::Quit)));
+    }
+
+    #[test]
+    fn test_parse_line_rejects_invalid_input() {
+        assert!(matches!(parse_line("fire three"), Err(LumidoxError::InvalidInput(_))));
+        assert!(matches!(parse_line("current"), Err(LumidoxError::InvalidInput(_))));
+        assert!(matches!(parse_line("repl"), Err(LumidoxError::InvalidInput(_))));
+        assert!(matches!(parse_line("launch"), Err(LumidoxError::InvalidInput(_))));
+    }
+}
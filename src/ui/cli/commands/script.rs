@@ -0,0 +1,181 @@
+//! Command script runner for Lumidox II Controller CLI
+//!
+//! This module implements the `run` command, which executes a file of
+//! commands against one connected device for repeatable experiments. Each
+//! line uses the REPL syntax (`arm`, `fire 3`, `current 500`, `off`), with
+//! `stage <n>` accepted as another spelling of `fire <n>`. A `wait <time>`
+//! line pauses the script, and everything after a `#` is a comment.
+//!
+//! The whole script is parsed before the first command is sent, so a typo
+//! near the end is reported with its line number instead of stopping an
+//! experiment halfway through.
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use crate::core::{LumidoxError, NoProgress, ProgressReporter, Result};
+use crate::core::progress::wait_with_progress;
+use crate::device::LumidoxDevice;
+use super::super::args::{parse_duration, Commands, OutputFormat};
+use super::super::progress::CountdownReporter;
+use super::execute_device_command;
+use super::repl::{parse_line, ReplInput};
+
+/// A single step of a command script
+#[derive(Clone)]
+pub enum ScriptStep {
+    /// Command to execute against the device
+    Command(Commands),
+    /// Pause before the next step
+    Wait(Duration),
+}
+
+/// A script step with the line it came from
+#[derive(Clone)]
+pub struct ScriptLine {
+    /// Line number in the script, starting at 1
+    pub number: usize,
+    /// Line text without comment and surrounding whitespace
+    pub text: String,
+    /// Parsed step
+    pub step: ScriptStep,
+}
+
+/// Parse a command script
+///
+/// Blank lines and comments produce no steps.
+///
+/// # Arguments
+/// * `script` - Script text
+///
+/// # Returns
+/// * `Result<Vec<ScriptLine>>` - Steps in order, or `InvalidInput` naming the
+///   first line that is not a valid command
+///
+/// # Example
+/// ```
+/// let steps = parse_script("arm\nstage 3\nwait 2s\noff\n")?;
+/// assert_eq!(steps.len(), 4);
+/// ```
+pub fn parse_script(script: &str) -> Result<Vec<ScriptLine>> {
+    let mut steps = Vec::new();
+    for (index, line) in script.lines().enumerate() {
+        let text = line.split('#').next().unwrap_or_default().trim();
+        if text.is_empty() {
+            continue;
+        }
+        let step = parse_step(text).map_err(|e| match e {
+            LumidoxError::InvalidInput(message) => {
+                LumidoxError::InvalidInput(format!("Script line {}: {}", index + 1, message))
+            }
+            e => e,
+        })?;
+        steps.push(ScriptLine { number: index + 1, text: text.to_string(), step });
+    }
+    Ok(steps)
+}
+
+/// Parse one non-empty script line
+fn parse_step(text: &str) -> Result<ScriptStep> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let line = match words.as_slice() {
+        ["wait", duration] => {
+            return parse_duration(duration).map(ScriptStep::Wait).map_err(LumidoxError::InvalidInput);
+        }
+        ["wait", ..] => return Err(LumidoxError::InvalidInput("Usage: wait <time>, e.g. wait 2s".to_string())),
+        ["stage", rest @ ..] => format!("fire {}", rest.join(" ")),
+        _ => text.to_string(),
+    };
+
+    match parse_line(&line)? {
+        ReplInput::Command(Commands::Run { .. }) => {
+            Err(LumidoxError::InvalidInput("Scripts cannot run other scripts".to_string()))
+        }
+        ReplInput::Command(command) => Ok(ScriptStep::Command(command)),
+        ReplInput::Empty | ReplInput::Quit => {
+            Err(LumidoxError::InvalidInput(format!("Unknown script command '{}'", text)))
+        }
+    }
+}
+
+/// Run the run command
+///
+/// Executes the steps in order against the connected device. If a step
+/// fails, the output is turned off before the error is returned, so a
+/// failed script does not leave the device firing unattended.
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `path` - Script file
+/// * `format` - Output format passed to each command
+///
+/// # Returns
+/// * `Result<()>` - Success, `InvalidInput` for an invalid script line,
+///   I/O error reading the script, or the error of the failed step
+///
+/// # Example
+/// ```
+/// run_script_command(&mut device, Path::new("experiment.txt"), OutputFormat::Text)?;
+/// ```
+pub fn run_script_command(device: &mut LumidoxDevice, path: &Path, format: OutputFormat) -> Result<()> {
+    let steps = parse_script(&fs::read_to_string(path)?)?;
+
+    for line in &steps {
+        println!("[{}] {}", line.number, line.text);
+        let result = match &line.step {
+            ScriptStep::Wait(duration) => {
+                wait_step(*duration, format);
+                Ok(())
+            }
+            ScriptStep::Command(command) => execute_device_command(device, command, format),
+        };
+        if let Err(e) = result {
+            eprintln!("Script stopped at line {}: {}", line.number, e);
+            let _ = device.turn_off();
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// Pause for a wait step, counting down in text output
+///
+/// # Arguments
+/// * `duration` - How long to wait
+/// * `format` - Output format; JSON output gets no countdown line
+fn wait_step(duration: Duration, format: OutputFormat) {
+    let mut countdown = CountdownReporter::stdout();
+    let mut quiet = NoProgress;
+    let progress: &mut dyn ProgressReporter = match format {
+        OutputFormat::Text => &mut countdown,
+        OutputFormat::Json => &mut quiet,
+    };
+    progress.start("Waiting", duration);
+    wait_with_progress(duration, progress);
+    progress.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_script_steps_and_comments() {
+        let steps = parse_script("# warm-up\narm\n\nstage 3  # fire\nwait 2s\ncurrent 500\noff\n").unwrap();
+        let numbers: Vec<usize> = steps.iter().map(|line| line.number).collect();
+        assert_eq!(numbers, vec![2, 4, 5, 6, 7]);
+        assert!(matches!(steps[0].step, ScriptStep::Command(Commands::Arm)));
+        assert!(matches!(steps[1].step, ScriptStep::Command(Commands::Fire { stage: Some(3), .. })));
+        assert!(matches!(steps[2].step, ScriptStep::Wait(duration) if duration == Duration::from_secs(2)));
+        assert!(matches!(steps[3].step, ScriptStep::Command(Commands::Current { value: 500 })));
+        assert_eq!(steps[1].text, "stage 3");
+    }
+
+    #[test]
+    fn test_parse_script_reports_line_of_unknown_command() {
+        let error = parse_script("arm\nstage 3\nlaunch\noff\n").err().unwrap();
+        assert!(matches!(error, LumidoxError::InvalidInput(ref message) if message.starts_with("Script line 3:")));
+        assert!(parse_script("wait soon").is_err());
+        assert!(parse_script("quit").is_err());
+    }
+}
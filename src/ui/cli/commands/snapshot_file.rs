@@ -0,0 +1,153 @@
+//! Device snapshot files for Lumidox II Controller CLI
+//!
+//! This module implements the `save-config` and `load-config` commands,
+//! which store a `DeviceSnapshot` as a JSON file and restore it later, for
+//! switching between experiment protocols without reconfiguring by hand.
+//!
+//! Restoring writes the ARM and FIRE currents, the mode and the optimized
+//! transitions setting. The stage parameters in the file are read-only
+//! calibration values: they are kept for reference and skipped on restore.
+//!
+//! JSON support needs the `serde` feature, which the `cli` feature enables;
+//! without it both commands fail with a `ConfigError`.
+
+use std::fs;
+use std::path::Path;
+use crate::core::{LumidoxError, Result};
+use crate::device::LumidoxDevice;
+use crate::device::models::DeviceSnapshot;
+
+/// Serialize a snapshot as pretty-printed JSON
+///
+/// # Arguments
+/// * `snapshot` - Snapshot to serialize
+///
+/// # Returns
+/// * `Result<String>` - JSON text, or `SystemError` if serialization fails
+#[cfg(feature = "serde")]
+pub fn snapshot_to_json(snapshot: &DeviceSnapshot) -> Result<String> {
+    serde_json::to_string_pretty(snapshot).map_err(|e| LumidoxError::system("Cannot serialize device snapshot", e))
+}
+
+/// Serialize a snapshot as pretty-printed JSON (unavailable without `serde`)
+#[cfg(not(feature = "serde"))]
+pub fn snapshot_to_json(_snapshot: &DeviceSnapshot) -> Result<String> {
+    Err(serde_unavailable())
+}
+
+/// Parse a snapshot from JSON
+///
+/// # Arguments
+/// * `json` - JSON text written by `snapshot_to_json`
+///
+/// # Returns
+/// * `Result<DeviceSnapshot>` - Parsed snapshot, or `ConfigError` for invalid JSON
+#[cfg(feature = "serde")]
+pub fn snapshot_from_json(json: &str) -> Result<DeviceSnapshot> {
+    serde_json::from_str(json).map_err(|e| LumidoxError::ConfigError(format!("Invalid device snapshot: {}", e)))
+}
+
+/// Parse a snapshot from JSON (unavailable without `serde`)
+#[cfg(not(feature = "serde"))]
+pub fn snapshot_from_json(_json: &str) -> Result<DeviceSnapshot> {
+    Err(serde_unavailable())
+}
+
+/// Error for snapshot files in a build without the `serde` feature
+#[cfg(not(feature = "serde"))]
+fn serde_unavailable() -> LumidoxError {
+    LumidoxError::ConfigError("Snapshot files need a build with the serde feature".to_string())
+}
+
+/// Run the save-config command
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `path` - JSON file to write
+///
+/// # Returns
+/// * `Result<()>` - Success, device error, or `SystemError` if the file cannot be written
+///
+/// # Example
+/// ```
+/// run_save_config_command(&mut device, Path::new("protocol-a.json"))?;
+/// ```
+pub fn run_save_config_command(device: &mut LumidoxDevice, path: &Path) -> Result<()> {
+    let snapshot = device.capture_snapshot()?;
+    fs::write(path, snapshot_to_json(&snapshot)? + "\n")
+        .map_err(|e| LumidoxError::system(format!("Cannot write {}", path.display()), e))?;
+    println!(
+        "Saved configuration to {} (ARM {}mA, FIRE {}mA, {:?} mode, {} stages)",
+        path.display(),
+        snapshot.config.arm_current_ma,
+        snapshot.config.fire_current_ma,
+        snapshot.config.mode,
+        snapshot.stages.len()
+    );
+    Ok(())
+}
+
+/// Run the load-config command
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `path` - JSON file written by `save-config`
+///
+/// # Returns
+/// * `Result<()>` - Success, `SystemError` if the file cannot be read,
+///   `ConfigError` for an invalid file, or validation/device error
+///
+/// # Example
+/// ```
+/// run_load_config_command(&mut device, Path::new("protocol-a.json"))?;
+/// ```
+pub fn run_load_config_command(device: &mut LumidoxDevice, path: &Path) -> Result<()> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| LumidoxError::system(format!("Cannot read {}", path.display()), e))?;
+    let snapshot = snapshot_from_json(&json)?;
+    device.apply_snapshot(&snapshot)?;
+    println!(
+        "Restored ARM {}mA, FIRE {}mA and {:?} mode from {}",
+        snapshot.config.arm_current_ma,
+        snapshot.config.fire_current_ma,
+        snapshot.config.restore_mode(),
+        path.display()
+    );
+    println!("Stage parameters are read-only and were not changed.");
+    Ok(())
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::device::models::{DeviceConfigSnapshot, DeviceMode};
+    use crate::device::operations::power::StageParameters;
+
+    #[test]
+    fn test_snapshot_json_round_trip() {
+        let snapshot = DeviceSnapshot {
+            config: DeviceConfigSnapshot {
+                arm_current_ma: 200,
+                fire_current_ma: 1500,
+                mode: DeviceMode::Armed,
+                optimize_transitions: true,
+            },
+            stages: vec![StageParameters {
+                stage_number: 1,
+                arm_current_ma: 100,
+                fire_current_ma: 500,
+                volt_limit_v: 24.5,
+                volt_start_v: 12.0,
+                power_total: 150.0,
+                power_per_led: 1.5,
+                total_units: "mW".to_string(),
+                per_led_units: "mW".to_string(),
+            }],
+        };
+
+        let decoded = snapshot_from_json(&snapshot_to_json(&snapshot).unwrap()).unwrap();
+        assert_eq!(decoded.config, snapshot.config);
+        assert_eq!(decoded.stages[0].volt_limit_v, 24.5);
+        assert!(matches!(snapshot_from_json("{\"config\": {}}"), Err(LumidoxError::ConfigError(_))));
+    }
+}
@@ -0,0 +1,228 @@
+//! Continuous status display for Lumidox II Controller CLI
+//!
+//! This module implements the `watch` command, which connects once and
+//! redraws the device state, mode and FIRE current setpoint every interval
+//! until Ctrl+C, like the Unix `watch` command. It is meant for keeping an
+//! eye on a long soak test.
+//!
+//! Ctrl+C ends the watch instead of the process. If the output was on when
+//! it was interrupted, the device is turned off before the command returns.
+
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use crate::core::{CancellationToken, LumidoxError, Result};
+use crate::device::LumidoxDevice;
+use crate::device::models::DeviceMode;
+
+/// Escape sequence that clears the terminal and moves the cursor home
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Longest sleep between checks for Ctrl+C while waiting for the next redraw
+const INTERRUPT_POLL: Duration = Duration::from_millis(50);
+
+/// Whether a watch is running; Ctrl+C outside a watch exits the process
+static WATCHING: AtomicBool = AtomicBool::new(false);
+
+/// One redraw of the watch display
+///
+/// Values that could not be read hold the error text instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchFrame {
+    /// Device state description, see `LumidoxDevice::read_device_state`
+    pub state: std::result::Result<String, String>,
+    /// Operating mode read back from the device
+    pub mode: std::result::Result<DeviceMode, String>,
+    /// FIRE current setpoint read back from the device (command 0x21), in mA
+    ///
+    /// The protocol has no measured-current readback, so this is the current
+    /// the device was asked for, not a measurement of the output.
+    pub fire_setpoint_ma: std::result::Result<u16, String>,
+}
+
+impl WatchFrame {
+    /// Read a frame from the device
+    ///
+    /// A failed read is shown in the frame rather than ending the watch, so
+    /// a transient error does not stop a soak test from being observed.
+    ///
+    /// # Arguments
+    /// * `device` - Connected device
+    ///
+    /// # Returns
+    /// * `WatchFrame` - Values read, or the error for each value that failed
+    pub fn read(device: &mut LumidoxDevice) -> Self {
+        Self {
+            state: device.read_device_state().map_err(|e| e.to_string()),
+            mode: device.read_remote_mode().map_err(|e| e.to_string()),
+            fire_setpoint_ma: device.read_fire_current().map_err(|e| e.to_string()),
+        }
+    }
+
+    /// Format the frame for display
+    ///
+    /// # Arguments
+    /// * `interval` - Time between redraws
+    /// * `elapsed` - Time since the watch started
+    ///
+    /// # Returns
+    /// * `String` - Header line and one line per value
+    ///
+    /// # Example
+    /// ```
+    /// println!("{}", WatchFrame::read(&mut device).render(Duration::from_secs(2), started.elapsed()));
+    /// ```
+    pub fn render(&self, interval: Duration, elapsed: Duration) -> String {
+        let state = self.state.clone().unwrap_or_else(|e| format!("unavailable ({})", e));
+        let mode = self.mode.as_ref().map_or_else(|e| format!("unavailable ({})", e), |mode| format!("{:?}", mode));
+        let setpoint = self.fire_setpoint_ma.as_ref().map_or_else(|e| format!("unavailable ({})", e), |ma| format!("{}mA", ma));
+        format!(
+            "Every {:.1}s, {}s elapsed (Ctrl+C to stop)\n\nState:            {}\nMode:             {}\nFIRE setpoint:    {}",
+            interval.as_secs_f64(),
+            elapsed.as_secs(),
+            state,
+            mode,
+            setpoint
+        )
+    }
+}
+
+/// Marks a watch as running for the Ctrl+C handler until dropped
+struct WatchingGuard;
+
+impl WatchingGuard {
+    fn new() -> Self {
+        WATCHING.store(true, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Drop for WatchingGuard {
+    fn drop(&mut self) {
+        WATCHING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Get the token cancelled by Ctrl+C, installing the handler on first use
+///
+/// The handler is installed once per process. While a watch runs, Ctrl+C
+/// cancels the token; at any other time it exits as Ctrl+C normally does.
+///
+/// # Returns
+/// * `Result<CancellationToken>` - Token reset for a new watch, or
+///   `SystemError` if the handler cannot be installed
+fn interrupt_token() -> Result<CancellationToken> {
+    static TOKEN: OnceLock<CancellationToken> = OnceLock::new();
+    if let Some(token) = TOKEN.get() {
+        token.reset();
+        return Ok(token.clone());
+    }
+
+    let token = CancellationToken::new();
+    let handler_token = token.clone();
+    ctrlc::set_handler(move || {
+        if WATCHING.load(Ordering::SeqCst) {
+            handler_token.cancel();
+        } else {
+            std::process::exit(130);
+        }
+    })
+    .map_err(|e| LumidoxError::system("Cannot install the Ctrl+C handler", e))?;
+    Ok(TOKEN.get_or_init(|| token).clone())
+}
+
+/// Sleep for `duration`, returning early if the token is cancelled
+fn sleep_unless_cancelled(duration: Duration, token: &CancellationToken) {
+    let deadline = Instant::now() + duration;
+    while !token.is_cancelled() {
+        let now = Instant::now();
+        if now >= deadline {
+            break;
+        }
+        std::thread::sleep(INTERRUPT_POLL.min(deadline - now));
+    }
+}
+
+/// Run the watch command
+///
+/// Redraws until Ctrl+C. The terminal is cleared before each redraw when
+/// standard output is a terminal; otherwise frames are printed one after
+/// another, so the output can be logged.
+///
+/// # Arguments
+/// * `device` - Connected device
+/// * `interval` - Time between redraws
+///
+/// # Returns
+/// * `Result<()>` - Success after Ctrl+C, `InvalidInput` for a zero
+///   interval, or the error turning the output off
+///
+/// # Example
+/// ```
+/// run_watch_command(&mut device, Duration::from_secs(2))?;
+/// ```
+pub fn run_watch_command(device: &mut LumidoxDevice, interval: Duration) -> Result<()> {
+    if interval.is_zero() {
+        return Err(LumidoxError::InvalidInput("Watch interval must be greater than zero".to_string()));
+    }
+
+    let token = interrupt_token()?;
+    let _watching = WatchingGuard::new();
+    let clear = io::stdout().is_terminal();
+    let started = Instant::now();
+    let mut last_mode = None;
+
+    while !token.is_cancelled() {
+        let frame = WatchFrame::read(device);
+        if let Ok(mode) = frame.mode {
+            last_mode = Some(mode);
+        }
+        if clear {
+            print!("{}", CLEAR_SCREEN);
+        }
+        println!("{}", frame.render(interval, started.elapsed()));
+        io::stdout().flush()?;
+        sleep_unless_cancelled(interval, &token);
+    }
+
+    println!();
+    let firing = device.read_remote_mode().map_or(last_mode == Some(DeviceMode::Remote), |mode| mode == DeviceMode::Remote);
+    if firing {
+        println!("Output was on; turning off device.");
+        device.turn_off()?;
+    }
+    println!("Watch stopped.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_shows_values_and_errors() {
+        let frame = WatchFrame {
+            state: Ok("Remote Armed (on, ready for firing)".to_string()),
+            mode: Ok(DeviceMode::Armed),
+            fire_setpoint_ma: Ok(1500),
+        };
+        let text = frame.render(Duration::from_millis(2500), Duration::from_secs(42));
+        assert!(text.starts_with("Every 2.5s, 42s elapsed"));
+        assert!(text.contains("State:            Remote Armed (on, ready for firing)"));
+        assert!(text.contains("Mode:             Armed"));
+        assert!(text.contains("FIRE setpoint:    1500mA"));
+
+        let failed = WatchFrame { fire_setpoint_ma: Err("timed out".to_string()), ..frame };
+        assert!(failed.render(Duration::from_secs(1), Duration::ZERO).contains("FIRE setpoint:    unavailable (timed out)"));
+    }
+
+    #[test]
+    fn test_sleep_returns_early_when_cancelled() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let started = Instant::now();
+        sleep_unless_cancelled(Duration::from_secs(5), &token);
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,203 @@
+//! Configuration file support for Lumidox II Controller CLI
+//!
+//! This module loads default connection settings from a `lumidox.toml`
+//! file, so a team can share one port and connection setup instead of
+//! typing the flags on every invocation:
+//!
+//! ```toml
+//! port = "COM5"
+//! auto = false
+//! verbose = true
+//! optimize_transitions = true
+//! ```
+//!
+//! The file given with `--config` is used if present. Otherwise
+//! `lumidox.toml` is searched in the current directory, then in the
+//! `lumidox` directory of the user configuration directory (for example
+//! `~/.config/lumidox/lumidox.toml` on Linux). Command-line flags override
+//! values from the file, which override the built-in defaults.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::core::{LumidoxError, Result};
+use super::args::Cli;
+
+/// Name of the configuration file searched for
+pub const CONFIG_FILE_NAME: &str = "lumidox.toml";
+
+/// Settings read from a configuration file
+///
+/// Each setting is None when the file does not set it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigFile {
+    /// Serial port to connect to
+    pub port: Option<String>,
+    /// Whether to detect the port automatically
+    pub auto: Option<bool>,
+    /// Whether to print verbose output during auto-detection
+    pub verbose: Option<bool>,
+    /// Whether to use optimized stage transitions
+    pub optimize_transitions: Option<bool>,
+}
+
+impl ConfigFile {
+    /// Load the configuration file for this invocation
+    ///
+    /// # Arguments
+    /// * `explicit` - File given with `--config`, which must exist
+    ///
+    /// # Returns
+    /// * `Result<Option<(PathBuf, ConfigFile)>>` - Path and settings of the file
+    ///   used, None if no file was found, `SystemError` for an unreadable file
+    ///   or `ConfigError` for an invalid one
+    ///
+    /// # Example
+    /// ```
+    /// if let Some((path, config)) = ConfigFile::load(cli.config.as_deref())? {
+    ///     config.apply_to(&mut cli);
+    /// }
+    /// ```
+    pub fn load(explicit: Option<&Path>) -> Result<Option<(PathBuf, ConfigFile)>> {
+        let path = match explicit {
+            Some(path) => path.to_path_buf(),
+            None => match Self::search_paths().into_iter().find(|path| path.is_file()) {
+                Some(path) => path,
+                None => return Ok(None),
+            },
+        };
+
+        let text = fs::read_to_string(&path).map_err(|e| {
+            LumidoxError::system(format!("Cannot read {}", path.display()), e)
+        })?;
+        let config = Self::parse(&text).map_err(|message| {
+            LumidoxError::ConfigError(format!("{}:{}", path.display(), message))
+        })?;
+        Ok(Some((path, config)))
+    }
+
+    /// Get the locations searched when no file is given, in order
+    ///
+    /// # Returns
+    /// * `Vec<PathBuf>` - Current directory file, then user configuration file
+    pub fn search_paths() -> Vec<PathBuf> {
+        let mut paths = vec![PathBuf::from(CONFIG_FILE_NAME)];
+        if let Some(config_dir) = dirs::config_dir() {
+            paths.push(config_dir.join("lumidox").join(CONFIG_FILE_NAME));
+        }
+        paths
+    }
+
+    /// Parse configuration file text
+    ///
+    /// # Arguments
+    /// * `text` - TOML text
+    ///
+    /// # Returns
+    /// * `std::result::Result<ConfigFile, String>` - Settings, or a message
+    ///   starting with the line and column of the problem, e.g. "3:8: ..."
+    ///
+    /// # Example
+    /// ```
+    /// let config = ConfigFile::parse("port = \"COM5\"")?;
+    /// ```
+    pub fn parse(text: &str) -> std::result::Result<ConfigFile, String> {
+        let table: toml::Table = text.parse().map_err(|e: toml::de::Error| {
+            let (line, column) = line_column(text, e.span().map_or(0, |span| span.start));
+            format!("{}:{}: {}", line, column, e.message().trim_end().replace('\n', "; "))
+        })?;
+
+        let mut config = ConfigFile::default();
+        for (key, value) in &table {
+            let location = key_location(text, key);
+            match key.as_str() {
+                "port" => config.port = Some(value.as_str()
+                    .ok_or_else(|| format!("{}: port must be a string", location))?
+                    .to_string()),
+                "auto" => config.auto = Some(expect_bool(value, key, &location)?),
+                "verbose" => config.verbose = Some(expect_bool(value, key, &location)?),
+                "optimize_transitions" => config.optimize_transitions = Some(expect_bool(value, key, &location)?),
+                _ => return Err(format!(
+                    "{}: unknown setting '{}'; expected port, auto, verbose or optimize_transitions",
+                    location, key
+                )),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Fill in the CLI arguments the command line did not set
+    ///
+    /// A port or `--auto`/`--verbose` given on the command line is kept.
+    /// `optimize_transitions` applies only when neither `--optimize` nor
+    /// `--no-optimize` was given.
+    ///
+    /// # Arguments
+    /// * `cli` - Parsed command-line arguments, updated in place
+    pub fn apply_to(&self, cli: &mut Cli) {
+        if cli.port.is_none() {
+            cli.port = self.port.clone();
+        }
+        cli.auto |= self.auto.unwrap_or(false);
+        cli.verbose |= self.verbose.unwrap_or(false);
+        if !cli.optimize && !cli.no_optimize {
+            cli.no_optimize = self.optimize_transitions == Some(false);
+        }
+    }
+}
+
+/// Read a boolean setting
+fn expect_bool(value: &toml::Value, key: &str, location: &str) -> std::result::Result<bool, String> {
+    value.as_bool().ok_or_else(|| format!("{}: {} must be true or false", location, key))
+}
+
+/// Find the "line:column" of the line setting a key, "1:1" if not found
+fn key_location(text: &str, key: &str) -> String {
+    let offset = text.lines()
+        .scan(0, |start, line| {
+            let line_start = *start;
+            *start += line.len() + 1;
+            Some((line_start, line))
+        })
+        .find(|(_, line)| line.trim_start().starts_with(key))
+        .map_or(0, |(start, line)| start + line.len() - line.trim_start().len());
+    let (line, column) = line_column(text, offset);
+    format!("{}:{}", line, column)
+}
+
+/// Convert a byte offset into a one-based line and column
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |newline| newline + 1) + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn test_command_line_overrides_file() {
+        let config = ConfigFile::parse("port = \"COM5\"\nauto = true\noptimize_transitions = false\n").unwrap();
+        assert_eq!(config.port.as_deref(), Some("COM5"));
+
+        let mut cli = Cli::try_parse_from(["lumidox", "status"]).unwrap();
+        config.apply_to(&mut cli);
+        assert_eq!(cli.port.as_deref(), Some("COM5"));
+        assert!(cli.auto);
+        assert!(!cli.optimize_transitions());
+
+        let mut cli = Cli::try_parse_from(["lumidox", "--port", "COM3", "--optimize", "status"]).unwrap();
+        config.apply_to(&mut cli);
+        assert_eq!(cli.port.as_deref(), Some("COM3"));
+        assert!(cli.optimize_transitions());
+    }
+
+    #[test]
+    fn test_invalid_file_reports_location() {
+        assert_eq!(ConfigFile::parse("port = \"COM5\"\nauto = yes\n").unwrap_err().split(':').next(), Some("2"));
+        assert!(ConfigFile::parse("\nverbose = 1").unwrap_err().starts_with("2:1: verbose must be"));
+        assert!(ConfigFile::parse("prot = \"COM5\"").unwrap_err().contains("unknown setting 'prot'"));
+    }
+}
@@ -0,0 +1,161 @@
+//! Confirmation of destructive CLI actions
+//!
+//! Firing, arming and changing device settings can be confirmed before they
+//! run. Interactive menus and single commands ask through the same helper,
+//! so `--confirm-fires` behaves alike in both, and `--yes` answers every
+//! prompt in advance.
+//!
+//! A prompt needs someone at the terminal. When standard input is not a
+//! terminal, e.g. in a scripted run, a confirmation that would prompt fails
+//! with `OperationCancelled` instead of waiting for input that never comes.
+
+use std::io::{self, IsTerminal};
+use std::sync::{Mutex, PoisonError};
+use crate::core::{LumidoxError, Result};
+use super::args::Commands;
+use super::interactive::input::{InputParser, InputProcessor};
+
+/// How destructive actions are confirmed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfirmPolicy {
+    /// Run destructive actions without asking
+    #[default]
+    Off,
+    /// Ask before each destructive action
+    Prompt,
+    /// Confirmations are enabled but answered yes in advance (`--yes`)
+    AssumeYes,
+}
+
+impl ConfirmPolicy {
+    /// Select the policy from the command-line flags
+    ///
+    /// # Arguments
+    /// * `confirm` - Whether `--confirm-fires` was given
+    /// * `assume_yes` - Whether `--yes` was given
+    ///
+    /// # Returns
+    /// * `ConfirmPolicy` - `AssumeYes` whenever `--yes` is given
+    pub fn from_flags(confirm: bool, assume_yes: bool) -> Self {
+        match (confirm, assume_yes) {
+            (_, true) => ConfirmPolicy::AssumeYes,
+            (true, false) => ConfirmPolicy::Prompt,
+            (false, false) => ConfirmPolicy::Off,
+        }
+    }
+
+    /// Check whether destructive actions are asked about
+    ///
+    /// # Returns
+    /// * `bool` - True only for `Prompt`
+    pub fn prompts(self) -> bool {
+        self == ConfirmPolicy::Prompt
+    }
+}
+
+/// Confirmation policy for this process
+static CONFIRM_POLICY: Mutex<ConfirmPolicy> = Mutex::new(ConfirmPolicy::Off);
+
+/// Set the confirmation policy for this process
+///
+/// # Arguments
+/// * `policy` - How destructive actions are confirmed from now on
+///
+/// # Example
+/// ```
+/// set_confirm_policy(ConfirmPolicy::from_flags(cli.confirm_fires, cli.yes));
+/// ```
+pub fn set_confirm_policy(policy: ConfirmPolicy) {
+    *CONFIRM_POLICY.lock().unwrap_or_else(PoisonError::into_inner) = policy;
+}
+
+/// Get the confirmation policy for this process
+///
+/// # Returns
+/// * `ConfirmPolicy` - Current policy, `Off` unless set
+pub fn confirm_policy() -> ConfirmPolicy {
+    *CONFIRM_POLICY.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Format the question asked before an action
+///
+/// # Arguments
+/// * `action` - What is about to happen, e.g. "Fire stage 3"
+///
+/// # Returns
+/// * `String` - Prompt such as "Fire stage 3? [y/N]: "
+pub fn prompt(action: &str) -> String {
+    format!("{}? [y/N]: ", action)
+}
+
+/// Ask whether to go ahead with an action
+///
+/// Any answer other than an explicit yes, including an empty answer,
+/// declines.
+///
+/// # Arguments
+/// * `action` - What is about to happen, e.g. "Fire stage 3"
+///
+/// # Returns
+/// * `Result<bool>` - True to go ahead, `OperationCancelled` if standard
+///   input is not a terminal, or input error
+pub fn ask(action: &str) -> Result<bool> {
+    if !io::stdin().is_terminal() {
+        return Err(LumidoxError::OperationCancelled(format!(
+            "{} needs confirmation but standard input is not a terminal; pass --yes to run unattended",
+            action
+        )));
+    }
+    let input = InputProcessor::get_user_input(&prompt(action))?;
+    Ok(InputParser::parse_yes_no(&input).unwrap_or(false))
+}
+
+/// Confirm a command before it runs, if it is destructive
+///
+/// Asks only under the `Prompt` policy; non-destructive commands always
+/// go ahead.
+///
+/// # Arguments
+/// * `command` - Command about to run
+///
+/// # Returns
+/// * `Result<()>` - Ok to go ahead, `OperationCancelled` if declined or if
+///   no one can be asked, or input error
+///
+/// # Example
+/// ```
+/// confirm_command(&Commands::Shutdown)?;
+/// device.shutdown()?;
+/// ```
+pub fn confirm_command(command: &Commands) -> Result<()> {
+    let Some(action) = command.destructive_action() else {
+        return Ok(());
+    };
+    if !confirm_policy().prompts() || ask(&action)? {
+        Ok(())
+    } else {
+        Err(LumidoxError::OperationCancelled(format!("{} cancelled", action)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yes_flag_overrides_prompting() {
+        assert_eq!(ConfirmPolicy::from_flags(false, false), ConfirmPolicy::Off);
+        assert_eq!(ConfirmPolicy::from_flags(true, false), ConfirmPolicy::Prompt);
+        assert_eq!(ConfirmPolicy::from_flags(true, true), ConfirmPolicy::AssumeYes);
+        assert_eq!(ConfirmPolicy::from_flags(false, true), ConfirmPolicy::AssumeYes);
+        assert!(!ConfirmPolicy::AssumeYes.prompts());
+    }
+
+    #[test]
+    fn test_non_destructive_commands_never_prompt() {
+        assert!(confirm_command(&Commands::Status).is_ok());
+        assert!(confirm_command(&Commands::Stop).is_ok());
+        assert!(Commands::SetArmCurrent { value: 200 }.is_destructive());
+        assert!(!Commands::Off.is_destructive());
+    }
+}
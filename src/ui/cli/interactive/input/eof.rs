@@ -0,0 +1,76 @@
+//! End of input in interactive mode
+//!
+//! When the interactive menus are driven from a pipe that ends, or the
+//! terminal is closed mid-session, standard input reaches end of file and
+//! every later read returns nothing at once. Rather than re-prompting in a
+//! loop, the read fails with `LumidoxError::EndOfInput` and the session ends
+//! cleanly, turning the device off first. What else is done to the device on
+//! the way out is set with `--on-eof`.
+
+use std::sync::{Mutex, PoisonError};
+use clap::ValueEnum;
+use crate::core::{LumidoxError, Result};
+
+/// What to do with the device when interactive input ends
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EofAction {
+    /// Turn the output off and return the device to local mode
+    #[default]
+    Shutdown,
+    /// Turn the output off but leave the device in remote mode
+    Off,
+}
+
+/// Action taken when interactive input ends
+static EOF_ACTION: Mutex<EofAction> = Mutex::new(EofAction::Shutdown);
+
+/// Set the action taken when interactive input ends
+///
+/// # Arguments
+/// * `action` - What to do with the device at end of input
+///
+/// # Example
+/// ```
+/// set_eof_action(cli.on_eof);
+/// ```
+pub fn set_eof_action(action: EofAction) {
+    *EOF_ACTION.lock().unwrap_or_else(PoisonError::into_inner) = action;
+}
+
+/// Get the action taken when interactive input ends
+///
+/// # Returns
+/// * `EofAction` - Current action, `Shutdown` unless set
+pub fn eof_action() -> EofAction {
+    *EOF_ACTION.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Check a line read from standard input for end of input
+///
+/// A line the user entered always ends with a newline, even when empty, so
+/// only end of input reads as an empty string.
+///
+/// # Arguments
+/// * `line` - Line as read, including its newline
+///
+/// # Returns
+/// * `Result<String>` - The line unchanged, or `EndOfInput` if it is empty
+pub fn check_end_of_input(line: String) -> Result<String> {
+    if line.is_empty() {
+        Err(LumidoxError::EndOfInput)
+    } else {
+        Ok(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_an_empty_read_is_end_of_input() {
+        assert!(matches!(check_end_of_input(String::new()), Err(LumidoxError::EndOfInput)));
+        assert_eq!(check_end_of_input("\n".to_string()).unwrap(), "\n");
+        assert_eq!(check_end_of_input("3\n".to_string()).unwrap(), "3\n");
+    }
+}
@@ -0,0 +1,113 @@
+//! Idle timeout for interactive input
+//!
+//! In a shared lab, an interactive session left open keeps the serial port
+//! locked for everyone else. With an idle timeout set, every line read by
+//! the interactive menus waits at most that long; when it expires the read
+//! fails with `LumidoxError::IdleTimeout` and the session shuts the device
+//! down and releases the port. Each line entered starts a new wait.
+//!
+//! A blocking stdin read cannot be abandoned, so with a timeout set, lines
+//! are read by a background thread and handed over through a channel. The
+//! thread lives until the process exits.
+
+use std::io;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::thread;
+use std::time::Duration;
+use crate::core::{LumidoxError, Result};
+
+/// Idle timeout applied to interactive input, None to wait forever
+static IDLE_TIMEOUT: Mutex<Option<Duration>> = Mutex::new(None);
+
+/// Lines read from stdin by the background reader
+static STDIN_LINES: OnceLock<Mutex<Receiver<io::Result<String>>>> = OnceLock::new();
+
+/// Set the idle timeout for interactive input
+///
+/// # Arguments
+/// * `timeout` - Longest wait for a line of input, None to wait forever
+///
+/// # Example
+/// ```
+/// set_idle_timeout(Some(Duration::from_secs(600)));
+/// ```
+pub fn set_idle_timeout(timeout: Option<Duration>) {
+    *IDLE_TIMEOUT.lock().unwrap_or_else(PoisonError::into_inner) = timeout;
+}
+
+/// Get the idle timeout for interactive input
+///
+/// # Returns
+/// * `Option<Duration>` - Longest wait for a line of input, None if unlimited
+pub fn idle_timeout() -> Option<Duration> {
+    *IDLE_TIMEOUT.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Read a line of input, giving up after the idle timeout
+///
+/// # Returns
+/// * `Result<String>` - Line including its newline, an empty string at end
+///   of input, `IdleTimeout` if no line was entered in time, or I/O error
+pub fn read_line() -> Result<String> {
+    let Some(timeout) = idle_timeout() else {
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        return Ok(input);
+    };
+
+    let lines = STDIN_LINES
+        .get_or_init(|| Mutex::new(spawn_stdin_reader()))
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    next_line(&lines, timeout)
+}
+
+/// Wait for the next line from the reader
+fn next_line(lines: &Receiver<io::Result<String>>, timeout: Duration) -> Result<String> {
+    match lines.recv_timeout(timeout) {
+        Ok(line) => Ok(line?),
+        Err(RecvTimeoutError::Timeout) => Err(LumidoxError::IdleTimeout { timeout }),
+        // The reader stops at end of input, which reads as an empty line
+        Err(RecvTimeoutError::Disconnected) => Ok(String::new()),
+    }
+}
+
+/// Start the thread reading stdin line by line
+fn spawn_stdin_reader() -> Receiver<io::Result<String>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || loop {
+        let mut input = String::new();
+        match io::stdin().read_line(&mut input) {
+            Ok(0) => break,
+            Ok(_) => {
+                if sender.send(Ok(input)).is_err() {
+                    break;
+                }
+            }
+            Err(e) => {
+                let _ = sender.send(Err(e));
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_line_times_out_without_input() {
+        let (sender, receiver) = mpsc::channel();
+        let timeout = Duration::from_millis(20);
+        assert!(matches!(next_line(&receiver, timeout), Err(LumidoxError::IdleTimeout { .. })));
+
+        sender.send(Ok("3\n".to_string())).unwrap();
+        assert_eq!(next_line(&receiver, timeout).unwrap(), "3\n");
+
+        drop(sender);
+        assert_eq!(next_line(&receiver, timeout).unwrap(), "");
+    }
+}
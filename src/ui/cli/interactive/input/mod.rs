@@ -3,6 +3,8 @@
 //! This module organizes input processing functionality into specialized components:
 //! - `validation`: Input validation utilities and error checking
 //! - `parsing`: Input parsing and data type conversion utilities
+//! - `idle`: Idle timeout that ends an abandoned session
+//! - `eof`: End of input, which ends the session
 //!
 //! The input processing system provides:
 //! - Comprehensive input validation with detailed error messages
@@ -12,6 +14,8 @@
 
 pub mod validation;
 pub mod parsing;
+pub mod idle;
+pub mod eof;
 
 // Re-export commonly used items for convenience
 pub use validation::InputValidator;
@@ -42,10 +46,24 @@ impl InputProcessor {
         print!("{}", prompt);
         io::stdout().flush()?;
         
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        
-        Ok(input)
+        Self::read_line()
+    }
+    
+    /// Read a line of input
+    /// 
+    /// Waits at most the idle timeout, if one is set.
+    /// 
+    /// # Returns
+    /// * `Result<String>` - Line including its newline, `IdleTimeout` if no
+    ///   line was entered in time, `EndOfInput` once standard input has
+    ///   ended, or I/O error
+    /// 
+    /// # Example
+    /// ```
+    /// let input = InputProcessor::read_line()?;
+    /// ```
+    pub fn read_line() -> Result<String> {
+        eof::check_end_of_input(idle::read_line()?)
     }
     
     /// Get validated menu choice from user
@@ -38,7 +38,7 @@ impl InputParser {
     /// ```
     pub fn parse_menu_choice(input: &str) -> Result<MenuChoice> {
         let choice_str = InputValidator::validate_choice_format(input)?;
-        let choice_num = InputValidator::validate_choice_range(&choice_str, 1, 16)?;
+        let choice_num = InputValidator::validate_choice_range(&choice_str, 1, 17)?;
         
         let category = Self::determine_choice_category(choice_num);
         let action = Self::determine_choice_action(choice_num);
@@ -130,7 +130,7 @@ impl InputParser {
     pub fn determine_choice_category(choice_num: u8) -> MenuCategory {
         match choice_num {
             1..=6 => MenuCategory::Stage,
-            7..=8 => MenuCategory::Device,
+            7..=8 | 17 => MenuCategory::Device,
             9..=16 => MenuCategory::Information,
             _ => MenuCategory::Invalid,
         }
@@ -168,6 +168,7 @@ impl InputParser {
             14 => MenuAction::ReadStageVoltageParameters,
             15 => MenuAction::SetArmCurrent,
             16 => MenuAction::ShutdownAndQuit,
+            17 => MenuAction::ReconnectSamePort,
             _ => MenuAction::Invalid,
         }
     }
@@ -356,6 +357,8 @@ pub enum MenuAction {
     ReadStageVoltageParameters,
     /// Set ARM current
     SetArmCurrent,
+    /// Reconnect to the same port and restore the session currents
+    ReconnectSamePort,
     /// Invalid action
     Invalid,
 }
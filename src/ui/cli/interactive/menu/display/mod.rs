@@ -3,6 +3,7 @@
 //! This module organizes menu display functionality into specialized components:
 //! - `stage_options`: Stage firing and custom current option display
 //! - `status_options`: Device status, information, and control option display
+//! - `strings`: Replaceable catalog of user-facing menu strings
 //!
 //! The display system provides:
 //! - Organized menu option display with proper formatting
@@ -12,10 +13,12 @@
 
 pub mod stage_options;
 pub mod status_options;
+pub mod strings;
 
 // Re-export commonly used items for convenience
 pub use stage_options::StageOptionsDisplay;
 pub use status_options::StatusOptionsDisplay;
+pub use strings::MenuStrings;
 
 use crate::core::Result;
 use crate::device::LumidoxDevice;
@@ -31,23 +34,24 @@ impl MenuDisplay {
     /// 
     /// # Arguments
     /// * `device` - Reference to the device for dynamic information display
+    /// * `strings` - Menu string catalog used for all displayed text
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error if device information cannot be retrieved
     /// 
     /// # Example
     /// ```
-    /// MenuDisplay::display_complete_menu(&device)?;
+    /// MenuDisplay::display_complete_menu(&mut device, &MenuStrings::default())?;
     /// ```
-    pub fn display_complete_menu(device: &mut LumidoxDevice) -> Result<()> {
-        println!("-- Select an action --");
+    pub fn display_complete_menu(device: &mut LumidoxDevice, strings: &MenuStrings) -> Result<()> {
+        println!("{}", strings.header);
         println!();
         
         // Display stage options
-        StageOptionsDisplay::display_all_stage_options(device)?;
+        StageOptionsDisplay::display_all_stage_options(device, strings)?;
         
         // Display status and control options
-        StatusOptionsDisplay::display_all_status_options()?;
+        StatusOptionsDisplay::display_all_status_options(strings)?;
         
         println!();
         Ok(())
@@ -57,15 +61,18 @@ impl MenuDisplay {
     /// 
     /// Shows the main menu header with action selection prompt.
     /// 
+    /// # Arguments
+    /// * `strings` - Menu string catalog providing the header text
+    /// 
     /// # Returns
     /// * `Result<()>` - Always succeeds for display operations
     /// 
     /// # Example
     /// ```
-    /// MenuDisplay::display_header()?;
+    /// MenuDisplay::display_header(&MenuStrings::default())?;
     /// ```
-    pub fn display_header() -> Result<()> {
-        println!("-- Select an action --");
+    pub fn display_header(strings: &MenuStrings) -> Result<()> {
+        println!("{}", strings.header);
         Ok(())
     }
     
@@ -73,16 +80,19 @@ impl MenuDisplay {
     /// 
     /// Shows the input prompt asking user to enter their choice.
     /// 
+    /// # Arguments
+    /// * `strings` - Menu string catalog providing the prompt text
+    /// 
     /// # Returns
     /// * `Result<()>` - Success or I/O error from stdout flush
     /// 
     /// # Example
     /// ```
-    /// MenuDisplay::display_input_prompt()?;
+    /// MenuDisplay::display_input_prompt(&MenuStrings::default())?;
     /// ```
-    pub fn display_input_prompt() -> Result<()> {
+    pub fn display_input_prompt(strings: &MenuStrings) -> Result<()> {
         use std::io::{self, Write};
-        print!("Please enter choice number, then press ENTER: ");
+        print!("{}", strings.choice_prompt);
         io::stdout().flush()?;
         Ok(())
     }
@@ -138,16 +148,19 @@ impl MenuDisplay {
     /// 
     /// Shows an error message for invalid menu choices.
     /// 
+    /// # Arguments
+    /// * `strings` - Menu string catalog providing the message text
+    /// 
     /// # Returns
     /// * `Result<()>` - Always succeeds for display operations
     /// 
     /// # Example
     /// ```
-    /// MenuDisplay::display_invalid_choice_message()?;
+    /// MenuDisplay::display_invalid_choice_message(&MenuStrings::default())?;
     /// ```
-    pub fn display_invalid_choice_message() -> Result<()> {
+    pub fn display_invalid_choice_message(strings: &MenuStrings) -> Result<()> {
         println!();
-        println!("Not a valid choice. Please try again.");
+        println!("{}", strings.invalid_choice);
         println!();
         Ok(())
     }
@@ -159,23 +172,24 @@ impl MenuDisplay {
     /// # Arguments
     /// * `choice` - Menu choice string
     /// * `device` - Reference to device for dynamic descriptions
+    /// * `strings` - Menu string catalog used for option labels
     /// 
     /// # Returns
     /// * `Result<Option<String>>` - Description if choice is valid, None otherwise
     /// 
     /// # Example
     /// ```
-    /// if let Ok(Some(desc)) = MenuDisplay::get_option_description("1", &device) {
+    /// if let Ok(Some(desc)) = MenuDisplay::get_option_description("1", &mut device, &MenuStrings::default()) {
     ///     println!("Option 1: {}", desc);
     /// }
     /// ```
-    pub fn get_option_description(choice: &str, device: &mut LumidoxDevice) -> Result<Option<String>> {
+    pub fn get_option_description(choice: &str, device: &mut LumidoxDevice, strings: &MenuStrings) -> Result<Option<String>> {
         if let Some(stage) = StageOptionsDisplay::parse_stage_number(choice) {
-            Ok(Some(StageOptionsDisplay::get_stage_description(device, stage)?))
+            Ok(Some(StageOptionsDisplay::get_stage_description(device, stage, strings)?))
         } else if StageOptionsDisplay::is_custom_current_option(choice) {
-            Ok(Some(StageOptionsDisplay::get_custom_current_description(device)?))
+            Ok(Some(StageOptionsDisplay::get_custom_current_description(device, strings)?))
         } else {
-            Ok(StatusOptionsDisplay::get_option_description(choice))
+            Ok(StatusOptionsDisplay::get_option_description(choice, strings))
         }
     }
     
@@ -186,25 +200,26 @@ impl MenuDisplay {
     /// # Arguments
     /// * `device` - Reference to the device for dynamic information
     /// * `sections` - List of section names to display
+    /// * `strings` - Menu string catalog used for all displayed text
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error if device information cannot be retrieved
     /// 
     /// # Example
     /// ```
-    /// MenuDisplay::display_sections(&device, &["stage", "control"])?;
+    /// MenuDisplay::display_sections(&mut device, &["stage", "control"], &MenuStrings::default())?;
     /// ```
-    pub fn display_sections(device: &mut LumidoxDevice, sections: &[&str]) -> Result<()> {
+    pub fn display_sections(device: &mut LumidoxDevice, sections: &[&str], strings: &MenuStrings) -> Result<()> {
         for section in sections {
             match *section {
-                "header" => Self::display_header()?,
-                "stage" => StageOptionsDisplay::display_all_stage_options(device)?,
-                "control" => StatusOptionsDisplay::display_control_options()?,
-                "status" => StatusOptionsDisplay::display_status_options()?,
-                "parameter" => StatusOptionsDisplay::display_parameter_options()?,
-                "current_control" => StatusOptionsDisplay::display_current_control_options()?,
+                "header" => Self::display_header(strings)?,
+                "stage" => StageOptionsDisplay::display_all_stage_options(device, strings)?,
+                "control" => StatusOptionsDisplay::display_control_options(strings)?,
+                "status" => StatusOptionsDisplay::display_status_options(strings)?,
+                "parameter" => StatusOptionsDisplay::display_parameter_options(strings)?,
+                "current_control" => StatusOptionsDisplay::display_current_control_options(strings)?,
                 _ => {
-                    println!("Unknown section: {}", section);
+                    println!("{}: {}", strings.unknown_section, section);
                 }
             }
         }
@@ -225,7 +240,7 @@ impl MenuDisplay {
     pub fn get_all_choices() -> Vec<&'static str> {
         vec![
             "1", "2", "3", "4", "5", "6",  // Stage options
-            "7", "8",                       // Control options (arm, turn off)
+            "7", "8", "17",                 // Control options (arm, turn off, reconnect)
             "9", "10", "11",                // Status options
             "12", "13", "14",               // Parameter options
             "15",                           // Current control options
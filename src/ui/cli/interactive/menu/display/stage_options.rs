@@ -13,6 +13,7 @@
 
 use crate::core::{Result, calculations::IrradianceCalculator};
 use crate::device::LumidoxDevice;
+use super::strings::MenuStrings;
 
 /// Stage options display utilities and functionality
 pub struct StageOptionsDisplay;
@@ -26,45 +27,19 @@ impl StageOptionsDisplay {
     /// 
     /// # Arguments
     /// * `device` - Reference to the device for power information queries
+    /// * `strings` - Menu string catalog used for option labels
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error if power information cannot be retrieved
     /// 
     /// # Example
     /// ```
-    /// StageOptionsDisplay::display_stage_options(&device)?;
+    /// StageOptionsDisplay::display_stage_options(&mut device, &MenuStrings::default())?;
     /// ```
-    pub fn display_stage_options(device: &mut LumidoxDevice) -> Result<()> {
+    pub fn display_stage_options(device: &mut LumidoxDevice, strings: &MenuStrings) -> Result<()> {
         // Display stage options with power info, current info, and mW/cm²
         for stage in 1..=5 {
-            // Try to get both power info and current info
-            let power_info_result = device.get_power_info(stage);
-            let fire_current_result = device.get_stage_fire_current(stage);
-            
-            match (power_info_result, fire_current_result) {
-                (Ok(power_info), Ok(fire_current)) => {
-                    // Display with both power, current info, and mW/cm²
-                    let irradiance_display = IrradianceCalculator::get_irradiance_display(&power_info);
-                    println!("{}) Turn on stage {}: {}mA, {} {}, {} {}{}", 
-                        stage, stage, fire_current, power_info.total_power, power_info.total_units, 
-                        power_info.per_power, power_info.per_units, irradiance_display);
-                }
-                (Ok(power_info), Err(_)) => {
-                    // Display with power info and mW/cm² only
-                    let irradiance_display = IrradianceCalculator::get_irradiance_display(&power_info);
-                    println!("{}) Turn on stage {}: {} {}, {} {}{}", 
-                        stage, stage, power_info.total_power, power_info.total_units, 
-                        power_info.per_power, power_info.per_units, irradiance_display);
-                }
-                (Err(_), Ok(fire_current)) => {
-                    // Display with current info only
-                    println!("{}) Turn on stage {}: {}mA", stage, stage, fire_current);
-                }
-                (Err(_), Err(_)) => {
-                    // Display basic info only
-                    println!("{}) Turn on stage {}", stage, stage);
-                }
-            }
+            println!("{}", Self::format_stage_option(device, stage, strings));
         }
         
         Ok(())
@@ -78,21 +53,17 @@ impl StageOptionsDisplay {
     /// 
     /// # Arguments
     /// * `device` - Reference to the device for maximum current query
+    /// * `strings` - Menu string catalog used for the option label
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error if maximum current cannot be retrieved
     /// 
     /// # Example
     /// ```
-    /// StageOptionsDisplay::display_custom_current_option(&device)?;
+    /// StageOptionsDisplay::display_custom_current_option(&mut device, &MenuStrings::default())?;
     /// ```
-    pub fn display_custom_current_option(device: &mut LumidoxDevice) -> Result<()> {
-        if let Ok(max_current) = device.get_max_current() {
-            println!("6) Turn on stage with specific current (up to {}mA).", max_current);
-        } else {
-            println!("6) Turn on stage with specific current.");
-        }
-        
+    pub fn display_custom_current_option(device: &mut LumidoxDevice, strings: &MenuStrings) -> Result<()> {
+        println!("{}", Self::get_custom_current_description(device, strings)?);
         Ok(())
     }
     
@@ -103,17 +74,18 @@ impl StageOptionsDisplay {
     /// 
     /// # Arguments
     /// * `device` - Reference to the device for information queries
+    /// * `strings` - Menu string catalog used for option labels
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error if device information cannot be retrieved
     /// 
     /// # Example
     /// ```
-    /// StageOptionsDisplay::display_all_stage_options(&device)?;
+    /// StageOptionsDisplay::display_all_stage_options(&mut device, &MenuStrings::default())?;
     /// ```
-    pub fn display_all_stage_options(device: &mut LumidoxDevice) -> Result<()> {
-        Self::display_stage_options(device)?;
-        Self::display_custom_current_option(device)?;
+    pub fn display_all_stage_options(device: &mut LumidoxDevice, strings: &MenuStrings) -> Result<()> {
+        Self::display_stage_options(device, strings)?;
+        Self::display_custom_current_option(device, strings)?;
         Ok(())
     }
     
@@ -125,22 +97,41 @@ impl StageOptionsDisplay {
     /// # Arguments
     /// * `device` - Reference to the device for power information
     /// * `stage` - Stage number (1-5)
+    /// * `strings` - Menu string catalog used for the option label
     /// 
     /// # Returns
     /// * `Result<String>` - Formatted stage description or error
     /// 
     /// # Example
     /// ```
-    /// let description = StageOptionsDisplay::get_stage_description(&device, 1)?;
+    /// let description = StageOptionsDisplay::get_stage_description(&mut device, 1, &MenuStrings::default())?;
     /// println!("{}", description);
     /// ```
-    pub fn get_stage_description(device: &mut LumidoxDevice, stage: u8) -> Result<String> {
+    pub fn get_stage_description(device: &mut LumidoxDevice, stage: u8, strings: &MenuStrings) -> Result<String> {
         if !(1..=5).contains(&stage) {
             return Err(crate::core::LumidoxError::InvalidInput(
                 format!("Invalid stage number: {}. Must be 1-5.", stage)
             ));
         }
         
+        Ok(Self::format_stage_option(device, stage, strings))
+    }
+    
+    /// Format a stage option line with available power and current information
+    /// 
+    /// Queries the device for the stage's power and FIRE current and includes
+    /// whichever values are available after the catalog label.
+    /// 
+    /// # Arguments
+    /// * `device` - Reference to the device for power information
+    /// * `stage` - Stage number (1-5)
+    /// * `strings` - Menu string catalog used for the option label
+    /// 
+    /// # Returns
+    /// * `String` - Formatted stage option line
+    fn format_stage_option(device: &mut LumidoxDevice, stage: u8, strings: &MenuStrings) -> String {
+        let label = strings.stage_option_label(stage);
+        
         // Try to get both power info and current info
         let power_info_result = device.get_power_info(stage);
         let fire_current_result = device.get_stage_fire_current(stage);
@@ -149,24 +140,24 @@ impl StageOptionsDisplay {
             (Ok(power_info), Ok(fire_current)) => {
                 // Include both power, current info, and mW/cm²
                 let irradiance_display = IrradianceCalculator::get_irradiance_display(&power_info);
-                Ok(format!("{}) Turn on stage {}: {}mA, {} {}, {} {}{}", 
-                    stage, stage, fire_current, power_info.total_power, power_info.total_units, 
-                    power_info.per_power, power_info.per_units, irradiance_display))
+                format!("{}: {}mA, {} {}, {} {}{}", 
+                    label, fire_current, power_info.total_power, power_info.total_units, 
+                    power_info.per_power, power_info.per_units, irradiance_display)
             }
             (Ok(power_info), Err(_)) => {
                 // Include power info and mW/cm² only
                 let irradiance_display = IrradianceCalculator::get_irradiance_display(&power_info);
-                Ok(format!("{}) Turn on stage {}: {} {}, {} {}{}", 
-                    stage, stage, power_info.total_power, power_info.total_units, 
-                    power_info.per_power, power_info.per_units, irradiance_display))
+                format!("{}: {} {}, {} {}{}", 
+                    label, power_info.total_power, power_info.total_units, 
+                    power_info.per_power, power_info.per_units, irradiance_display)
             }
             (Err(_), Ok(fire_current)) => {
                 // Include current info only
-                Ok(format!("{}) Turn on stage {}: {}mA", stage, stage, fire_current))
+                format!("{}: {}mA", label, fire_current)
             }
             (Err(_), Err(_)) => {
                 // Basic info only
-                Ok(format!("{}) Turn on stage {}", stage, stage))
+                label
             }
         }
     }
@@ -178,20 +169,21 @@ impl StageOptionsDisplay {
     /// 
     /// # Arguments
     /// * `device` - Reference to the device for maximum current information
+    /// * `strings` - Menu string catalog used for the option label
     /// 
     /// # Returns
     /// * `Result<String>` - Formatted custom current description or error
     /// 
     /// # Example
     /// ```
-    /// let description = StageOptionsDisplay::get_custom_current_description(&device)?;
+    /// let description = StageOptionsDisplay::get_custom_current_description(&mut device, &MenuStrings::default())?;
     /// println!("{}", description);
     /// ```
-    pub fn get_custom_current_description(device: &mut LumidoxDevice) -> Result<String> {
+    pub fn get_custom_current_description(device: &mut LumidoxDevice, strings: &MenuStrings) -> Result<String> {
         if let Ok(max_current) = device.get_max_current() {
-            Ok(format!("6) Turn on stage with specific current (up to {}mA).", max_current))
+            Ok(format!("6) {} ({} {}mA).", strings.custom_current, strings.up_to, max_current))
         } else {
-            Ok("6) Turn on stage with specific current.".to_string())
+            Ok(format!("6) {}.", strings.custom_current))
         }
     }
     
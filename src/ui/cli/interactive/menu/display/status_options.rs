@@ -13,6 +13,7 @@
 //! - Formatted output for menu organization
 
 use crate::core::Result;
+use super::strings::MenuStrings;
 
 /// Status and information options display utilities and functionality
 pub struct StatusOptionsDisplay;
@@ -23,16 +24,20 @@ impl StatusOptionsDisplay {
     /// Shows basic device control options including arm and turn off.
     /// Quit option is moved to the end after all other options.
     /// 
+    /// # Arguments
+    /// * `strings` - Menu string catalog used for option labels
+    /// 
     /// # Returns
     /// * `Result<()>` - Always succeeds for display operations
     /// 
     /// # Example
     /// ```
-    /// StatusOptionsDisplay::display_control_options()?;
+    /// StatusOptionsDisplay::display_control_options(&MenuStrings::default())?;
     /// ```
-    pub fn display_control_options() -> Result<()> {
-        println!("7) Arm device (prepare for firing).");
-        println!("8) Turn off device.");
+    pub fn display_control_options(strings: &MenuStrings) -> Result<()> {
+        println!("{}", strings.option_line("7").unwrap_or_default());
+        println!("{}", strings.option_line("8").unwrap_or_default());
+        println!("{}", strings.option_line("17").unwrap_or_default());
         Ok(())
     }
       /// Display device status and information options
@@ -40,18 +45,21 @@ impl StatusOptionsDisplay {
     /// Shows options for reading device status, remote mode state,
     /// and current settings information.
     /// 
+    /// # Arguments
+    /// * `strings` - Menu string catalog used for option labels
+    /// 
     /// # Returns
     /// * `Result<()>` - Always succeeds for display operations
     /// 
     /// # Example
     /// ```
-    /// StatusOptionsDisplay::display_status_options()?;
+    /// StatusOptionsDisplay::display_status_options(&MenuStrings::default())?;
     /// ```
-    pub fn display_status_options() -> Result<()> {
-        println!("--- Device Status & Information ---");
-        println!("9) Show device status.");
-        println!("10) Read remote mode state.");
-        println!("11) Read ARM/FIRE current settings.");
+    pub fn display_status_options(strings: &MenuStrings) -> Result<()> {
+        println!("{}", strings.status_section);
+        println!("{}", strings.option_line("9").unwrap_or_default());
+        println!("{}", strings.option_line("10").unwrap_or_default());
+        println!("{}", strings.option_line("11").unwrap_or_default());
         Ok(())
     }
       /// Display stage parameter information options
@@ -59,34 +67,40 @@ impl StatusOptionsDisplay {
     /// Shows options for reading detailed stage parameter information
     /// including complete parameters, ARM current, and voltage parameters.
     /// 
+    /// # Arguments
+    /// * `strings` - Menu string catalog used for option labels
+    /// 
     /// # Returns
     /// * `Result<()>` - Always succeeds for display operations
     /// 
     /// # Example
     /// ```
-    /// StatusOptionsDisplay::display_parameter_options()?;
+    /// StatusOptionsDisplay::display_parameter_options(&MenuStrings::default())?;
     /// ```
-    pub fn display_parameter_options() -> Result<()> {
-        println!("--- Stage Parameter Information ---");
-        println!("12) Show complete stage parameters.");
-        println!("13) Read stage ARM current.");
-        println!("14) Read stage voltage parameters.");
+    pub fn display_parameter_options(strings: &MenuStrings) -> Result<()> {
+        println!("{}", strings.parameter_section);
+        println!("{}", strings.option_line("12").unwrap_or_default());
+        println!("{}", strings.option_line("13").unwrap_or_default());
+        println!("{}", strings.option_line("14").unwrap_or_default());
         Ok(())
     }    /// Display current control options
     /// 
     /// Shows options for controlling device current settings
     /// including ARM current configuration.
     /// 
+    /// # Arguments
+    /// * `strings` - Menu string catalog used for option labels
+    /// 
     /// # Returns
     /// * `Result<()>` - Always succeeds for display operations
     /// 
     /// # Example
     /// ```
-    /// StatusOptionsDisplay::display_current_control_options()?;
+    /// StatusOptionsDisplay::display_current_control_options(&MenuStrings::default())?;
     /// ```
-    pub fn display_current_control_options() -> Result<()> {
-        println!("--- Current Control ---");
-        println!("15) Set ARM current.");
+    pub fn display_current_control_options(strings: &MenuStrings) -> Result<()> {
+        println!("{}", strings.current_control_section);
+        println!("{}", strings.option_line("15").unwrap_or_default());
         Ok(())
     }
     
@@ -94,16 +108,19 @@ impl StatusOptionsDisplay {
     /// 
     /// Shows the quit program option at the end of the menu.
     /// 
+    /// # Arguments
+    /// * `strings` - Menu string catalog used for option labels
+    /// 
     /// # Returns
     /// * `Result<()>` - Always succeeds for display operations
     /// 
     /// # Example
     /// ```
-    /// StatusOptionsDisplay::display_quit_option()?;
+    /// StatusOptionsDisplay::display_quit_option(&MenuStrings::default())?;
     /// ```
-    pub fn display_quit_option() -> Result<()> {
+    pub fn display_quit_option(strings: &MenuStrings) -> Result<()> {
         println!();
-        println!("16) Quit program.");
+        println!("{}", strings.option_line("16").unwrap_or_default());
         Ok(())
     }
       /// Display all status and information options
@@ -111,21 +128,24 @@ impl StatusOptionsDisplay {
     /// Combines all status, information, and control options into
     /// a complete non-stage options display section.
     /// 
+    /// # Arguments
+    /// * `strings` - Menu string catalog used for option labels
+    /// 
     /// # Returns
     /// * `Result<()>` - Always succeeds for display operations
     /// 
     /// # Example
     /// ```
-    /// StatusOptionsDisplay::display_all_status_options()?;
+    /// StatusOptionsDisplay::display_all_status_options(&MenuStrings::default())?;
     /// ```
-    pub fn display_all_status_options() -> Result<()> {
+    pub fn display_all_status_options(strings: &MenuStrings) -> Result<()> {
         println!();
-        Self::display_control_options()?;
+        Self::display_control_options(strings)?;
         println!();
-        Self::display_status_options()?;
-        Self::display_parameter_options()?;
-        Self::display_current_control_options()?;
-        Self::display_quit_option()?;
+        Self::display_status_options(strings)?;
+        Self::display_parameter_options(strings)?;
+        Self::display_current_control_options(strings)?;
+        Self::display_quit_option(strings)?;
         Ok(())
     }
     
@@ -136,30 +156,19 @@ impl StatusOptionsDisplay {
     /// 
     /// # Arguments
     /// * `choice` - Menu choice string
+    /// * `strings` - Menu string catalog used for option labels
     /// 
     /// # Returns
     /// * `Option<String>` - Description if choice is valid, None otherwise
     /// 
     /// # Example
     /// ```
-    /// if let Some(desc) = StatusOptionsDisplay::get_option_description("10") {
+    /// if let Some(desc) = StatusOptionsDisplay::get_option_description("10", &MenuStrings::default()) {
     ///     println!("Option 10: {}", desc);
     /// }
     /// ```
-    pub fn get_option_description(choice: &str) -> Option<String> {
-        match choice {
-            "7" => Some("Arm device (prepare for firing)".to_string()),
-            "8" => Some("Turn off device".to_string()),
-            "9" => Some("Show device status".to_string()),
-            "10" => Some("Read remote mode state".to_string()),
-            "11" => Some("Read ARM/FIRE current settings".to_string()),
-            "12" => Some("Show complete stage parameters".to_string()),
-            "13" => Some("Read stage ARM current".to_string()),
-            "14" => Some("Read stage voltage parameters".to_string()),
-            "15" => Some("Set ARM current".to_string()),
-            "16" => Some("Quit program".to_string()),
-            _ => None,
-        }
+    pub fn get_option_description(choice: &str, strings: &MenuStrings) -> Option<String> {
+        strings.option_label(choice).map(str::to_string)
     }
     
     /// Check if a choice is a valid control option
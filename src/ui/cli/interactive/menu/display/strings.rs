@@ -0,0 +1,190 @@
+//! Menu string catalog for interactive CLI
+//!
+//! This module centralizes the user-facing strings shown by the interactive
+//! menu so they can be replaced without modifying the menu logic. The default
+//! catalog provides the English strings used by the application.
+//!
+//! The string catalog provides:
+//! - Menu header, prompt, and error message text
+//! - Section headings for grouped menu options
+//! - Option labels for stage, control, status, and parameter choices
+//! - Helpers for composing numbered option lines
+
+/// Catalog of user-facing strings displayed by the interactive menu
+///
+/// All fields are owned strings so a consumer can load translations at
+/// runtime (for example from a configuration file) and pass the catalog
+/// to the `_with_strings` menu entry points. Numbered prefixes such as
+/// `"7) "` and trailing punctuation are added by the display layer and
+/// should not be included in the labels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuStrings {
+    /// Header shown above the menu options
+    pub header: String,
+    /// Prompt shown when asking for a menu choice
+    pub choice_prompt: String,
+    /// Message shown for an invalid menu choice
+    pub invalid_choice: String,
+    /// Label prefix for stage firing options (followed by the stage number)
+    pub turn_on_stage: String,
+    /// Label for the custom current firing option
+    pub custom_current: String,
+    /// Qualifier used before the maximum current of the custom current option
+    pub up_to: String,
+    /// Label for the arm device option
+    pub arm_device: String,
+    /// Label for the turn off device option
+    pub turn_off_device: String,
+    /// Label for the reconnect to the same port option
+    pub reconnect: String,
+    /// Heading of the device status and information section
+    pub status_section: String,
+    /// Label for the show device status option
+    pub show_status: String,
+    /// Label for the read remote mode state option
+    pub read_remote_mode: String,
+    /// Label for the read ARM/FIRE current settings option
+    pub read_current_settings: String,
+    /// Heading of the stage parameter information section
+    pub parameter_section: String,
+    /// Label for the show complete stage parameters option
+    pub show_stage_parameters: String,
+    /// Label for the read stage ARM current option
+    pub read_stage_arm_current: String,
+    /// Label for the read stage voltage parameters option
+    pub read_stage_voltages: String,
+    /// Heading of the current control section
+    pub current_control_section: String,
+    /// Label for the set ARM current option
+    pub set_arm_current: String,
+    /// Label for the quit option
+    pub quit: String,
+    /// Message shown when an unknown menu section is requested
+    pub unknown_section: String,
+}
+
+impl Default for MenuStrings {
+    fn default() -> Self {
+        Self {
+            header: "-- Select an action --".to_string(),
+            choice_prompt: "Please enter choice number, then press ENTER: ".to_string(),
+            invalid_choice: "Not a valid choice. Please try again.".to_string(),
+            turn_on_stage: "Turn on stage".to_string(),
+            custom_current: "Turn on stage with specific current".to_string(),
+            up_to: "up to".to_string(),
+            arm_device: "Arm device (prepare for firing)".to_string(),
+            turn_off_device: "Turn off device".to_string(),
+            reconnect: "Reconnect to the same port (after a cable glitch)".to_string(),
+            status_section: "--- Device Status & Information ---".to_string(),
+            show_status: "Show device status".to_string(),
+            read_remote_mode: "Read remote mode state".to_string(),
+            read_current_settings: "Read ARM/FIRE current settings".to_string(),
+            parameter_section: "--- Stage Parameter Information ---".to_string(),
+            show_stage_parameters: "Show complete stage parameters".to_string(),
+            read_stage_arm_current: "Read stage ARM current".to_string(),
+            read_stage_voltages: "Read stage voltage parameters".to_string(),
+            current_control_section: "--- Current Control ---".to_string(),
+            set_arm_current: "Set ARM current".to_string(),
+            quit: "Quit program".to_string(),
+            unknown_section: "Unknown section".to_string(),
+        }
+    }
+}
+
+impl MenuStrings {
+    /// Get the numbered label for a stage firing option
+    ///
+    /// # Arguments
+    /// * `stage` - Stage number (1-5)
+    ///
+    /// # Returns
+    /// * `String` - Label such as "3) Turn on stage 3"
+    ///
+    /// # Example
+    /// ```
+    /// let strings = MenuStrings::default();
+    /// assert_eq!(strings.stage_option_label(3), "3) Turn on stage 3");
+    /// ```
+    pub fn stage_option_label(&self, stage: u8) -> String {
+        format!("{}) {} {}", stage, self.turn_on_stage, stage)
+    }
+
+    /// Get the label for a non-stage menu choice
+    ///
+    /// Maps the status, control, parameter, and quit choices (7-17) to
+    /// their catalog labels.
+    ///
+    /// # Arguments
+    /// * `choice` - Menu choice string
+    ///
+    /// # Returns
+    /// * `Option<&str>` - Label if the choice has one, None otherwise
+    ///
+    /// # Example
+    /// ```
+    /// let strings = MenuStrings::default();
+    /// assert_eq!(strings.option_label("16"), Some("Quit program"));
+    /// ```
+    pub fn option_label(&self, choice: &str) -> Option<&str> {
+        match choice {
+            "7" => Some(&self.arm_device),
+            "8" => Some(&self.turn_off_device),
+            "9" => Some(&self.show_status),
+            "10" => Some(&self.read_remote_mode),
+            "11" => Some(&self.read_current_settings),
+            "12" => Some(&self.show_stage_parameters),
+            "13" => Some(&self.read_stage_arm_current),
+            "14" => Some(&self.read_stage_voltages),
+            "15" => Some(&self.set_arm_current),
+            "16" => Some(&self.quit),
+            "17" => Some(&self.reconnect),
+            _ => None,
+        }
+    }
+
+    /// Get the numbered menu line for a non-stage menu choice
+    ///
+    /// # Arguments
+    /// * `choice` - Menu choice string
+    ///
+    /// # Returns
+    /// * `Option<String>` - Line such as "8) Turn off device." if the choice has a label
+    pub fn option_line(&self, choice: &str) -> Option<String> {
+        self.option_label(choice)
+            .map(|label| format!("{}) {}.", choice, label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_labels_match_original_menu() {
+        let strings = MenuStrings::default();
+        assert_eq!(strings.stage_option_label(1), "1) Turn on stage 1");
+        assert_eq!(strings.option_line("7").unwrap(), "7) Arm device (prepare for firing).");
+        assert_eq!(strings.option_line("16").unwrap(), "16) Quit program.");
+    }
+
+    #[test]
+    fn test_all_status_choices_have_labels() {
+        let strings = MenuStrings::default();
+        for choice in ["7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17"] {
+            assert!(strings.option_label(choice).is_some(), "Choice {} should have a label", choice);
+        }
+        assert!(strings.option_label("6").is_none());
+        assert!(strings.option_label("18").is_none());
+    }
+
+    #[test]
+    fn test_custom_strings_are_used() {
+        let strings = MenuStrings {
+            turn_on_stage: "Stufe einschalten".to_string(),
+            quit: "Programm beenden".to_string(),
+            ..MenuStrings::default()
+        };
+        assert_eq!(strings.stage_option_label(2), "2) Stufe einschalten 2");
+        assert_eq!(strings.option_line("16").unwrap(), "16) Programm beenden.");
+    }
+}
@@ -9,6 +9,7 @@
 //! - Device arming with status feedback
 //! - Device turn off operations
 //! - Device shutdown with proper cleanup
+//! - Reconnecting to the same port after a transient failure
 //! - Error handling and user-friendly messages
 //! - Integration with device control operations
 
@@ -149,6 +150,49 @@ impl DeviceActionHandlers {
         Ok(false) // Exit menu loop
     }
     
+    /// Handle reconnect to the same port action
+    /// 
+    /// Reopens the port of the current session without scanning for ports,
+    /// checks that the same device answered and restores the ARM and FIRE
+    /// currents recorded this session. A failure is reported and the menu
+    /// continues, so the reconnect can be tried again.
+    /// 
+    /// # Arguments
+    /// * `device` - Mutable reference to the device to reconnect
+    /// 
+    /// # Returns
+    /// * `Result<bool>` - True to continue menu loop
+    /// 
+    /// # Example
+    /// ```
+    /// let continue_menu = DeviceActionHandlers::handle_reconnect(&mut device)?;
+    /// ```
+    pub fn handle_reconnect(device: &mut LumidoxDevice) -> Result<bool> {
+        println!();
+        println!("Reconnecting to the same port...");
+
+        match device.reconnect_same_port() {
+            Ok(restored) => {
+                if let Some(info) = device.info() {
+                    println!("Reconnected to {} (S/N: {}).", info.model_number, info.serial_number);
+                }
+                if let Some(arm_ma) = restored.arm_ma {
+                    println!("ARM current restored to {}mA.", arm_ma);
+                }
+                if let Some(fire_ma) = restored.fire_ma {
+                    println!("FIRE current restored to {}mA.", fire_ma);
+                }
+            }
+            Err(e) => {
+                println!("Error reconnecting: {}", e);
+                println!("Check the cable and try again, or restart to scan for ports.");
+            }
+        }
+        println!();
+
+        Ok(true)
+    }
+    
     /// Handle device control action based on choice
     /// 
     /// Routes device control menu choices to appropriate handlers.
@@ -171,6 +215,7 @@ impl DeviceActionHandlers {
             "7" => Ok(Some(Self::handle_arm_device(device)?)),
             "8" => Ok(Some(Self::handle_turn_off_device(device)?)),
             "16" => Ok(Some(Self::handle_shutdown_and_quit(device)?)),
+            "17" => Ok(Some(Self::handle_reconnect(device)?)),
             _ => Ok(None)
         }
     }
@@ -363,6 +408,7 @@ impl DeviceActionHandlers {
                 Self::display_shutdown_confirmation()?;
                 Ok(Some(Self::handle_shutdown_and_quit(device)?))
             }
+            "17" => Ok(Some(Self::handle_reconnect(device)?)),
             _ => Ok(None)
         }
     }
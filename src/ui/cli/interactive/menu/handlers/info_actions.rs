@@ -14,8 +14,49 @@
 
 use crate::core::Result;
 use crate::device::LumidoxDevice;
+use crate::ui::cli::interactive::input::{InputProcessor, InputValidator};
 use std::io::{self, Write};
 
+/// Outcome of setting the ARM current, confirmed by reading it back
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArmCurrentUpdate {
+    /// ARM current that was written, in mA
+    pub requested_ma: u16,
+    /// ARM current read back from the device afterwards, in mA
+    pub read_back_ma: u16,
+}
+
+impl ArmCurrentUpdate {
+    /// Check whether the device reports the requested value
+    ///
+    /// # Returns
+    /// * `bool` - True if the read-back value equals the requested value
+    pub fn is_verified(&self) -> bool {
+        self.requested_ma == self.read_back_ma
+    }
+
+    /// Describe the update for display
+    ///
+    /// # Returns
+    /// * `String` - Confirmation, or a warning naming both values on a mismatch
+    ///
+    /// # Example
+    /// ```
+    /// let update = ArmCurrentUpdate { requested_ma: 500, read_back_ma: 500 };
+    /// assert_eq!(update.summary(), "ARM current set to 500 mA (verified)");
+    /// ```
+    pub fn summary(&self) -> String {
+        if self.is_verified() {
+            format!("ARM current set to {} mA (verified)", self.requested_ma)
+        } else {
+            format!(
+                "Warning: ARM current set to {} mA but the device reports {} mA",
+                self.requested_ma, self.read_back_ma
+            )
+        }
+    }
+}
+
 /// Information and status action handlers utilities and functionality
 pub struct InfoActionHandlers;
 
@@ -151,8 +192,7 @@ impl InfoActionHandlers {
         print!("Enter stage number (1-5): ");
         io::stdout().flush()?;
         
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
+        let input = InputProcessor::read_line()?;
         
         match input.trim().parse::<u8>() {
             Ok(stage) if (1..=5).contains(&stage) => {
@@ -163,8 +203,8 @@ impl InfoActionHandlers {
                         println!("Stage {} Parameters:", params.stage_number);
                         println!("  ARM Current: {}mA", params.arm_current_ma);
                         println!("  FIRE Current: {}mA", params.fire_current_ma);
-                        println!("  Voltage Limit: {:.1}V", params.volt_limit_v);
-                        println!("  Voltage Start: {:.1}V", params.volt_start_v);
+                        println!("  Voltage Limit: {:.2}V", params.volt_limit_v);
+                        println!("  Voltage Start: {:.2}V", params.volt_start_v);
                         println!("  Total Power: {:.1} {}", params.power_total, params.total_units);
                         println!("  Per LED Power: {:.1} {}", params.power_per_led, params.per_led_units);
                     }
@@ -197,8 +237,7 @@ impl InfoActionHandlers {
         print!("Enter stage number (1-5): ");
         io::stdout().flush()?;
         
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
+        let input = InputProcessor::read_line()?;
         
         match input.trim().parse::<u8>() {
             Ok(stage) if (1..=5).contains(&stage) => {
@@ -235,8 +274,7 @@ impl InfoActionHandlers {
         print!("Enter stage number (1-5): ");
         io::stdout().flush()?;
         
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
+        let input = InputProcessor::read_line()?;
         
         match input.trim().parse::<u8>() {
             Ok(stage) if (1..=5).contains(&stage) => {
@@ -244,13 +282,13 @@ impl InfoActionHandlers {
                 
                 // Display voltage limit
                 match device.get_stage_volt_limit(stage) {
-                    Ok(limit) => println!("Stage {} Voltage Limit: {:.1}V", stage, limit),
+                    Ok(limit) => println!("Stage {} Voltage Limit: {:.2}V", stage, limit),
                     Err(e) => println!("Error reading voltage limit: {}", e),
                 }
                 
                 // Display voltage start
                 match device.get_stage_volt_start(stage) {
-                    Ok(start) => println!("Stage {} Voltage Start: {:.1}V", stage, start),
+                    Ok(start) => println!("Stage {} Voltage Start: {:.2}V", stage, start),
                     Err(e) => println!("Error reading voltage start: {}", e),
                 }
             }
@@ -280,25 +318,53 @@ impl InfoActionHandlers {
         print!("Enter ARM current in mA: ");
         io::stdout().flush()?;
         
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
+        let input = InputProcessor::read_line()?;
         
-        match input.trim().parse::<u16>() {
+        let validated = match device.info().map(|info| info.max_current_ma) {
+            Some(max_current) => InputValidator::validate_current_with_range(&input, max_current),
+            None => InputValidator::validate_current_value(&input),
+        };
+        
+        match validated {
             Ok(current) => {
                 println!("Setting ARM current to {}mA...", current);
                 
-                match device.set_arm_current(current) {
-                    Ok(()) => println!("ARM current set successfully."),
+                match Self::set_arm_current_verified(device, current) {
+                    Ok(update) => println!("{}", update.summary()),
                     Err(e) => println!("Error setting ARM current: {}", e),
                 }
             }
-            Err(_) => println!("Invalid current value. Must be a whole number."),
+            Err(e) => println!("{}", e),
         }
         
         println!();
         Ok(true)
     }
     
+    /// Set the ARM current and read it back to confirm the write
+    /// 
+    /// # Arguments
+    /// * `device` - Mutable reference to the device
+    /// * `current_ma` - ARM current to set in mA
+    /// 
+    /// # Returns
+    /// * `Result<ArmCurrentUpdate>` - Requested and read-back values, or device error
+    /// 
+    /// # Example
+    /// ```
+    /// let update = InfoActionHandlers::set_arm_current_verified(&mut device, 500)?;
+    /// if !update.is_verified() {
+    ///     println!("{}", update.summary());
+    /// }
+    /// ```
+    pub fn set_arm_current_verified(device: &mut LumidoxDevice, current_ma: u16) -> Result<ArmCurrentUpdate> {
+        device.set_arm_current(current_ma)?;
+        Ok(ArmCurrentUpdate {
+            requested_ma: current_ma,
+            read_back_ma: device.read_arm_current()?,
+        })
+    }
+    
     /// Handle information action based on choice
     /// 
     /// Routes information and status menu choices to appropriate handlers.
@@ -346,8 +412,7 @@ impl InfoActionHandlers {
         print!("Enter stage number (1-5): ");
         io::stdout().flush()?;
         
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
+        let input = InputProcessor::read_line()?;
         
         match input.trim().parse::<u8>() {
             Ok(stage) if (1..=5).contains(&stage) => Ok(Some(stage)),
@@ -389,3 +454,19 @@ impl InfoActionHandlers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arm_current_update_summary() {
+        let verified = ArmCurrentUpdate { requested_ma: 500, read_back_ma: 500 };
+        assert!(verified.is_verified());
+        assert_eq!(verified.summary(), "ARM current set to 500 mA (verified)");
+
+        let mismatch = ArmCurrentUpdate { requested_ma: 500, read_back_ma: 450 };
+        assert!(!mismatch.is_verified());
+        assert!(mismatch.summary().starts_with("Warning:"));
+    }
+}
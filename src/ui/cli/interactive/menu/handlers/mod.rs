@@ -22,6 +22,7 @@ pub use info_actions::InfoActionHandlers;
 
 use crate::core::Result;
 use crate::device::LumidoxDevice;
+use super::super::super::confirm;
 
 /// Menu action handlers coordination utilities and functionality
 pub struct MenuActionHandlers;
@@ -123,7 +124,7 @@ impl MenuActionHandlers {
     pub fn get_choice_category(choice: &str) -> Option<&'static str> {
         match choice {
             "1" | "2" | "3" | "4" | "5" | "6" => Some("stage"),
-            "7" | "8" | "9" => Some("device"),
+            "7" | "8" | "9" | "17" => Some("device"),
             "10" | "11" | "12" | "13" | "14" | "15" | "16" => Some("info"),
             _ => None,
         }
@@ -176,14 +177,15 @@ impl MenuActionHandlers {
             "6" => Some("Fire with custom current"),
             "7" => Some("Arm device"),
             "8" => Some("Turn off device"),
-            "9" => Some("Shutdown and quit"),
-            "10" => Some("Show device status"),
-            "11" => Some("Read remote mode state"),
-            "12" => Some("Read current settings"),
-            "13" => Some("Show stage parameters"),
-            "14" => Some("Read stage ARM current"),
-            "15" => Some("Read stage voltage parameters"),
-            "16" => Some("Set ARM current"),
+            "9" => Some("Show device status"),
+            "10" => Some("Read remote mode state"),
+            "11" => Some("Read current settings"),
+            "12" => Some("Show stage parameters"),
+            "13" => Some("Read stage ARM current"),
+            "14" => Some("Read stage voltage parameters"),
+            "15" => Some("Set ARM current"),
+            "16" => Some("Shutdown and quit"),
+            "17" => Some("Reconnect to the same port"),
             _ => None,
         }
     }
@@ -211,8 +213,9 @@ impl MenuActionHandlers {
     
     /// Check if choice is potentially destructive
     /// 
-    /// Determines whether a menu choice could potentially cause device state changes
-    /// that might be considered destructive or require extra caution.
+    /// Determines whether a menu choice energizes the output or changes a
+    /// setpoint. Turning the device off or quitting only makes it safer, so
+    /// those are never confirmed.
     /// 
     /// # Arguments
     /// * `choice` - Menu choice string
@@ -230,10 +233,8 @@ impl MenuActionHandlers {
         match choice {
             "1" | "2" | "3" | "4" | "5" | "6" => true, // Firing operations
             "7" => true,  // Arming
-            "8" => true,  // Turn off
-            "9" => true,  // Shutdown
-            "16" => true, // Set ARM current
-            _ => false,   // Information reading operations
+            "15" => true, // Set ARM current
+            _ => false,   // Turning off, quitting and information reading
         }
     }
     
@@ -260,8 +261,8 @@ impl MenuActionHandlers {
     pub fn get_safety_level(choice: &str) -> Option<&'static str> {
         match choice {
             "1" | "2" | "3" | "4" | "5" | "6" => Some("high_impact"), // Firing operations
-            "7" | "8" | "9" | "16" => Some("medium_impact"), // Control and configuration
-            "10" | "11" | "12" | "13" | "14" | "15" => Some("low_impact"), // Information reading
+            "7" | "8" | "15" | "16" | "17" => Some("medium_impact"), // Control and configuration
+            "9" | "10" | "11" | "12" | "13" | "14" => Some("low_impact"), // Information reading
             _ => None,
         }
     }
@@ -282,6 +283,32 @@ impl MenuActionHandlers {
     /// let continue_menu = MenuActionHandlers::execute_choice_safely(&mut device, "3")?;
     /// ```
     pub fn execute_choice_safely(device: &mut LumidoxDevice, choice: &str) -> Result<bool> {
+        Self::execute_choice_safely_with_confirmation(device, choice, false)
+    }
+    
+    /// Execute choice with error handling and optional safety confirmation
+    /// 
+    /// Same as `execute_choice_safely`, but when `confirm_destructive` is set,
+    /// potentially destructive choices (firing, arming, etc.) require the user
+    /// to confirm before they are executed.
+    /// 
+    /// # Arguments
+    /// * `device` - Mutable reference to the device for operations
+    /// * `choice` - User menu choice string
+    /// * `confirm_destructive` - Whether to ask for confirmation before destructive actions
+    /// 
+    /// # Returns
+    /// * `Result<bool>` - True to continue menu loop, false to exit
+    /// 
+    /// # Example
+    /// ```
+    /// let continue_menu = MenuActionHandlers::execute_choice_safely_with_confirmation(&mut device, "3", true)?;
+    /// ```
+    pub fn execute_choice_safely_with_confirmation(
+        device: &mut LumidoxDevice,
+        choice: &str,
+        confirm_destructive: bool,
+    ) -> Result<bool> {
         if !Self::is_valid_choice_format(choice) {
             println!();
             println!("Invalid choice format. Please enter a number.");
@@ -289,6 +316,12 @@ impl MenuActionHandlers {
             return Ok(true);
         }
         
+        if confirm_destructive && !Self::confirm_action(choice)? {
+            println!("Action cancelled.");
+            println!();
+            return Ok(true);
+        }
+        
         match Self::handle_choice_with_status(device, choice)? {
             Some(result) => Ok(result),
             None => {
@@ -299,4 +332,66 @@ impl MenuActionHandlers {
             }
         }
     }
+    
+    /// Get the confirmation prompt for a choice
+    /// 
+    /// # Arguments
+    /// * `choice` - Menu choice string
+    /// 
+    /// # Returns
+    /// * `String` - Prompt such as "Fire stage 3? [y/N]: "
+    /// 
+    /// # Example
+    /// ```
+    /// let prompt = MenuActionHandlers::get_confirmation_prompt("3");
+    /// ```
+    pub fn get_confirmation_prompt(choice: &str) -> String {
+        confirm::prompt(Self::get_action_description(choice).unwrap_or("Perform this action"))
+    }
+    
+    /// Ask the user to confirm a potentially destructive choice
+    /// 
+    /// Non-destructive choices are confirmed without prompting. Any answer
+    /// other than an explicit yes (including an empty answer) declines.
+    /// 
+    /// # Arguments
+    /// * `choice` - Menu choice string
+    /// 
+    /// # Returns
+    /// * `Result<bool>` - True if the action should proceed, or input error
+    /// 
+    /// # Example
+    /// ```
+    /// if MenuActionHandlers::confirm_action("3")? {
+    ///     // Fire stage 3
+    /// }
+    /// ```
+    pub fn confirm_action(choice: &str) -> Result<bool> {
+        if !Self::is_potentially_destructive(choice) {
+            return Ok(true);
+        }
+        
+        confirm::ask(Self::get_action_description(choice).unwrap_or("Perform this action"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_destructive_classification_matches_dispatch() {
+        for choice in ["1", "2", "3", "4", "5", "6", "7", "15"] {
+            assert!(MenuActionHandlers::is_potentially_destructive(choice), "Choice {} should be destructive", choice);
+        }
+        for choice in ["8", "9", "10", "11", "12", "13", "14", "16", "17"] {
+            assert!(!MenuActionHandlers::is_potentially_destructive(choice), "Choice {} should not be destructive", choice);
+        }
+    }
+
+    #[test]
+    fn test_confirmation_prompt() {
+        assert_eq!(MenuActionHandlers::get_confirmation_prompt("3"), "Fire stage 3? [y/N]: ");
+        assert_eq!(MenuActionHandlers::get_confirmation_prompt("15"), "Set ARM current? [y/N]: ");
+    }
 }
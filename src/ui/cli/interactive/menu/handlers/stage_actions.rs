@@ -12,6 +12,7 @@
 
 use crate::core::{Result, operations::{StageOperations, DeviceOperationData}};
 use crate::device::LumidoxDevice;
+use crate::ui::cli::interactive::input::InputProcessor;
 use std::io::{self, Write};
 
 /// Stage action handlers utilities and functionality
@@ -87,8 +88,7 @@ impl StageActionHandlers {
         print!("Please enter current in mA (no decimals), then press ENTER: ");
         io::stdout().flush()?;
         
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
+        let input = InputProcessor::read_line()?;
         let current_str = input.trim();
         
         match current_str.parse::<u16>() {
@@ -202,8 +202,7 @@ impl StageActionHandlers {
             print!("Please enter current in mA (no decimals), then press ENTER: ");
             io::stdout().flush()?;
             
-            let mut input = String::new();
-            io::stdin().read_line(&mut input)?;
+            let input = InputProcessor::read_line()?;
             
             if let Some(current) = Self::validate_current_input(&input)? {
                 return Ok(Some(current));
@@ -4,6 +4,7 @@
 //! - `display`: Menu display and formatting utilities
 //!   - `stage_options`: Stage firing and custom current option display
 //!   - `status_options`: Device status, information, and control option display
+//!   - `strings`: Replaceable catalog of user-facing menu strings
 //! - `handlers`: Menu action handlers for different categories
 //!   - `stage_actions`: Stage firing and custom current action handlers
 //!   - `device_actions`: Device control action handlers (arm, turn off, shutdown)
@@ -19,12 +20,12 @@ pub mod display;
 pub mod handlers;
 
 // Re-export commonly used items for convenience
-pub use display::MenuDisplay;
+pub use display::{MenuDisplay, MenuStrings};
 pub use handlers::MenuActionHandlers;
 
-use crate::core::Result;
+use crate::core::{LumidoxError, Result};
 use crate::device::LumidoxDevice;
-use super::input::{InputProcessor, MenuChoice};
+use super::input::{InputParser, InputProcessor, MenuChoice};
 
 /// Menu system coordination utilities and functionality
 pub struct MenuSystem;
@@ -46,8 +47,31 @@ impl MenuSystem {
     /// println!("User selected: {}", choice.number);
     /// ```
     pub fn display_and_get_choice(device: &mut LumidoxDevice) -> Result<MenuChoice> {
-        MenuDisplay::display_complete_menu(device)?;
-        InputProcessor::get_menu_choice()
+        Self::display_and_get_choice_with_strings(device, &MenuStrings::default())
+    }
+
+    /// Display menu and get user choice using a custom string catalog
+    ///
+    /// Shows the complete interactive menu with the provided strings and gets
+    /// a validated user choice, allowing translated menus without changing
+    /// the menu logic.
+    ///
+    /// # Arguments
+    /// * `device` - Reference to the device for dynamic menu information
+    /// * `strings` - Menu string catalog used for all displayed text
+    ///
+    /// # Returns
+    /// * `Result<MenuChoice>` - Validated menu choice or input error
+    ///
+    /// # Example
+    /// ```
+    /// let strings = MenuStrings::default();
+    /// let choice = MenuSystem::display_and_get_choice_with_strings(&mut device, &strings)?;
+    /// ```
+    pub fn display_and_get_choice_with_strings(device: &mut LumidoxDevice, strings: &MenuStrings) -> Result<MenuChoice> {
+        MenuDisplay::display_complete_menu(device, strings)?;
+        let input = InputProcessor::get_user_input(&strings.choice_prompt)?;
+        InputParser::parse_menu_choice(&input)
     }
 
     /// Execute menu choice
@@ -66,10 +90,36 @@ impl MenuSystem {
     /// let continue_menu = MenuSystem::execute_choice(&mut device, choice)?;
     /// ```
     pub fn execute_choice(device: &mut LumidoxDevice, choice: MenuChoice) -> Result<bool> {
-        match MenuActionHandlers::execute_choice_safely(device, &choice.number.to_string())? {
-            true => Ok(true),
-            false => Ok(false),
-        }
+        Self::execute_choice_with_confirmation(device, choice, false)
+    }
+
+    /// Execute menu choice with optional safety confirmation
+    ///
+    /// Executes a menu choice, asking the user to confirm potentially
+    /// destructive actions first when `confirm_destructive` is set.
+    ///
+    /// # Arguments
+    /// * `device` - Mutable reference to the device for operations
+    /// * `choice` - Menu choice to execute
+    /// * `confirm_destructive` - Whether to confirm destructive actions before executing
+    ///
+    /// # Returns
+    /// * `Result<bool>` - True to continue menu loop, false to exit
+    ///
+    /// # Example
+    /// ```
+    /// let continue_menu = MenuSystem::execute_choice_with_confirmation(&mut device, choice, true)?;
+    /// ```
+    pub fn execute_choice_with_confirmation(
+        device: &mut LumidoxDevice,
+        choice: MenuChoice,
+        confirm_destructive: bool,
+    ) -> Result<bool> {
+        MenuActionHandlers::execute_choice_safely_with_confirmation(
+            device,
+            &choice.number.to_string(),
+            confirm_destructive,
+        )
     }
 
     /// Run interactive menu loop
@@ -87,13 +137,58 @@ impl MenuSystem {
     /// MenuSystem::run_menu_loop(&mut device)?;
     /// ```
     pub fn run_menu_loop(device: &mut LumidoxDevice) -> Result<()> {
+        Self::run_menu_loop_with_strings(device, &MenuStrings::default())
+    }
+
+    /// Run interactive menu loop using a custom string catalog
+    ///
+    /// Same as `run_menu_loop` but displays the menu with the provided strings.
+    ///
+    /// # Arguments
+    /// * `device` - Mutable reference to the device for operations
+    /// * `strings` - Menu string catalog used for all displayed text
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or error during menu operation
+    ///
+    /// # Example
+    /// ```
+    /// MenuSystem::run_menu_loop_with_strings(&mut device, &MenuStrings::default())?;
+    /// ```
+    pub fn run_menu_loop_with_strings(device: &mut LumidoxDevice, strings: &MenuStrings) -> Result<()> {
+        Self::run_menu_loop_with_confirmation(device, strings, false)
+    }
+
+    /// Run interactive menu loop with optional safety confirmation
+    ///
+    /// Same as `run_menu_loop_with_strings`, but when `confirm_destructive` is
+    /// set the user must confirm firing and other destructive actions.
+    ///
+    /// # Arguments
+    /// * `device` - Mutable reference to the device for operations
+    /// * `strings` - Menu string catalog used for all displayed text
+    /// * `confirm_destructive` - Whether to confirm destructive actions before executing
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or error during menu operation
+    ///
+    /// # Example
+    /// ```
+    /// MenuSystem::run_menu_loop_with_confirmation(&mut device, &MenuStrings::default(), true)?;
+    /// ```
+    pub fn run_menu_loop_with_confirmation(
+        device: &mut LumidoxDevice,
+        strings: &MenuStrings,
+        confirm_destructive: bool,
+    ) -> Result<()> {
         let mut continue_loop = true;
 
         while continue_loop {
-            match Self::display_and_get_choice(device) {
+            match Self::display_and_get_choice_with_strings(device, strings) {
                 Ok(choice) => {
-                    continue_loop = Self::execute_choice(device, choice)?;
+                    continue_loop = Self::execute_choice_with_confirmation(device, choice, confirm_destructive)?;
                 }
+                Err(e @ (LumidoxError::IdleTimeout { .. } | LumidoxError::EndOfInput)) => return Err(e),
                 Err(e) => {
                     InputProcessor::display_input_error(&e);
                     continue_loop = true; // Continue on input errors
@@ -120,6 +215,58 @@ impl MenuSystem {
     /// MenuSystem::run_enhanced_menu_loop(&mut device, 3)?;
     /// ```
     pub fn run_enhanced_menu_loop(device: &mut LumidoxDevice, max_input_attempts: u8) -> Result<()> {
+        Self::run_enhanced_menu_loop_with_strings(device, max_input_attempts, &MenuStrings::default())
+    }
+
+    /// Run enhanced menu loop with retry logic using a custom string catalog
+    ///
+    /// Same as `run_enhanced_menu_loop` but displays the menu with the provided strings.
+    ///
+    /// # Arguments
+    /// * `device` - Mutable reference to the device for operations
+    /// * `max_input_attempts` - Maximum attempts for invalid input
+    /// * `strings` - Menu string catalog used for all displayed text
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or error during menu operation
+    ///
+    /// # Example
+    /// ```
+    /// MenuSystem::run_enhanced_menu_loop_with_strings(&mut device, 3, &MenuStrings::default())?;
+    /// ```
+    pub fn run_enhanced_menu_loop_with_strings(
+        device: &mut LumidoxDevice,
+        max_input_attempts: u8,
+        strings: &MenuStrings,
+    ) -> Result<()> {
+        Self::run_enhanced_menu_loop_with_confirmation(device, max_input_attempts, strings, false)
+    }
+
+    /// Run enhanced menu loop with retry logic and optional safety confirmation
+    ///
+    /// Same as `run_enhanced_menu_loop_with_strings`, but when
+    /// `confirm_destructive` is set the user must confirm firing and other
+    /// destructive actions.
+    ///
+    /// # Arguments
+    /// * `device` - Mutable reference to the device for operations
+    /// * `max_input_attempts` - Maximum attempts for invalid input
+    /// * `strings` - Menu string catalog used for all displayed text
+    /// * `confirm_destructive` - Whether to confirm destructive actions before executing
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or error during menu operation
+    ///
+    /// # Example
+    /// ```
+    /// MenuSystem::run_enhanced_menu_loop_with_confirmation(&mut device, 3, &MenuStrings::default(), true)?;
+    /// ```
+    pub fn run_enhanced_menu_loop_with_confirmation(
+        device: &mut LumidoxDevice,
+        max_input_attempts: u8,
+        strings: &MenuStrings,
+        confirm_destructive: bool,
+    ) -> Result<()> {
         let mut continue_loop = true;
 
         while continue_loop {
@@ -127,11 +274,12 @@ impl MenuSystem {
             let mut choice_obtained = false;
 
             while attempts < max_input_attempts && !choice_obtained {
-                match Self::display_and_get_choice(device) {
+                match Self::display_and_get_choice_with_strings(device, strings) {
                     Ok(choice) => {
-                        continue_loop = Self::execute_choice(device, choice)?;
+                        continue_loop = Self::execute_choice_with_confirmation(device, choice, confirm_destructive)?;
                         choice_obtained = true;
                     }
+                    Err(e @ (LumidoxError::IdleTimeout { .. } | LumidoxError::EndOfInput)) => return Err(e),
                     Err(e) => {
                         InputProcessor::display_input_error(&e);
                         attempts += 1;
@@ -36,58 +36,197 @@ pub mod menu;
 pub mod input;
 
 // Re-export commonly used items for convenience
-pub use menu::MenuSystem;
+pub use menu::{MenuStrings, MenuSystem};
 pub use input::InputProcessor;
 
-use crate::core::Result;
-use crate::device::LumidoxDevice;
-use super::device::create_device_controller_with_fallback;
+use std::time::Duration;
+use crate::core::{LumidoxError, Result};
+use crate::device::{LumidoxDevice, OperationConfig};
+use super::device::create_device_controller_from_config;
+use input::eof::{eof_action, EofAction};
 
 /// Interactive CLI system coordination utilities and functionality
 pub struct InteractiveSystem;
 
 impl InteractiveSystem {
-    /// Run interactive mode with device connection
+    /// Run interactive mode
     /// 
-    /// Establishes device connection and runs the interactive menu system
-    /// with comprehensive error handling and user feedback.
+    /// Connects to the device described by `config` (retrying up to
+    /// `config.retries` additional times and falling back to manual port
+    /// selection) and runs the interactive menu system.
     /// 
     /// # Arguments
-    /// * `port_name` - Optional specific port name to connect to
-    /// * `auto_detect` - Whether to use automatic port detection
-    /// * `optimize_transitions` - Whether to optimize device state transitions
-    /// * `verbose` - Whether to enable verbose output
+    /// * `config` - Connection and operation configuration
+    /// * `max_input_attempts` - Consecutive invalid inputs allowed before the
+    ///   menu exits, or None to keep prompting indefinitely
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error during interactive operation
     /// 
     /// # Example
     /// ```
-    /// InteractiveSystem::run_interactive_mode(None, true, true, false)?;
+    /// InteractiveSystem::run_interactive(&OperationConfig::default(), Some(3))?;
     /// ```
-    pub fn run_interactive_mode(
-        port_name: Option<String>,
-        auto_detect: bool,
-        optimize_transitions: bool,
-        verbose: bool
+    pub fn run_interactive(config: &OperationConfig, max_input_attempts: Option<u8>) -> Result<()> {
+        Self::run_interactive_with_confirmation(config, max_input_attempts, false)
+    }
+    
+    /// Run interactive mode with optional firing confirmation
+    /// 
+    /// Same as `run_interactive`, but when `confirm_fires` is set the menu
+    /// asks for confirmation before firing and other destructive actions.
+    /// 
+    /// # Arguments
+    /// * `config` - Connection and operation configuration
+    /// * `max_input_attempts` - Consecutive invalid inputs allowed before the
+    ///   menu exits, or None to keep prompting indefinitely
+    /// * `confirm_fires` - Whether to confirm destructive actions before executing
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error during interactive operation
+    /// 
+    /// # Example
+    /// ```
+    /// InteractiveSystem::run_interactive_with_confirmation(&cli.operation_config(), None, cli.confirm_fires)?;
+    /// ```
+    pub fn run_interactive_with_confirmation(
+        config: &OperationConfig,
+        max_input_attempts: Option<u8>,
+        confirm_fires: bool
     ) -> Result<()> {
         // Establish device connection
-        let mut device = create_device_controller_with_fallback(
-            port_name,
-            auto_detect,
-            optimize_transitions,
-            verbose
-        )?;
+        let mut device = create_device_controller_from_config(config)?;
 
         println!("Device connected successfully!");
         
+        // Record the session currents, so a manual reconnect can restore them
+        if let Err(e) = device.snapshot_config() {
+            log::debug!("Could not read the current settings: {}", e);
+        }
+        
         // Display device information
         Self::display_device_info(&device)?;
         
         // Run the interactive menu system
-        MenuSystem::run_menu_loop(&mut device)?;
+        let strings = MenuStrings::default();
+        let result = match max_input_attempts {
+            Some(attempts) => MenuSystem::run_enhanced_menu_loop_with_confirmation(&mut device, attempts, &strings, confirm_fires),
+            None => MenuSystem::run_menu_loop_with_confirmation(&mut device, &strings, confirm_fires),
+        };
         
-        Ok(())
+        match result {
+            Err(LumidoxError::IdleTimeout { timeout }) => Self::end_idle_session(device, timeout),
+            Err(LumidoxError::EndOfInput) => Self::end_input_session(device, eof_action()),
+            result => result,
+        }
+    }
+    
+    /// End a session that received no input within the idle timeout
+    /// 
+    /// Turns the output off, returns the device to local mode and closes the
+    /// port so another user can connect.
+    /// 
+    /// # Arguments
+    /// * `device` - Device of the abandoned session, disconnected when dropped
+    /// * `timeout` - Idle timeout that expired
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success, or the error turning the output off
+    fn end_idle_session(mut device: LumidoxDevice, timeout: Duration) -> Result<()> {
+        println!();
+        println!("No input for {}s.", timeout.as_secs());
+        let result = device.shutdown();
+        drop(device);
+        println!("Device turned off and disconnected; the port is free for other users.");
+        result
+    }
+    
+    /// End a session whose standard input has ended
+    /// 
+    /// Turns the output off first, then returns the device to local mode
+    /// unless `--on-eof off` was given, and closes the port.
+    /// 
+    /// # Arguments
+    /// * `device` - Device of the session, disconnected when dropped
+    /// * `action` - What to do with the device besides turning it off
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success, or the error turning the device off
+    fn end_input_session(mut device: LumidoxDevice, action: EofAction) -> Result<()> {
+        println!();
+        println!("End of input.");
+        let result = device.turn_off().and_then(|()| match action {
+            EofAction::Shutdown => device.shutdown(),
+            EofAction::Off => Ok(()),
+        });
+        drop(device);
+        match action {
+            EofAction::Shutdown => println!("Device turned off, returned to local mode and disconnected."),
+            EofAction::Off => println!("Device turned off and disconnected."),
+        }
+        result
+    }
+    
+    /// Run interactive mode with device connection
+    /// 
+    /// # Arguments
+    /// * `port_name` - Optional specific port name to connect to
+    /// * `auto_detect` - Whether to use automatic port detection
+    /// * `optimize_transitions` - Whether to optimize device state transitions
+    /// * `verbose` - Whether to enable verbose output
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error during interactive operation
+    #[deprecated(note = "Use InteractiveSystem::run_interactive instead")]
+    pub fn run_interactive_mode(
+        port_name: Option<String>,
+        auto_detect: bool,
+        optimize_transitions: bool,
+        verbose: bool
+    ) -> Result<()> {
+        Self::run_interactive(&Self::legacy_config(port_name, auto_detect, optimize_transitions, verbose), None)
+    }
+    
+    /// Run interactive mode with optional firing confirmation
+    /// 
+    /// # Arguments
+    /// * `port_name` - Optional specific port name to connect to
+    /// * `auto_detect` - Whether to use automatic port detection
+    /// * `optimize_transitions` - Whether to optimize device state transitions
+    /// * `verbose` - Whether to enable verbose output
+    /// * `confirm_fires` - Whether to confirm destructive actions before executing
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error during interactive operation
+    #[deprecated(note = "Use InteractiveSystem::run_interactive_with_confirmation instead")]
+    pub fn run_interactive_mode_with_confirmation(
+        port_name: Option<String>,
+        auto_detect: bool,
+        optimize_transitions: bool,
+        verbose: bool,
+        confirm_fires: bool
+    ) -> Result<()> {
+        let config = Self::legacy_config(port_name, auto_detect, optimize_transitions, verbose);
+        Self::run_interactive_with_confirmation(&config, None, confirm_fires)
+    }
+    
+    /// Run interactive mode using an operation configuration
+    /// 
+    /// Same as `run_interactive_with_confirmation` without an input attempt limit.
+    /// 
+    /// # Arguments
+    /// * `config` - Connection and operation configuration
+    /// * `confirm_fires` - Whether to confirm destructive actions before executing
+    /// 
+    /// # Returns
+    /// * `Result<()>` - Success or error during interactive operation
+    /// 
+    /// # Example
+    /// ```
+    /// InteractiveSystem::run_interactive_mode_with_config(&cli.operation_config(), cli.confirm_fires)?;
+    /// ```
+    pub fn run_interactive_mode_with_config(config: &OperationConfig, confirm_fires: bool) -> Result<()> {
+        Self::run_interactive_with_confirmation(config, None, confirm_fires)
     }
     
     /// Display device information header
@@ -123,9 +262,6 @@ impl InteractiveSystem {
     
     /// Run enhanced interactive mode with retry logic
     /// 
-    /// Enhanced version with retry logic for connection failures and
-    /// improved error handling throughout the interactive session.
-    /// 
     /// # Arguments
     /// * `port_name` - Optional specific port name to connect to
     /// * `auto_detect` - Whether to use automatic port detection
@@ -135,11 +271,7 @@ impl InteractiveSystem {
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error during interactive operation
-    /// 
-    /// # Example
-    /// ```
-    /// InteractiveSystem::run_enhanced_interactive_mode(None, true, true, false, 3)?;
-    /// ```
+    #[deprecated(note = "Use InteractiveSystem::run_interactive instead")]
     pub fn run_enhanced_interactive_mode(
         port_name: Option<String>,
         auto_detect: bool,
@@ -147,23 +279,8 @@ impl InteractiveSystem {
         verbose: bool,
         max_input_attempts: u8
     ) -> Result<()> {
-        // Establish device connection
-        let mut device = create_device_controller_with_fallback(
-            port_name,
-            auto_detect,
-            optimize_transitions,
-            verbose
-        )?;
-
-        println!("Device connected successfully!");
-        
-        // Display device information
-        Self::display_device_info(&device)?;
-        
-        // Run the enhanced interactive menu system
-        MenuSystem::run_enhanced_menu_loop(&mut device, max_input_attempts)?;
-        
-        Ok(())
+        let config = Self::legacy_config(port_name, auto_detect, optimize_transitions, verbose);
+        Self::run_interactive(&config, Some(max_input_attempts))
     }
     
     /// Validate interactive system integrity
@@ -211,9 +328,6 @@ impl InteractiveSystem {
     
     /// Run interactive mode with connection retry
     /// 
-    /// Attempts to establish device connection with retry logic before
-    /// starting the interactive session.
-    /// 
     /// # Arguments
     /// * `port_name` - Optional specific port name to connect to
     /// * `auto_detect` - Whether to use automatic port detection
@@ -223,11 +337,7 @@ impl InteractiveSystem {
     /// 
     /// # Returns
     /// * `Result<()>` - Success or error during interactive operation
-    /// 
-    /// # Example
-    /// ```
-    /// InteractiveSystem::run_with_connection_retry(None, true, true, false, 3)?;
-    /// ```
+    #[deprecated(note = "Use InteractiveSystem::run_interactive with OperationConfig::retries instead")]
     pub fn run_with_connection_retry(
         port_name: Option<String>,
         auto_detect: bool,
@@ -235,37 +345,41 @@ impl InteractiveSystem {
         verbose: bool,
         max_connection_attempts: u8
     ) -> Result<()> {
-        let mut last_error = None;
-        
-        for attempt in 1..=max_connection_attempts {
-            match create_device_controller_with_fallback(
-                port_name.clone(),
-                auto_detect,
-                optimize_transitions,
-                verbose
-            ) {
-                Ok(mut device) => {
-                    println!("Device connected successfully on attempt {}!", attempt);
-                    Self::display_device_info(&device)?;
-                    return MenuSystem::run_menu_loop(&mut device);
-                }
-                Err(e) => {
-                    last_error = Some(e);
-                    if attempt < max_connection_attempts {
-                        println!("Connection attempt {} failed. Retrying...", attempt);
-                    }
-                }
-            }
-        }
-        
-        if let Some(error) = last_error {
-            Err(error)
-        } else {
-            Err(crate::core::LumidoxError::DeviceError(
-                "Failed to establish device connection after all attempts".to_string()
-            ))
+        let config = Self::connection_retry_config(port_name, auto_detect, optimize_transitions, verbose, max_connection_attempts);
+        Self::run_interactive(&config, None)
+    }
+    
+    /// Build the configuration used by `run_with_connection_retry`
+    ///
+    /// The legacy attempt count includes the first attempt, while
+    /// `OperationConfig::retries` counts only the retries.
+    fn connection_retry_config(
+        port_name: Option<String>,
+        auto_detect: bool,
+        optimize_transitions: bool,
+        verbose: bool,
+        max_connection_attempts: u8
+    ) -> OperationConfig {
+        OperationConfig {
+            retries: max_connection_attempts.saturating_sub(1),
+            ..Self::legacy_config(port_name, auto_detect, optimize_transitions, verbose)
         }
     }
+    
+    /// Build an operation configuration from the legacy boolean parameters
+    fn legacy_config(
+        port_name: Option<String>,
+        auto_detect: bool,
+        optimize_transitions: bool,
+        verbose: bool
+    ) -> OperationConfig {
+        OperationConfig::builder()
+            .port_option(port_name)
+            .auto_detect(auto_detect)
+            .verbose(verbose)
+            .optimize_transitions(optimize_transitions)
+            .build()
+    }
 }
 
 /// Interactive system statistics
@@ -310,7 +424,7 @@ pub fn interactive_menu(device: &mut LumidoxDevice) -> Result<bool> {
 /// 
 /// Provides backward compatibility with the original run_interactive_mode function.
 /// This function is deprecated and will be removed in future versions.
-/// Use `InteractiveSystem::run_interactive_mode` instead.
+/// Use `InteractiveSystem::run_interactive` instead.
 /// 
 /// # Arguments
 /// * `port_name` - Optional specific port name to connect to
@@ -322,16 +436,24 @@ pub fn interactive_menu(device: &mut LumidoxDevice) -> Result<bool> {
 /// ```
 /// run_interactive_mode(Some("COM3".to_string()))?;
 /// ```
-#[deprecated(note = "Use InteractiveSystem::run_interactive_mode instead")]
+#[deprecated(note = "Use InteractiveSystem::run_interactive instead")]
 pub fn run_interactive_mode(port_name: Option<String>) -> Result<()> {
-    InteractiveSystem::run_interactive_mode(port_name, false, false, true)
+    InteractiveSystem::run_interactive(&run_interactive_mode_config(port_name), None)
+}
+
+/// Build the configuration used by the legacy `run_interactive_mode`
+///
+/// The original function connected to the given port without
+/// auto-detection, with verbose output and the full safety sequence.
+fn run_interactive_mode_config(port_name: Option<String>) -> OperationConfig {
+    InteractiveSystem::legacy_config(port_name, false, false, true)
 }
 
 /// Legacy function for backward compatibility
 /// 
 /// Provides backward compatibility with the original run_interactive_mode_with_optimization function.
 /// This function is deprecated and will be removed in future versions.
-/// Use `InteractiveSystem::run_interactive_mode` instead.
+/// Use `InteractiveSystem::run_interactive` instead.
 /// 
 /// # Arguments
 /// * `port_name` - Optional specific port name to connect to
@@ -346,12 +468,55 @@ pub fn run_interactive_mode(port_name: Option<String>) -> Result<()> {
 /// ```
 /// run_interactive_mode_with_optimization(None, true, false, true)?;
 /// ```
-#[deprecated(note = "Use InteractiveSystem::run_interactive_mode instead")]
+#[deprecated(note = "Use InteractiveSystem::run_interactive instead")]
 pub fn run_interactive_mode_with_optimization(
     port_name: Option<String>,
     auto_detect: bool,
     verbose: bool,
     optimize_transitions: bool
 ) -> Result<()> {
-    InteractiveSystem::run_interactive_mode(port_name, auto_detect, optimize_transitions, verbose)
+    // Note the parameter order differs from InteractiveSystem::legacy_config
+    let config = InteractiveSystem::legacy_config(port_name, auto_detect, optimize_transitions, verbose);
+    InteractiveSystem::run_interactive(&config, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_interactive_mode_shim_defaults() {
+        let config = run_interactive_mode_config(Some("COM3".to_string()));
+        assert_eq!(config.port.as_deref(), Some("COM3"));
+        assert!(!config.auto_detect);
+        assert!(config.verbose);
+        assert!(!config.optimize_transitions);
+    }
+
+    #[test]
+    fn test_legacy_config_keeps_each_flag_in_place() {
+        // run_interactive_mode_with_optimization(port, auto_detect, verbose, optimize_transitions)
+        let config = InteractiveSystem::legacy_config(None, true, false, true);
+        assert_eq!(config.port, None);
+        assert!(config.auto_detect);
+        assert!(!config.optimize_transitions);
+        assert!(config.verbose);
+
+        let config = InteractiveSystem::legacy_config(Some("COM4".to_string()), false, true, false);
+        assert_eq!(config.port.as_deref(), Some("COM4"));
+        assert!(!config.auto_detect);
+        assert!(config.optimize_transitions);
+        assert!(!config.verbose);
+    }
+
+    #[test]
+    fn test_connection_retry_shim_counts_first_attempt() {
+        let config = InteractiveSystem::connection_retry_config(None, true, true, false, 3);
+        assert_eq!(config.retries, 2);
+        assert_eq!(InteractiveSystem::connection_retry_config(None, true, true, false, 0).retries, 0);
+        assert_eq!(
+            OperationConfig { retries: 0, ..config },
+            InteractiveSystem::legacy_config(None, true, true, false)
+        );
+    }
 }
@@ -0,0 +1,89 @@
+//! Diagnostic logging for Lumidox II Controller CLI
+//!
+//! Internal diagnostics go through the `log` facade: protocol round-trips
+//! and reconnect attempts at debug level, connection retries at info level
+//! and recoverable problems at warn level. This module installs the backend
+//! that writes them, so protocol tracing can be turned on with
+//! `--log-level debug` without rebuilding. Command results and prompts stay
+//! on stdout and are not affected.
+//!
+//! Messages go to stderr, keeping the `Warning:` prefix warnings have always
+//! had. With `--log-file`, every message at the selected level is also
+//! appended to the file with a timestamp and the module it came from.
+
+use std::fs::OpenOptions;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use log::{Level, LevelFilter};
+use crate::core::{LumidoxError, Result};
+
+/// Install the logging backend
+///
+/// # Arguments
+/// * `level` - Most verbose level written
+/// * `file` - File messages are also appended to, if any
+///
+/// # Returns
+/// * `Result<()>` - Success, or `SystemError` if the log file cannot be
+///   opened or a logger is already installed
+///
+/// # Example
+/// ```
+/// init_logging(LevelFilter::Debug, Some(Path::new("lumidox.log")))?;
+/// ```
+pub fn init_logging(level: LevelFilter, file: Option<&Path>) -> Result<()> {
+    let stderr = fern::Dispatch::new()
+        .format(|out, message, record| out.finish(format_args!("{}{}", stderr_prefix(record.level(), record.target()), message)))
+        .chain(std::io::stderr());
+
+    let mut dispatch = fern::Dispatch::new().level(level).chain(stderr);
+    if let Some(path) = file {
+        let log_file = OpenOptions::new().create(true).append(true).open(path).map_err(|e| {
+            LumidoxError::system(format!("Cannot open log file {}", path.display()), e)
+        })?;
+        dispatch = dispatch.chain(
+            fern::Dispatch::new()
+                .format(|out, message, record| {
+                    out.finish(format_args!("{} {:<5} {}: {}", timestamp(), record.level(), record.target(), message))
+                })
+                .chain(log_file),
+        );
+    }
+
+    dispatch.apply().map_err(|e| LumidoxError::system("Cannot start logging", e))
+}
+
+/// Prefix of a message written to stderr
+///
+/// Errors and warnings read like the messages printed before logging was
+/// added, info messages are printed as they are, and debug and trace
+/// messages name their level and module.
+fn stderr_prefix(level: Level, target: &str) -> String {
+    match level {
+        Level::Error => "Error: ".to_string(),
+        Level::Warn => "Warning: ".to_string(),
+        Level::Info => String::new(),
+        Level::Debug | Level::Trace => format!("[{} {}] ", level, target),
+    }
+}
+
+/// Seconds since the Unix epoch, with milliseconds
+fn timestamp() -> String {
+    let elapsed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    format!("{}.{:03}", elapsed.as_secs(), elapsed.subsec_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stderr_prefix() {
+        assert_eq!(stderr_prefix(Level::Warn, "lumidox"), "Warning: ");
+        assert_eq!(stderr_prefix(Level::Info, "lumidox"), "");
+        assert_eq!(
+            stderr_prefix(Level::Debug, "lumidox::communication"),
+            "[DEBUG lumidox::communication] "
+        );
+    }
+}
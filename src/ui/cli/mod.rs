@@ -2,6 +2,10 @@
 //!
 //! This module organizes CLI functionality into logical components:
 //! - args: Command-line argument parsing and definitions
+//! - config_file: Default settings loaded from lumidox.toml
+//! - logging: Diagnostic logging backend for --log-level and --log-file
+//! - progress: Countdown line for timed operations
+//! - confirm: Confirmation of destructive actions (--confirm-fires, --yes)
 //! - ports: Serial port management and selection
 //! - interactive: Interactive menu system with hierarchical sub-modules
 //!   - menu: Menu display and organization logic
@@ -12,13 +16,17 @@
 //! - device: Device controller creation and management
 
 pub mod args;
+pub mod config_file;
+pub mod logging;
+pub mod progress;
+pub mod confirm;
 pub mod ports;
 pub mod interactive;
 pub mod commands;
 pub mod device;
 
 // Re-export commonly used items for convenience
-pub use args::{Cli, Commands};
+pub use args::{Cli, Commands, OutputFormat};
 pub use ports::list_serial_ports;
 pub use interactive::run_interactive_mode_with_optimization;
-pub use commands::run_command_mode_with_optimization;
+pub use commands::{run_command_mode_with_optimization, run_command_mode_with_format};
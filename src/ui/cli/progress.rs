@@ -0,0 +1,104 @@
+//! Countdown display for timed CLI operations
+//!
+//! This module implements `ProgressReporter` for the terminal: a single
+//! countdown line such as "Stage 3: 4s remaining" that is rewritten in place
+//! with a carriage return once per second, and ended with a newline when the
+//! timed operation is over.
+
+use std::io::{self, Write};
+use std::time::Duration;
+use crate::core::ProgressReporter;
+use crate::core::progress::seconds_remaining;
+
+/// Countdown line written to a terminal or other writer
+///
+/// # Example
+/// ```
+/// let mut countdown = CountdownReporter::stdout();
+/// device.fire_stage_for_with_progress(3, Duration::from_secs(5), &mut countdown)?;
+/// ```
+pub struct CountdownReporter<W: Write> {
+    /// Destination of the countdown line
+    out: W,
+    /// What is being timed, shown before the countdown
+    label: String,
+    /// Seconds shown by the last update, to redraw only when it changes
+    shown: Option<u64>,
+}
+
+impl CountdownReporter<io::Stdout> {
+    /// Create a countdown on standard output
+    ///
+    /// # Returns
+    /// * `CountdownReporter<Stdout>` - Countdown printing to the terminal
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write> CountdownReporter<W> {
+    /// Create a countdown on any writer
+    ///
+    /// # Arguments
+    /// * `out` - Destination of the countdown line
+    ///
+    /// # Returns
+    /// * `CountdownReporter<W>` - Countdown writing to `out`
+    pub fn new(out: W) -> Self {
+        Self { out, label: String::new(), shown: None }
+    }
+
+    /// Get the writer back, e.g. to inspect what was written
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    /// Rewrite the countdown line; output errors are ignored
+    fn draw(&mut self, seconds: u64) {
+        let _ = write!(self.out, "\r{}: {}s remaining ", self.label, seconds);
+        let _ = self.out.flush();
+        self.shown = Some(seconds);
+    }
+}
+
+impl<W: Write> ProgressReporter for CountdownReporter<W> {
+    fn start(&mut self, label: &str, total: Duration) {
+        self.label = label.to_string();
+        self.draw(seconds_remaining(Duration::ZERO, total));
+    }
+
+    fn tick(&mut self, elapsed: Duration, total: Duration) {
+        let seconds = seconds_remaining(elapsed, total);
+        if self.shown != Some(seconds) {
+            self.draw(seconds);
+        }
+    }
+
+    fn finish(&mut self) {
+        if self.shown.take().is_some() {
+            let _ = writeln!(self.out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_countdown_redraws_once_per_second() {
+        let mut countdown = CountdownReporter::new(Vec::new());
+        let total = Duration::from_secs(3);
+        countdown.start("Stage 3", total);
+        for millis in (0..=3000).step_by(100) {
+            countdown.tick(Duration::from_millis(millis), total);
+        }
+        countdown.finish();
+
+        let output = String::from_utf8(countdown.into_inner()).unwrap();
+        assert_eq!(
+            output,
+            "\rStage 3: 3s remaining \rStage 3: 2s remaining \rStage 3: 1s remaining \rStage 3: 0s remaining \n"
+        );
+    }
+}
@@ -286,6 +286,9 @@ impl DisplayComponents {
                 LumidoxError::CommunicationError(_) => {
                     "Communication with device failed. Please verify the connection and retry.".to_string()
                 }
+                LumidoxError::Timeout { .. } => {
+                    "The device did not respond in time. Please retry the operation.".to_string()
+                }
                 LumidoxError::ValidationError(_) => {
                     "Input validation failed. Please check your input values and try again.".to_string()
                 }
@@ -318,6 +321,7 @@ impl DisplayComponents {
         match error {
             LumidoxError::DeviceError(_) => ErrorType::Error,
             LumidoxError::CommunicationError(_) => ErrorType::Error,
+            LumidoxError::Timeout { .. } => ErrorType::Warning,
             LumidoxError::ValidationError(_) => ErrorType::Warning,
             LumidoxError::SystemError(_) => ErrorType::Error,
             LumidoxError::ConfigError(_) => ErrorType::Warning,
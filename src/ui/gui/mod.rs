@@ -20,15 +20,18 @@
 // pub use application::LumidoxApplication;
 
 use iced::{Element, Task, Theme};
-use crate::core::{LumidoxError, DeviceControlOperations, DeviceOperationData};
+use crate::core::{CancellationToken, LumidoxError, DeviceControlOperations, DeviceOperationData, ProgressReporter};
+use crate::core::progress::{fraction_done, seconds_remaining};
 use crate::core::calculations::irradiance::IrradianceCalculator;
-use crate::ui::cli::device::create_device_controller_with_fallback;
-use crate::device::LumidoxDevice;
-use crate::device::models::PowerInfo;
+use crate::device::{LumidoxDevice, OperationConfig};
+use crate::device::controller::{ConnectionState, ConnectionStateMachine};
+use crate::device::models::{CharacterizationKey, DeviceInfo, DeviceMode, PowerInfo};
 use std::error::Error;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::sync::Mutex;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 /// Stage information for GUI display
 #[derive(Debug, Clone, Default)]
@@ -47,6 +50,201 @@ pub struct StageInfo {
     pub updating: bool,
     /// Error message if retrieval failed
     pub error: Option<String>,
+    /// Whether the values were read before the connection was lost
+    pub stale: bool,
+}
+
+/// Serial port offered in the port pick-list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortChoice {
+    /// Port name used to connect, e.g. "COM3" or "/dev/ttyUSB0"
+    pub name: String,
+    /// USB product string, when the port is a USB device that reports one
+    pub product: Option<String>,
+}
+
+impl PortChoice {
+    /// Describe a port found by `serialport::available_ports`
+    ///
+    /// # Arguments
+    /// * `info` - Port information from the operating system
+    ///
+    /// # Returns
+    /// * `PortChoice` - Port name with the USB product string, if any
+    pub fn from_info(info: &serialport::SerialPortInfo) -> Self {
+        let product = match &info.port_type {
+            serialport::SerialPortType::UsbPort(usb) => usb.product.clone(),
+            _ => None,
+        };
+        Self { name: info.port_name.clone(), product }
+    }
+}
+
+impl std::fmt::Display for PortChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.product {
+            Some(product) => write!(f, "{} - {}", self.name, product),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// List the serial ports for the port pick-list
+///
+/// # Returns
+/// * `Task<Message>` - Task producing `PortsListed`
+fn list_ports_task() -> Task<Message> {
+    Task::perform(
+        async {
+            serialport::available_ports()
+                .map(|ports| ports.iter().map(PortChoice::from_info).collect())
+                .map_err(|e| e.to_string())
+        },
+        Message::PortsListed,
+    )
+}
+
+/// Device identification summary shown in the header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceSummary {
+    /// Model, firmware and serial number line
+    pub text: String,
+    /// Whether the summary was read before the connection was lost
+    pub stale: bool,
+}
+
+/// Per-stage firing history for the current session
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StageFireHistory {
+    /// Number of successful fires since the last reset
+    pub fire_count: u32,
+    /// Time of the most recent successful fire
+    pub last_fired: Option<Instant>,
+}
+
+impl StageFireHistory {
+    /// Record a successful fire at the given time
+    ///
+    /// # Arguments
+    /// * `at` - Time the stage was fired
+    pub fn record_fire(&mut self, at: Instant) {
+        self.fire_count = self.fire_count.saturating_add(1);
+        self.last_fired = Some(at);
+    }
+
+    /// Format the history for display in a stage box
+    ///
+    /// # Arguments
+    /// * `now` - Current time used to compute the elapsed time since the last fire
+    ///
+    /// # Returns
+    /// * `Option<String>` - Summary such as "fired 3× · 12s ago", None if never fired
+    ///
+    /// # Example
+    /// ```
+    /// let mut history = StageFireHistory::default();
+    /// history.record_fire(Instant::now());
+    /// println!("{}", history.summary(Instant::now()).unwrap());
+    /// ```
+    pub fn summary(&self, now: Instant) -> Option<String> {
+        let last_fired = self.last_fired?;
+        Some(format!(
+            "fired {}× · {} ago",
+            self.fire_count,
+            format_elapsed(now.saturating_duration_since(last_fired))
+        ))
+    }
+}
+
+/// Format an elapsed duration using its largest whole unit (s, m, or h)
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h", secs / 3600)
+    }
+}
+
+/// Unit the custom current is entered in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CurrentInputUnit {
+    /// Absolute current in milliamps
+    #[default]
+    Milliamps,
+    /// Percentage of the device maximum current
+    Percent,
+}
+
+impl CurrentInputUnit {
+    /// Get the display label for the unit
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Milliamps => "mA",
+            Self::Percent => "%",
+        }
+    }
+
+    /// Get the other input unit
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Milliamps => Self::Percent,
+            Self::Percent => Self::Milliamps,
+        }
+    }
+}
+
+/// Resolve the custom current input to milliamps
+///
+/// Percent input is converted using the device maximum current, and values
+/// in either unit are checked against the maximum when it is known.
+///
+/// # Arguments
+/// * `input` - Text entered in the custom current field
+/// * `unit` - Unit the value was entered in
+/// * `max_current_ma` - Device maximum current cached at connection, if known
+///
+/// # Returns
+/// * `Result<u16, String>` - Current in milliamps or a message describing the invalid input
+///
+/// # Example
+/// ```
+/// assert_eq!(resolve_custom_current("25", CurrentInputUnit::Percent, Some(4000)), Ok(1000));
+/// ```
+pub fn resolve_custom_current(input: &str, unit: CurrentInputUnit, max_current_ma: Option<u16>) -> Result<u16, String> {
+    let current_ma = match unit {
+        CurrentInputUnit::Milliamps => input.trim().parse::<u16>()
+            .map_err(|_| "Invalid current value".to_string())?,
+        CurrentInputUnit::Percent => {
+            let percent = input.trim().parse::<f32>()
+                .map_err(|_| "Invalid percentage".to_string())?;
+            if !(0.0..=100.0).contains(&percent) {
+                return Err("Percentage must be between 0 and 100".to_string());
+            }
+            let max_current_ma = max_current_ma
+                .ok_or_else(|| "Device maximum current unknown; connect or enter mA".to_string())?;
+            (f32::from(max_current_ma) * percent / 100.0).round() as u16
+        }
+    };
+
+    if current_ma == 0 {
+        return Err("Current must be greater than 0mA".to_string());
+    }
+    if let Some(max_current_ma) = max_current_ma {
+        if current_ma > max_current_ma {
+            return Err(format!("{}mA exceeds device maximum {}mA", current_ma, max_current_ma));
+        }
+    }
+    Ok(current_ma)
+}
+
+/// Express a current as a percentage of the device maximum
+fn percent_of_max(current_ma: u16, max_current_ma: Option<u16>) -> Option<f32> {
+    max_current_ma
+        .filter(|&max| max > 0)
+        .map(|max| f32::from(current_ma) * 100.0 / f32::from(max))
 }
 
 /// Custom current information for GUI display
@@ -54,6 +252,8 @@ pub struct StageInfo {
 pub struct CustomCurrentInfo {
     /// Custom current value in mA
     pub current_ma: u16,
+    /// Custom current as a percentage of the device maximum, if known
+    pub percent_of_max: Option<f32>,
     /// Estimated total power value
     pub estimated_total_power: Option<f32>,
     /// Estimated per-well power value
@@ -64,6 +264,243 @@ pub struct CustomCurrentInfo {
     pub error: Option<String>,
 }
 
+/// Device status shown by the view, cached outside the device lock
+///
+/// Device tasks publish a new snapshot after each operation while they
+/// still hold the device mutex. The view and status refresh read the
+/// snapshot instead, so rendering never waits for device I/O.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DashboardSnapshot {
+    /// Device mode after the last operation
+    pub mode: Option<DeviceMode>,
+    /// Model, firmware and serial number of the connected device
+    pub device_summary: Option<String>,
+    /// Name of the last operation that updated the snapshot
+    pub last_operation: Option<String>,
+}
+
+impl DashboardSnapshot {
+    /// Capture the cached state of a device
+    ///
+    /// Only library-side state is read, so this performs no device I/O.
+    ///
+    /// # Arguments
+    /// * `device` - Device the operation ran on
+    /// * `operation` - Name of the operation that just completed
+    ///
+    /// # Returns
+    /// * `DashboardSnapshot` - Snapshot of the device after the operation
+    pub fn capture(device: &LumidoxDevice, operation: &str) -> Self {
+        Self {
+            mode: device.current_mode(),
+            device_summary: device.info().map(|info| format!(
+                "Model: {} | Firmware: {} | Serial: {}",
+                info.model_number,
+                info.firmware_version,
+                info.serial_number
+            )),
+            last_operation: Some(operation.to_string()),
+        }
+    }
+
+    /// Describe the snapshot for the status line
+    ///
+    /// # Returns
+    /// * `String` - Mode and last operation, e.g. "Mode: Armed (last: Arm)"
+    pub fn status_line(&self) -> String {
+        let mode = self.mode.map_or_else(|| "Unknown".to_string(), |mode| format!("{:?}", mode));
+        match &self.last_operation {
+            Some(operation) => format!("Mode: {} (last: {})", mode, operation),
+            None => format!("Mode: {}", mode),
+        }
+    }
+}
+
+/// Shared dashboard snapshot, written by device tasks and read by the view
+type SharedDashboard = Arc<RwLock<DashboardSnapshot>>;
+
+/// Publish the state of a device after an operation
+///
+/// Called while the device mutex is held, so the snapshot always reflects
+/// the most recent operation.
+///
+/// # Arguments
+/// * `dashboard` - Shared snapshot to update
+/// * `device` - Device the operation ran on
+/// * `operation` - Name of the operation that just completed
+fn publish_snapshot(dashboard: &SharedDashboard, device: &LumidoxDevice, operation: &str) {
+    let snapshot = DashboardSnapshot::capture(device, operation);
+    *dashboard.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = snapshot;
+}
+
+/// Read the current dashboard snapshot without touching the device lock
+fn read_snapshot(dashboard: &SharedDashboard) -> DashboardSnapshot {
+    dashboard.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+}
+
+/// Progress of the timed operation currently running on the device
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedProgress {
+    /// What is being timed, e.g. "Stage 3"
+    pub label: String,
+    /// Time since the operation began
+    pub elapsed: Duration,
+    /// Planned duration of the operation
+    pub total: Duration,
+}
+
+impl TimedProgress {
+    /// Fraction of the operation that has passed, for the progress bar
+    pub fn fraction(&self) -> f32 {
+        fraction_done(self.elapsed, self.total)
+    }
+
+    /// Describe the progress for the text next to the progress bar
+    ///
+    /// # Returns
+    /// * `String` - Label and remaining time, e.g. "Stage 3: 4s remaining"
+    pub fn status_line(&self) -> String {
+        format!("{}: {}s remaining", self.label, seconds_remaining(self.elapsed, self.total))
+    }
+}
+
+/// Shared timed-operation progress, written by device tasks and read by the view
+type SharedProgress = Arc<RwLock<Option<TimedProgress>>>;
+
+/// Progress reporter for device tasks that updates the GUI progress bar
+///
+/// Device tasks run timed operations with this reporter; the view reads the
+/// shared progress on each tick, like the dashboard snapshot, so rendering
+/// never waits for the device.
+pub struct GuiProgressReporter {
+    /// Progress shown by the view, None while nothing is timed
+    shared: SharedProgress,
+}
+
+impl GuiProgressReporter {
+    /// Replace the shown progress
+    fn publish(&self, progress: Option<TimedProgress>) {
+        *self.shared.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = progress;
+    }
+}
+
+impl ProgressReporter for GuiProgressReporter {
+    fn start(&mut self, label: &str, total: Duration) {
+        self.publish(Some(TimedProgress { label: label.to_string(), elapsed: Duration::ZERO, total }));
+    }
+
+    fn tick(&mut self, elapsed: Duration, total: Duration) {
+        let mut shared = self.shared.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(progress) = shared.as_mut() {
+            progress.elapsed = elapsed;
+            progress.total = total;
+        }
+    }
+
+    fn finish(&mut self) {
+        self.publish(None);
+    }
+}
+
+/// Read the progress of the running timed operation, if any
+fn read_progress(progress: &SharedProgress) -> Option<TimedProgress> {
+    progress.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+}
+
+/// Count of emergency stops, shared with device tasks
+///
+/// Device tasks wait in line for the device mutex, so an ARM or fire
+/// clicked before EMERGENCY OFF could otherwise run after it and energize
+/// the output again. Energizing tasks record the count when dispatched and
+/// give up if it has changed by the time they hold the device.
+#[derive(Debug, Clone, Default)]
+struct EmergencyEpoch(Arc<AtomicU64>);
+
+impl EmergencyEpoch {
+    /// Get the number of emergency stops so far
+    fn current(&self) -> u64 {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Record an emergency stop
+    fn trigger(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Check whether an emergency stop happened after a task was dispatched
+    ///
+    /// # Arguments
+    /// * `dispatched` - Count returned by `current` when the task was dispatched
+    ///
+    /// # Returns
+    /// * `Result<(), LumidoxError>` - Ok if no emergency stop happened since
+    ///   dispatch, otherwise `OperationCancelled`
+    fn check(&self, dispatched: u64) -> std::result::Result<(), LumidoxError> {
+        if self.current() == dispatched {
+            Ok(())
+        } else {
+            Err(LumidoxError::OperationCancelled("Skipped after emergency off".to_string()))
+        }
+    }
+}
+
+/// Connection attempt counter, identifying the one connect task allowed to finish
+///
+/// Connecting runs in a background task that can take seconds. Starting an
+/// attempt or disconnecting moves the counter on, so a task dispatched
+/// earlier finds it is no longer the current attempt: it drops the device
+/// it opened instead of storing it, and its result message is ignored.
+/// This keeps a slow connect that finishes after a disconnect, or after
+/// a newer connect, from taking over the shared device.
+#[derive(Debug, Clone, Default)]
+struct ConnectAttempts(Arc<AtomicU64>);
+
+impl ConnectAttempts {
+    /// Start a new attempt, superseding any attempt in flight
+    ///
+    /// # Returns
+    /// * `u64` - Identifier of the new attempt
+    fn start(&self) -> u64 {
+        self.0.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Cancel the attempt in flight, if any
+    fn cancel(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Check whether an attempt is still the current one
+    ///
+    /// # Arguments
+    /// * `attempt` - Identifier returned by `start`
+    ///
+    /// # Returns
+    /// * `bool` - True if no attempt was started or cancelled since
+    fn is_current(&self, attempt: u64) -> bool {
+        self.0.load(Ordering::SeqCst) == attempt
+    }
+}
+
+/// Interval of the GUI tick unless configured otherwise
+pub const DEFAULT_TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// GUI behaviour settings
+///
+/// The tick refreshes time-based display such as "time since last fire"
+/// and the stale-data age. On a slow link it can be slowed down, and with
+/// the tick off the window only updates after an operation or user input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppSettings {
+    /// Interval of the background tick, None to turn it off
+    pub tick_interval: Option<Duration>,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self { tick_interval: Some(DEFAULT_TICK_INTERVAL) }
+    }
+}
+
 /// Run the GUI application
 /// 
 /// Launches the Lumidox II Controller GUI application with the specified
@@ -108,29 +545,67 @@ pub fn run_gui(
     auto_detect: bool,
     verbose: bool,
     optimize_transitions: bool,
+) -> std::result::Result<(), Box<dyn Error>> {
+    run_gui_with_settings(port_name, auto_detect, verbose, optimize_transitions, AppSettings::default())
+}
+
+/// Run the GUI application with custom GUI settings
+///
+/// Same as `run_gui`, with the GUI behaviour taken from `app_settings`.
+///
+/// # Arguments
+/// * `port_name` - Optional specific port name for device connection
+/// * `auto_detect` - Whether to use automatic port detection
+/// * `verbose` - Enable verbose output during operations
+/// * `optimize_transitions` - Enable optimized stage transitions
+/// * `app_settings` - GUI behaviour settings such as the tick interval
+///
+/// # Returns
+/// * `Result<(), Box<dyn Error>>` - Ok if GUI ran successfully, Err with error details
+///
+/// # Example
+/// ```
+/// use lumidox_ii_controller::ui::gui::{run_gui_with_settings, AppSettings};
+///
+/// // Slow serial link: tick every 5 seconds
+/// let app_settings = AppSettings { tick_interval: Some(Duration::from_secs(5)) };
+/// run_gui_with_settings(Some("COM3".to_string()), false, false, true, app_settings)?;
+/// ```
+pub fn run_gui_with_settings(
+    port_name: Option<String>,
+    auto_detect: bool,
+    verbose: bool,
+    optimize_transitions: bool,
+    app_settings: AppSettings,
 ) -> std::result::Result<(), Box<dyn Error>> {
     // Create application settings
     let settings = create_application_settings();
     
-    // Clone values for the closure
-    let port_name_clone = port_name.clone();
-    let auto_detect_clone = auto_detect;
-    let verbose_clone = verbose;
-    let optimize_transitions_clone = optimize_transitions;
+    // Build the connection configuration once for the application lifetime
+    let operation_config = OperationConfig::builder()
+        .port_option(port_name)
+        .auto_detect(auto_detect)
+        .verbose(verbose)
+        .optimize_transitions(optimize_transitions)
+        .build();
 
     // Run the simple Iced application using the 0.13.x API
     match iced::application("Lumidox II Controller", update, view)
+        .subscription(subscription)
         .theme(theme)
         .settings(settings)
         .run_with(move || {
-            let mut initial_state = AppState::default();
-            initial_state.port_name = port_name_clone;
-            initial_state.auto_detect = auto_detect_clone;
-            initial_state.verbose = verbose_clone;
-            initial_state.optimize_transitions = optimize_transitions_clone;
+            let mut initial_state = AppState {
+                operation_config,
+                settings: app_settings,
+                ..AppState::default()
+            };
+            if !initial_state.operation_config.has_connection_target() {
+                initial_state.status_message = "Select a port first".to_string();
+            }
 
             // Auto-connect if requested
-            let initial_task = if auto_detect_clone {
+            let connect_task = if initial_state.operation_config.auto_detect {
                 Task::perform(
                     async move { Message::Connect },
                     |msg| msg,
@@ -139,7 +614,7 @@ pub fn run_gui(
                 Task::none()
             };
 
-            (initial_state, initial_task)
+            (initial_state, Task::batch([list_ports_task(), connect_task]))
         }) {
         Ok(_) => Ok(()),
         Err(error) => {
@@ -259,7 +734,7 @@ pub fn check_gui_compatibility() -> crate::core::Result<()> {
     // Check if we're in a headless environment
     if std::env::var("DISPLAY").is_err() && std::env::var("WAYLAND_DISPLAY").is_err() {
         #[cfg(unix)]
-        return Err(LumidoxError::SystemError(
+        return Err(LumidoxError::ConfigError(
             "No display server detected. GUI requires X11 or Wayland.".to_string()
         ));
     }
@@ -434,17 +909,23 @@ pub fn test_gui() -> crate::core::Result<()> {
 pub struct AppState {
     /// Device controller for communication
     device: Arc<Mutex<Option<LumidoxDevice>>>,
-    /// Connection configuration
-    port_name: Option<String>,
-    auto_detect: bool,
-    verbose: bool,
-    optimize_transitions: bool,
-    /// Application state
-    connected: bool,
-    connecting: bool,
+    /// Device status for the view, readable without the device lock
+    dashboard: SharedDashboard,
+    /// Progress of the running timed operation, readable without the device lock
+    progress: SharedProgress,
+    /// Connection configuration; a port chosen in the pick-list is stored in its `port`
+    operation_config: OperationConfig,
+    /// Serial ports offered in the port pick-list
+    ports: Vec<PortChoice>,
+    /// Connection state, shared with the device layer's state machine
+    connection: ConnectionStateMachine,
+    /// Identifies the connect task whose result is still wanted
+    connect_attempts: ConnectAttempts,
     status_message: String,
     error_message: Option<String>,    /// Device status
-    device_info: Option<String>,
+    device_info: Option<DeviceSummary>,
+    /// When the connection was lost, while last-known data is shown as stale
+    connection_lost_at: Option<Instant>,
     /// UI state
     custom_current: String,
     /// Stage information for each stage (1-5)
@@ -453,6 +934,22 @@ pub struct AppState {
     custom_current_info: CustomCurrentInfo,
     /// Whether we're currently refreshing stage information
     refreshing_stages: bool,
+    /// Firing history for each stage (1-5) during this session
+    fire_history: HashMap<u8, StageFireHistory>,
+    /// Token used to cancel the in-progress firing sequence
+    cancel_token: CancellationToken,
+    /// Emergency stop count, used to drop energizing tasks queued before it
+    emergency: EmergencyEpoch,
+    /// Stage whose output is active, highlighted in the stage row
+    active_stage: Option<u8>,
+    /// Device the stage information was read from
+    characterized_for: Option<CharacterizationKey>,
+    /// Unit the custom current is entered in
+    current_unit: CurrentInputUnit,
+    /// Maximum current of the connected device, cached at connection
+    max_current_ma: Option<u16>,
+    /// GUI behaviour settings
+    settings: AppSettings,
 }
 
 impl Default for AppState {
@@ -465,20 +962,22 @@ impl Default for AppState {
 
         Self {
             device: Arc::new(Mutex::new(None)),
-            port_name: None,
-            auto_detect: true,
-            verbose: false,
-            optimize_transitions: true,
-            connected: false,
-            connecting: false,
+            dashboard: SharedDashboard::default(),
+            progress: SharedProgress::default(),
+            operation_config: OperationConfig::default(),
+            ports: Vec::new(),
+            connection: ConnectionStateMachine::default(),
+            connect_attempts: ConnectAttempts::default(),
             status_message: "Ready to connect".to_string(),
             error_message: None,            device_info: None,
+            connection_lost_at: None,
             custom_current: "500".to_string(),
             stage_info,            custom_current_info: {
                 // Initialize with default current value using factory calibration
                 let power_info = IrradianceCalculator::estimate_power_from_current(500, None);
                 CustomCurrentInfo {
                     current_ma: 500,
+                    percent_of_max: None,
                     estimated_total_power: Some(power_info.total_power),
                     estimated_per_power: Some(power_info.per_power),
                     has_estimate: true,
@@ -486,26 +985,55 @@ impl Default for AppState {
                 }
             },
             refreshing_stages: false,
+            fire_history: HashMap::new(),
+            cancel_token: CancellationToken::new(),
+            emergency: EmergencyEpoch::default(),
+            active_stage: None,
+            characterized_for: None,
+            current_unit: CurrentInputUnit::default(),
+            max_current_ma: None,
+            settings: AppSettings::default(),
         }
     }
 }
 
+impl AppState {
+    /// Create a progress reporter for a timed operation
+    ///
+    /// Hand the reporter to the device task running the operation; the view
+    /// shows its progress bar until the reporter's `finish` is called.
+    ///
+    /// # Returns
+    /// * `GuiProgressReporter` - Reporter updating this state's progress bar
+    ///
+    /// # Example
+    /// ```
+    /// let mut progress = state.progress_reporter();
+    /// device.fire_stage_for_with_progress(3, Duration::from_secs(5), &mut progress)?;
+    /// ```
+    pub fn progress_reporter(&self) -> GuiProgressReporter {
+        GuiProgressReporter { shared: Arc::clone(&self.progress) }
+    }
+}
+
 impl std::fmt::Debug for AppState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AppState")
-            .field("port_name", &self.port_name)
-            .field("auto_detect", &self.auto_detect)
-            .field("verbose", &self.verbose)
-            .field("optimize_transitions", &self.optimize_transitions)
-            .field("connected", &self.connected)
-            .field("connecting", &self.connecting)
+            .field("operation_config", &self.operation_config)
+            .field("ports", &self.ports)
+            .field("connection", &self.connection.state())
             .field("status_message", &self.status_message)
             .field("error_message", &self.error_message)
             .field("device_info", &self.device_info)
+            .field("connection_lost_at", &self.connection_lost_at)
             .field("custom_current", &self.custom_current)
             .field("stage_info", &self.stage_info)
             .field("custom_current_info", &self.custom_current_info)
             .field("refreshing_stages", &self.refreshing_stages)
+            .field("fire_history", &self.fire_history)
+            .field("cancel_token", &self.cancel_token)
+            .field("emergency", &self.emergency)
+            .field("active_stage", &self.active_stage)
             .field("device", &"Arc<Mutex<Option<LumidoxDevice>>>")
             .finish()
     }
@@ -517,18 +1045,27 @@ pub enum Message {
     /// Device connection messages
     Connect,
     Disconnect,
-    ConnectionSuccess(String), // Device info string instead of device object
-    ConnectionFailed(String),  // Error message
+    RefreshPorts,
+    PortsListed(std::result::Result<Vec<PortChoice>, String>), // available ports or enumeration error
+    PortSelected(PortChoice),
+    ConnectionSuccess(u64, String, Option<u8>, Option<DeviceInfo>), // Attempt, device info string, active stage and identification instead of device object
+    ConnectionFailed(u64, String),  // Attempt, error message
     /// Device control messages
     FireStage(u8),
+    StageFired(u8, String), // stage number, success message
+    ResetFireCounters,
+    CancelOperation,
     FireWithCurrent,
     ArmDevice,
     TurnOff,
+    EmergencyOff,
+    ResetDefaults,
     /// Device operation results
     OperationResult(std::result::Result<String, LumidoxError>),
     /// UI state messages
     StageSelected(u8),
     CurrentChanged(String),
+    CurrentUnitToggled,
     RefreshStatus,
     ClearError,
     /// Stage information messages
@@ -543,28 +1080,31 @@ pub enum Message {
 fn update(state: &mut AppState, message: Message) -> Task<Message> {
     match message {
         Message::Connect => {
-            if !state.connecting && !state.connected {
-                state.connecting = true;
+            if !state.operation_config.has_connection_target() {
+                state.status_message = "Select a port first".to_string();
+                Task::none()
+            } else if state.connection.state().can_transition_to(ConnectionState::Connecting) {
+                set_connection_state(state, ConnectionState::Connecting);
                 state.status_message = "Connecting...".to_string();
                 state.error_message = None;
 
-                let port_name = state.port_name.clone();
-                let auto_detect = state.auto_detect;
-                let optimize_transitions = state.optimize_transitions;
-                let verbose = state.verbose;
+                let attempt = state.connect_attempts.start();
+                let attempts = state.connect_attempts.clone();
+                let operation_config = state.operation_config.clone();
                 let device_arc = state.device.clone();
+                let dashboard = state.dashboard.clone();
 
                 Task::perform(
                     async move {
-                        let result = create_device_controller_with_fallback(
-                            port_name,
-                            auto_detect,
-                            optimize_transitions,
-                            verbose,
-                        );
+                        let result = LumidoxDevice::connect(&operation_config);
 
                         match result {
-                            Ok(device) => {
+                            Ok(mut device) => {
+                                // Read which stage is firing so the UI matches the hardware
+                                let active_stage = device.read_active_stage().unwrap_or(None);
+
+                                let identification = device.info().cloned();
+
                                 // Extract device info
                                 let device_info = if let Some(info) = device.info() {
                                     format!(
@@ -577,74 +1117,148 @@ fn update(state: &mut AppState, message: Message) -> Task<Message> {
                                     "Device connected".to_string()
                                 };
 
-                                // Store device
+                                // Store the device unless the attempt was superseded meanwhile;
+                                // dropping it closes the port again
                                 let mut device_guard = device_arc.lock().await;
+                                if !attempts.is_current(attempt) {
+                                    return Message::ConnectionFailed(attempt, "Connection cancelled".to_string());
+                                }
+                                publish_snapshot(&dashboard, &device, "Connect");
                                 *device_guard = Some(device);
 
-                                Message::ConnectionSuccess(device_info)
+                                Message::ConnectionSuccess(attempt, device_info, active_stage, identification)
                             }
-                            Err(e) => Message::ConnectionFailed(format!("Error: {}", e))
+                            Err(e) => Message::ConnectionFailed(attempt, format!("Error: {}", e))
                         }
                     },
                     |msg| msg,
                 )
             } else {
+                // Only one connect task may be in flight; further clicks are ignored
                 Task::none()
             }
-        }        Message::ConnectionSuccess(device_info) => {
-            state.connecting = false;
-            state.connected = true;
+        }
+
+        Message::ConnectionSuccess(attempt, _, _, _) | Message::ConnectionFailed(attempt, _)
+            if !state.connect_attempts.is_current(attempt) =>
+        {
+            // Result of a connect that was cancelled or superseded
+            Task::none()
+        }
+
+        Message::ConnectionSuccess(_, device_info, active_stage, identification) => {
+            let characterization = identification.as_ref().map(|info| info.characterization_key());
+            // A strict mismatch already failed the connection; a lenient one is shown as a warning
+            let model_warning = state.operation_config.expected_model.as_ref()
+                .zip(identification.as_ref())
+                .and_then(|(expected, info)| expected.check(info).ok().flatten());
+            state.max_current_ma = identification.map(|info| info.max_current_ma);
+            set_connection_state(state, ConnectionState::Connected);
+            state.active_stage = active_stage;
             state.status_message = "Connected successfully".to_string();
-            state.error_message = None;
-            state.device_info = Some(device_info);
+            state.error_message = model_warning.map(|warning| format!("Warning: {}", warning));
+            state.device_info = Some(DeviceSummary { text: device_info, stale: false });
+            state.connection_lost_at = None;
+
+            // Stage data read from another unit or firmware must not be shown for this one
+            if characterization != state.characterized_for {
+                let firmware_changed = match (&characterization, &state.characterized_for) {
+                    (Some(current), Some(cached)) => current.firmware_changed(cached),
+                    _ => false,
+                };
+                if firmware_changed {
+                    state.status_message = "Connected - firmware changed, re-reading stage information".to_string();
+                }
+                invalidate_stage_info(&mut state.stage_info);
+                state.characterized_for = characterization;
+            }
             
+            // Re-validate the custom current against the connected device's maximum
+            update_custom_current_info(state);
+
             // Automatically refresh stage information when connected
             return Task::perform(async {}, |_| Message::RefreshStageInfo);
         }
 
-        Message::ConnectionFailed(error) => {
-            state.connecting = false;
-            state.connected = false;
+        Message::ConnectionFailed(_, error) => {
+            set_connection_state(state, ConnectionState::Failed);
             state.status_message = "Connection failed".to_string();
             state.error_message = Some(error);
             Task::none()
         }
 
+        Message::RefreshPorts => list_ports_task(),
+
+        Message::PortsListed(Ok(ports)) => {
+            state.ports = ports;
+            Task::none()
+        }
+
+        Message::PortsListed(Err(error)) => {
+            state.ports.clear();
+            state.error_message = Some(format!("Could not list serial ports: {}", error));
+            Task::none()
+        }
+
+        Message::PortSelected(port) => {
+            // A chosen port replaces auto-detection for the next connect
+            state.status_message = if state.connection.is_connected() {
+                format!("{} selected; reconnect to use it", port.name)
+            } else {
+                format!("{} selected", port.name)
+            };
+            state.operation_config.port = Some(port.name);
+            state.operation_config.auto_detect = false;
+            Task::none()
+        }
+
         Message::Disconnect => {
-            state.connected = false;
+            // Disconnecting while connecting cancels the pending connect
+            state.connect_attempts.cancel();
+            set_connection_state(state, ConnectionState::Disconnected);
             state.status_message = "Disconnected".to_string();
             state.error_message = None;
             state.device_info = None;
+            state.connection_lost_at = None;
+            state.fire_history.clear();
+            state.active_stage = None;
+
+            // An explicit disconnect discards the last-known data
+            invalidate_stage_info(&mut state.stage_info);
+            state.characterized_for = None;
 
             let device_arc = state.device.clone();
+            let dashboard = state.dashboard.clone();
             Task::perform(
                 async move {
                     let mut device_guard = device_arc.lock().await;
                     *device_guard = None;
+                    *dashboard.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = DashboardSnapshot::default();
                 },
                 |_| Message::ClearError,
             )
         }
 
         Message::FireStage(stage) => {
-            if state.connected {
+            if state.connection.is_connected() {
                 let device_arc = state.device.clone();
+                state.cancel_token.reset();
+                let cancel_token = state.cancel_token.clone();
+                let dashboard = state.dashboard.clone();
+                let emergency = state.emergency.clone();
+                let dispatched = emergency.current();
                 Task::perform(
                     async move {
                         let mut device_guard = device_arc.lock().await;
+                        if let Err(e) = emergency.check(dispatched) {
+                            return Message::OperationResult(Err(e));
+                        }
                         if let Some(ref mut device) = *device_guard {
                             // Use unified operation layer
-                            match crate::core::operations::StageOperations::fire_stage_unified(device, stage) {
-                                Ok(response) => {
-                                    // GUI-specific presentation of the unified result
-                                    let mut message = response.message.clone();
-                                    if let crate::core::operations::DeviceOperationData::StageFiring { current_ma, .. } = response.data {
-                                        if let Some(current) = current_ma {
-                                            message.push_str(&format!(" (Current: {}mA)", current));
-                                        }
-                                    }
-                                    Message::OperationResult(Ok(message))
-                                }
+                            let result = crate::core::operations::StageOperations::fire_stage_cancellable_unified(device, stage, &cancel_token);
+                            publish_snapshot(&dashboard, device, &format!("Fire stage {}", stage));
+                            match result {
+                                Ok(response) => Message::StageFired(stage, response.message),
                                 Err(e) => Message::OperationResult(Err(e))
                             }
                         } else {
@@ -660,51 +1274,40 @@ fn update(state: &mut AppState, message: Message) -> Task<Message> {
                 Task::none()
             }
         }        Message::CurrentChanged(value) => {
-            state.custom_current = value.clone();            // Update custom current info with power estimation using actual device data when available
-            match value.trim().parse::<u16>() {
-                Ok(current_ma) if current_ma > 0 => {
-                    // Use actual stage calibration data for better accuracy
-                    let power_info = IrradianceCalculator::estimate_power_with_device_data(
-                        current_ma, 
-                        Some(&state.stage_info)
-                    );
-                    
-                    // Debug print to see what values we're getting
-                    println!("DEBUG: Custom current {}mA - Total power: {} mW, Per power: {} mW", 
-                             current_ma, power_info.total_power, power_info.per_power);
-                    
-                    state.custom_current_info = CustomCurrentInfo {
-                        current_ma,
-                        estimated_total_power: Some(power_info.total_power),
-                        estimated_per_power: Some(power_info.per_power),
-                        has_estimate: true,
-                        error: None,
-                    };
-                }
-                _ => {
-                    // Invalid current value
-                    state.custom_current_info = CustomCurrentInfo {
-                        current_ma: 0,
-                        estimated_total_power: None,
-                        estimated_per_power: None,
-                        has_estimate: false,
-                        error: Some("Invalid current value".to_string()),
-                    };
-                }
+            state.custom_current = value;
+            update_custom_current_info(state);
+            Task::none()
+        }
+
+        Message::CurrentUnitToggled => {
+            // Convert the entered value so the resolved current is unchanged
+            let resolved = resolve_custom_current(&state.custom_current, state.current_unit, state.max_current_ma);
+            state.current_unit = state.current_unit.toggled();
+            if let Ok(current_ma) = resolved {
+                state.custom_current = match state.current_unit {
+                    CurrentInputUnit::Milliamps => current_ma.to_string(),
+                    CurrentInputUnit::Percent => percent_of_max(current_ma, state.max_current_ma)
+                        .map(|percent| format!("{:.1}", percent))
+                        .unwrap_or_else(|| state.custom_current.clone()),
+                };
             }
-            
+            update_custom_current_info(state);
             Task::none()
         }
 
         Message::TurnOff => {
-            if state.connected {
+            if state.connection.is_connected() {
+                state.active_stage = None;
                 let device_arc = state.device.clone();
+                let dashboard = state.dashboard.clone();
                 Task::perform(
                     async move {
                         let mut device_guard = device_arc.lock().await;
                         if let Some(ref mut device) = *device_guard {
                             // Use unified operation layer
-                            match DeviceControlOperations::turn_off_device(device) {
+                            let result = DeviceControlOperations::turn_off_device(device);
+                            publish_snapshot(&dashboard, device, "Turn off");
+                            match result {
                                 Ok(response) => {
                                     // GUI-specific presentation of the unified result
                                     let gui_message = if let DeviceOperationData::DeviceControl { new_state, .. } = &response.data {
@@ -734,15 +1337,81 @@ fn update(state: &mut AppState, message: Message) -> Task<Message> {
             }
         }
 
+        Message::EmergencyOff => {
+            if state.connection.is_connected() {
+                // Stop the running sequence and drop energizing tasks still waiting for the device
+                state.cancel_token.cancel();
+                state.emergency.trigger();
+                state.active_stage = None;
+                state.status_message = "EMERGENCY OFF - turning off output...".to_string();
+                let device_arc = state.device.clone();
+                let dashboard = state.dashboard.clone();
+                Task::perform(
+                    async move {
+                        let mut device_guard = device_arc.lock().await;
+                        if let Some(ref mut device) = *device_guard {
+                            let result = device.emergency_stop();
+                            publish_snapshot(&dashboard, device, "Emergency off");
+                            Message::OperationResult(result.map(|_| "EMERGENCY OFF: output turned off".to_string()))
+                        } else {
+                            Message::OperationResult(Err(LumidoxError::DeviceError(
+                                "Device not connected".to_string()
+                            )))
+                        }
+                    },
+                    |msg| msg,
+                )
+            } else {
+                Task::none()
+            }
+        }
+
+        Message::ResetDefaults => {
+            if state.connection.is_connected() {
+                state.active_stage = None;
+                let device_arc = state.device.clone();
+                let dashboard = state.dashboard.clone();
+                Task::perform(
+                    async move {
+                        let mut device_guard = device_arc.lock().await;
+                        if let Some(ref mut device) = *device_guard {
+                            let result = DeviceControlOperations::reset_device_to_defaults(device);
+                            publish_snapshot(&dashboard, device, "Reset defaults");
+                            match result {
+                                Ok(response) => Message::OperationResult(Ok(response.message)),
+                                Err(e) => Message::OperationResult(Err(e))
+                            }
+                        } else {
+                            Message::OperationResult(Err(LumidoxError::DeviceError(
+                                "Device not connected".to_string()
+                            )))
+                        }
+                    },
+                    |msg| msg,
+                )
+            } else {
+                state.error_message = Some("Device not connected".to_string());
+                Task::none()
+            }
+        }
+
         Message::ArmDevice => {
-            if state.connected {
+            if state.connection.is_connected() {
                 let device_arc = state.device.clone();
+                let dashboard = state.dashboard.clone();
+                let emergency = state.emergency.clone();
+                let dispatched = emergency.current();
                 Task::perform(
                     async move {
                         let mut device_guard = device_arc.lock().await;
+                        if let Err(e) = emergency.check(dispatched) {
+                            return Message::OperationResult(Err(e));
+                        }
                         if let Some(ref mut device) = *device_guard {
                             // Use unified operation layer
-                            match DeviceControlOperations::arm_device(device) {
+                            let result = DeviceControlOperations::arm_device(device);
+                            publish_snapshot(&dashboard, device, "Arm");
+                            match result {
                                 Ok(response) => {
                                     // GUI-specific presentation of the unified result
                                     let gui_message = if let DeviceOperationData::DeviceControl { new_state, .. } = &response.data {
@@ -773,29 +1442,37 @@ fn update(state: &mut AppState, message: Message) -> Task<Message> {
         }
 
         Message::FireWithCurrent => {
-            if state.connected {
-                let current_str = state.custom_current.clone();
-                if let Ok(current) = current_str.parse::<u16>() {
-                    let device_arc = state.device.clone();
-                    Task::perform(
-                        async move {
-                            let mut device_guard = device_arc.lock().await;
-                            if let Some(ref mut device) = *device_guard {
-                                let result = device.fire_with_current(current)
-                                    .map(|_| format!("Fired with {}mA successfully", current))
-                                    .map_err(|e| e);
-                                Message::OperationResult(result)
-                            } else {
-                                Message::OperationResult(Err(LumidoxError::DeviceError(
-                                    "Device not connected".to_string()
-                                )))
-                            }
-                        },
-                        |msg| msg,
-                    )
-                } else {
-                    state.error_message = Some("Invalid current value".to_string());
-                    Task::none()
+            if state.connection.is_connected() {
+                match resolve_custom_current(&state.custom_current, state.current_unit, state.max_current_ma) {
+                    Ok(current) => {
+                        let device_arc = state.device.clone();
+                        let dashboard = state.dashboard.clone();
+                        let emergency = state.emergency.clone();
+                        let dispatched = emergency.current();
+                        Task::perform(
+                            async move {
+                                let mut device_guard = device_arc.lock().await;
+                                if let Err(e) = emergency.check(dispatched) {
+                                    return Message::OperationResult(Err(e));
+                                }
+                                if let Some(ref mut device) = *device_guard {
+                                    let result = device.fire_with_current(current)
+                                        .map(|_| format!("Fired with {}mA successfully", current));
+                                    publish_snapshot(&dashboard, device, &format!("Fire {}mA", current));
+                                    Message::OperationResult(result)
+                                } else {
+                                    Message::OperationResult(Err(LumidoxError::DeviceError(
+                                        "Device not connected".to_string()
+                                    )))
+                                }
+                            },
+                            |msg| msg,
+                        )
+                    }
+                    Err(error) => {
+                        state.error_message = Some(error);
+                        Task::none()
+                    }
                 }
             } else {
                 state.error_message = Some("Device not connected".to_string());
@@ -804,35 +1481,40 @@ fn update(state: &mut AppState, message: Message) -> Task<Message> {
         }
 
         Message::RefreshStatus => {
-            if state.connected {
-                let device_arc = state.device.clone();
-                Task::perform(
-                    async move {
-                        let device_guard = device_arc.lock().await;
-                        if let Some(ref device) = *device_guard {
-                            let device_info = if let Some(info) = device.info() {
-                                format!(
-                                    "Model: {} | Firmware: {} | Serial: {}",
-                                    info.model_number,
-                                    info.firmware_version,
-                                    info.serial_number
-                                )
-                            } else {
-                                "Device status refreshed".to_string()
-                            };
-                            Message::OperationResult(Ok(device_info))
-                        } else {
-                            Message::OperationResult(Err(LumidoxError::DeviceError(
-                                "Device not connected".to_string()
-                            )))
-                        }
-                    },
-                    |msg| msg,
-                )
+            if state.connection.is_connected() {
+                // Read the cached snapshot so a busy device does not stall the UI
+                let snapshot = read_snapshot(&state.dashboard);
+                state.status_message = match snapshot.device_summary {
+                    Some(ref summary) => format!("{} | {}", summary, snapshot.status_line()),
+                    None => snapshot.status_line(),
+                };
+                state.error_message = None;
             } else {
                 state.error_message = Some("Device not connected".to_string());
-                Task::none()
             }
+            Task::none()
+        }
+
+        Message::StageFired(stage, success_msg) => {
+            state.fire_history
+                .entry(stage)
+                .or_default()
+                .record_fire(Instant::now());
+            state.active_stage = Some(stage);
+            state.status_message = success_msg;
+            state.error_message = None;
+            Task::none()
+        }
+
+        Message::ResetFireCounters => {
+            state.fire_history.clear();
+            Task::none()
+        }
+
+        Message::CancelOperation => {
+            state.cancel_token.cancel();
+            state.status_message = "Cancelling...".to_string();
+            Task::none()
         }
 
         Message::OperationResult(result) => {
@@ -841,6 +1523,20 @@ fn update(state: &mut AppState, message: Message) -> Task<Message> {
                     state.status_message = success_msg;
                     state.error_message = None;
                 }
+                Err(error) if error.is_cancelled() => {
+                    // Cancellation is a user decision, not a failure
+                    state.status_message = error.to_string();
+                    state.error_message = None;
+                }
+                Err(error) if error.is_unsupported() => {
+                    // The device is fine; this firmware just lacks the feature
+                    state.status_message = error.to_string();
+                    state.error_message = None;
+                }
+                Err(error) if error.is_communication_error() && state.connection.is_connected() => {
+                    mark_connection_lost(state, Instant::now());
+                    state.error_message = Some(format!("Connection lost: {}", error));
+                }
                 Err(error) => {
                     state.error_message = Some(format!("Operation failed: {}", error));
                 }
@@ -852,7 +1548,7 @@ fn update(state: &mut AppState, message: Message) -> Task<Message> {
         }
 
         Message::RefreshStageInfo => {
-            if state.connected && !state.refreshing_stages {
+            if state.connection.is_connected() && !state.refreshing_stages {
                 state.refreshing_stages = true;
                 
                 // Mark all stages as updating
@@ -861,30 +1557,8 @@ fn update(state: &mut AppState, message: Message) -> Task<Message> {
                     stage_info.error = None;
                 }
 
-                let device_arc = state.device.clone();
-                
-                // Create tasks for all stages
-                let mut tasks = Vec::new();
-                for stage in 1u8..=5 {
-                    let device_arc_clone = device_arc.clone();
-                    tasks.push(Task::perform(
-                        async move {
-                            let mut device_guard = device_arc_clone.lock().await;
-                            if let Some(ref mut device) = *device_guard {
-                                retrieve_stage_info(device, stage).await
-                            } else {
-                                (stage, Err("Device not connected".to_string()))
-                            }
-                        },
-                        |(stage, result)| match result {
-                            Ok(info) => Message::StageInfoUpdated(stage, info),
-                            Err(error) => Message::StageInfoFailed(stage, error),
-                        },
-                    ));
-                }
-
-                // Execute all tasks
-                Task::batch(tasks)
+                // Stages are read one at a time; see `read_stage_task`
+                read_stage_task(state.device.clone(), 1)
             } else {
                 Task::none()
             }
@@ -893,14 +1567,7 @@ fn update(state: &mut AppState, message: Message) -> Task<Message> {
         Message::StageInfoUpdated(stage, mut info) => {
             info.updating = false;
             state.stage_info.insert(stage, info);
-            
-            // Check if all stages are done updating
-            let all_done = state.stage_info.values().all(|info| !info.updating);
-            if all_done {
-                state.refreshing_stages = false;
-            }
-            
-            Task::none()
+            next_stage_read(state, stage)
         }
 
         Message::StageInfoFailed(stage, error) => {
@@ -908,19 +1575,149 @@ fn update(state: &mut AppState, message: Message) -> Task<Message> {
                 stage_info.updating = false;
                 stage_info.error = Some(error);
             }
-            
-            // Check if all stages are done updating
-            let all_done = state.stage_info.values().all(|info| !info.updating);
-            if all_done {
-                state.refreshing_stages = false;
-            }
-            
-            Task::none()
+            next_stage_read(state, stage)
         }
 
         _ => Task::none()    }
 }
 
+/// Recompute the custom current estimate from the entered value and unit
+///
+/// # Arguments
+/// * `state` - Application state
+fn update_custom_current_info(state: &mut AppState) {
+    state.custom_current_info = match resolve_custom_current(&state.custom_current, state.current_unit, state.max_current_ma) {
+        Ok(current_ma) => {
+            // Use actual stage calibration data for better accuracy
+            let power_info = IrradianceCalculator::estimate_power_with_device_data(
+                current_ma,
+                Some(&state.stage_info)
+            );
+            CustomCurrentInfo {
+                current_ma,
+                percent_of_max: percent_of_max(current_ma, state.max_current_ma),
+                estimated_total_power: Some(power_info.total_power),
+                estimated_per_power: Some(power_info.per_power),
+                has_estimate: true,
+                error: None,
+            }
+        }
+        Err(error) => CustomCurrentInfo {
+            error: Some(error),
+            ..CustomCurrentInfo::default()
+        },
+    };
+}
+
+/// Create the task that reads one stage during a stage information refresh
+///
+/// Concurrency policy: the device mutex is FIFO, so a control operation
+/// (fire, arm, off) waits behind every task already queued on it. A refresh
+/// therefore never queues more than one stage read at a time; the next stage
+/// is only requested once the previous read has finished and released the
+/// lock. A control operation issued during a refresh waits for at most one
+/// stage read, and the remaining reads run after it.
+///
+/// # Arguments
+/// * `device_arc` - Shared device handle
+/// * `stage` - Stage number to read (1-5)
+///
+/// # Returns
+/// * `Task<Message>` - Task producing `StageInfoUpdated` or `StageInfoFailed`
+fn read_stage_task(device_arc: Arc<Mutex<Option<LumidoxDevice>>>, stage: u8) -> Task<Message> {
+    Task::perform(
+        async move {
+            let mut device_guard = device_arc.lock().await;
+            if let Some(ref mut device) = *device_guard {
+                retrieve_stage_info(device, stage).await
+            } else {
+                (stage, Err("Device not connected".to_string()))
+            }
+        },
+        |(stage, result)| match result {
+            Ok(info) => Message::StageInfoUpdated(stage, info),
+            Err(error) => Message::StageInfoFailed(stage, error),
+        },
+    )
+}
+
+/// Continue a stage information refresh after a stage has been read
+///
+/// # Arguments
+/// * `state` - Application state
+/// * `stage` - Stage whose read just finished
+///
+/// # Returns
+/// * `Task<Message>` - Read of the next stage, or no task once all stages are done
+fn next_stage_read(state: &mut AppState, stage: u8) -> Task<Message> {
+    if stage < 5 && state.refreshing_stages {
+        return read_stage_task(state.device.clone(), stage + 1);
+    }
+
+    state.refreshing_stages = state.stage_info.values().any(|info| info.updating);
+    Task::none()
+}
+
+/// Move the GUI connection to another state
+///
+/// The update handlers only request transitions the state machine allows;
+/// a rejected transition is a bug and is shown as an error.
+///
+/// # Arguments
+/// * `state` - Application state
+/// * `to` - State to move to
+fn set_connection_state(state: &mut AppState, to: ConnectionState) {
+    if let Err(error) = state.connection.transition(to) {
+        state.error_message = Some(error.to_string());
+    }
+}
+
+/// Keep the last-known device and stage information after a lost connection
+///
+/// The data stays on screen marked stale until a reconnect refreshes it;
+/// only an explicit disconnect clears it.
+///
+/// # Arguments
+/// * `state` - Application state
+/// * `now` - Time the connection was lost
+fn mark_connection_lost(state: &mut AppState, now: Instant) {
+    set_connection_state(state, ConnectionState::Failed);
+    state.active_stage = None;
+    state.connection_lost_at = Some(now);
+    state.status_message = "Connection lost - showing last known data".to_string();
+    if let Some(summary) = state.device_info.as_mut() {
+        summary.stale = true;
+    }
+    for info in state.stage_info.values_mut() {
+        info.stale = true;
+    }
+}
+
+/// Format the label shown next to stale data
+///
+/// # Arguments
+/// * `lost_at` - When the connection was lost
+/// * `now` - Current time
+///
+/// # Returns
+/// * `String` - Label such as "stale · last updated 12s ago"
+fn stale_label(lost_at: Option<Instant>, now: Instant) -> String {
+    match lost_at {
+        Some(lost_at) => format!("stale · last updated {} ago", format_elapsed(now.saturating_duration_since(lost_at))),
+        None => "stale".to_string(),
+    }
+}
+
+/// Discard cached stage information so it is read fresh from the device
+///
+/// # Arguments
+/// * `stage_info` - Stage information keyed by stage number
+fn invalidate_stage_info(stage_info: &mut HashMap<u8, StageInfo>) {
+    for info in stage_info.values_mut() {
+        *info = StageInfo::default();
+    }
+}
+
 /// Async function to retrieve stage information
 async fn retrieve_stage_info(device: &mut LumidoxDevice, stage: u8) -> (u8, Result<StageInfo, String>) {
     let mut stage_info = StageInfo::default();
@@ -931,8 +1728,7 @@ async fn retrieve_stage_info(device: &mut LumidoxDevice, stage: u8) -> (u8, Resu
             stage_info.fire_current_ma = Some(current);
         }
         Err(e) => {
-            let error_msg = format!("Failed to get current for stage {}: {}", stage, e);
-            return (stage, Err(error_msg));
+            return (stage, Err(e.to_string()));
         }
     }
     
@@ -953,16 +1749,74 @@ async fn retrieve_stage_info(device: &mut LumidoxDevice, stage: u8) -> (u8, Resu
     (stage, Ok(stage_info))
 }
 
+/// Find the error shared by most stages whose info could not be read at all
+///
+/// A systemic failure such as a disconnected device makes every stage fail
+/// with the same error; it is shown once as a banner instead of in each box.
+///
+/// # Arguments
+/// * `stage_info` - Stage information keyed by stage number
+///
+/// # Returns
+/// * `Option<&str>` - Error shared by more than half of the stages, if any
+fn common_stage_error(stage_info: &HashMap<u8, StageInfo>) -> Option<&str> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for info in stage_info.values().filter(|info| !info.updating && info.fire_current_ma.is_none()) {
+        if let Some(error) = info.error.as_deref() {
+            *counts.entry(error).or_default() += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .find(|&(_, count)| count * 2 > stage_info.len())
+        .map(|(error, _)| error)
+}
+
+/// Subscription function for Iced 0.13.x API
+///
+/// Emits a `Tick` at the configured interval while any stage has firing
+/// history so the "time since last fire" shown in the stage boxes stays
+/// current, and while a timed operation moves the progress bar.
+fn subscription(state: &AppState) -> iced::Subscription<Message> {
+    match tick_interval(state) {
+        Some(interval) => iced::time::every(interval).map(|_| Message::Tick),
+        None => iced::Subscription::none(),
+    }
+}
+
+/// Get the interval of the GUI tick
+///
+/// # Arguments
+/// * `state` - Application state
+///
+/// # Returns
+/// * `Option<Duration>` - Tick interval, None when the tick is turned off or
+///   nothing on screen depends on elapsed time
+fn tick_interval(state: &AppState) -> Option<Duration> {
+    if state.fire_history.is_empty() && state.connection_lost_at.is_none() && read_progress(&state.progress).is_none() {
+        return None;
+    }
+    state.settings.tick_interval
+}
+
 /// View function for Iced 0.13.x API
 fn view(state: &AppState) -> Element<Message> {
-    use iced::widget::{button, column, container, row, text, text_input, Space};
+    use iced::widget::{button, column, container, pick_list, progress_bar, row, text, text_input, tooltip, Space};
     use iced::{Alignment, Length};
 
-    // Header with title and device info
+    // Header with title and device info, dimmed while it is stale
+    let now = Instant::now();
     let header = column![
         text("Lumidox II Controller").size(24),
         if let Some(ref info) = state.device_info {
-            text(info).size(12)
+            if info.stale {
+                text(format!("{} ({})", info.text, stale_label(state.connection_lost_at, now)))
+                    .size(12)
+                    .color(iced::Color::from_rgb(0.5, 0.5, 0.5))
+            } else {
+                text(&info.text).size(12)
+            }
         } else {
             text("No device connected").size(12)
         }
@@ -971,19 +1825,49 @@ fn view(state: &AppState) -> Element<Message> {
     .align_x(Alignment::Center);
 
     // Connection controls
+    let connect_button: Element<Message> = if state.connection.is_connected() {
+        button("Disconnect").on_press(Message::Disconnect).into()
+    } else if state.connection.state() == ConnectionState::Connecting {
+        button("Connecting...").into()
+    } else if state.operation_config.has_connection_target() {
+        button("Connect").on_press(Message::Connect).into()
+    } else {
+        // Without auto-detection or a port there is nothing to connect to
+        tooltip(
+            button("Connect"),
+            container(text("Select a port first").size(12))
+                .padding(5)
+                .style(container::rounded_box),
+            tooltip::Position::Bottom,
+        )
+        .into()
+    };
+
+    // Port selection, disabled when no port is found
+    let selected_port = state.ports.iter()
+        .find(|port| state.operation_config.port.as_deref() == Some(port.name.as_str()))
+        .cloned();
+    let port_select: Element<Message> = if state.ports.is_empty() {
+        text_input("No serial ports found", "").width(Length::Fixed(220.0)).into()
+    } else {
+        pick_list(state.ports.clone(), selected_port, Message::PortSelected)
+            .placeholder(if state.operation_config.auto_detect { "Auto-detect" } else { "Select a port" })
+            .width(Length::Fixed(220.0))
+            .into()
+    };
+
     let connection_controls = row![
-        if state.connected {
-            button("Disconnect").on_press(Message::Disconnect)
-        } else if state.connecting {
-            button("Connecting...")
-        } else {
-            button("Connect").on_press(Message::Connect)
-        },
+        port_select,
+        button("Refresh Ports").on_press(Message::RefreshPorts),
+        Space::with_width(Length::Fixed(10.0)),
+        connect_button,
         Space::with_width(Length::Fixed(10.0)),
         text(&state.status_message),
         Space::with_width(Length::Fixed(10.0)),
+        text(if state.connection.is_connected() { read_snapshot(&state.dashboard).status_line() } else { String::new() }).size(12),
+        Space::with_width(Length::Fixed(10.0)),
         button("Refresh Stage Info")
-            .on_press_maybe(if state.connected && !state.refreshing_stages { 
+            .on_press_maybe(if state.connection.is_connected() && !state.refreshing_stages { 
                 Some(Message::RefreshStageInfo) 
             } else { 
                 None 
@@ -991,22 +1875,63 @@ fn view(state: &AppState) -> Element<Message> {
     ]
     .align_y(Alignment::Center);
 
-    // Create individual stage boxes
+    // Progress of a timed operation, shown only while one runs
+    let timed_progress = match read_progress(&state.progress) {
+        Some(progress) => column![
+            text(progress.status_line()).size(14),
+            progress_bar(0.0..=1.0, progress.fraction()).height(Length::Fixed(8.0))
+        ]
+        .spacing(5),
+        None => column![],
+    };
+
+    // Emergency off stays enabled while connected, even during other operations
+    let emergency_off = button(
+        text("EMERGENCY OFF").size(28).width(Length::Fill).align_x(Alignment::Center)
+    )
+    .style(button::danger)
+    .padding(20)
+    .width(Length::Fill)
+    .on_press_maybe(if state.connection.is_connected() { Some(Message::EmergencyOff) } else { None });
+
+    // Create individual stage boxes, showing only errors that differ from the shared one
+    let common_error = common_stage_error(&state.stage_info);
     let stage_boxes: Vec<Element<Message>> = (1u8..=5).map(|stage| {
-        create_stage_box(stage, state.stage_info.get(&stage), state.connected)
+        let history = state.fire_history.get(&stage).and_then(|history| history.summary(now));
+        let info = state.stage_info.get(&stage);
+        let error = info
+            .and_then(|info| info.error.as_deref())
+            .filter(|&error| Some(error) != common_error);
+        let stale = info
+            .filter(|info| info.stale)
+            .map(|_| stale_label(state.connection_lost_at, now));
+        create_stage_box(stage, info, history, error, stale, state.active_stage == Some(stage), state.connection.is_connected())
     }).collect();
 
+    // Single banner for a failure shared by most stages
+    let stage_error_banner = if let Some(error) = common_error {
+        column![
+            text(format!("Device not responding — stage info unavailable ({})", error))
+                .size(12)
+                .color(iced::Color::from_rgb(0.9, 0.3, 0.3))
+        ]
+    } else {
+        column![]
+    };
+
     // Arrange stage boxes in a row
     let stages_row = row(stage_boxes)
         .spacing(20)
         .align_y(Alignment::Start);    // Custom current control section
     let current_control_input = row![
-        text("Custom Current (mA):").width(Length::Fixed(140.0)),
-        text_input("500", &state.custom_current)
+        text(format!("Custom Current ({}):", state.current_unit.label())).width(Length::Fixed(140.0)),
+        text_input(if state.current_unit == CurrentInputUnit::Percent { "25" } else { "500" }, &state.custom_current)
             .on_input(Message::CurrentChanged)
             .width(Length::Fixed(100.0)),
+        button(text(format!("Enter in {}", state.current_unit.toggled().label())))
+            .on_press(Message::CurrentUnitToggled),
         button("Fire with Current")
-            .on_press_maybe(if state.connected { Some(Message::FireWithCurrent) } else { None })
+            .on_press_maybe(if state.connection.is_connected() { Some(Message::FireWithCurrent) } else { None })
     ]
     .spacing(10)
     .align_y(Alignment::Center);
@@ -1031,11 +1956,17 @@ fn view(state: &AppState) -> Element<Message> {
     // Device controls
     let device_controls = row![
         button("ARM")
-            .on_press_maybe(if state.connected { Some(Message::ArmDevice) } else { None }),
+            .on_press_maybe(if state.connection.is_connected() { Some(Message::ArmDevice) } else { None }),
         button("Turn Off")
-            .on_press_maybe(if state.connected { Some(Message::TurnOff) } else { None }),
+            .on_press_maybe(if state.connection.is_connected() { Some(Message::TurnOff) } else { None }),
+        button("Reset Defaults")
+            .on_press_maybe(if state.connection.is_connected() { Some(Message::ResetDefaults) } else { None }),
         button("Refresh Status")
-            .on_press_maybe(if state.connected { Some(Message::RefreshStatus) } else { None })
+            .on_press_maybe(if state.connection.is_connected() { Some(Message::RefreshStatus) } else { None }),
+        button("Cancel")
+            .on_press_maybe(if state.connection.is_connected() { Some(Message::CancelOperation) } else { None }),
+        button("Reset Counters")
+            .on_press_maybe(if state.fire_history.is_empty() { None } else { Some(Message::ResetFireCounters) })
     ]
     .spacing(10);    // Error display
     let error_display = if let Some(ref error) = state.error_message {
@@ -1053,9 +1984,13 @@ fn view(state: &AppState) -> Element<Message> {
         header,
         Space::with_height(Length::Fixed(20.0)),
         connection_controls,
+        Space::with_height(Length::Fixed(20.0)),
+        timed_progress,
+        emergency_off,
         Space::with_height(Length::Fixed(30.0)),
         text("Stage Controls").size(18),
         Space::with_height(Length::Fixed(10.0)),
+        stage_error_banner,
         stages_row,
         Space::with_height(Length::Fixed(30.0)),
         text("Custom Current Control").size(18),
@@ -1078,8 +2013,16 @@ fn view(state: &AppState) -> Element<Message> {
         .into()
 }
 
-/// Create a stage box with button and information
-fn create_stage_box(stage: u8, stage_info: Option<&StageInfo>, connected: bool) -> Element<Message> {
+/// Create a stage box with button, firing history, and information
+fn create_stage_box<'a>(
+    stage: u8,
+    stage_info: Option<&StageInfo>,
+    fire_history: Option<String>,
+    error: Option<&'a str>,
+    stale: Option<String>,
+    active: bool,
+    connected: bool,
+) -> Element<'a, Message> {
     use iced::widget::{button, column, container, text, Space};
     use iced::{Alignment, Length, Border};    // Stage button
     let stage_button = button(text(format!("Stage {}", stage)))
@@ -1094,10 +2037,19 @@ fn create_stage_box(stage: u8, stage_info: Option<&StageInfo>, connected: bool)
             ]
             .spacing(2)
             .align_x(Alignment::Center)
-        } else if let Some(ref _error) = info.error {
+        } else if info.error.is_some() {
             // Show error but also show available info
             let mut info_column = column![];
             
+            // Show the error unless it is already shown in the shared banner
+            if let Some(error) = error {
+                info_column = info_column.push(
+                    text(error)
+                        .size(9)
+                        .color(iced::Color::from_rgb(0.9, 0.3, 0.3))
+                );
+            }
+            
             // Show current if available
             if let Some(current) = info.fire_current_ma {
                 info_column = info_column.push(text(format!("{}mA", current)).size(12));
@@ -1207,10 +2159,24 @@ fn create_stage_box(stage: u8, stage_info: Option<&StageInfo>, connected: bool)
         .align_x(Alignment::Center)
     };
 
+    // Session firing history
+    let fire_history_display = text(fire_history.unwrap_or_else(|| "not fired".to_string()))
+        .size(10)
+        .color(iced::Color::from_rgb(0.6, 0.6, 0.6));
+
+    // Last-known values from before a lost connection
+    let dimmed = stale.is_some();
+    let stale_display = match stale {
+        Some(label) => column![text(label).size(9).color(iced::Color::from_rgb(0.6, 0.6, 0.6))],
+        None => column![],
+    };
+
     // Combine button and info in a box
     let stage_content = column![
         stage_button,
+        fire_history_display,
         Space::with_height(Length::Fixed(10.0)),
+        stale_display,
         stage_info_display
     ]
     .spacing(5)
@@ -1223,12 +2189,14 @@ fn create_stage_box(stage: u8, stage_info: Option<&StageInfo>, connected: bool)
         .style(move |_theme: &iced::Theme| {
             container::Style {
                 border: Border {
-                    color: if connected { 
+                    color: if active {
+                        iced::Color::from_rgb(0.2, 0.8, 0.4)
+                    } else if connected { 
                         iced::Color::from_rgb(0.4, 0.4, 0.4) 
                     } else { 
                         iced::Color::from_rgb(0.2, 0.2, 0.2) 
                     },
-                    width: 1.0,
+                    width: if active { 2.0 } else { 1.0 },
                     radius: 8.0.into(),
                 },
                 background: Some(iced::Background::Color(
@@ -1238,7 +2206,7 @@ fn create_stage_box(stage: u8, stage_info: Option<&StageInfo>, connected: bool)
                         iced::Color::from_rgba(0.05, 0.05, 0.05, 0.1) 
                     }
                 )),
-                text_color: None,
+                text_color: if dimmed { Some(iced::Color::from_rgb(0.5, 0.5, 0.5)) } else { None },
                 shadow: iced::Shadow {
                     color: iced::Color::from_rgba(0.0, 0.0, 0.0, 0.3),
                     offset: iced::Vector::new(2.0, 2.0),
@@ -1257,9 +2225,13 @@ fn create_custom_current_info_box(custom_current_info: &CustomCurrentInfo) -> El
     let content = if custom_current_info.has_estimate {
         let mut info_column = column![];
         
-        // Show current with mA
+        // Show current in mA and as a percentage of the device maximum
+        let current_label = match custom_current_info.percent_of_max {
+            Some(percent) => format!("{}mA ({:.1}%)", custom_current_info.current_ma, percent),
+            None => format!("{}mA", custom_current_info.current_ma),
+        };
         info_column = info_column.push(
-            text(format!("{}mA", custom_current_info.current_ma))
+            text(current_label)
                 .size(12)
                 .color(iced::Color::from_rgb(0.9, 0.9, 0.9))
         );        // Show estimated total power
@@ -1364,3 +2336,173 @@ fn create_custom_current_info_box(custom_current_info: &CustomCurrentInfo) -> El
 fn theme(_state: &AppState) -> Theme {
     Theme::Dark
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fire_history_summary() {
+        let start = Instant::now();
+        let mut history = StageFireHistory::default();
+        assert_eq!(history.summary(start), None);
+
+        history.record_fire(start);
+        history.record_fire(start);
+        history.record_fire(start);
+        assert_eq!(history.fire_count, 3);
+        assert_eq!(history.summary(start + Duration::from_secs(12)).unwrap(), "fired 3× · 12s ago");
+    }
+
+    #[test]
+    fn test_tick_interval_follows_settings() {
+        let mut state = AppState::default();
+        assert_eq!(tick_interval(&state), None);
+
+        state.connection_lost_at = Some(Instant::now());
+        assert_eq!(tick_interval(&state), Some(DEFAULT_TICK_INTERVAL));
+
+        state.settings.tick_interval = None;
+        assert_eq!(tick_interval(&state), None);
+    }
+
+    #[test]
+    fn test_selecting_a_port_replaces_auto_detection() {
+        let mut state = AppState {
+            operation_config: OperationConfig::builder().auto_detect(true).build(),
+            ..AppState::default()
+        };
+        let port = PortChoice { name: "COM7".to_string(), product: Some("Lumidox II".to_string()) };
+        assert_eq!(port.to_string(), "COM7 - Lumidox II");
+
+        let _ = update(&mut state, Message::PortSelected(port));
+        assert_eq!(state.operation_config.port.as_deref(), Some("COM7"));
+        assert!(!state.operation_config.auto_detect);
+        assert!(state.operation_config.has_connection_target());
+
+        let _ = update(&mut state, Message::PortsListed(Err("access denied".to_string())));
+        assert!(state.ports.is_empty());
+        assert!(state.error_message.unwrap().contains("access denied"));
+    }
+
+    #[test]
+    fn test_gui_progress_reporter_updates_shared_progress() {
+        let state = AppState::default();
+        let mut reporter = state.progress_reporter();
+        let total = Duration::from_secs(5);
+
+        reporter.start("Stage 3", total);
+        reporter.tick(Duration::from_millis(1500), total);
+        let progress = read_progress(&state.progress).unwrap();
+        assert_eq!(progress.fraction(), 0.3);
+        assert_eq!(progress.status_line(), "Stage 3: 4s remaining");
+        assert_eq!(tick_interval(&state), Some(DEFAULT_TICK_INTERVAL));
+
+        reporter.finish();
+        assert_eq!(read_progress(&state.progress), None);
+        assert_eq!(tick_interval(&state), None);
+    }
+
+    #[test]
+    fn test_format_elapsed_units() {
+        assert_eq!(format_elapsed(Duration::from_secs(59)), "59s");
+        assert_eq!(format_elapsed(Duration::from_secs(125)), "2m");
+        assert_eq!(format_elapsed(Duration::from_secs(7300)), "2h");
+    }
+
+    #[test]
+    fn test_common_stage_error_collapses_shared_failures() {
+        let failed = |error: &str| StageInfo { error: Some(error.to_string()), ..StageInfo::default() };
+        let mut stage_info: HashMap<u8, StageInfo> = (1u8..=5).map(|stage| (stage, failed("Device not connected"))).collect();
+        assert_eq!(common_stage_error(&stage_info), Some("Device not connected"));
+
+        stage_info.insert(1, failed("Timed out"));
+        stage_info.insert(2, failed("Checksum mismatch"));
+        stage_info.insert(3, StageInfo { fire_current_ma: Some(500), ..StageInfo::default() });
+        assert_eq!(common_stage_error(&stage_info), None);
+    }
+
+    #[test]
+    fn test_resolve_custom_current_units() {
+        assert_eq!(resolve_custom_current("1500", CurrentInputUnit::Milliamps, None), Ok(1500));
+        assert_eq!(resolve_custom_current("25", CurrentInputUnit::Percent, Some(4000)), Ok(1000));
+        assert_eq!(resolve_custom_current("12.5", CurrentInputUnit::Percent, Some(4000)), Ok(500));
+        assert!(resolve_custom_current("25", CurrentInputUnit::Percent, None).is_err());
+        assert!(resolve_custom_current("150", CurrentInputUnit::Percent, Some(4000)).is_err());
+        assert!(resolve_custom_current("5000", CurrentInputUnit::Milliamps, Some(4000)).is_err());
+        assert!(resolve_custom_current("0", CurrentInputUnit::Milliamps, Some(4000)).is_err());
+    }
+
+    #[test]
+    fn test_dashboard_snapshot_status_line() {
+        let dashboard = SharedDashboard::default();
+        assert_eq!(read_snapshot(&dashboard).status_line(), "Mode: Unknown");
+
+        *dashboard.write().unwrap() = DashboardSnapshot {
+            mode: Some(DeviceMode::Armed),
+            device_summary: None,
+            last_operation: Some("Arm".to_string()),
+        };
+        assert_eq!(read_snapshot(&dashboard).status_line(), "Mode: Armed (last: Arm)");
+    }
+
+    #[test]
+    fn test_lost_connection_keeps_last_known_data_as_stale() {
+        let mut state = AppState::default();
+        state.connection = ConnectionStateMachine::connected();
+        state.device_info = Some(DeviceSummary { text: "Model: LX2".to_string(), stale: false });
+        state.stage_info.insert(2, StageInfo { fire_current_ma: Some(1500), ..StageInfo::default() });
+
+        let lost_at = Instant::now();
+        mark_connection_lost(&mut state, lost_at);
+        assert_eq!(state.connection.state(), ConnectionState::Failed);
+        assert!(state.device_info.as_ref().is_some_and(|info| info.stale && info.text == "Model: LX2"));
+        assert_eq!(state.stage_info[&2].fire_current_ma, Some(1500));
+        assert!(state.stage_info[&2].stale);
+        assert_eq!(stale_label(Some(lost_at), lost_at + Duration::from_secs(12)), "stale · last updated 12s ago");
+
+        let _ = update(&mut state, Message::Disconnect);
+        assert!(state.device_info.is_none());
+        assert_eq!(state.stage_info[&2].fire_current_ma, None);
+        assert!(!state.stage_info[&2].stale);
+    }
+
+    #[test]
+    fn test_disconnect_cancels_pending_connect() {
+        let mut state = AppState::default();
+        state.operation_config.port = Some("COM3".to_string());
+
+        let _ = update(&mut state, Message::Connect);
+        let attempt = state.connect_attempts.0.load(Ordering::SeqCst);
+        assert_eq!(state.connection.state(), ConnectionState::Connecting);
+
+        // A second click while connecting starts no new attempt
+        let _ = update(&mut state, Message::Connect);
+        assert!(state.connect_attempts.is_current(attempt));
+
+        let _ = update(&mut state, Message::Disconnect);
+        assert!(!state.connect_attempts.is_current(attempt));
+
+        // The cancelled connect finishing late is ignored
+        let _ = update(&mut state, Message::ConnectionSuccess(attempt, "Model: LX2".to_string(), None, None));
+        assert_eq!(state.connection.state(), ConnectionState::Disconnected);
+        assert!(state.device_info.is_none());
+
+        // So is a superseded attempt failing after a newer one started
+        let _ = update(&mut state, Message::Connect);
+        let _ = update(&mut state, Message::ConnectionFailed(attempt, "Error: port busy".to_string()));
+        assert_eq!(state.connection.state(), ConnectionState::Connecting);
+        assert!(state.error_message.is_none());
+    }
+
+    #[test]
+    fn test_emergency_epoch_drops_tasks_dispatched_before_stop() {
+        let emergency = EmergencyEpoch::default();
+        let queued = emergency.current();
+        assert!(emergency.check(queued).is_ok());
+
+        emergency.clone().trigger();
+        assert!(matches!(emergency.check(queued), Err(LumidoxError::OperationCancelled(_))));
+        assert!(emergency.check(emergency.current()).is_ok());
+    }
+}
@@ -58,7 +58,7 @@ pub use gui::run_gui;
 // Re-export commonly used items for convenience
 pub use cli::{Cli, Commands,
               run_interactive_mode_with_optimization, run_command_mode_with_optimization,
-              list_serial_ports};
+              run_command_mode_with_format, list_serial_ports};
 
 // Re-export GUI functionality for dual-mode integration
 // (Already re-exported above based on feature flags)